@@ -1,4 +1,6 @@
 from .icedpygui import IPG
+from .icedpygui import IpgBatch
+from .icedpygui import IpgEvent
 from .icedpygui import IpgAlignment, IpgHorizontalAlignment, IpgVerticalAlignment 
 from .icedpygui import IpgButtonArrow, IpgButtonParam, IpgButtonStyleParam
 from .icedpygui import IpgDrawMode, IpgCanvasParam, IpgCanvasWidget
@@ -1,17 +1,98 @@
 from dataclasses import dataclass
-from typing import Any, Callable, List, Optional, Union, DataFrame, PyDataFrame
+from typing import Any, Callable, Coroutine, List, Optional, Union, DataFrame, PyDataFrame
+
+class IpgError(Exception):
+    """Base class of the IPG errors."""
+
+class IpgIdError(IpgError):
+    """A widget, container or window id was not found."""
+
+class IpgParamError(IpgError):
+    """A parameter or its value is not valid."""
+
+class IpgCallbackError(IpgError):
+    """A callback raised an error or has the wrong parameters, 
+    the original error is the __cause__."""
+
+class IpgBatch:
+    """The context manager returned by IPG.batch()."""
+    def __enter__(self) -> "IpgBatch": ...
+    def __exit__(self, exc_type, exc_value, traceback) -> bool: ...
+
+
+class IpgEvent:
+    """
+    The single arg of every callback when IPG(event_objects=True) is used.
+
+    Attributes
+    ----------
+    widget_id: int
+        The id of the widget, container, window or event that called back.
+    kind: str
+        The name of the event, like "on_press", "on_change" or "move".
+    window_id: Optional[str]
+        The window of the widget, None when it is not known.
+    value: Any
+        What the callback would have been given after the id, None when nothing,
+        a tuple when several values, like the index and the value of a divider.
+    position: Optional[tuple[float, float]]
+        The mouse position of a move event.
+    modifiers: list[str]
+        The keyboard modifiers held, "Shift", "Control", "Alt" and "Logo".
+    timestamp: float
+        The time of the callback in seconds since the epoch.
+    user_data: Any
+        The user_data of the widget or None.
+    """
+    widget_id: int
+    kind: str
+    window_id: Optional[str]
+    value: Any
+    position: Optional[tuple[float, float]]
+    modifiers: list[str]
+    timestamp: float
+    user_data: Any
+
 
 class IPG:
     """
     Main class that is instantiated and that calls the corresponding rust file to implement the widgets
     """
-    def __init__(self) -> None:
-        ...
+    def __init__(self, event_objects: bool=False, window_state_file: Optional[str]=None) -> None:
+        """
+        Parameters
+        ----------
+        event_objects: bool
+            When True every callback is called with a single IpgEvent
+            in place of its own args and the user_data.
+        window_state_file: Optional[str]
+            The path of a json file where the position, size and mode of each
+            window are saved, by window_id, when the window is closed.
+            The next time the window is added, they replace the ones given,
+            the saved size is kept within the min and max sizes.
+            The saved position isn't checked against the monitors, a window
+            saved on a monitor that's no longer connected may open off screen,
+            removing its entry from the file resets it.
+        """
 
-    def start_session(self) -> None:
+    def start_session(self, on_error: Optional[Callable]=None) -> None:
         """
         Starts the gui session.  Must be the last called.
 
+        Errors found while the gui is running, like a wrong update_item value,
+        an unknown id or a callback raising an exception, do not end the session.
+        They are passed to on_error(error), where error is an IpgIdError,
+        IpgParamError or IpgCallbackError, or printed when on_error is None.
+
+        The GIL is released while the gui is running so that other
+        python threads can run.  The following calls are safe from any
+        thread and wake the gui to process them right away:
+        update_item, update_canvas_item, update_dataframe, move_widget,
+        delete_item and show_items.  Window updates go through update_item
+        and are safe as well.  append_samples is safe from any thread too,
+        the samples are drawn on the next canvas timer tick.  All other calls, like adding widgets,
+        should be made from the main thread or from a callback.
+
         Returns
         -------
         None
@@ -47,6 +128,7 @@ class IPG:
                     debug: bool=False,
                     exit_on_close: bool=True,
                     mode: IpgWindowMode=IpgWindowMode.Windowed,
+                    icon: Optional[Union[bytes, str]]=None,
                     gen_id: Optional[int]=None,
                     ) -> int:
         """
@@ -92,6 +174,8 @@ class IPG:
                 Sets the window mode, Windowed(default), FullScreen, Hidden.
             debug: bool
                 If set, draws a box around widgets to see the layout.
+            icon: Optional[Union[bytes, str]]
+                The window icon, the bytes of an image file, like a png, or its path.
             gen_id: int
                 The only allowable entry for this id is that generated by ipg.generate_id().
         
@@ -115,10 +199,40 @@ class IPG:
                     border_ipg_color: Optional[IpgColor]=None,
                     background_rgba_color: Optional[list[float, 4]]=None,
                     background_ipg_color: Optional[IpgColor]=None,
+                    on_pinch: Optional[Callable]=None,
+                    on_rotate: Optional[Callable]=None,
+                    on_pan: Optional[Callable]=None,
+                    on_swipe: Optional[Callable]=None,
+                    on_long_press: Optional[Callable]=None,
+                    long_press_ms: int=500,
+                    user_data: Optional[Any]=None,
+                    show: bool=True,
                     gen_id: Optional[int]=None,
                     )  -> int:
         """
         Add a canvas for drawing.
+        The touch gestures made on the canvas call back with the canvas id and
+        the same dict as the gestures of add_event_touch, the x and y are in the canvas.
+
+        Parameters
+        ----------
+            on_pinch: Callable
+                Calls a function as two fingers spread or close, the dict has the scale.
+            on_rotate: Callable
+                Calls a function as two fingers turn, the dict has the degrees.
+            on_pan: Callable
+                Calls a function as the fingers move, the dict has the dx, dy and fingers.
+            on_swipe: Callable
+                Calls a function when a finger is lifted after a quick stroke,
+                the dict has the direction and speed.
+            on_long_press: Callable
+                Calls a function when a finger is held still, the dict has the finger.
+            long_press_ms: int
+                How long a finger is held for a long press.
+            user_data: Any
+                Any data that might be needed in the callback functions.
+            show: bool
+                Shows or hides widget.
         """ 
 
     def add_column(self,
@@ -573,6 +687,7 @@ class IPG:
                         on_scroll: Optional[Callable]=None,
                         style_id: Optional[str]=None,
                         user_data: Optional[any]=None,
+                        show: bool=True,
                         ) -> int:
         """
         Wraps a scrollable widget around a container.
@@ -728,6 +843,7 @@ class IPG:
                         padding: float=0.0,
                         gap: int=10,
                         style_id: str,
+                        show: bool=True,
                      ) -> int:
     
         """
@@ -751,6 +867,8 @@ class IPG:
                 Sets the distance away from the widget.
             style: str
                 Sets the style of the tooltip.
+            show: bool
+                Shows or hides the tooltip and its widget.
 
         Returns
         -------
@@ -822,8 +940,10 @@ class IPG:
                     style_id: Optional[int]=None,
                     style_standard: Optional[IpgStyleStandard]=None,
                     style_arrow: Optional[IpgButtonArrow]=None,
+                    accelerator: Optional[str]=None,
                     user_data: Optional[any]=None,
                     show: bool=True, 
+                    enabled: bool=True,
                     ) -> int:
         """
         Adds a button to the gui
@@ -865,10 +985,16 @@ class IPG:
                 IpgStyleStandard: Primary, Success, Danger, Text
             style_arrow: IpgButtonArrows
                 See dropdown list when IpgButtonArrow. is typed in when period is typed.
+            accelerator: Optional[str]
+                The shortcut shown after the label, as in a menu item, "Mod+S" shows Ctrl+S or Cmd+S.
+                Only the text is shown, the shortcut is added with add_shortcut.
+                Only a button menu item shows its accelerator.
             user_data: any 
                 Any data in any form needed by user to be passed through as a callback. 
             show: bool
                 Shows or hides widget.
+            enabled: bool
+                Disabled widgets are drawn faded and do not call back.
             
         Returns
         -------
@@ -1077,6 +1203,77 @@ class IPG:
         """
     
     
+    def add_chart(self,
+                    parent_id: str,
+                    polars_df: PyDataFrame,
+                    x_column: str,
+                    y_columns: list[str],
+                    *,
+                    kind: IpgChartKind=IpgChartKind.Line,
+                    series_kinds: Optional[list[IpgChartKind]]=None,
+                    series_rgba_colors: Optional[list[list[float]]]=None,
+                    title: Optional[str]=None,
+                    x_label: Optional[str]=None,
+                    y_label: Optional[str]=None,
+                    show_legend: bool=True,
+                    text_size: float=12.0,
+                    width: Optional[float]=None,
+                    width_fill: bool=True,
+                    height: Optional[float]=300.0,
+                    height_fill: bool=False,
+                    gen_id: Optional[int]=None,
+                    show: bool=True,
+                    ) -> int:
+        """
+        Adds a chart drawn from the columns of a polars DataFrame.
+        The mouse wheel zooms the x axis, a left drag pans and a right click resets.
+        Hovering near a point shows its values.
+
+        Parameters
+        ----------
+            parent_id: str
+                id of another container or window.
+            polars_df: PyDataFrame
+                The DataFrame holding the data, use update_dataframe to replace it.
+            x_column: str
+                The column used for the x axis, a string column is treated as categories.
+            y_columns: list[str]
+                The columns plotted as series, each must be numeric.
+            kind: IpgChartKind
+                The kind of series used when series_kinds is not given.
+            series_kinds: list[IpgChartKind]
+                A kind for each series, allows mixing bars with lines.
+            series_rgba_colors: list[list[float]]
+                A color for each series, a default palette is used otherwise.
+            title: str
+                The title above the chart.
+            x_label: str
+                The label below the x axis.
+            y_label: str
+                The label above the y axis, written horizontally.
+            show_legend: bool
+                Whether to show the legend.
+            text_size: float
+                The size of the text, ticks are drawn slightly smaller.
+            width: float
+                Sets the width of the widget.
+            width_fill: bool
+                Sets the width to fill the available space, overrides width.
+            height: float
+                Sets the height of the widget.
+            height_fill: bool
+                Sets the height to fill the available space, overrides height.
+            gen_id: int
+                The only allowable entry for this id is the one generated by ipg.generate_id().
+            show: bool
+                Shows or hides widget.
+
+        Returns
+        -------
+        id: int
+            Internal id of widget and can be used by user if equated.
+        """
+
     def add_checkbox(self,
                     parent_id: str,
                     *,
@@ -1097,6 +1294,7 @@ class IPG:
                     style_standard: Optional[IpgStyleStandard]=None,
                     user_data: Optional[Any]=None,
                     show: bool=True,
+                    enabled: bool=True,
                     ) -> int:
         """
         Adds a checkbox to the gui.
@@ -1141,6 +1339,8 @@ class IPG:
                 Any data in any form needed by user to be passed through as a callback. 
             show: bool
                 Shows or hides widget.
+            enabled: bool
+                Disabled widgets are drawn faded and do not call back.
            
         Returns
         -------
@@ -1160,7 +1360,13 @@ class IPG:
                             border_radius: list=[0.0], 
                             border_width: float=1.0,
                             text_color: Optional[IpgColor]=None, 
-                            text_rgba: Optional[list]=None
+                            text_rgba: Optional[list]=None,
+                            background_color_disabled: Optional[IpgColor]=None,
+                            background_rgba_disabled: Optional[list[float, 4]]=None,
+                            icon_color_disabled: Optional[IpgColor]=None,
+                            icon_rgba_disabled: Optional[list[float, 4]]=None,
+                            text_color_disabled: Optional[IpgColor]=None,
+                            text_rgba_disabled: Optional[list[float, 4]]=None,
                         ) -> int:
         """
         Adds styling to container
@@ -1192,6 +1398,19 @@ class IPG:
                 The text color, if not defined, will either be a Black or White variation based on theme background.
             text_rgba: Optional[list[float, 4]]=None
                 The text color in rgba format.
+            background_color_disabled: Optional[IpgColor]=None
+                The background color of the box when the checkbox is disabled,
+                if not defined, the background is faded.
+            background_rgba_disabled: Optional[list[float, 4]]=None
+                The disabled background color in rgba format.
+            icon_color_disabled: Optional[IpgColor]=None
+                The icon color when disabled, if not defined, the icon is faded.
+            icon_rgba_disabled: Optional[list[float, 4]]=None
+                The disabled icon color in rgba format.
+            text_color_disabled: Optional[IpgColor]=None
+                The text color when disabled, if not defined, the text is faded.
+            text_rgba_disabled: Optional[list[float, 4]]=None
+                The disabled text color in rgba format.
         """
 
     def add_color_picker(
@@ -1214,6 +1433,7 @@ class IPG:
                         style_arrow: Optional[IpgButtonArrow]=None,
                         user_data: Optional[Any]=None,
                         show: bool=True, 
+                        enabled: bool=True,
                         ) -> int:
         """
         Adds a color picker.  The args for style and such are for the activation button.
@@ -1256,6 +1476,8 @@ class IPG:
                 Sets the style of the button to an arrow.
             show: bool
                 To show the widget or not.
+            enabled: bool
+                Disabled widgets are drawn faded and do not call back.
             user_data: any
                 Any data that might be needed in the callback function.
             
@@ -1330,6 +1552,7 @@ class IPG:
                         on_submit: Optional[Callable]=None,
                         user_data: Optional[Any]=None,
                         show=False,
+                        enabled: bool=True,
                         button_style_standard,
                         )  -> int:
         
@@ -1355,6 +1578,8 @@ class IPG:
                 Callback function selected date is submitted.
             show: bool
                 To show the widget or not.
+            enabled: bool
+                Disabled widgets are drawn faded and do not call back.
             user_data: any
                 Any data that might be needed in the callback function.
             button_style_standard: IpgStyle_standard
@@ -1488,6 +1713,152 @@ class IPG:
                 Internal id of widget and can be used by user if equated.
         """
     
+    def add_gauge(self,
+                    parent_id: str,
+                    *,
+                    min: float=0.0,
+                    max: float=100.0,
+                    value: float=0.0,
+                    bands: Optional[list[tuple[float, float, list[float]]]]=None,
+                    tick_count: int=11,
+                    minor_ticks: int=4,
+                    label: Optional[str]=None,
+                    units: str="",
+                    precision: int=0,
+                    needle_rgba_color: Optional[list[float]]=None,
+                    text_size: float=14.0,
+                    size: float=200.0,
+                    animation_ms: int=300,
+                    gen_id: Optional[int]=None,
+                    show: bool=True,
+                    ) -> int:
+        """
+        Adds a radial gauge with a needle, the needle moves smoothly to a new value.
+
+        Parameters
+        ----------
+            parent_id: str
+                id of another container or window.
+            min: float
+                The value at the start of the scale.
+            max: float
+                The value at the end of the scale.
+            value: float
+                The value the needle points to.
+            bands: list[tuple[float, float, list[float]]]
+                Colored threshold bands as (from, to, rgba).
+            tick_count: int
+                The number of labeled ticks including the ends.
+            minor_ticks: int
+                The number of ticks between the labeled ticks.
+            label: str
+                A label below the value.
+            units: str
+                Added after the value.
+            precision: int
+                The decimal places of the value.
+            needle_rgba_color: list[float]
+                The needle color, the theme primary color if None.
+            text_size: float
+                The size of the value text.
+            size: float
+                The width and height of the gauge.
+            animation_ms: int
+                The time the needle takes to reach a new value, 0 jumps to it.
+            gen_id: int
+                The only allowable entry for this id is the one generated by ipg.generate_id().
+            show: bool
+                Shows or hides widget.
+
+        Returns
+        -------
+        id: int
+            Internal id of widget and can be used by user if equated.
+        """
+
+    def add_heatmap(self,
+                    parent_id: str,
+                    *,
+                    polars_df: Optional[PyDataFrame]=None,
+                    values: Optional[list[list[float]]]=None,
+                    row_labels: Optional[list[str]]=None,
+                    column_labels: Optional[list[str]]=None,
+                    row_label_column: Optional[str]=None,
+                    colormap: IpgColorMap=IpgColorMap.Viridis,
+                    vmin: Optional[float]=None,
+                    vmax: Optional[float]=None,
+                    center: Optional[float]=None,
+                    show_values: bool=False,
+                    precision: int=2,
+                    show_colorbar: bool=True,
+                    text_size: float=12.0,
+                    on_hover: Optional[Callable]=None,
+                    user_data: Optional[Any]=None,
+                    width: Optional[float]=None,
+                    width_fill: bool=True,
+                    height: Optional[float]=300.0,
+                    height_fill: bool=False,
+                    gen_id: Optional[int]=None,
+                    show: bool=True,
+                    ) -> int:
+        """
+        Adds a heatmap drawing a grid of values with a colormap.
+        The values come from either a polars DataFrame or a list of rows.
+
+        Parameters
+        ----------
+            parent_id: str
+                id of another container or window.
+            polars_df: PyDataFrame
+                Each numeric column is a column of the grid, the column names are the labels.
+                Use update_dataframe with IpgHeatmapParam.PolarsDf to replace it.
+            values: list[list[float]]
+                The rows of the grid when no DataFrame is used, None leaves a cell blank.
+            row_labels: list[str]
+                The labels left of the rows.
+            column_labels: list[str]
+                The labels above the columns.
+            row_label_column: str
+                A DataFrame column used for the row labels rather than as data.
+            colormap: IpgColorMap
+                The colormap, diverging maps are centered on 0 when the values cross it.
+            vmin: float
+                The value at the low end of the colormap, the minimum value if None.
+            vmax: float
+                The value at the high end of the colormap, the maximum value if None.
+            center: float
+                The value at the middle of the colormap, used with diverging maps.
+            show_values: bool
+                Whether to write the values in the cells.
+            precision: int
+                The decimal places of the values in the cells.
+            show_colorbar: bool
+                Whether to show the colorbar legend.
+            text_size: float
+                The size of the labels.
+            on_hover: Callable
+                Called when the mouse moves to another cell with (id, (row, column, value)).
+            user_data: any
+                Any data in any form needed by user to be passed through as a callback.
+            width: float
+                Sets the width of the widget.
+            width_fill: bool
+                Sets the width to fill the available space, overrides width.
+            height: float
+                Sets the height of the widget.
+            height_fill: bool
+                Sets the height to fill the available space, overrides height.
+            gen_id: int
+                The only allowable entry for this id is the one generated by ipg.generate_id().
+            show: bool
+                Shows or hides widget.
+
+        Returns
+        -------
+        id: int
+            Internal id of widget and can be used by user if equated.
+        """
+
     def add_image(self, 
                     parent_id: str, 
                     image_path: str,
@@ -1582,6 +1953,151 @@ class IPG:
             Internal id of widget and can be used by user if equated.
         """
 
+    def add_knob(self,
+                    parent_id: str,
+                    *,
+                    min: float=0.0,
+                    max: float=100.0,
+                    step: float=1.0,
+                    value: float=0.0,
+                    on_change: Optional[Callable]=None,
+                    on_release: Optional[Callable]=None,
+                    bands: Optional[list[tuple[float, float, list[float]]]]=None,
+                    tick_count: int=11,
+                    label: Optional[str]=None,
+                    units: str="",
+                    precision: int=0,
+                    knob_rgba_color: Optional[list[float]]=None,
+                    text_size: float=14.0,
+                    size: float=100.0,
+                    user_data: Optional[Any]=None,
+                    gen_id: Optional[int]=None,
+                    show: bool=True,
+                    ) -> int:
+        """
+        Adds a rotary knob, dragging up or scrolling increases the value.
+        The callbacks are the same as the slider, (id, value) or (id, value, user_data).
+
+        Parameters
+        ----------
+            parent_id: str
+                id of another container or window.
+            min: float
+                The minimum value.
+            max: float
+                The maximum value.
+            step: float
+                The value is rounded to a multiple of the step, 0 for no rounding.
+            value: float
+                The starting value.
+            on_change: Callable
+                The function called while the value changes.
+            on_release: Callable
+                The function called when the mouse button is released.
+            bands: list[tuple[float, float, list[float]]]
+                Colored threshold bands as (from, to, rgba).
+            tick_count: int
+                The number of ticks around the knob including the ends.
+            label: str
+                A label drawn on the knob.
+            units: str
+                Added after the value.
+            precision: int
+                The decimal places of the value.
+            knob_rgba_color: list[float]
+                The color of the knob body.
+            text_size: float
+                The size of the value text.
+            size: float
+                The width and height of the knob.
+            user_data: any
+                Any data in any form needed by user to be passed through as a callback.
+            gen_id: int
+                The only allowable entry for this id is the one generated by ipg.generate_id().
+            show: bool
+                Shows or hides widget.
+
+        Returns
+        -------
+        id: int
+            Internal id of widget and can be used by user if equated.
+        """
+
+    def add_meter(self,
+                    parent_id: str,
+                    *,
+                    min: float=0.0,
+                    max: float=100.0,
+                    value: float=0.0,
+                    bands: Optional[list[tuple[float, float, list[float]]]]=None,
+                    tick_count: int=6,
+                    minor_ticks: int=1,
+                    label: Optional[str]=None,
+                    units: str="",
+                    precision: int=0,
+                    bar_rgba_color: Optional[list[float]]=None,
+                    text_size: float=14.0,
+                    vertical: bool=False,
+                    width: Optional[float]=None,
+                    width_fill: bool=True,
+                    height: Optional[float]=60.0,
+                    height_fill: bool=False,
+                    animation_ms: int=300,
+                    gen_id: Optional[int]=None,
+                    show: bool=True,
+                    ) -> int:
+        """
+        Adds a linear meter, the bar takes the color of the band the value is in.
+
+        Parameters
+        ----------
+            parent_id: str
+                id of another container or window.
+            min: float
+                The value at the start of the scale.
+            max: float
+                The value at the end of the scale.
+            value: float
+                The value shown by the bar.
+            bands: list[tuple[float, float, list[float]]]
+                Colored threshold bands as (from, to, rgba).
+            tick_count: int
+                The number of labeled ticks including the ends.
+            minor_ticks: int
+                The number of ticks between the labeled ticks.
+            label: str
+                A label shown with the value.
+            units: str
+                Added after the value.
+            precision: int
+                The decimal places of the value.
+            bar_rgba_color: list[float]
+                The bar color outside of any band, the theme primary color if None.
+            text_size: float
+                The size of the text.
+            vertical: bool
+                Whether the bar fills upwards rather than to the right.
+            width: float
+                Sets the width of the widget.
+            width_fill: bool
+                Sets the width to fill the available space, overrides width.
+            height: float
+                Sets the height of the widget.
+            height_fill: bool
+                Sets the height to fill the available space, overrides height.
+            animation_ms: int
+                The time the bar takes to reach a new value, 0 jumps to it.
+            gen_id: int
+                The only allowable entry for this id is the one generated by ipg.generate_id().
+            show: bool
+                Shows or hides widget.
+
+        Returns
+        -------
+        id: int
+            Internal id of widget and can be used by user if equated.
+        """
+
     def add_menu(self,
                  window_id: str,
                  container_id: str, 
@@ -1632,6 +2148,7 @@ class IPG:
                 menu_style id.
             on_select: Callable
                 The callback for when the menu item is selected.
+                A button menu item can show a shortcut with add_button(accelerator=...).
             gen_id: int
                 The only allowable entry for this id is that generated by ipg.generate_id().
             user_data: any
@@ -1843,6 +2360,7 @@ class IPG:
                         style_id: Optional[int]=None,
                         user_data: Optional[any]=None,
                         show: bool=True,
+                        enabled: bool=True,
                       ) -> int:
         """
         Adds a pick list to the gui.
@@ -1892,6 +2410,8 @@ class IPG:
                 Any data in any form needed by user to be passed through as a callback.
             show: bool
                 Shows or hides the widget.
+            enabled: bool
+                Disabled widgets are drawn faded and do not call back.
     
         Returns
         -------
@@ -1914,6 +2434,10 @@ class IPG:
                             border_rgba_hovered: Optional[list[float, 4]]=None,
                             border_radius: Optional[list[float]]=None,
                             border_width: Optional[float]=None,
+                            background_color_disabled: Optional[IpgColor]=None,
+                            background_rgba_disabled: Optional[list[float, 4]]=None,
+                            text_color_disabled: Optional[IpgColor]=None,
+                            text_rgba_disabled: Optional[list[float, 4]]=None,
                             gen_id: Optional[int]=None,
                             ) -> int:
         """
@@ -1949,6 +2473,15 @@ class IPG:
                 Radius of the corners [one value]=all corners, [4 values]=[top-left, top_right, , bottom-right, bottom-left]
             border_width: Optional[float]=None,
                 Width of the border.
+            background_color_disabled: Optional[IpgColor]=None,
+                Color of the background when the pick list is disabled,
+                if not defined, the background is faded.
+            background_rgba_disabled: Optional[list[float, 4]]=None,
+                Color of the background in rgba format when disabled.
+            text_color_disabled: Optional[IpgColor]=None,
+                Color of the text when disabled, if not defined, the text is faded.
+            text_rgba_disabled: Optional[list[float, 4]]=None,
+                Color of the text in rgba format when disabled.
             gen_id: Optional[int]=None,
                 The only allowable entry for this id is that generated by ipg.generate_id().
         """
@@ -2060,6 +2593,7 @@ class IPG:
                     text_shaping: str="basic",
                     user_data: Union[None, any]=None,
                     show: bool=True,
+                    enabled: bool=True,
                     style_id: Optional[int]=None,
                   ) -> int:
         """
@@ -2096,6 +2630,8 @@ class IPG:
                 Sets the line height for the box around the radio labels.
             show: bool
                 Shows or hides the widget.
+            enabled: bool
+                Disabled widgets are drawn faded and do not call back.
             size: float,
                 Radius of the round radio button.
             text_spacing: f32
@@ -2133,6 +2669,12 @@ class IPG:
                         dot_rgba_hovered: Optional[list[float, 4]]=None,
                         text_color: Optional[IpgColor]=None,
                         text_rgba: Optional[list[float, 4]]=None,
+                        background_color_disabled: Optional[IpgColor]=None,
+                        background_rgba_disabled: Optional[list[float, 4]]=None,
+                        dot_color_disabled: Optional[IpgColor]=None,
+                        dot_rgba_disabled: Optional[list[float, 4]]=None,
+                        text_color_disabled: Optional[IpgColor]=None,
+                        text_rgba_disabled: Optional[list[float, 4]]=None,
                         gen_id: Optional[int]=None,
                         ) -> int:
         """
@@ -2179,6 +2721,24 @@ class IPG:
             text_rgba: Optional[list[float, 4]]=None,
                 The color of the text.
                 Defaults to a contrast color of the background.
+            background_color_disabled: Optional[IpgColor]=None,
+                The color of the background when the radio is disabled.
+                Defaults to the faded background.
+            background_rgba_disabled: Optional[list[float, 4]]=None,
+                The color of the background when disabled in rgba format.
+                Defaults to the faded background.
+            dot_color_disabled: Optional[IpgColor]=None,
+                The color of the dot when disabled.
+                Defaults to the faded dot color.
+            dot_rgba_disabled: Optional[list[float, 4]]=None,
+                The color of the dot when disabled in rgba format.
+                Defaults to the faded dot color.
+            text_color_disabled: Optional[IpgColor]=None,
+                The color of the text when disabled.
+                Defaults to the faded text color.
+            text_rgba_disabled: Optional[list[float, 4]]=None,
+                The color of the text when disabled in rgba format.
+                Defaults to the faded text color.
             gen_id: Optional[int]=None,
                 The only allowable entry for this id is that generated by ipg.generate_id().
 
@@ -2429,6 +2989,7 @@ class IPG:
                    *,
                    gen_id: Optional[int]=None, 
                    show: bool=True, 
+                   enabled: bool=True,
                    on_change: Optional[Callable]=None, 
                    on_release: Optional[Callable]=None, 
                    user_data: Optional[any]=None, 
@@ -2459,6 +3020,8 @@ class IPG:
                 The only allowable entry for this id is that generated by ipg.generate_id(). 
             show:
                 shows or hides the widget.
+            enabled: bool
+                Disabled widgets are drawn faded and do not call back.
             on_change: Callable
                 If set, will use callback with each change. 
             on_release: Callable
@@ -2495,6 +3058,10 @@ class IPG:
                         handle_border_width: Optional[float]=None,
                         handle_border_color: Optional[IpgColor]=None,
                         handle_border_rgba: Optional[list[float, 4]]=None,
+                        rail_color_disabled: Optional[IpgColor]=None,
+                        rail_rgba_disabled: Optional[list[float, 4]]=None,
+                        handle_color_disabled: Optional[IpgColor]=None,
+                        handle_rgba_disabled: Optional[list[float, 4]]=None,
                         gen_id: Union[None, int]=None,
                          ) -> int:
         """
@@ -2534,6 +3101,14 @@ class IPG:
                 Handle border color, default=transparent.
             handle_border_rgba: Optional[list[float, 4]]=None
                 Handle border color in rgba format, default=transparent.
+            rail_color_disabled: Optional[IpgColor]=None
+                The rail color when the slider is disabled, default=faded rail color.
+            rail_rgba_disabled: Optional[list[float, 4]]=None
+                The rail color when disabled in rgba format, default=faded rail color.
+            handle_color_disabled: Optional[IpgColor]=None
+                The handle color when disabled, default=faded handle color.
+            handle_rgba_disabled: Optional[list[float, 4]]=None
+                The handle color when disabled in rgba format, default=faded handle color.
             gen_id: Union[None, int]=None
                 The only allowable entry for this id is that generated by ipg.generate_id().
         Returns
@@ -2576,6 +3151,72 @@ class IPG:
             Internal id of widget and can be used by user if equated.
         """
 
+    def add_stream_plot(self,
+                        parent_id: str,
+                        series_names: list[str],
+                        *,
+                        capacity: int=1000,
+                        x_window: Optional[float]=None,
+                        y_min: Optional[float]=None,
+                        y_max: Optional[float]=None,
+                        series_rgba_colors: Optional[list[list[float]]]=None,
+                        title: Optional[str]=None,
+                        show_legend: bool=True,
+                        text_size: float=12.0,
+                        width: Optional[float]=None,
+                        width_fill: bool=True,
+                        height: Optional[float]=200.0,
+                        height_fill: bool=False,
+                        gen_id: Optional[int]=None,
+                        show: bool=True,
+                        ) -> int:
+        """
+        Adds a plot for streaming data, samples are added with append_samples().
+        Each series keeps the newest samples up to the capacity.
+        The plot is redrawn on the tick of the canvas timer, so a canvas timer
+        needs to be running for the samples to show.
+
+        Parameters
+        ----------
+            parent_id: str
+                id of another container or window.
+            series_names: list[str]
+                The name of each series, the index in the list is used in append_samples().
+            capacity: int
+                The number of samples kept for each series, from 1 to 1,000,000.
+            x_window: float
+                The span of the x axis ending at the newest sample, if None all samples are shown.
+            y_min: float
+                A fixed minimum of the y axis, auto-fitted if None.
+            y_max: float
+                A fixed maximum of the y axis, auto-fitted if None.
+            series_rgba_colors: list[list[float]]
+                A color for each series, a default palette is used otherwise.
+            title: str
+                The title above the plot.
+            show_legend: bool
+                Whether to show the series names when there is more than one series.
+            text_size: float
+                The size of the text, ticks are drawn slightly smaller.
+            width: float
+                Sets the width of the widget.
+            width_fill: bool
+                Sets the width to fill the available space, overrides width.
+            height: float
+                Sets the height of the widget.
+            height_fill: bool
+                Sets the height to fill the available space, overrides height.
+            gen_id: int
+                The only allowable entry for this id is the one generated by ipg.generate_id().
+            show: bool
+                Shows or hides widget.
+
+        Returns
+        -------
+        id: int
+            Internal id of widget and can be used by user if equated.
+        """
+
     def add_svg(self, 
                     parent_id: str, 
                     svg_path: str,
@@ -3001,6 +3642,8 @@ class IPG:
                         on_submit: Optional[Callable]=None,
                         on_input: Optional[Callable]=None,
                         on_paste: Optional[Callable]=None,
+                        on_focus: Optional[Callable]=None,
+                        on_blur: Optional[Callable]=None,
                         width: Optional[float]=None,
                         width_fill: bool=False,
                         padding: list[float]=[10.0],
@@ -3013,6 +3656,7 @@ class IPG:
                         is_secure: bool=False,
                         style_id: Optional[str]=None,
                         show: bool=True,
+                        enabled: bool=True,
                        ) -> int:
         """
         Adds a text_input widget to the gui.  Callbacks on_input and on_submit are
@@ -3033,6 +3677,11 @@ class IPG:
                 Calls a function each time a letter is enter into the text box.
             on_paste: Callable
                 Calls a function when the text is pasted into the text box, pressing enter will also submit.
+            on_focus: Callable
+                Calls a function with the value when the text box gets the focus,
+                by a click, Tab or ipg.focus().
+            on_blur: Callable
+                Calls a function with the value when the text box loses the focus.
             width: Optional[float]
                 Sets the width to a specific value.
             width_fill: bool
@@ -3058,6 +3707,8 @@ class IPG:
                 The string style_id of add_text_input_style().
             show: bool=True
                 Shows or hides the widget.
+            enabled: bool
+                Disabled widgets are drawn faded and do not call back.
         Returns
         -------
         id: int
@@ -3138,6 +3789,8 @@ class IPG:
                     on_start: Optional[Callable]=None,
                     on_stop: Optional[Callable]=None,
                     on_tick: Optional[Callable]=None,
+                    on_finished: Optional[Callable]=None,
+                    countdown_ms: Optional[int]=None,
                     label: str="Start Timer",
                     width: Optional[float]=None,
                     height: Optional[float]=None,
@@ -3153,6 +3806,9 @@ class IPG:
                     ) -> int:
         """
         Adds a timer event in millisecond duration.
+        Any number of timers can run at the same time, each with its own duration.
+        The ticks are counted from the time the timer has run, less any pauses,
+        so a late tick doesn't make the counter fall behind.
 
         Parameters
         ----------
@@ -3166,6 +3822,11 @@ class IPG:
             The optional function that executes when the timer stops.
         on_tick: Optional[Callable]
             The optional function that executes on every timer tick as indicated by duration_ms.
+        on_finished: Optional[Callable]
+            The optional function that executes when a countdown reaches zero, the timer then stops.
+        countdown_ms: Optional[int]
+            The length of a countdown, the time left is read with the RemainingMs param.
+            Starting a finished countdown starts it over.
         label: str="Start Timer"
             The default start label of the timer button.
         width: Optional[float]
@@ -3392,6 +4053,7 @@ class IPG:
                     spacing: float=10.0,
                     user_data: Optional[Any]=None,
                     show: bool=True,
+                    enabled: bool=True,
                     style_id: Optional[int]=None,
                     ) -> int:
         """
@@ -3425,6 +4087,8 @@ class IPG:
                 Any data in any form needed by user to be passed through as a callback. 
             show: bool
                 Shows or hides widget.
+            enabled: bool
+                Disabled widgets are drawn faded and do not call back.
             style: str
                 style_id of the add_toggler_style().
             
@@ -3702,45 +4366,46 @@ class IPG:
             The id of the event which can be used to modify the event through update_item.
         """
 
-    def add_polygon(self,
+    def add_dimension(self,
                     canvas_id: str,
-                    position_xy: tuple[float, float],
-                    radius: float,
-                    sides: int,
+                    start: tuple[float, float],
+                    end: tuple[float, float],
                     *,
-                    degrees: float=0.0,
+                    unit_scale: float=1.0,
+                    units: str="",
+                    precision: int=2,
+                    text_size: float=14.0,
                     stroke_width: float=2.0,
                     stroke_ipg_color: Optional[IpgColor]=None,
                     stroke_rgba_color: Optional[list[float, 4]]=None,
-                    fill_ipg_color: Optional[IpgColor]=None,
-                    fill_rgba_color: Optional[list[float, 4]]=None,
                     gen_id: Optional[int]=None,
                     ) -> int:
         """
-        Add a polygon to the canvas.
+        Add a dimension to the canvas, a line with end ticks labeled with its length.
+        The label is recalculated whenever the dimension is edited.
         
         Parameters
         ----------
             canvas_id: str
                 String id of the add_canvas().
-            position_xy: tuple[float, float]
-                Position of the center point of the polygon.
-            radius: float
-                Defines the radius
-            sides: int
-                The number of side for the polygon
-            degrees: float
-                The rotation of the polygon in degrees
+            start: tuple[float, float]
+                Tuple of starting position
+            end: tuple[float, float]
+                Tuple of ending position
+            unit_scale: float
+                The pixel length is multiplied by this value, i.e. 0.1 for 10 pixels per unit.
+            units: str
+                The units appended to the label, i.e. "mm".
+            precision: int
+                The number of decimal places in the label.
+            text_size: float
+                The size of the label.
             stroke_width: float
                 Width of the stoke.
             stroke_ipg_color: Optional[IpgColor]
-                Whether to use the IpgColor for the stroke
+                Whether to use the IpgColor for the stroke and label
             stroke_rgba_color: Optional[list[float, 4]]
-                Whether to use the rgba color for the stroke
-            fill_ipg_color: Optional[IpgColor]
-                Whether to use the IpgColor for the fill or None
-            fill_rgba_color: Optional[list[float, 4]]
-                Whether to use the rgba color for the fill or None
+                Whether to use the rgba color for the stroke and label
             gen_id: Optional[int]=None
                 The only allowable entry for this id is that generated by ipg.generate_id().
         
@@ -3749,7 +4414,143 @@ class IPG:
             id: int
             The id of the event which can be used to modify the event through update_item.
         """
-        
+
+    def add_angle_measure(self,
+                        canvas_id: str,
+                        vertex: tuple[float, float],
+                        start: tuple[float, float],
+                        end: tuple[float, float],
+                        *,
+                        precision: int=1,
+                        text_size: float=14.0,
+                        stroke_width: float=2.0,
+                        stroke_ipg_color: Optional[IpgColor]=None,
+                        stroke_rgba_color: Optional[list[float, 4]]=None,
+                        gen_id: Optional[int]=None,
+                        ) -> int:
+        """
+        Add an angle measure to the canvas, two arms with an arc labeled
+        with the inner angle in degrees.
+        
+        Parameters
+        ----------
+            canvas_id: str
+                String id of the add_canvas().
+            vertex: tuple[float, float]
+                Tuple of the point where the arms meet
+            start: tuple[float, float]
+                Tuple of the end of the first arm
+            end: tuple[float, float]
+                Tuple of the end of the second arm
+            precision: int
+                The number of decimal places in the label.
+            text_size: float
+                The size of the label.
+            stroke_width: float
+                Width of the stoke.
+            stroke_ipg_color: Optional[IpgColor]
+                Whether to use the IpgColor for the stroke and label
+            stroke_rgba_color: Optional[list[float, 4]]
+                Whether to use the rgba color for the stroke and label
+            gen_id: Optional[int]=None
+                The only allowable entry for this id is that generated by ipg.generate_id().
+        
+        Returns
+        -------
+            id: int
+            The id of the event which can be used to modify the event through update_item.
+        """
+
+    def add_callout(self,
+                    canvas_id: str,
+                    anchor_xy: tuple[float, float],
+                    text_xy: tuple[float, float],
+                    text: str,
+                    *,
+                    text_size: float=16.0,
+                    stroke_width: float=1.0,
+                    stroke_ipg_color: Optional[IpgColor]=None,
+                    stroke_rgba_color: Optional[list[float, 4]]=None,
+                    gen_id: Optional[int]=None,
+                    ) -> int:
+        """
+        Add a callout to the canvas, a text label with a leader line to an anchor point.
+        
+        Parameters
+        ----------
+            canvas_id: str
+                String id of the add_canvas().
+            anchor_xy: tuple[float, float]
+                Tuple of the point being annotated
+            text_xy: tuple[float, float]
+                Tuple of the text position
+            text: str
+                The text of the callout.
+            text_size: float
+                The size of the text.
+            stroke_width: float
+                Width of the leader line.
+            stroke_ipg_color: Optional[IpgColor]
+                Whether to use the IpgColor for the stroke and text
+            stroke_rgba_color: Optional[list[float, 4]]
+                Whether to use the rgba color for the stroke and text
+            gen_id: Optional[int]=None
+                The only allowable entry for this id is that generated by ipg.generate_id().
+        
+        Returns
+        -------
+            id: int
+            The id of the event which can be used to modify the event through update_item.
+        """
+
+    def add_polygon(self,
+                    canvas_id: str,
+                    position_xy: tuple[float, float],
+                    radius: float,
+                    sides: int,
+                    *,
+                    degrees: float=0.0,
+                    stroke_width: float=2.0,
+                    stroke_ipg_color: Optional[IpgColor]=None,
+                    stroke_rgba_color: Optional[list[float, 4]]=None,
+                    fill_ipg_color: Optional[IpgColor]=None,
+                    fill_rgba_color: Optional[list[float, 4]]=None,
+                    gen_id: Optional[int]=None,
+                    ) -> int:
+        """
+        Add a polygon to the canvas.
+        
+        Parameters
+        ----------
+            canvas_id: str
+                String id of the add_canvas().
+            position_xy: tuple[float, float]
+                Position of the center point of the polygon.
+            radius: float
+                Defines the radius
+            sides: int
+                The number of side for the polygon
+            degrees: float
+                The rotation of the polygon in degrees
+            stroke_width: float
+                Width of the stoke.
+            stroke_ipg_color: Optional[IpgColor]
+                Whether to use the IpgColor for the stroke
+            stroke_rgba_color: Optional[list[float, 4]]
+                Whether to use the rgba color for the stroke
+            fill_ipg_color: Optional[IpgColor]
+                Whether to use the IpgColor for the fill or None
+            fill_rgba_color: Optional[list[float, 4]]
+                Whether to use the rgba color for the fill or None
+            gen_id: Optional[int]=None
+                The only allowable entry for this id is that generated by ipg.generate_id().
+        
+        Returns
+        -------
+            id: int
+            The id of the event which can be used to modify the event through update_item.
+        """
+        
     def add_poly_line(self,
                     canvas_id: str,
                     points: list[(float, float)],
@@ -3873,19 +4674,26 @@ class IPG:
                             *,
                             on_key_press: Optional[Callable]=None,
                             on_key_release: Optional[Callable]=None,
+                            on_text_input: Optional[Callable]=None,
                             user_data: Optional[any]=None, 
                            ) -> int:
         """
         Add a keyboard event handler to process keyboard actions.
+        The callbacks get the event id and a dict with the name, key, modifier,
+        modifiers, a list of the modifiers held, location and the window_id
+        of the window having the keyboard focus.
 
         Parameters
         ----------
         enabled: bool
             Enables the event
         on_key_press: Callable
-            Calls a function when a key is pressed.
+            Calls a function when a key is pressed, the dict has the text typed, if any.
         on_key_release: Callable
             Calls a function when a key is released.
+        on_text_input: Callable
+            Calls a function with the text typed, after the key press.  A composed or
+            unicode character may take several keys, Enter, Backspace and Ctrl+key type no text.
         user_data: any
             Any data that might be needed in the callback function.
 
@@ -3907,10 +4715,19 @@ class IPG:
                             on_right_release: Optional[Callable]=None,
                             on_middle_scroll_line: Optional[Callable]=None,
                             on_middle_scroll_pixel: Optional[Callable]=None,
+                            on_double_click: Optional[Callable]=None,
+                            on_triple_click: Optional[Callable]=None,
+                            click_interval_ms: int=500,
                             user_data: Optional[Any]=None,
                           ) ->int:
         """
         Add a mouse button handlers to process mouse actions.
+        A move, scroll, double or triple click callback gets the event id and a dict with
+        the window_id, the modifiers held, and the position [x, y] of the cursor in the window,
+        once it has moved there.  A move also has the x and y of the cursor, a scroll has the
+        x and y scrolled.  The enter, exit, press and release callbacks only get the event id,
+        the dict is in the IpgEvent with IPG(event_objects=True).
+        A press on a widget, like a button, is used by the widget and not sent.
 
         Parameters
         ----------
@@ -3934,6 +4751,69 @@ class IPG:
             Calls a function when the middle mouse scroll is scrolled, sends line count.
         on_middle_scroll_pixel: Callable
             Calls a function when the middle mouse scroll is scrolled, send pixel count.
+        on_double_click: Callable
+            Calls a function after the second press of a button, the dict has the button.
+        on_triple_click: Callable
+            Calls a function after the third press of a button, the dict has the button.
+        click_interval_ms: int
+            The longest time between the presses of a double or triple click.
+        user_data: any
+            Any data that might be needed in the callback function.
+        
+        Returns
+        -------
+        id: int
+            The id of the event which can be used to modify the event through update_item.
+        """
+
+    def add_event_touch(self,
+                            enabled: bool,
+                            *,
+                            on_finger_pressed: Optional[Callable]=None,
+                            on_finger_moved: Optional[Callable]=None,
+                            on_finger_lifted: Optional[Callable]=None,
+                            on_finger_lost: Optional[Callable]=None,
+                            on_pinch: Optional[Callable]=None,
+                            on_rotate: Optional[Callable]=None,
+                            on_pan: Optional[Callable]=None,
+                            on_swipe: Optional[Callable]=None,
+                            on_long_press: Optional[Callable]=None,
+                            long_press_ms: int=500,
+                            user_data: Optional[Any]=None,
+                          ) ->int:
+        """
+        Add touch handlers for the fingers and the gestures they make.
+        The finger callbacks get the event id, a dict with the finger and
+        a dict with the position.
+        The gesture callbacks get the event id and a dict with the window_id
+        and the x and y of the gesture.  The scale, degrees, dx and dy are the
+        totals since a finger was last pressed or lifted.
+
+        Parameters
+        ----------
+        enabled: bool
+            Enables the event
+        on_finger_pressed: Callable
+            Calls a function when a finger touches.
+        on_finger_moved: Callable
+            Calls a function when a finger moves.
+        on_finger_lifted: Callable
+            Calls a function when a finger is lifted.
+        on_finger_lost: Callable
+            Calls a function when a finger is lost.
+        on_pinch: Callable
+            Calls a function as two fingers spread or close, the dict has the scale.
+        on_rotate: Callable
+            Calls a function as two fingers turn, the dict has the degrees.
+        on_pan: Callable
+            Calls a function as the fingers move, the dict has the dx, dy and fingers.
+        on_swipe: Callable
+            Calls a function when a finger is lifted after a quick stroke,
+            the dict has the direction, left, right, up or down, and the speed.
+        on_long_press: Callable
+            Calls a function when a finger is held still, the dict has the finger.
+        long_press_ms: int
+            How long a finger is held for a long press.
         user_data: any
             Any data that might be needed in the callback function.
         
@@ -3975,6 +4855,8 @@ class IPG:
             When a redraw command is requested.
         on_close_requested: Optional[Callable]=None
             When a window close is requested, the window setting on_close_request must be set to False.
+            The window is hidden after the callback unless the callback returns False,
+            for example to ask about saving the changes first.  An async callback can't keep it open.
         on_focused: Optional[Callable]=None
             When an unfocused window is brought into focus.
         on_unfocused: Optional[Callable]=None
@@ -4019,6 +4901,8 @@ class IPG:
     def delete_item(self, window_id: str, wid: int):
         """
         Deletes an item using the widgets id.
+        Deleting a container deletes everything inside of it.
+        The callbacks, user data and any styles no longer used are deleted too.
         Example: btn_id = add_button("Button")
                  delete_item(btn_id)
 
@@ -4034,12 +4918,29 @@ class IPG:
         None
         """
         
+    def clear_container(self, container_id: str):
+        """
+        Deletes everything inside of the container, the container remains.
+        The styles are kept so the container can be filled again.
+        Example: clear_container("list")
+                 add_text("list", "new item")
+
+        Parameters
+        ----------
+        container_id: str
+            The container_id of the container to clear.
+
+        Returns
+        -------
+        None
+        """
+        
     def show_items(self,
                   window_id: str,
                   ids: List[tuple[int, bool]]
                   ):
         """
-        Shows or hides items
+        Shows or hides items, a hidden container hides everything inside of it.
         
         Args:
             window_id (str):
@@ -4048,6 +4949,237 @@ class IPG:
                 A list of the ids and they bool value to indicate either sgoe(True) or Hide(False).
         """
     
+    def append_samples(self,
+                        wid: int,
+                        xs: list[float],
+                        ys: list[float],
+                        series: int=0,
+                        ):
+        """
+        Appends samples to a stream plot without copying the widget.
+        Safe to call from any thread, the gui is woken once and draws
+        all the samples appended until then, no canvas timer is needed.
+        Until they are drawn, only the last capacity samples of a series are kept.
+        An unknown wid raises an IpgIdError, an unknown series an IpgParamError.
+
+        Parameters
+        ----------
+        wid: int
+            The id of the stream plot.
+        xs: list[float]
+            The x values, usually a time.
+        ys: list[float]
+            The y values, same length as xs.
+        series: int
+            The index of the series in series_names.
+
+        Returns
+        -------
+        None
+        """
+
+    def run_task(self,
+                 coro: Coroutine,
+                 on_done: Optional[Callable]=None,
+                 user_data: Optional[any]=None,
+                 ) -> int:
+        """
+        Runs a coroutine on the asyncio loop owned by the session, 
+        so that slow work does not block the gui.  The on_done callback is 
+        called on the gui thread with the result, on_done(task_id, result) 
+        or on_done(task_id, result, user_data).
+
+        Callbacks defined with async def are run on the same loop, 
+        they return right away and the gui keeps drawing.  Use update_item 
+        within them to change the widgets, it is safe from any thread.
+
+        Parameters
+        ----------
+        coro: Coroutine
+            The coroutine, for example, fetch_data(url).
+        on_done: Callable
+            The callback called with the result.
+        user_data: any
+            Any data to pass to on_done.
+
+        Returns
+        -------
+        task_id: int
+        """
+
+    def call_later(self,
+                   delay_ms: int,
+                   callback: Callable,
+                   user_data: Optional[any]=None,
+                   ) -> int:
+        """
+        Calls the callback once after the delay, callback(handle) 
+        or callback(handle, user_data).  No widget is needed and 
+        any number of calls can be waiting at the same time.
+
+        Parameters
+        ----------
+        delay_ms: int
+            The delay in milliseconds, greater than 0.
+        callback: Callable
+            The callback to call.
+        user_data: any
+            Any data to pass to the callback.
+
+        Returns
+        -------
+        handle: int
+            Used with cancel_call().
+        """
+
+    def call_every(self,
+                   interval_ms: int,
+                   callback: Callable,
+                   user_data: Optional[any]=None,
+                   ) -> int:
+        """
+        Calls the callback every interval until cancel_call(handle),
+        callback(handle) or callback(handle, user_data).
+
+        Parameters
+        ----------
+        interval_ms: int
+            The interval in milliseconds, greater than 0.
+        callback: Callable
+            The callback to call.
+        user_data: any
+            Any data to pass to the callback.
+
+        Returns
+        -------
+        handle: int
+            Used with cancel_call().
+        """
+
+    def cancel_call(self, handle: int) -> bool:
+        """
+        Cancels a call_later or call_every.
+
+        Parameters
+        ----------
+        handle: int
+            The handle returned by call_later or call_every.
+
+        Returns
+        -------
+        bool
+            False when the call already ran or was cancelled.
+        """
+
+    def add_shortcut(self,
+                     accelerator: str,
+                     callback: Callable,
+                     *,
+                     window_id: Optional[str]=None,
+                     when_focused: Optional[int]=None,
+                     user_data: any=None,
+                     ) -> int:
+        """
+        Adds a keyboard shortcut, the keys are matched without calling python,
+        the callback is called with the shortcut id and its accelerator text.
+
+        Parameters
+        ----------
+        accelerator: str
+            The modifiers and key joined by +, "Ctrl+Shift+S", "Alt+F4" or "Ctrl++".
+            The modifiers are Ctrl, Shift, Alt, Cmd and Mod, which is Cmd on macOS and Ctrl elsewhere.
+            The keys are a single character or a named key, Tab, Enter, Escape, Backspace, Delete,
+            Insert, Home, End, PageUp, PageDown, Up, Down, Left, Right, Space and F1 to F24.
+            A chord is typed one key after the other, "Ctrl+K Ctrl+C".
+            To show it in a menu, the menu item must be a button added with the same accelerator,
+            the other menu item widgets, like a checkbox or a toggler, can't show it.
+        callback: Callable
+            The function called when the shortcut is typed.
+        window_id: Optional[str]
+            The window the shortcut is used in, all windows when None.
+            A window shortcut is used before one for all windows.
+        when_focused: Optional[int]
+            The id of a text input, the shortcut is only used when it has the focus.
+        user_data: any
+            Any data the user may need during the callback.
+
+        Returns
+        -------
+        shortcut_id: int
+            The id used to remove the shortcut.
+
+        Raises
+        ------
+        IpgParamError
+            The accelerator is not valid or conflicts with a shortcut having the same
+            window_id and when_focused, the same keys or the start of a chord.
+        """
+
+    def remove_shortcut(self, shortcut_id: int) -> bool:
+        """
+        Removes a shortcut.
+
+        Parameters
+        ----------
+        shortcut_id: int
+            The id returned by add_shortcut.
+
+        Returns
+        -------
+        bool
+            False when the shortcut was already removed.
+        """
+
+    def focus(self, wid: int):
+        """
+        Gives a text input the focus.  Called before start_session,
+        the text input is focused when the window opens.
+
+        Parameters
+        ----------
+        wid: int
+            The id of the text input.
+        """
+
+    def open_window(self, window_id: str):
+        """
+        Shows a window, one added with mode=IpgWindowMode.Closed or
+        one that was closed.  A window closed by the user is opened again.
+        A window added after start_session is opened when added, unless
+        its mode is IpgWindowMode.Closed.
+
+        Parameters
+        ----------
+        window_id: str
+            The id of the window.
+        """
+
+    def close_window(self, window_id: str):
+        """
+        Closes a window, it's hidden and can be opened again with open_window.
+        The session ends when all of the windows are closed.
+
+        Parameters
+        ----------
+        window_id: str
+            The id of the window.
+        """
+
+    def set_tab_order(self, window_id: str, wids: list[int]):
+        """
+        Sets the order the text inputs of a window are focused in by Tab,
+        Shift+Tab goes back through it and the order goes around at the end.
+        The hidden or disabled text inputs are passed over.  Without an order,
+        or with an empty one, Tab goes through the text inputs of the window in the order they were added.
+
+        Parameters
+        ----------
+        window_id: str
+            The id of the window.
+        wids: list[int]
+            The ids of the text inputs.
+        """
+
     def update_dataframe(self,
                     wid: int, 
                     param: str, 
@@ -4055,6 +5187,8 @@ class IPG:
                     ):
         """
         Update a DataFrame example: update_dataframe(table_id, IpgTableParams.PolarsDf, my_df)
+        A chart or heatmap is updated the same way using IpgChartParam.PolarsDf
+        or IpgHeatmapParam.PolarsDf.
         
         Parameters
         ----------
@@ -4093,7 +5227,215 @@ class IPG:
         -------
         None
         """
+
+    def update_items(self,
+                     updates: list[tuple[int, any, any]],
+                     ):
+        """
+        Updates several widgets at once, the updates are applied in the same 
+        pass and are drawn together.  The ids and param classes are checked 
+        first and nothing is queued if one is wrong.  When a value fails while 
+        being applied, the items changed by the other updates are put back and 
+        the error is sent to on_error.  Canvas drawing and window actions, 
+        like a resize, are not put back.
+
+        Parameters
+        ----------
+        updates: list[tuple[int, param, any]]
+            The (wid, param, value) of each update, 
+            for example, [(btn_id, IpgButtonParam.Label, "Save"), (chk_id, IpgCheckboxParam.IsChecked, False)]
+
+        Returns
+        -------
+        None
+
+        Raises
+        ------
+        IpgIdError
+            An id was not found.
+        IpgParamError
+            A param is not of the class used by the item.
+        """
+
+    def batch(self) -> IpgBatch:
+        """
+        Collects the update_item and update_items calls made inside of a 
+        with block, they are queued as one update_items when the block ends.
+        The id and param class of an update_item are checked when it is called,
+        an IpgIdError or IpgParamError is raised right away.
+        When the block raises an exception, none of the updates are queued.
+        When an update fails while the batch is applied, the widgets, containers,
+        canvas and windows changed by the others are put back.
+
+            with ipg.batch():
+                ipg.update_item(name_id, IpgTextInputParam.Value, "")
+                ipg.update_item(email_id, IpgTextInputParam.Value, "")
+
+        Returns
+        -------
+        IpgBatch
+            The context manager.
+        """
+        
+    def get_item(self,
+                 wid: int, 
+                 param: any,
+                 ) -> any:
+        """
+        Gets the current value of a widget, container or window parameter.
+        While the gui is running, the value includes the changes made by the user,
+        for example, the text typed into a text input.
+        
+        Parameters
+        ----------
+        wid: int
+            The id of the widget, container or window.
+        param: class property
+            The same parameter used with update_item, for example, IpgTextInputParam.Value.
+
+        Returns
+        -------
+        The value, a bool, float, int, str, list or an Ipg class like IpgAlignment.
+        A color is returned as an rgba list and a width or height that fills
+        or shrinks is returned as None.  Raises IpgIdError when the id is not found
+        and IpgParamError when the parameter is not one of the item.
+        """
+
+    def get_items(self,
+                  wid: int, 
+                  ) -> dict[str, any]:
+        """
+        Gets the current values of all of the parameters of a widget, container or window.
+        
+        Parameters
+        ----------
+        wid: int
+            The id of the widget, container or window.
+
+        Returns
+        -------
+        A dictionary with the parameter names as keys, for example, {"Label": "Press Me", ...}
+        """
+
+    def add_value(self,
+                  name: str,
+                  value: Union[bool, float, str],
+                  ):
+        """
+        Adds a named value that widgets can be bound to with bind().
+        
+        Parameters
+        ----------
+        name: str
+            The name of the value.
+        value: bool | float | str
+            The starting value, the widgets bound to it start with this value.
+
+        Raises
+        ------
+        IpgParamError
+            The name is already used or the value is not a bool, number or str.
+        """
+
+    def set_value(self,
+                  name: str,
+                  value: Union[bool, float, str],
+                  ):
+        """
+        Changes a named value, the widgets bound to it are updated.
         
+        Parameters
+        ----------
+        name: str
+            The name of the value.
+        value: bool | float | str
+            The new value.
+
+        Raises
+        ------
+        IpgIdError
+            The value was not found.
+        """
+
+    def get_value(self,
+                  name: str,
+                  ) -> Union[bool, float, str]:
+        """
+        Gets the current named value, including the changes made 
+        by widgets with a two way binding.
+        
+        Parameters
+        ----------
+        name: str
+            The name of the value.
+
+        Returns
+        -------
+        The value.
+        """
+
+    def bind(self,
+             wid: int,
+             param: any,
+             name: str,
+             two_way: bool=False,
+             format: Optional[str]=None,
+             scale: float=1.0,
+             offset: float=0.0,
+             ):
+        """
+        Binds a widget parameter to a named value.  The parameter follows the value,
+        with two_way=True the value also follows the parameter when the widget is used.
+        The other bound widgets are updated inside of the gui, no callback is needed.
+
+            ipg.add_value("volume", 0.5)
+            ipg.bind(slider_id, IpgSliderParam.Value, "volume", two_way=True, scale=100.0)
+            ipg.bind(text_id, IpgTextParam.Content, "volume", format="Volume {:.0}%", scale=100.0)
+            ipg.bind(bar_id, IpgProgressBarParam.Value, "volume", scale=100.0)
+        
+        Parameters
+        ----------
+        wid: int
+            The id of the widget.
+        param: class property
+            The parameter to bind, for example, IpgSliderParam.Value.
+        name: str
+            The name of the value, added by add_value().
+        two_way: bool
+            Whether using the widget changes the value.
+        format: Optional[str]
+            Used when the parameter is text, the first {} is replaced by the value,
+            {:.2} gives a number with 2 decimals.
+        scale: float
+            A number is multiplied by scale, then offset is added, on its way to the widget.
+            A two way binding reverses this on the way back.
+        offset: float
+            See scale.
+
+        Raises
+        ------
+        IpgIdError
+            The widget or value was not found.
+        IpgParamError
+            The param is not of the class used by the widget or scale is 0.0.
+        """
+
+    def unbind(self,
+               wid: int,
+               name: Optional[str]=None,
+               ):
+        """
+        Removes the bindings of a widget, all of them or only those to the named value.
+        Deleting a widget removes its bindings.
+        
+        Parameters
+        ----------
+        wid: int
+            The id of the widget.
+        name: Optional[str]
+            The name of the value.
+        """
+
     def update_canvas_item(self,
                     wid: int, 
                     param: str, 
@@ -4183,6 +5525,8 @@ class IpgButtonParam:
 
     Parameters
     ----------
+    Accelerator: Optional[str]
+        The shortcut shown after the label, None removes it.
     ArrowStyle: IpgButtonArrow
         A button becomes a type of arrow.
     Height: float
@@ -4195,6 +5539,8 @@ class IpgButtonParam:
         The padding around the button.
     Clip: bool
         Whether the label is clipped or not
+    Enabled: bool
+        Whether the widget responds to the user.
     Show: bool
         Whether to show the button
     StyleId: int
@@ -4206,12 +5552,14 @@ class IpgButtonParam:
     WidthFill: bool
          Whether the button width fills the available space of a container.
     """
+    Accelerator: str
     ArrowStyle: IpgButtonArrow
     Height: float
     HeightFill: bool
     Label: str
     Padding: list
     Clip: bool
+    Enabled: bool
     Show: bool
     StyleId: int
     StyleStandard: IpgStyleStandard
@@ -4302,6 +5650,10 @@ class IpgCanvasParam:
         The number of points to use for polygon and polyline.
     Widget IpgCanvasWidget
         One of the IpgCanvasWidgets to use.
+    UnitScale float
+        The scale used by the dimension widgets, existing dimensions are updated.
+    Units str
+        The units used by the dimension widgets, existing dimensions are updated.
     
     """
     Clear: bool
@@ -4314,6 +5666,8 @@ class IpgCanvasParam:
     Widget: IpgCanvasWidget
     Load: None
     Save: None
+    UnitScale: float
+    Units: str
     
     
 class IpgCanvasWidget:
@@ -4328,6 +5682,9 @@ class IpgCanvasWidget:
     RightTriangle: str
     FreeHand: str
     Text: str
+    Dimension: str
+    AngleMeasure: str
+    Callout: str
 
 
 class IpgCanvasImageParam:
@@ -4374,6 +5731,64 @@ class IpgCardParam:
     Style: str
 
 
+class IpgChartKind:
+    """
+    The kinds of chart series
+    """
+    Line: int
+    Scatter: int
+    Area: int
+    Bar: int
+
+
+class IpgChartParam:
+    """
+    The Chart parameters
+
+    Parameters
+    ----------
+    Height: float
+        The height of the chart.
+    HeightFill: bool
+        Whether the height fills the available space.
+    Kind: IpgChartKind
+        The kind used for all series.
+    PolarsDf: PyDataFrame
+        Used only with update_dataframe.
+    Show: bool
+        Whether to show the chart.
+    ShowLegend: bool
+        Whether to show the legend.
+    Title: str
+        The title of the chart.
+    Width: float
+        The width of the chart.
+    WidthFill: bool
+        Whether the width fills the available space.
+    XColumn: str
+        The column used for the x axis.
+    XLabel: str
+        The x axis label.
+    YColumns: list[str]
+        The columns plotted as series.
+    YLabel: str
+        The y axis label.
+    """
+    Height: float
+    HeightFill: bool
+    Kind: IpgChartKind
+    PolarsDf: PyDataFrame
+    Show: bool
+    ShowLegend: bool
+    Title: str
+    Width: float
+    WidthFill: bool
+    XColumn: str
+    XLabel: str
+    YColumns: list[str]
+    YLabel: str
+
+
 class IpgCheckboxParam:
     """
     The Checkbox parameters
@@ -4388,6 +5803,8 @@ class IpgCheckboxParam:
         Whether the checkbox is checked or not.
     Label: str
         THe label of the checkbox.
+    Enabled: bool
+        Whether the widget responds to the user.
     Show: bool
         Whether to show the checkbox.
     Size: float
@@ -4411,6 +5828,7 @@ class IpgCheckboxParam:
     IconX: bool
     IsChecked: bool
     Label: str
+    Enabled: bool
     Show: bool
     Size: float
     Spacing: float
@@ -4452,6 +5870,18 @@ class IpgCheckboxStyleParam:
         The label text color in IpgColor format
     TextRgbaColor: list[float, 4]
         The label text color in rgba format
+    BackgroundIpgColorDisabled: IpgColor
+        When disabled, the background color in IpgColor format
+    BackgroundRgbaColorDisabled: list[float, 4]
+        When disabled, the background color in rgba format
+    IconIpgColorDisabled: IpgColor
+        When disabled, the icon color in IpgColor format
+    IconRgbaColorDisabled: list[float, 4]
+        When disabled, the icon color in rgba format
+    TextIpgColorDisabled: IpgColor
+        When disabled, the label text color in IpgColor format
+    TextRgbaColorDisabled: list[float, 4]
+        When disabled, the label text color in rgba format
     """
     BackgroundIpgColor:IpgColor
     BackgroundRgbaColor:list[float, 4]
@@ -4469,6 +5899,12 @@ class IpgCheckboxStyleParam:
     IconRgbaColor:list[float, 4]
     TextIpgColor:IpgColor
     TextRgbaColor:list[float, 4]
+    BackgroundIpgColorDisabled:IpgColor
+    BackgroundRgbaColorDisabled:list[float, 4]
+    IconIpgColorDisabled:IpgColor
+    IconRgbaColorDisabled:list[float, 4]
+    TextIpgColorDisabled:IpgColor
+    TextRgbaColorDisabled:list[float, 4]
 
 
 class IpgColorPickerParam:
@@ -4489,6 +5925,8 @@ class IpgColorPickerParam:
         The padding around the button.
     Clip: bool
         Whether the label is clipped or not
+    Enabled: bool
+        Whether the widget responds to the user.
     Show: bool
         Whether to show the button
     StyleId: int
@@ -4508,6 +5946,7 @@ class IpgColorPickerParam:
     Label: str
     Padding: list
     Clip: bool
+    Enabled: bool
     Show: bool
     StyleId: int
     StyleStandard: IpgStyleStandard
@@ -4620,12 +6059,15 @@ class IpgDatePickerParam:
         Padding around the button
     SizeFactor: float
         Size of the calendar
+    Enabled: bool
+        Whether the widget responds to the user.
     Show: bool
         Whether to show or not.
     """
     Label: str
     Padding: list[float]
     SizeFactor: float
+    Enabled: bool
     Show: bool
 
     
@@ -4740,6 +6182,137 @@ class IpgImageRotation:
     Solid: str
 
 
+class IpgColorMap:
+    """
+    Colormaps used by the heatmap, CoolWarm, RdBu and RdYlGn are diverging.
+    """
+    Viridis: int
+    Magma: int
+    Plasma: int
+    Inferno: int
+    Greys: int
+    CoolWarm: int
+    RdBu: int
+    RdYlGn: int
+
+
+class IpgGaugeParam:
+    """
+    The parameters of the gauge, knob and meter, not all apply to each.
+
+    Parameters
+    ----------
+    AnimationMs: int
+        The needle animation time, gauge and meter.
+    Bands: list[tuple[float, float, list[float]]]
+        The threshold bands as (from, to, rgba).
+    Height: float
+        The height, meter only.
+    HeightFill: bool
+        Whether the height fills the available space, meter only.
+    Label: str
+        The label.
+    Max: float
+        The maximum value.
+    Min: float
+        The minimum value.
+    MinorTicks: int
+        The ticks between the labeled ticks, gauge and meter.
+    Precision: int
+        The decimal places of the value.
+    Show: bool
+        Whether to show the widget.
+    Size: float
+        The width and height, gauge and knob.
+    Step: float
+        The value step, knob only.
+    TickCount: int
+        The number of labeled ticks.
+    Units: str
+        The units added after the value.
+    Value: float
+        The value.
+    Width: float
+        The width, meter only.
+    WidthFill: bool
+        Whether the width fills the available space, meter only.
+    """
+    AnimationMs: int
+    Bands: list
+    Height: float
+    HeightFill: bool
+    Label: str
+    Max: float
+    Min: float
+    MinorTicks: int
+    Precision: int
+    Show: bool
+    Size: float
+    Step: float
+    TickCount: int
+    Units: str
+    Value: float
+    Width: float
+    WidthFill: bool
+
+
+class IpgHeatmapParam:
+    """
+    The Heatmap parameters
+
+    Parameters
+    ----------
+    Center: float | None
+        The value at the middle of the colormap.
+    ColorMap: IpgColorMap
+        The colormap.
+    ColumnLabels: list[str]
+        The labels above the columns.
+    Height: float
+        The height of the heatmap.
+    HeightFill: bool
+        Whether the height fills the available space.
+    PolarsDf: PyDataFrame
+        Used only with update_dataframe.
+    Precision: int
+        The decimal places of the values in the cells.
+    RowLabels: list[str]
+        The labels left of the rows.
+    Show: bool
+        Whether to show the heatmap.
+    ShowColorbar: bool
+        Whether to show the colorbar.
+    ShowValues: bool
+        Whether to write the values in the cells.
+    Values: list[list[float]]
+        Replaces the values of the grid.
+    VMax: float | None
+        The value at the high end of the colormap.
+    VMin: float | None
+        The value at the low end of the colormap.
+    Width: float
+        The width of the heatmap.
+    WidthFill: bool
+        Whether the width fills the available space.
+    """
+    Center: float
+    ColorMap: IpgColorMap
+    ColumnLabels: list[str]
+    Height: float
+    HeightFill: bool
+    PolarsDf: PyDataFrame
+    Precision: int
+    RowLabels: list[str]
+    Show: bool
+    ShowColorbar: bool
+    ShowValues: bool
+    Values: list
+    VMax: float
+    VMin: float
+    Width: float
+    WidthFill: bool
+
+
 class IpgImageParam:
     """
     Image parameters
@@ -4923,6 +6496,8 @@ class IpgPickListParam:
         A placeholder in the picklist box.
     Padding: list[float]
         Padding around the picklist.
+    Enabled: bool
+        Whether the widget responds to the user.
     Show: bool
         Whether to show or hide the widget.
     StyleId: int
@@ -4940,6 +6515,7 @@ class IpgPickListParam:
     Selected: str
     Placeholder: str
     Padding: list[float]
+    Enabled: bool
     Show: bool
     StyleId: int
     TextSize: float
@@ -4988,6 +6564,14 @@ class IpgPickListStyleParam:
         The label text color in IpgColor format
     TextRgbaColor: list[float, 4]
         The label text color in rgba format
+    BackgroundIpgColorDisabled: IpgColor
+        When disabled, the background color in IpgColor format
+    BackgroundRgbaColorDisabled: list[float, 4]
+        When disabled, the background color in rgba format
+    TextIpgColorDisabled: IpgColor
+        When disabled, the text color in IpgColor format
+    TextRgbaColorDisabled: list[float, 4]
+        When disabled, the text color in rgba format
     """
     BackgroundIpgColor:IpgColor
     BackgroundRbgaColor:List[float, 4]
@@ -5001,6 +6585,10 @@ class IpgPickListStyleParam:
     PlaceholderRgbaColor:list[float, 4]
     TextIpgColor:IpgColor
     TextRgbaColor:list[float, 4]
+    BackgroundIpgColorDisabled:IpgColor
+    BackgroundRgbaColorDisabled:list[float, 4]
+    TextIpgColorDisabled:IpgColor
+    TextRgbaColorDisabled:list[float, 4]
 
 class IpgProgressBarParam:
     """
@@ -5096,6 +6684,8 @@ class IpgRadioParam:
         The padding around the radio
     SelectedIndex: int
         The index of the radio selected
+    Enabled: bool
+        Whether the widget responds to the user.
     Show: bool
         Whether to show or hide the radio group
     Size: float
@@ -5127,6 +6717,7 @@ class IpgRadioParam:
     Labels: list[str]
     Padding: list[float]
     SelectedIndex: int
+    Enabled: bool
     Show: bool
     Size: float
     Spacing: float
@@ -5170,6 +6761,18 @@ class IpgRadioStyleParam:
         The label text color in IpgColor format
     TextRgbaColor: list[float, 4]
         The label text color in rgba format
+    BackgroundIpgColorDisabled: IpgColor
+        When disabled, the background color in IpgColor format
+    BackgroundRgbaColorDisabled: list[float, 4]
+        When disabled, the background color in rgba format
+    DotIpgColorDisabled: IpgColor
+        When disabled, the dot color in IpgColor format
+    DotRgbaColorDisabled: list[float, 4]
+        When disabled, the dot color in rgba format
+    TextIpgColorDisabled: IpgColor
+        When disabled, the text color in IpgColor format
+    TextRgbaColorDisabled: list[float, 4]
+        When disabled, the text color in rgba format
     """
     BackgroundIpgColor:IpgColor
     BackgroundRbgaColor:List[float, 4]
@@ -5182,6 +6785,12 @@ class IpgRadioStyleParam:
     BorderWidth:float
     TextIpgColor:IpgColor
     TextRgbaColor:list[float, 4]
+    BackgroundIpgColorDisabled:IpgColor
+    BackgroundRgbaColorDisabled:list[float, 4]
+    DotIpgColorDisabled:IpgColor
+    DotRgbaColorDisabled:list[float, 4]
+    TextIpgColorDisabled:IpgColor
+    TextRgbaColorDisabled:list[float, 4]
 
 
 class IpgRowParam:
@@ -5247,6 +6856,8 @@ class IpgScrollableParam:
     If > 0.0 moves scroller right.
     VBarAlignment: IpgAlignment
         The vertical bar alignment.
+    Show: bool
+        Shows or hides the scrollable and its content.
         
     Examples
     --------
@@ -5267,6 +6878,7 @@ class IpgScrollableParam:
     VScrollerWidth: float
     VSpacing: float
     VBarAlignment: IpgAlignment
+    Show: bool
 
 
 class IpgScrollableStyleParam:
@@ -5441,6 +7053,8 @@ class IpgSliderParam:
         The height of the widget.
     StyleId: int
         The id of the add_slider_style() function.
+    Enabled: bool
+        Whether the widget responds to the user.
     Show: bool
         Whether to show or hide the widget.
     """
@@ -5452,6 +7066,7 @@ class IpgSliderParam:
     WidthFill: bool
     Height: float
     StyleId: int
+    Enabled: bool
     Show: bool
 
 
@@ -5471,6 +7086,10 @@ class IpgSliderStyleParam:
     HandleCircleRadius:float
     HandleRectangleWidth:int
     HandleRectangleBorderRadius:list[float]
+    RailIpgColorDisabled:IpgColor
+    RailRgbaColorDisabled:list[float, 4]
+    HandleIpgColorDisabled:IpgColor
+    HandleRgbaColorDisabled:list[float, 4]
     
     
 class IpgStackParam:
@@ -5484,6 +7103,52 @@ class IpgStackParam:
     """
     ShowStack: bool
 
+class IpgStreamPlotParam:
+    """
+    The StreamPlot parameters
+
+    Parameters
+    ----------
+    Capacity: int
+        The number of samples kept for each series, older samples are dropped,
+        from 1 to 1,000,000.
+    Clear: bool
+        If True, removes all the samples.
+    Height: float
+        The height of the plot.
+    HeightFill: bool
+        Whether the height fills the available space.
+    Show: bool
+        Whether to show the plot.
+    ShowLegend: bool
+        Whether to show the series names.
+    Title: str
+        The title of the plot.
+    Width: float
+        The width of the plot.
+    WidthFill: bool
+        Whether the width fills the available space.
+    XWindow: float | None
+        The span of the x axis, None shows all samples.
+    YMax: float | None
+        A fixed maximum of the y axis, None auto-fits.
+    YMin: float | None
+        A fixed minimum of the y axis, None auto-fits.
+    """
+    Capacity: int
+    Clear: bool
+    Height: float
+    HeightFill: bool
+    Show: bool
+    ShowLegend: bool
+    Title: str
+    Width: float
+    WidthFill: bool
+    XWindow: float
+    YMax: float
+    YMin: float
+
+
 class IpgStyleStandard:
     """
     Standard styles for widgets
@@ -5675,6 +7340,8 @@ class IpgTextInputParam:
         The height of the text box in pixels.
     LineHeightRelative: float
         The height of the text box relative to the default size.
+    Enabled: bool
+        Whether the widget responds to the user.
     StyleId: int
         The id of add_textinput_style() function.
     """
@@ -5686,6 +7353,7 @@ class IpgTextInputParam:
     Size:float
     LineHeightPixels:float
     LineHeightRelative:float
+    Enabled:bool
     StyleId:int
 
 
@@ -5754,14 +7422,34 @@ class IpgTextInputStyleParam:
 
 
 class IpgTimerParam:
+    """
+    Timer parameters
+
+    Parameters
+    ----------
+    CountdownMs: Optional[int]
+        The length of the countdown, None removes it.
+    Counter: int
+        The ticks so far, setting it moves the elapsed time to counter * duration_ms.
+    ElapsedMs: int
+        The time the timer has run, less the pauses.
+    Paused: bool
+        Pauses a running timer keeping its elapsed time, False resumes it.
+    RemainingMs: int
+        The time left of the countdown, None without one.
+    """
     DurationMs:int
     ArrowStyle:str
+    CountdownMs:int
     Counter:int
+    ElapsedMs:int
     Height:float
     HeightFill:bool
     Label:str
     Padding:list
+    Paused:bool
     Clip:bool
+    RemainingMs:int
     Show:bool
     StyleId:int
     StyleStandard:str
@@ -5851,6 +7539,8 @@ class IpgTogglerParam:
         String label for widget.
     LineHeight: float
         The height of the text box for the widget.
+    Enabled: bool
+        Whether the widget responds to the user.
     Show: bool
         Whether to show or hide the widget.
     Size: float
@@ -5865,6 +7555,7 @@ class IpgTogglerParam:
     Alignment: IpgAlignment
     Label: str
     LineHeight: float
+    Enabled: bool
     Show: bool
     Size: float
     TextSize: float
@@ -5887,6 +7578,7 @@ class IpgToolTipParam:
     Padding: list[float, 4]
     SnapWithinViewport: bool
     StyleId: int
+    Show: bool
     
     
 class IpgWindowParam:
@@ -5907,6 +7599,15 @@ class IpgWindowParam:
         Window theme.
     ScaleFactor: float=1.0
         Window scale factor
+    Icon: Union[bytes, str]
+        The window icon, the bytes of an image file, like a png, or its path.
+    MinSize: Optional[list[float, float]]
+        The minimum [width, height] of the window, None removes it.
+        A smaller window is resized.
+    MaxSize: Optional[list[float, float]]
+        The maximum [width, height] of the window, None removes it.
+        A larger window is resized.
+        The min and max sizes given to add_window are kept by the platform.
     '''
     Debug:bool
     Decorations:int
@@ -5916,6 +7617,9 @@ class IpgWindowParam:
     Size:tuple[int, float, float]
     Theme:IpgWindowTheme
     ScaleFactor:float
+    Icon:Union[bytes, str]
+    MinSize:Optional[list[float, float]]
+    MaxSize:Optional[list[float, float]]
     
 
 
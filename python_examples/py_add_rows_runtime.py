@@ -0,0 +1,69 @@
+from icedpygui import IPG, IpgColor
+
+
+ipg = IPG()
+
+# The data the rows are built from, a new row is added
+# for each entry while the session is running.
+data = ["Volume", "Bass", "Treble", "Balance"]
+count = 0
+
+
+def add_row(btn_id: int):
+    global count
+    if count >= len(data):
+        return
+    name = data[count]
+    count += 1
+
+    # A new style, container and widgets can all
+    # be added after the session has started.
+    style_id = ipg.add_checkbox_style(
+                    accent_color=IpgColor.GREEN,
+                    text_color=IpgColor.WHITE)
+
+    ipg.add_row(
+            window_id="main",
+            container_id=f"row_{name}",
+            parent_id="col")
+
+    ipg.add_text(
+            parent_id=f"row_{name}",
+            content=name,
+            width=80.0)
+
+    ipg.add_slider(
+            parent_id=f"row_{name}",
+            min=0.0,
+            max=100.0,
+            step=1.0,
+            value=50.0,
+            width=150.0)
+
+    ipg.add_checkbox(
+            parent_id=f"row_{name}",
+            label="Mute",
+            style_id=style_id)
+
+
+# Add the window
+ipg.add_window(
+            window_id="main",
+            title="Add Rows",
+            width=500,
+            height=400,
+            pos_centered=True)
+
+# Add a column to hold the rows
+ipg.add_column(
+            window_id="main",
+            container_id="col",
+            width_fill=True,
+            height_fill=True)
+
+ipg.add_button(
+            parent_id="col",
+            label="Add a Row",
+            on_press=add_row)
+
+ipg.start_session()
@@ -0,0 +1,56 @@
+from icedpygui import IPG, IpgProgressBarParam, IpgSliderParam
+from icedpygui import IpgTextInputParam, IpgTextParam
+
+
+ipg = IPG()
+
+# A shared value, the slider, text input, text and progress bar
+# are all kept in step with it without any callbacks.
+ipg.add_value("volume", 0.5)
+
+ipg.add_window(
+        window_id="main",
+        title="Bindings",
+        width=400,
+        height=300,
+        pos_centered=True)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+slider_id = ipg.add_slider(
+                parent_id="col",
+                min=0.0,
+                max=100.0,
+                step=1.0,
+                value=50.0,
+                width=250.0)
+
+input_id = ipg.add_text_input(
+                parent_id="col",
+                placeholder="0 to 100",
+                width=250.0)
+
+text_id = ipg.add_text(
+                parent_id="col",
+                content="")
+
+bar_id = ipg.add_progress_bar(
+                parent_id="col",
+                min=0.0,
+                max=100.0,
+                width=250.0)
+
+# The value goes from 0.0 to 1.0 so the widgets use a scale of 100,
+# the two way bindings change the value when they are used.
+ipg.bind(slider_id, IpgSliderParam.Value, "volume", two_way=True, scale=100.0)
+ipg.bind(input_id, IpgTextInputParam.Value, "volume", two_way=True,
+         format="{:.0}", scale=100.0)
+ipg.bind(text_id, IpgTextParam.Content, "volume", format="Volume {:.0}%", scale=100.0)
+ipg.bind(bar_id, IpgProgressBarParam.Value, "volume", scale=100.0)
+
+ipg.start_session()
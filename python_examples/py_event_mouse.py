@@ -28,6 +28,12 @@ def mouse_button_released(_mouse_id: int, _user_data: any):
     ipg.update_item(text_for_released, IpgTextParam.Content, f"Button released")
 
 
+# The double and triple clicks follow the press, the button is in the dict
+def mouse_clicked(_mouse_id: int, click: dict, _user_data: any):
+    ipg.update_item(text_for_pressed, IpgTextParam.Content, 
+                    f"{click.get('button')} button clicked more than once")
+
+
 # The scroll data is a dictionary
 def mouse_button_scrolled_line(_mouse_id: int, scroll: dict, _user_data: any):
     global scroll_total_line_y
@@ -46,6 +52,9 @@ ipg.add_event_mouse(enabled=True,
                     on_right_press=mouse_button_pressed,
                     on_right_release=mouse_button_released,
                     on_middle_scroll_line=mouse_button_scrolled_line,
+                    on_double_click=mouse_clicked,
+                    on_triple_click=mouse_clicked,
+                    click_interval_ms=400,
                     user_data="Some Data",
                     )
 
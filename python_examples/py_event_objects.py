@@ -0,0 +1,55 @@
+from icedpygui import IPG, IpgEvent, IpgTextParam
+
+
+# Every callback is given a single IpgEvent, whatever the widget.
+ipg = IPG(event_objects=True)
+
+
+def show_event(event: IpgEvent):
+    text = (f"{event.kind} from {event.widget_id} in {event.window_id}\n"
+            f"value: {event.value}\n"
+            f"modifiers: {event.modifiers}\n"
+            f"user data: {event.user_data}")
+    ipg.update_item(text_id, IpgTextParam.Content, text)
+
+
+ipg.add_window(
+        window_id="main",
+        title="Event Objects",
+        width=400,
+        height=400,
+        pos_centered=True)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+ipg.add_button(
+        parent_id="col",
+        label="Press with or without Shift",
+        on_press=show_event,
+        user_data="button")
+
+ipg.add_checkbox(
+        parent_id="col",
+        label="Check",
+        on_toggle=show_event)
+
+ipg.add_slider(
+        parent_id="col",
+        min=0.0,
+        max=100.0,
+        step=1.0,
+        value=50.0,
+        width=250.0,
+        on_change=show_event,
+        user_data={"units": "%"})
+
+text_id = ipg.add_text(
+                parent_id="col",
+                content="")
+
+ipg.start_session()
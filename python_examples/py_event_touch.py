@@ -0,0 +1,63 @@
+from icedpygui import IPG, IpgTextParam
+
+
+ipg = IPG()
+
+
+# The gestures need a touch screen.  The scale, degrees and
+# dx, dy are the totals since a finger was last pressed or lifted.
+def on_pinch(_event_id: int, gesture: dict):
+    ipg.update_item(pinch_id, IpgTextParam.Content,
+                    f"pinch: scale {gesture['scale']:.2f}")
+
+
+def on_rotate(_event_id: int, gesture: dict):
+    ipg.update_item(rotate_id, IpgTextParam.Content,
+                    f"rotate: {gesture['degrees']:.0f} degrees")
+
+
+def on_pan(_event_id: int, gesture: dict):
+    ipg.update_item(pan_id, IpgTextParam.Content,
+                    f"pan: {gesture['fingers']} finger(s) dx {gesture['dx']:.0f} dy {gesture['dy']:.0f}")
+
+
+def on_swipe(_event_id: int, gesture: dict):
+    ipg.update_item(swipe_id, IpgTextParam.Content,
+                    f"swipe: {gesture['direction']} at {gesture['speed']:.1f} px/ms")
+
+
+def on_long_press(_event_id: int, gesture: dict):
+    ipg.update_item(long_press_id, IpgTextParam.Content,
+                    f"long press at {gesture['x']:.0f}, {gesture['y']:.0f} in {gesture['window_id']}")
+
+
+ipg.add_event_touch(
+        enabled=True,
+        on_pinch=on_pinch,
+        on_rotate=on_rotate,
+        on_pan=on_pan,
+        on_swipe=on_swipe,
+        on_long_press=on_long_press,
+        long_press_ms=600)
+
+ipg.add_window(
+        window_id="main",
+        title="Touch Gestures",
+        width=400,
+        height=400,
+        pos_centered=True)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+pinch_id = ipg.add_text(parent_id="col", content="pinch:")
+rotate_id = ipg.add_text(parent_id="col", content="rotate:")
+pan_id = ipg.add_text(parent_id="col", content="pan:")
+swipe_id = ipg.add_text(parent_id="col", content="swipe:")
+long_press_id = ipg.add_text(parent_id="col", content="long press:")
+
+ipg.start_session()
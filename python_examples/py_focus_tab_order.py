@@ -0,0 +1,66 @@
+from icedpygui import IPG, IpgTextParam
+
+
+ipg = IPG()
+
+
+# A data entry form, Tab goes across the two columns
+# and Enter goes to the next field too.
+def on_submit(input_id: int, value: str):
+    index = order.index(input_id)
+    ipg.focus(order[(index + 1) % len(order)])
+
+
+def on_focus(input_id: int, value: str):
+    ipg.update_item(status_id, IpgTextParam.Content, f"editing {names[input_id]}")
+
+
+def on_blur(input_id: int, value: str):
+    ipg.update_item(status_id, IpgTextParam.Content, f"{names[input_id]} is '{value}'")
+
+
+ipg.add_window(
+        window_id="main",
+        title="Tab Order",
+        width=500,
+        height=300,
+        pos_centered=True)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+ipg.add_row(
+        window_id="main",
+        container_id="row",
+        parent_id="col")
+
+names = {}
+columns = [["first name", "last name", "email"], ["street", "city", "zip code"]]
+for i, column in enumerate(columns):
+    ipg.add_column(
+            window_id="main",
+            container_id=f"fields{i}",
+            parent_id="row")
+    for name in column:
+        input_id = ipg.add_text_input(
+                        parent_id=f"fields{i}",
+                        placeholder=name,
+                        width=200.0,
+                        on_submit=on_submit,
+                        on_focus=on_focus,
+                        on_blur=on_blur)
+        names[input_id] = name
+
+status_id = ipg.add_text(parent_id="col", content="")
+
+# Without an order, Tab would go down the first column and then the second one.
+ids = list(names.keys())
+order = [wid for pair in zip(ids[:3], ids[3:]) for wid in pair]
+ipg.set_tab_order("main", order)
+ipg.focus(order[0])
+
+ipg.start_session()
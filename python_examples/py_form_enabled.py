@@ -0,0 +1,67 @@
+import asyncio
+from icedpygui import IPG, IpgButtonParam, IpgCheckboxParam, IpgTextInputParam, IpgTextParam
+
+
+ipg = IPG()
+
+
+# The inputs are locked while the save is running
+# so the values can't change underneath it.  The batch
+# makes the inputs change together.
+def lock_form(enabled: bool):
+    with ipg.batch():
+        ipg.update_item(name_id, IpgTextInputParam.Enabled, enabled)
+        ipg.update_item(subscribe_id, IpgCheckboxParam.Enabled, enabled)
+        ipg.update_item(save_id, IpgButtonParam.Enabled, enabled)
+
+
+async def save():
+    # stands in for writing to a file or a database
+    await asyncio.sleep(2.0)
+    return "Saved"
+
+
+def on_save(btn_id: int):
+    lock_form(False)
+    ipg.update_item(status_id, IpgTextParam.Content, "Saving...")
+    ipg.run_task(save(), on_done=on_saved)
+
+
+def on_saved(task_id: int, result: str):
+    ipg.update_item(status_id, IpgTextParam.Content, result)
+    lock_form(True)
+
+
+ipg.add_window(
+        window_id="main",
+        title="Form",
+        width=400,
+        height=300,
+        pos_centered=True)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+name_id = ipg.add_text_input(
+                parent_id="col",
+                placeholder="Name",
+                width=250.0)
+
+subscribe_id = ipg.add_checkbox(
+                    parent_id="col",
+                    label="Subscribe")
+
+save_id = ipg.add_button(
+                parent_id="col",
+                label="Save",
+                on_press=on_save)
+
+status_id = ipg.add_text(
+                parent_id="col",
+                content="")
+
+ipg.start_session()
@@ -0,0 +1,76 @@
+from icedpygui import IPG, IpgTextParam, IpgTimerParam
+
+
+ipg = IPG()
+
+
+# Two timers running at the same time with their own durations,
+# the user_data is the id of the timer's text.
+def on_start(timer_id: int, text_id: int):
+    ipg.update_item(timer_id, IpgTimerParam.Label, "Stop")
+    ipg.update_item(timer_id, IpgTimerParam.Counter, 0)
+
+
+def on_stop(timer_id: int, counter: int, text_id: int):
+    ipg.update_item(timer_id, IpgTimerParam.Label, "Start")
+
+
+def on_tick(timer_id: int, counter: int, text_id: int):
+    ipg.update_item(text_id, IpgTextParam.Content, f"ticks: {counter}")
+
+
+# call_every and call_later need no widget.
+def on_clock(handle: int):
+    global seconds
+    seconds += 1
+    ipg.update_item(clock_id, IpgTextParam.Content, f"running for {seconds}s")
+
+
+def on_message(handle: int, message: str):
+    ipg.update_item(message_id, IpgTextParam.Content, message)
+
+
+def on_stop_clock(btn_id: int):
+    ipg.cancel_call(clock_handle)
+    ipg.update_item(message_id, IpgTextParam.Content, "clock stopped")
+
+
+ipg.add_window(
+        window_id="main",
+        title="Timers and Scheduler",
+        width=400,
+        height=400,
+        pos_centered=True)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+for duration in [100, 1000]:
+    ipg.add_text(parent_id="col", content=f"every {duration} ms")
+    text_id = ipg.add_text(parent_id="col", content="ticks: 0")
+    ipg.add_timer(
+            parent_id="col",
+            duration_ms=duration,
+            label="Start",
+            on_start=on_start,
+            on_stop=on_stop,
+            on_tick=on_tick,
+            user_data=text_id)
+
+seconds = 0
+clock_id = ipg.add_text(parent_id="col", content="")
+message_id = ipg.add_text(parent_id="col", content="")
+
+ipg.add_button(
+        parent_id="col",
+        label="Stop the clock",
+        on_press=on_stop_clock)
+
+clock_handle = ipg.call_every(1000, on_clock)
+ipg.call_later(3000, on_message, user_data="3 seconds have passed")
+
+ipg.start_session()
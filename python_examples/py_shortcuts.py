@@ -0,0 +1,65 @@
+from icedpygui import IPG, IpgTextParam, IpgStyleStandard
+
+
+ipg = IPG()
+
+
+# The shortcuts are matched without calling python,
+# the callback gets the shortcut id and its text.
+def on_shortcut(shortcut_id: int, text: str, action: str):
+    ipg.update_item(status_id, IpgTextParam.Content, f"{text}: {action}")
+
+
+def on_menu(btn_id: int, action: str):
+    ipg.update_item(status_id, IpgTextParam.Content, f"menu: {action}")
+
+
+def on_find(shortcut_id: int, text: str):
+    ipg.update_item(status_id, IpgTextParam.Content, f"{text}: find in the text input")
+
+
+ipg.add_window(
+        window_id="main",
+        title="Shortcuts",
+        width=400,
+        height=400,
+        pos_centered=True)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+ipg.add_menu(
+        window_id="main",
+        container_id="menu",
+        parent_id="col",
+        bar_items=1,
+        menu_items=[3],
+        item_widths=[200.0])
+
+ipg.add_button(parent_id="menu", label="File", style_standard=IpgStyleStandard.Text)
+
+# Mod is Ctrl, or Cmd on macOS, the menu shows which one
+for action, accelerator in [("open", "Mod+O"), ("save", "Mod+S"), ("save as", "Mod+Shift+S")]:
+    ipg.add_button(
+            parent_id="menu",
+            label=action,
+            accelerator=accelerator,
+            width_fill=True,
+            style_standard=IpgStyleStandard.Text,
+            on_press=on_menu,
+            user_data=action)
+    ipg.add_shortcut(accelerator, on_shortcut, user_data=action)
+
+# a chord, Ctrl+K followed by Ctrl+C
+ipg.add_shortcut("Ctrl+K Ctrl+C", on_shortcut, user_data="comment")
+
+input_id = ipg.add_text_input(parent_id="col", placeholder="Ctrl+F works here")
+ipg.add_shortcut("Ctrl+F", on_find, when_focused=input_id)
+
+status_id = ipg.add_text(parent_id="col", content="Type a shortcut")
+
+ipg.start_session()
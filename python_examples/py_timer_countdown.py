@@ -0,0 +1,64 @@
+from icedpygui import IPG, IpgTextParam, IpgTimerParam, IpgButtonParam
+
+
+ipg = IPG()
+
+
+# A 10 second countdown that can be paused and resumed,
+# the ticks come from the time run so pausing doesn't lose any time.
+def on_start(timer_id: int):
+    ipg.update_item(timer_id, IpgTimerParam.Label, "Stop")
+
+
+def on_stop(timer_id: int, counter: int):
+    ipg.update_item(timer_id, IpgTimerParam.Label, "Start")
+
+
+def on_tick(timer_id: int, counter: int):
+    remaining = ipg.get_item(timer_id, IpgTimerParam.RemainingMs)
+    ipg.update_item(text_id, IpgTextParam.Content, f"{remaining / 1000:.1f}s left")
+
+
+def on_finished(timer_id: int):
+    ipg.update_item(timer_id, IpgTimerParam.Label, "Start Over")
+    ipg.update_item(text_id, IpgTextParam.Content, "Done")
+
+
+def on_pause(btn_id: int):
+    paused = not ipg.get_item(timer_id, IpgTimerParam.Paused)
+    ipg.update_item(timer_id, IpgTimerParam.Paused, paused)
+    ipg.update_item(btn_id, IpgButtonParam.Label, "Resume" if paused else "Pause")
+
+
+ipg.add_window(
+        window_id="main",
+        title="Countdown",
+        width=300,
+        height=250,
+        pos_centered=True)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+text_id = ipg.add_text(parent_id="col", content="10.0s left")
+
+timer_id = ipg.add_timer(
+                parent_id="col",
+                duration_ms=100,
+                countdown_ms=10_000,
+                label="Start",
+                on_start=on_start,
+                on_stop=on_stop,
+                on_tick=on_tick,
+                on_finished=on_finished)
+
+ipg.add_button(
+        parent_id="col",
+        label="Pause",
+        on_press=on_pause)
+
+ipg.start_session()
@@ -0,0 +1,96 @@
+from icedpygui import IPG, IpgWindowMode
+
+
+ipg = IPG()
+
+changed = False
+
+
+def on_input(_input_id: int, _value: str):
+    global changed
+    changed = True
+
+
+# Returning False keeps the window open, the window
+# needs exit_on_close=False for the close to be requested.
+def on_close_requested(window_id: int, _name: str):
+    if window_id == main_id and changed:
+        ipg.open_window("dialog")
+        return False
+    return True
+
+
+def save(_btn_id: int):
+    print("saved")
+    quit_app()
+
+
+def discard(_btn_id: int):
+    quit_app()
+
+
+def cancel(_btn_id: int):
+    ipg.close_window("dialog")
+
+
+# The session ends once all of the windows are closed.
+def quit_app():
+    ipg.close_window("dialog")
+    ipg.close_window("main")
+
+
+ipg.add_event_window(
+        enabled=True,
+        on_close_requested=on_close_requested)
+
+main_id = ipg.add_window(
+                window_id="main",
+                title="Editor",
+                width=400,
+                height=300,
+                pos_centered=True,
+                exit_on_close=False)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+ipg.add_text(parent_id="col", content="Type something and close the window.")
+
+ipg.add_text_input(
+        parent_id="col",
+        placeholder="text",
+        on_input=on_input)
+
+# The dialog is added closed and opened when needed.
+ipg.add_window(
+        window_id="dialog",
+        title="Save Changes?",
+        width=300,
+        height=120,
+        pos_centered=True,
+        resizable=False,
+        mode=IpgWindowMode.Closed)
+
+ipg.add_column(
+        window_id="dialog",
+        container_id="dialog_col",
+        width_fill=True,
+        height_fill=True,
+        padding=[10.0])
+
+ipg.add_text(parent_id="dialog_col", content="Save the changes before closing?")
+
+ipg.add_row(
+        window_id="dialog",
+        container_id="buttons",
+        parent_id="dialog_col")
+
+ipg.add_button(parent_id="buttons", label="Save", on_press=save)
+ipg.add_button(parent_id="buttons", label="Don't Save", on_press=discard)
+ipg.add_button(parent_id="buttons", label="Cancel", on_press=cancel)
+
+ipg.start_session()
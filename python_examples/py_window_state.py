@@ -0,0 +1,52 @@
+from icedpygui import IPG, IpgWindowParam, IpgTextParam
+import os
+
+
+cwd = os.getcwd()
+
+# Move or resize the window and close it, the next run
+# opens it where it was left.
+ipg = IPG(window_state_file=cwd + "/python_examples/resources/window_state.json")
+
+ferris = cwd + "/python_examples/resources/ferris_0.png"
+with open(cwd + "/python_examples/resources/ferris_5.png", "rb") as f:
+    ferris_bytes = f.read()
+
+
+def change_icon(_btn_id: int):
+    ipg.update_item(wnd_id, IpgWindowParam.Icon, ferris_bytes)
+
+
+def limit_size(_btn_id: int):
+    ipg.update_item(wnd_id, IpgWindowParam.MinSize, [300.0, 200.0])
+    ipg.update_item(wnd_id, IpgWindowParam.MaxSize, [500.0, 400.0])
+    ipg.update_item(text_id, IpgTextParam.Content, "between 300x200 and 500x400")
+
+
+def remove_limits(_btn_id: int):
+    ipg.update_item(wnd_id, IpgWindowParam.MinSize, None)
+    ipg.update_item(wnd_id, IpgWindowParam.MaxSize, None)
+    ipg.update_item(text_id, IpgTextParam.Content, "any size")
+
+
+wnd_id = ipg.add_window(
+                window_id="main",
+                title="Window State",
+                width=400,
+                height=300,
+                pos_centered=True,
+                icon=ferris)
+
+ipg.add_column(
+        window_id="main",
+        container_id="col",
+        width_fill=True,
+        height_fill=True,
+        padding=[20.0])
+
+ipg.add_button(parent_id="col", label="Change the icon", on_press=change_icon)
+ipg.add_button(parent_id="col", label="Limit the size", on_press=limit_size)
+ipg.add_button(parent_id="col", label="Remove the limits", on_press=remove_limits)
+text_id = ipg.add_text(parent_id="col", content="any size")
+
+ipg.start_session()
@@ -3,20 +3,25 @@
 #![allow(clippy::map_clone)]
 use std::collections::HashMap;
 use std::mem;
+use std::sync::atomic::Ordering;
 
 use iced::widget::container::Id;
 use iced::widget::scrollable::Viewport;
 use iced::window::Position;
-use iced::{font, window, Size};
+use iced::{font, keyboard, mouse, touch, window, Size};
 use iced::event::{Event, Status};
 use iced::{Element, Point, Subscription, Task, Theme};
 use iced::widget::{scrollable, Space};
 use iced::executor;
-use iced::widget::{focus_next, horizontal_space, Canvas, Column};
+use iced::futures::channel::mpsc;
+use iced::futures::{SinkExt, Stream, StreamExt};
+use iced::widget::{horizontal_space, Canvas, Column};
 use iced::time;
 use iced::Color;
 use once_cell::sync::Lazy;
 use polars::frame::DataFrame;
+use pyo3::{PyObject, PyResult};
+use pyo3_polars::PyDataFrame;
 
 
 use crate::canvas::draw_canvas::IpgCanvasState;
@@ -30,18 +35,30 @@ use crate::ipg_widgets::ipg_table::{table_callback, TableMessage};
 use crate::ipg_widgets::ipg_timer_canvas::{canvas_tick_callback, 
     canvas_timer_callback, construct_canvas_timer, CanvasTimerMessage};
 use crate::ipg_widgets::ipg_tool_tip;
-use crate::{access_canvas_state, access_canvas_update_items, access_user_data2, access_update_items, access_user_data1, access_window_actions, ipg_widgets, match_container, match_container_for_df, match_widget, set_state_of_widget_running_state, IpgState};
+use crate::{access_canvas_state, access_canvas_update_items, access_user_data2, access_update_items, access_user_data1, access_window_actions, find_parent_uid, get_subtree_ids, ipg_widgets, match_container, match_container_for_df, match_container_items, match_widget, match_widget_for_df, match_widget_items, mirror_item, mirror_state, remove_callbacks_and_user_data, remove_mirror_item, IpgState, WAKE_UP, WAKE_UP_PENDING};
+use ipg_widgets::ipg_async::process_task_results;
+use ipg_widgets::ipg_bindings::{access_bindings, item_to_py};
+use ipg_widgets::callbacks::{event_objects, set_event_modifiers, set_event_window};
+use ipg_widgets::ipg_scheduler::{process_scheduled_call, scheduled_calls};
+use ipg_widgets::ipg_shortcuts::{has_shortcuts, match_key_stroke, process_shortcut, IpgKeyStroke};
+use ipg_widgets::ipg_focus::{find_focused, focus_id, focus_text_input, next_in_tab_order, tab_order, watching_focus};
 use ipg_widgets::ipg_button::{BTNMessage, construct_button, button_callback};
-use ipg_widgets::ipg_canvas::{canvas_callback, construct_canvas, CanvasMessage};
+use ipg_widgets::ipg_canvas::{canvas_callback, canvas_long_press, construct_canvas, CanvasMessage};
 use ipg_widgets::ipg_card::{CardMessage, construct_card, card_callback};
+use ipg_widgets::ipg_chart::construct_chart;
 use ipg_widgets::ipg_checkbox::{CHKMessage, construct_checkbox, checkbox_callback};
 use ipg_widgets::ipg_column::construct_column;
 use ipg_widgets::ipg_container::construct_container;
 use ipg_widgets::ipg_date_picker::{DPMessage, construct_date_picker, date_picker_update};
 use ipg_widgets::ipg_enums::{IpgContainers, IpgWidgets};
-use ipg_widgets::ipg_events::{IpgKeyBoardEvent, process_keyboard_events, 
-    process_mouse_events, process_touch_events, process_window_event};
-use ipg_widgets::helpers::find_key_for_value;
+use ipg_widgets::ipg_errors::{report_error, IpgIdError, IpgParamError};
+use ipg_widgets::ipg_events::{IpgKeyBoardEvent, IpgMouseInput, process_keyboard_events, 
+    process_long_press, process_mouse_events, process_touch_events, process_window_event};
+use ipg_widgets::ipg_gestures::IpgGestures;
+use ipg_widgets::helpers::{find_key_for_value, IpgItemValue};
+use ipg_widgets::ipg_gauge::{animate_gauges, construct_gauge, construct_knob, 
+    construct_meter, gauges_animating, knob_callback, KnobMessage};
+use ipg_widgets::ipg_heatmap::{construct_heatmap, heatmap_callback, HeatmapMessage};
 use ipg_widgets::ipg_image::{ImageMessage, construct_image, image_callback};
 use ipg_widgets::ipg_menu::construct_menu;
 // use ipg_widgets::ipg_modal::{construct_modal, modal_callback, ModalMessage};
@@ -59,14 +76,16 @@ use ipg_widgets::ipg_selectable_text::{SLTXTMessage, construct_selectable_text,
 use ipg_widgets::ipg_slider::{SLMessage, construct_slider, slider_callback};
 use ipg_widgets::ipg_space::construct_space;
 use ipg_widgets::ipg_stack::construct_stack;
+use ipg_widgets::ipg_stream_plot::{construct_stream_plot, process_stream_samples};
 use ipg_widgets::ipg_svg::{SvgMessage, construct_svg, svg_callback};
 use ipg_widgets::ipg_table::{construct_table};
 use ipg_widgets::ipg_text::construct_text;
-use ipg_widgets::ipg_text_input::{TIMessage, construct_text_input, text_input_callback};
+use ipg_widgets::ipg_text_input::{TIMessage, construct_text_input, text_input_callback, text_input_focus_callback};
 use ipg_widgets::ipg_timer::{construct_timer, timer_callback, TIMMessage, tick_callback};
 use ipg_widgets::ipg_toggle::{construct_toggler, toggle_callback, TOGMessage};
 use ipg_widgets::ipg_tool_tip::construct_tool_tip;
-use ipg_widgets::ipg_window::{WndMessage, IpgWindow, add_windows, construct_window};
+use ipg_widgets::ipg_window::{WndMessage, IpgWindow, add_windows, all_windows_hidden, construct_window, 
+    get_iced_mode, hide_window, open_window, set_window_mode};
 use ipg_widgets::ipg_window::IpgWindowMode;
 use crate::{access_state, IpgIds};
 
@@ -81,11 +100,15 @@ pub enum Message {
     ColorPicker(usize, ColPikMessage),
     DatePicker(usize, DPMessage),
     Divider(usize, DivMessage),
-    EventKeyboard(Event),
-    EventMouse(Event),
+    EventKeyboard(window::Id, Event),
+    EventMouse(window::Id, Event, Status),
     EventWindow((window::Id, Event)),
-    EventTouch(Event),
+    EventTouch(window::Id, Event),
+    LongPressTick,
+    GaugeFrame(time::Instant),
+    Heatmap(usize, HeatmapMessage),
     Image(usize, ImageMessage),
+    Knob(usize, KnobMessage),
     // Modal(usize, ModalMessage),
     PickList(usize, PLMessage),
     Radio(usize, RDMessage),
@@ -101,8 +124,9 @@ pub enum Message {
     TextInput(usize, TIMessage),
     Toggler(usize, TOGMessage),
     CanvasTextBlink,
-    Tick,
-    CanvasTick,
+    Tick(usize),
+    CanvasTick(usize),
+    ScheduledCall(usize),
     Timer(usize, TIMMessage),
     CanvasTimer(usize, CanvasTimerMessage),
     FontLoaded(Result<(), font::Error>),
@@ -119,6 +143,17 @@ pub enum Message {
     MouseAreaOnExit(usize),
 
     OpaqueOnPress(usize),
+    ModifiersChanged(keyboard::Modifiers),
+    ShortcutKey(window::Id, IpgKeyStroke),
+    // the shortcuts matched and the widget focused when the key was pressed
+    ShortcutFocused(window::Id, Vec<(usize, Option<usize>)>, Vec<iced::advanced::widget::Id>),
+    // Tab or Shift+Tab, then the widget focused when it was pressed
+    TabKey(window::Id, bool),
+    TabFocused(window::Id, bool, Vec<iced::advanced::widget::Id>),
+    FocusCheck(window::Id),
+    FocusChanged(window::Id, Vec<iced::advanced::widget::Id>),
+
+    WakeUp,
 }
 
 
@@ -136,7 +171,15 @@ impl App {
         let mut canvas_state = IpgCanvasState::default();
         clone_canvas_state(&mut canvas_state);
         
+        // the widgets start with the values they are bound to
+        let bound_values = mem::take(&mut access_update_items().bound_values);
+        for name in bound_values.iter() {
+            apply_bound_value(&mut state, &mut canvas_state, name, None);
+        }
+
         let mut open = add_windows(&mut state);
+        state.focus_on_open = access_window_actions().focus.take();
+        mirror_state(&state);
         open.push(font::load(include_bytes!("./graphics/fonts/bootstrap-icons.ttf").as_slice()).map(Message::FontLoaded));
 
         (
@@ -151,18 +194,33 @@ impl App {
 
     pub fn title(&self, iced_window_id: window::Id) -> String {
         
-        let ipg_window_id = match self.state.windows_iced_ipg_ids.get(&iced_window_id) {
-            Some(id) => *id,
-            None => panic!("App: title, Unable to find ipg_window_id based on iced_window_id {:?}.", iced_window_id)
-        };
-        
-        let window_opt = self.state.containers.get(&ipg_window_id);
-        let ipg_window = get_window_container(window_opt);
-
-        ipg_window.title.clone()
+        match find_window(&self.state, iced_window_id, "title") {
+            Some(ipg_window) => ipg_window.title.clone(),
+            None => String::new(),
+        }
     }
 
     pub fn update(&mut self, message: Message) -> Task<Message> {
+        let id = message_id(&message);
+        if event_objects() {
+            set_event_window(message_window(&self.state, &message));
+        }
+        // a disabled widget can't change its value or call back
+        if let Some(id) = id {
+            if !widget_enabled(&self.state, id) {
+                return Task::none()
+            }
+        }
+        let task = self.update_message(message);
+        // the callbacks mirror the widgets they change, the
+        // bound widgets are mirrored when the value is applied
+        if let Some(id) = id {
+            sync_bindings(&mut self.state, &mut self.canvas_state, id);
+        }
+        task
+    }
+
+    fn update_message(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::FontLoaded(_) => {
                 Task::none()
@@ -202,31 +260,60 @@ impl App {
                 process_updates(&mut self.state, &mut self.canvas_state);
                 Task::none()
             },
-            Message::EventKeyboard(event) => {
-                process_keyboard_events(event, self.state.keyboard_event_id_enabled.0);
+            Message::EventKeyboard(window_id, event) => {
+                let window_str_id = iced_window_str_id(&self.state, window_id).unwrap_or_default();
+                process_keyboard_events(event, self.state.keyboard_event_id_enabled.0, window_str_id);
                 process_updates(&mut self.state, &mut self.canvas_state);
                 Task::none()
             },
-            Message::EventMouse(event) => {
-                process_mouse_events(event, self.state.mouse_event_id_enabled.0);
+            Message::EventMouse(window_id, event, status) => {
+                let window_str_id = iced_window_str_id(&self.state, window_id).unwrap_or_default();
+                process_mouse_events(&mut self.state.mouse_input,
+                                    self.state.mouse_event_id_enabled.0,
+                                    event,
+                                    status,
+                                    window_id,
+                                    window_str_id);
                 process_updates(&mut self.state, &mut self.canvas_state);
                 Task::none()
             },
             Message::EventWindow((window_id, event)) => {
                 process_window_event(&mut self.state, event, window_id);
                 process_updates(&mut self.state, &mut self.canvas_state);
-                if self.state.windows_opened.len() == self.state.windows_hidden.len() {
+                if all_windows_hidden(&self.state) {
                     iced::exit()
                 } else {
                     // check for any other window changes
                     get_tasks(&mut self.state)
                 }
             },
-            Message::WindowOpened(_, _, _) => {
+            Message::WindowOpened(window_id, _, _) => {
+                match self.state.focus_on_open {
+                    Some(id) if widget_window(&self.state, id) == Some(window_id) => {
+                        self.state.focus_on_open = None;
+                        focus_text_input(id)
+                            .chain(find_focused().map(move |focused| Message::FocusChanged(window_id, focused)))
+                    },
+                    _ => Task::none(),
+                }
+            },
+            Message::EventTouch(window_id, event) => {
+                let window_str_id = iced_window_str_id(&self.state, window_id).unwrap_or_default();
+                self.state.touch_window_str_id = window_str_id.clone();
+                process_touch_events(&mut self.state.touch_gestures,
+                                    self.state.touch_event_id_enabled.0,
+                                    event,
+                                    window_str_id);
+                process_updates(&mut self.state, &mut self.canvas_state);
                 Task::none()
             },
-            Message::EventTouch(event) => {
-                process_touch_events(event, self.state.touch_event_id_enabled.0);
+            Message::LongPressTick => {
+                if self.state.touch_event_id_enabled.1 {
+                    process_long_press(&mut self.state.touch_gestures,
+                                        self.state.touch_event_id_enabled.0,
+                                        self.state.touch_window_str_id.clone());
+                }
+                canvas_long_press(&mut self.canvas_state);
                 process_updates(&mut self.state, &mut self.canvas_state);
                 Task::none()
             },
@@ -310,6 +397,20 @@ impl App {
                 process_updates(&mut self.state, &mut self.canvas_state);
                 Task::none()
             },
+            Message::GaugeFrame(now) => {
+                animate_gauges(&mut self.state, now);
+                Task::none()
+            },
+            Message::Heatmap(id, message) => {
+                heatmap_callback(id, message);
+                process_updates(&mut self.state, &mut self.canvas_state);
+                Task::none()
+            },
+            Message::Knob(id, message) => {
+                knob_callback(&mut self.state, id, message);
+                process_updates(&mut self.state, &mut self.canvas_state);
+                Task::none()
+            },
             Message::Svg(id, message) => {
                 svg_callback(&mut self.state, id, message);
                 process_updates(&mut self.state, &mut self.canvas_state);
@@ -351,39 +452,127 @@ impl App {
                 self.canvas_state.request_text_redraw();
                 Task::none()
             },
-            Message::Tick => {
-                tick_callback(&mut self.state);
+            Message::Tick(id) => {
+                if tick_callback(&mut self.state, id) {
+                    self.state.running_timers.remove(&id);
+                }
                 process_updates(&mut self.state, &mut self.canvas_state);
                 Task::none()
             },
-            Message::CanvasTick => {
-                canvas_tick_callback(&mut self.state);
+            Message::CanvasTick(id) => {
+                canvas_tick_callback(&mut self.state, id);
+                process_stream_samples(&mut self.state);
                 process_canvas_updates(&mut self.canvas_state);
                 process_updates(&mut self.state, &mut self.canvas_state); 
                 self.canvas_state.request_image_redraw();
                 Task::none()
             },
             Message::Timer(id, _) => {
-                self.state.timer_event_id_enabled.1 = !self.state.timer_event_id_enabled.1;
-                self.state.timer_event_id_enabled.0 = id;
-                let started = self.state.timer_event_id_enabled.1;
-                self.state.timer_duration = timer_callback(&mut self.state, id, started);
+                let started = !self.state.running_timers.contains(&id);
+                timer_callback(&mut self.state, id, started);
+                if started {
+                    self.state.running_timers.insert(id);
+                } else {
+                    self.state.running_timers.remove(&id);
+                }
                 process_updates(&mut self.state, &mut self.canvas_state);    
                 Task::none()
             },
             Message::CanvasTimer(id, message) => {
-                self.state.canvas_timer_event_id_enabled.1 = !self.state.canvas_timer_event_id_enabled.1;
-                self.state.canvas_timer_event_id_enabled.0 = id;
-                let started = self.state.canvas_timer_event_id_enabled.1;
-                self.state.canvas_timer_duration = canvas_timer_callback(&mut self.state, id, started);
+                let started = !self.state.running_canvas_timers.contains_key(&id);
+                let duration = canvas_timer_callback(&mut self.state, id, started);
+                if started {
+                    self.state.running_canvas_timers.insert(id, duration);
+                } else {
+                    self.state.running_canvas_timers.remove(&id);
+                }
                 process_updates(&mut self.state, &mut self.canvas_state);    
                 Task::none()
             },
+            Message::ScheduledCall(handle) => {
+                process_scheduled_call(handle);
+                process_updates(&mut self.state, &mut self.canvas_state);
+                get_tasks(&mut self.state)
+            },
             Message::Toggler(id, message) => {
                 toggle_callback(&mut self.state, id, message);
                 process_updates(&mut self.state, &mut self.canvas_state);
                 get_tasks(&mut self.state)
             },
+            Message::ModifiersChanged(modifiers) => {
+                set_event_modifiers(modifiers);
+                Task::none()
+            },
+            Message::ShortcutKey(window_id, stroke) => {
+                let window = iced_window_str_id(&self.state, window_id);
+                let matched = match_key_stroke(window.as_deref(), stroke);
+                // the focused widget is only looked for when it's needed
+                if matched.iter().any(|(_, when_focused)| when_focused.is_some()) {
+                    return find_focused()
+                        .map(move |focused| Message::ShortcutFocused(window_id, matched.clone(), focused))
+                }
+                if let Some((id, _)) = matched.first() {
+                    process_shortcut(*id);
+                }
+                process_updates(&mut self.state, &mut self.canvas_state);
+                get_tasks(&mut self.state)
+            },
+            Message::ShortcutFocused(window_id, matched, focused) => {
+                let focused = focused_in_window(&self.state, window_id, focused);
+                let shortcut = matched.iter()
+                    .find(|(_, when_focused)| 
+                        when_focused.is_none() || when_focused.map(focus_id) == focused);
+                if let Some((id, _)) = shortcut {
+                    process_shortcut(*id);
+                }
+                process_updates(&mut self.state, &mut self.canvas_state);
+                get_tasks(&mut self.state)
+            },
+            Message::TabKey(window_id, backward) => {
+                find_focused().map(move |focused| Message::TabFocused(window_id, backward, focused))
+            },
+            Message::TabFocused(window_id, backward, focused) => {
+                let focused = focused_in_window(&self.state, window_id, focused)
+                    .and_then(|focused| focused_text_input(&self.state, &focused));
+                // without an order, the focus goes through the text inputs of the window in the order they were added
+                let order = iced_window_str_id(&self.state, window_id)
+                    .and_then(|window_str_id| tab_order(&window_str_id))
+                    .unwrap_or_else(|| window_text_inputs(&self.state, window_id));
+                match next_in_tab_order(&order, focused, backward, |id| can_focus(&self.state, id)) {
+                    Some(id) => focus_text_input(id)
+                        .chain(find_focused().map(move |focused| Message::FocusChanged(window_id, focused))),
+                    None => Task::none(),
+                }
+            },
+            Message::FocusCheck(window_id) => {
+                find_focused().map(move |focused| Message::FocusChanged(window_id, focused))
+            },
+            Message::FocusChanged(window_id, focused) => {
+                let focused = focused_in_window(&self.state, window_id, focused)
+                    .and_then(|focused| focused_text_input(&self.state, &focused));
+                if focused != self.state.focused {
+                    let blurred = mem::replace(&mut self.state.focused, focused);
+                    if let Some(id) = blurred {
+                        text_input_focus_callback(&self.state, id, false);
+                    }
+                    if let Some(id) = focused {
+                        text_input_focus_callback(&self.state, id, true);
+                    }
+                    process_updates(&mut self.state, &mut self.canvas_state);
+                }
+                get_tasks(&mut self.state)
+            },
+            Message::WakeUp => {
+                // cleared first so that an update queued while
+                // processing sends another wake up
+                WAKE_UP_PENDING.store(false, Ordering::Release);
+                process_task_results();
+                process_stream_samples(&mut self.state);
+                process_canvas_updates(&mut self.canvas_state);
+                process_updates(&mut self.state, &mut self.canvas_state);
+                self.canvas_state.request_image_redraw();
+                get_tasks(&mut self.state)
+            },
         }
         
     }
@@ -408,23 +597,127 @@ impl App {
 
         let mut subscriptions = vec![];
         
-        if self.state.timer_event_id_enabled.1 {
+        // each timer and scheduled call is keyed by its id so
+        // any number of them can run with their own durations
+        for id in self.state.running_timers.iter() {
+            // a paused timer keeps its place but doesn't tick
+            let tim = match self.state.widgets.get(id) {
+                Some(IpgWidgets::IpgTimer(tim)) if !tim.paused => tim,
+                _ => continue,
+            };
+            subscriptions
+            .push(time::every(iced::time::Duration::from_millis(tim.duration_ms.max(1)))
+                .with(*id)
+                .map(|(id, _)| Message::Tick(id)));
+            // the end of a countdown doesn't wait for the next tick
+            if let Some(finish_in_ms) = tim.finish_in_ms {
+                subscriptions
+                .push(time::every(iced::time::Duration::from_millis(finish_in_ms))
+                    .with((*id, finish_in_ms))
+                    .map(|((id, _), _)| Message::Tick(id)));
+            }
+        }
+        for (id, duration) in self.state.running_canvas_timers.iter() {
             subscriptions
-            .push(time::every(iced::time::Duration::from_millis(
-                self.state.timer_duration)).map(|_| Message::Tick));
+            .push(time::every(iced::time::Duration::from_millis(*duration))
+                .with(*id)
+                .map(|(id, _)| Message::CanvasTick(id)));
         }
-        if self.state.canvas_timer_event_id_enabled.1 {
+        for (handle, interval) in scheduled_calls() {
             subscriptions
-            .push(time::every(iced::time::Duration::from_millis(
-                self.state.canvas_timer_duration)).map(|_| Message::CanvasTick));
+            .push(time::every(iced::time::Duration::from_millis(interval))
+                .with(handle)
+                .map(|(handle, _)| Message::ScheduledCall(handle)));
         }
         
         if self.state.keyboard_event_id_enabled.1 {
-            subscriptions.push(iced::event::listen().map(Message::EventKeyboard));
+            subscriptions.push(iced::event::listen_with(|event, status, window| {
+                match (&event, status) {
+                    (Event::Keyboard(_), Status::Ignored) => Some(Message::EventKeyboard(window, event)),
+                    _ => None,
+                }
+            }));
         }
-
+        // the captured events and the modifiers are also needed
+        // to follow the cursor and modifiers for the mouse events
         if self.state.mouse_event_id_enabled.1 {
-            subscriptions.push(iced::event::listen().map(Message::EventMouse));
+            subscriptions.push(iced::event::listen_with(|event, status, window| {
+                match event {
+                    Event::Mouse(_) |
+                    Event::Keyboard(keyboard::Event::ModifiersChanged(_)) => 
+                        Some(Message::EventMouse(window, event, status)),
+                    _ => None,
+                }
+            }));
+        }
+        if self.state.touch_event_id_enabled.1 {
+            subscriptions.push(iced::event::listen_with(|event, _status, window| {
+                match event {
+                    Event::Touch(_) => Some(Message::EventTouch(window, event)),
+                    _ => None,
+                }
+            }));
+        }
+        // a finger held still is checked until it's a long press
+        let touch_pending = self.state.touch_event_id_enabled.1 && 
+                                self.state.touch_gestures.long_press_pending();
+        if touch_pending || self.canvas_state.gestures.long_press_pending() {
+            subscriptions.push(time::every(iced::time::Duration::from_millis(50))
+                .map(|_| Message::LongPressTick));
+        }
+        // Tab is left alone by the text inputs, a captured one was used by another widget
+        subscriptions.push(iced::event::listen_with(|event, status, window| {
+            match event {
+                Event::Keyboard(keyboard::Event::KeyPressed { 
+                    key: keyboard::Key::Named(keyboard::key::Named::Tab), modifiers, .. }) 
+                    if status == Status::Ignored && 
+                        !(modifiers.control() || modifiers.alt() || modifiers.logo()) => 
+                        Some(Message::TabKey(window, modifiers.shift())),
+                _ => None,
+            }
+        }));
+        // a click, touch or escape can move the focus off of a text input
+        if watching_focus() {
+            subscriptions.push(iced::event::listen_with(|event, _status, window| {
+                match event {
+                    Event::Mouse(mouse::Event::ButtonPressed(_)) |
+                    Event::Touch(touch::Event::FingerPressed { .. }) |
+                    Event::Keyboard(keyboard::Event::KeyPressed { 
+                        key: keyboard::Key::Named(keyboard::key::Named::Escape), .. }) => 
+                            Some(Message::FocusCheck(window)),
+                    _ => None,
+                }
+            }));
+        }
+        // the IpgEvent has the modifiers being held
+        if event_objects() {
+            subscriptions.push(iced::event::listen_with(|event, _status, _window| {
+                match event {
+                    Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => 
+                        Some(Message::ModifiersChanged(modifiers)),
+                    _ => None,
+                }
+            }));
+        }
+        // a key typed into a text input is only a shortcut with Ctrl, Alt or Logo
+        if has_shortcuts() {
+            subscriptions.push(iced::event::listen_with(|event, status, window| {
+                match event {
+                    Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
+                        let command = modifiers.control() || modifiers.alt() || modifiers.logo();
+                        if status == Status::Captured && !command {
+                            return None
+                        }
+                        IpgKeyStroke::from_key(key.as_ref(), modifiers)
+                            .map(|stroke| Message::ShortcutKey(window, stroke))
+                    },
+                    _ => None,
+                }
+            }));
+        }
+        // frames are only needed while a gauge needle is moving
+        if gauges_animating(&self.state) {
+            subscriptions.push(window::frames().map(Message::GaugeFrame));
         }
         if self.canvas_state.timer_event_enabled {
             subscriptions.push(time::every(
@@ -440,6 +733,9 @@ impl App {
 
         subscriptions.push(w_event);
 
+        // wakes the runtime when updates are queued from another thread
+        subscriptions.push(Subscription::run(wake_up_worker));
+
         if !subscriptions.is_empty() {
             Subscription::batch(subscriptions)
         }
@@ -450,30 +746,18 @@ impl App {
 
     pub fn theme(&self, iced_window_id: window::Id) -> Theme {
 
-        let ipg_window_id_opt = self.state.windows_iced_ipg_ids.get(&iced_window_id);
-        let ipg_window_id = match ipg_window_id_opt {
-            Some(id) => *id,
-            None => panic!("App: theme, Unable to find ipg_window_id based on iced_window_id {:?}.", iced_window_id)
-        };
-        
-        let window_opt = self.state.containers.get(&ipg_window_id);
-        let ipg_window = get_window_container(window_opt);
-
-        ipg_window.theme.clone()
+        match find_window(&self.state, iced_window_id, "theme") {
+            Some(ipg_window) => ipg_window.theme.clone(),
+            None => Theme::Dark,
+        }
     }
 
     pub fn scale_factor(&self, iced_window_id: window::Id) -> f64 {
 
-        let ipg_window_id_opt = self.state.windows_iced_ipg_ids.get(&iced_window_id);
-        let ipg_window_id = match ipg_window_id_opt {
-            Some(id) => *id,
-            None => panic!("App: title, Unable to find ipg_window_id based on iced_window_id {:?}.", iced_window_id)
-        };
-        
-        let window_opt = self.state.containers.get(&ipg_window_id);
-        let ipg_window = get_window_container(window_opt);
-
-        ipg_window.scale_factor
+        match find_window(&self.state, iced_window_id, "scale_factor") {
+            Some(ipg_window) => ipg_window.scale_factor,
+            None => 1.0,
+        }
     }
 
 }
@@ -481,19 +765,10 @@ impl App {
 
 fn get_window_values(iced_window_id: window::Id, state: &IpgState) -> (bool, Theme) {
 
-    let ipg_window_id_opt = state.windows_iced_ipg_ids.get(&iced_window_id);
-    let ipg_window_id = match ipg_window_id_opt {
-        Some(id) => *id,
-        None => panic!("App: get_window_values, Unable to find ipg_window_id based on iced_window_id {:?}.", iced_window_id)
-    };
-    
-    let window_opt = state.containers.get(&ipg_window_id);
-    let ipg_window = get_window_container(window_opt);
-
-    let debug = ipg_window.debug;
-    let theme = ipg_window.theme.clone();
-
-    (debug, theme)
+    match find_window(state, iced_window_id, "get_window_values") {
+        Some(ipg_window) => (ipg_window.debug, ipg_window.theme.clone()),
+        None => (false, Theme::Dark),
+    }
 }
 
 fn get_tasks(ipg_state: &mut IpgState) -> Task<Message> {
@@ -502,6 +777,38 @@ fn get_tasks(ipg_state: &mut IpgState) -> Task<Message> {
 
     let mut actions = vec![];
 
+    // closed the same as an accepted close request, the window is hidden
+    let close = mem::take(&mut state.close);
+    for ipg_id in close.iter() {
+        if let Some(iced_id) = iced_window_id(ipg_state, *ipg_id) {
+            hide_window(ipg_state, &mut state, *ipg_id, iced_id);
+        }
+    }
+    if !close.is_empty() && all_windows_hidden(ipg_state) {
+        return iced::exit()
+    }
+
+    let open = mem::take(&mut state.open);
+    for ipg_id in open {
+        let mut wnd = match ipg_state.containers.get(&ipg_id) {
+            Some(IpgContainers::IpgWindow(wnd)) => wnd.clone(),
+            _ => continue,
+        };
+        if wnd.mode == IpgWindowMode::Closed {
+            wnd.mode = IpgWindowMode::Windowed;
+        }
+        match iced_window_id(ipg_state, ipg_id) {
+            Some(iced_id) => {
+                ipg_state.windows_hidden.retain(|id| *id != iced_id);
+                state.mode.push((ipg_id, get_iced_mode(&wnd.mode)));
+                actions.push(window::gain_focus(iced_id));
+            },
+            // the window was closed by the user
+            None => actions.push(open_window(ipg_state, &wnd, true)),
+        }
+        set_window_mode(ipg_state, ipg_id, wnd.mode);
+    }
+
     for (ipg_id, mode) in state.mode.iter() {
         let iced_id = find_key_for_value(ipg_state.windows_iced_ipg_ids.clone(), *ipg_id);
         actions.push(window::change_mode(iced_id, *mode));
@@ -514,13 +821,21 @@ fn get_tasks(ipg_state: &mut IpgState) -> Task<Message> {
     }
     state.decorations = vec![];
 
+    // a closed window is opened with its last size
     for (id, width, height) in state.resize.iter() {
-        let iced_id = find_key_for_value(ipg_state.windows_iced_ipg_ids.clone(), *id);
-        let size = Size::new(*width, *height);
-        actions.push(window::resize(iced_id, size))
+        if let Some(iced_id) = iced_window_id(ipg_state, *id) {
+            let size = Size::new(*width, *height);
+            actions.push(window::resize(iced_id, size))
+        }
     }
     state.resize = vec![];
 
+    for (id, icon) in state.icon.drain(..) {
+        if let Some(iced_id) = iced_window_id(ipg_state, id) {
+            actions.push(window::change_icon(iced_id, icon))
+        }
+    }
+
     for (id, x, y) in state.position.iter() {
         let iced_id = find_key_for_value(ipg_state.windows_iced_ipg_ids.clone(), *id);
         let point = Point::new(*x, *y);
@@ -534,8 +849,20 @@ fn get_tasks(ipg_state: &mut IpgState) -> Task<Message> {
     }
     state.level = vec![];
 
+    let focus = state.focus.take();
+
     drop(state);
 
+    // the focus is checked after it's changed for the on_focus and on_blur
+    if let Some(id) = focus {
+        let task = match widget_window(ipg_state, id) {
+            Some(window_id) => focus_text_input(id)
+                .chain(find_focused().map(move |focused| Message::FocusChanged(window_id, focused))),
+            None => focus_text_input(id),
+        };
+        actions.push(task);
+    }
+
     if actions.is_empty() {
         actions.push(Task::none());
     }
@@ -551,7 +878,10 @@ fn create_content<'a>(iced_id: window::Id, state: &'a IpgState, canvas_state: &'
 
     let ipg_window_id = match ipg_window_id_opt {
         Some(id) => id,
-        None => panic!("App::create_content: Unable to find ipg_window_id with iced_id {:?}.", iced_id),
+        None => {
+            report_error(IpgIdError::new_err(format!("App::create_content: Unable to find ipg_window_id with iced_id {:?}.", iced_id)));
+            return Column::new().into()
+        },
     };
 
     // First we find the unique containers in the window
@@ -566,7 +896,7 @@ fn create_content<'a>(iced_id: window::Id, state: &'a IpgState, canvas_state: &'
                                                                 &unique_parent_ids,
                                                                 state,
                                                                 canvas_state,);
-    content
+    content.unwrap_or_else(|| Column::new().into())
 }
 
 fn get_unique_parents(ids: Option<&Vec<usize>>) -> Vec<usize> {
@@ -613,22 +943,29 @@ fn get_combine_parents_and_children(parent_ids: &Vec<usize>, ids_opt: Option<&Ve
     parent_child_ids
 }
 
+// A container or widget that is no longer there is reported and left out.
 fn get_children<'a>(parents: &Vec<ParentChildIds>, 
                 index: &usize, 
                 parent_ids: &Vec<usize>, 
                 state: &'a IpgState,
                 canvas_state: &'a IpgCanvasState,
-                ) -> Element<'a, Message> 
+                ) -> Option<Element<'a, Message>> 
 {
 
     let mut content= vec![];
 
     for child in parents[*index].child_ids.iter() {
         if parent_ids.contains(child) {
+            // a hidden container is left out, its children keep their state
+            if !container_is_shown(state, child) {
+                continue
+            }
             let index = parents.iter().position(|r| &r.parent_id == child).unwrap();
-            content.push(get_children(parents, &index, parent_ids, state, canvas_state));
-        } else if get_widget(state, child).is_some() {
-                content.push(get_widget(state, child).unwrap());
+            if let Some(container) = get_children(parents, &index, parent_ids, state, canvas_state) {
+                content.push(container);
+            }
+        } else if let Some(widget) = get_widget(state, child) {
+                content.push(widget);
         }
     }
     let id = &parents[*index].parent_id;
@@ -636,7 +973,28 @@ fn get_children<'a>(parents: &Vec<ParentChildIds>,
     if id != &0 {
         get_container(state, id, content, canvas_state)
     } else {
-        Column::with_children(content).into()  // the final container
+        Some(Column::with_children(content).into())  // the final container
+    }
+}
+
+fn container_is_shown(state: &IpgState, id: &usize) -> bool {
+    match state.containers.get(id) {
+        Some(container) => 
+            match container {
+                IpgContainers::IpgCanvas(canvas) => canvas.show,
+                IpgContainers::IpgColumn(col) => col.show,
+                IpgContainers::IpgContainer(con) => con.show,
+                IpgContainers::IpgMenu(menu) => menu.show,
+                IpgContainers::IpgMouseArea(m_area) => m_area.show,
+                IpgContainers::IpgOpaque(op) => op.show,
+                IpgContainers::IpgStack(stack) => stack.show,
+                IpgContainers::IpgTable(table) => table.show,
+                IpgContainers::IpgRow(row) => row.show,
+                IpgContainers::IpgScrollable(scroll) => scroll.show,
+                IpgContainers::IpgToolTip(tool) => tool.show,
+                IpgContainers::IpgWindow(_) => true,
+            },
+        None => true,
     }
 }
 
@@ -645,11 +1003,11 @@ fn get_container<'a>(state: &'a IpgState,
                     id: &usize, 
                     content: Vec<Element<'a, Message>>,
                     canvas_state: &'a IpgCanvasState,
-                    ) -> Element<'a, Message> {
+                    ) -> Option<Element<'a, Message>> {
 
     let container_opt: Option<&IpgContainers> = state.containers.get(id);
 
-    match container_opt 
+    let container = match container_opt 
     {
         Some(container) => 
             match container {
@@ -749,9 +1107,12 @@ fn get_container<'a>(state: &'a IpgState,
                 },
             },
         
-        None => panic!("Container not found in fn get_container id={}", id),        
-    }
-    
+        None => {
+            report_error(IpgIdError::new_err(format!("App: Container not found in fn get_container id={}", id)));
+            return None
+        },
+    };
+    Some(container)
 }
 
 fn get_widget<'a>(state: &'a IpgState, id: &usize) -> Option<Element<'a, Message>> {
@@ -781,6 +1142,9 @@ fn get_widget<'a>(state: &'a IpgState, id: &usize) -> Option<Element<'a, Message
                     };
                     construct_card(crd, style_opt)
                 },
+                IpgWidgets::IpgChart(chart) => {
+                    construct_chart(chart)
+                },
                 IpgWidgets::IpgCheckBox(chk) => {
                     let style_opt = match chk.style_id {
                         Some(id) => {
@@ -817,9 +1181,21 @@ fn get_widget<'a>(state: &'a IpgState, id: &usize) -> Option<Element<'a, Message
                     };
                     construct_divider_vertical(div, style_opt)
                 },
+                IpgWidgets::IpgGauge(gauge) => {
+                    construct_gauge(gauge)
+                },
+                IpgWidgets::IpgHeatmap(heatmap) => {
+                    construct_heatmap(heatmap)
+                },
                 IpgWidgets::IpgImage(image) => {
                     construct_image(image)
                 },
+                IpgWidgets::IpgKnob(knob) => {
+                    construct_knob(knob)
+                },
+                IpgWidgets::IpgMeter(meter) => {
+                    construct_meter(meter)
+                },
                 // IpgWidgets::IpgMenu(menu) => {
                 //     Some(construct_menu(menu.clone(), state))
                 // },
@@ -892,6 +1268,9 @@ fn get_widget<'a>(state: &'a IpgState, id: &usize) -> Option<Element<'a, Message
                 IpgWidgets::IpgSpace(sp) => {
                     construct_space(sp)
                 },
+                IpgWidgets::IpgStreamPlot(plot) => {
+                    construct_stream_plot(plot)
+                },
                 IpgWidgets::IpgSvg(svg) => {
                     construct_svg(svg)
                 },
@@ -937,7 +1316,10 @@ fn get_widget<'a>(state: &'a IpgState, id: &usize) -> Option<Element<'a, Message
                 _ => None,
 
             },
-        None => panic!("App: Widgets not found in fn get_widget id={}", id)
+        None => {
+            report_error(IpgIdError::new_err(format!("App: Widgets not found in fn get_widget id={}", id)));
+            None
+        },
     }
 }
 
@@ -970,188 +1352,619 @@ fn match_theme_with_debug_color(theme: Theme) -> Color {
     }
 }
 
-fn get_window_container(container_opt: Option<&IpgContainers>) -> &IpgWindow {
-    
-    let container = match container_opt {
-        Some(cnt) => cnt,
-        None => panic!("App: get_window_container: Cannot find IpgContainer"),
-    };
+// A window that is no longer there is reported, the caller uses its defaults.
+fn find_window<'a>(state: &'a IpgState, iced_window_id: window::Id, name: &str) -> Option<&'a IpgWindow> {
+    let window_opt = state.windows_iced_ipg_ids.get(&iced_window_id)
+                        .and_then(|ipg_window_id| state.containers.get(ipg_window_id));
 
-    match container {
-        IpgContainers::IpgWindow(wnd) => {
-            wnd
+    match window_opt {
+        Some(IpgContainers::IpgWindow(wnd)) => Some(wnd),
+        _ => {
+            report_error(IpgIdError::new_err(format!("App: {name}, Unable to find the window with iced_window_id {:?}.", iced_window_id)));
+            None
         },
-        _ => panic!("get_window: Not a Window")
     }
 }
 
+fn wake_up_worker() -> impl Stream<Item = Message> {
+    iced::stream::channel(10, |mut output| async move {
+        let (sender, mut receiver) = mpsc::unbounded();
+        *WAKE_UP.lock().unwrap() = Some(sender);
+
+        // anything queued before the sender was set
+        let _ = output.send(Message::WakeUp).await;
+
+        while receiver.next().await.is_some() {
+            let _ = output.send(Message::WakeUp).await;
+        }
+    })
+}
+
 fn process_updates(state: &mut IpgState, canvas_state: &mut IpgCanvasState) {
     
+    // The queues are taken and the lock released before processing,
+    // other threads can then keep queueing while the python side is called.
     let mut all_updates = access_update_items();
+    let deletes = mem::take(&mut all_updates.deletes);
+    let clears = mem::take(&mut all_updates.clears);
+    let moves = mem::take(&mut all_updates.moves);
+    let updates = mem::take(&mut all_updates.updates);
+    let shows = mem::take(&mut all_updates.shows);
+    let dataframes = mem::take(&mut all_updates.dataframes);
+    let bound_values = mem::take(&mut all_updates.bound_values);
+    drop(all_updates);
+
+    // Each item is processed on its own, an error is reported
+    // and the remaining items are still processed.
+    for (window_id, wid) in deletes.iter() {
+        if let Err(err) = process_delete(state, window_id, wid) {
+            report_error(err);
+        }
+    }
 
-    // for deletes
-    for (window_id, wid) in all_updates.deletes.iter() {
-        let iced_id = match state.windows_str_ids.get(window_id) {
-            Some(id) => *id,
-            None => panic!("Window_id {} not found in delete_item", window_id)
-        };
+    for (window_id, container_id) in clears.iter() {
+        if let Err(err) = process_clear(state, window_id, container_id) {
+            report_error(err);
+        }
+    }
 
-        let ipg_ids = match state.ids.get_mut(&iced_id) {
-            Some(ids) => ids,
-            None => panic!("Ids not found for window_id {} in delete_item", window_id)
+    for (window_id, 
+        widget_id, 
+        target_container_str_id, 
+        move_after, 
+        move_before) in moves.iter() {
+        if let Err(err) = process_move(state, window_id, widget_id, 
+                                        target_container_str_id, 
+                                        *move_after, *move_before) {
+            report_error(err);
+        }
+    }  
+
+    for batch in updates.iter() {
+        match batch.as_slice() {
+            [(wid, item, value)] => {
+                if let Err(err) = process_item_update(state, canvas_state, wid, item, value) {
+                    report_error(err);
+                }
+                mirror_item(state, *wid);
+            },
+            _ => process_batch(state, canvas_state, batch),
+        }
+        for (wid, _, _) in batch.iter() {
+            sync_bindings(state, canvas_state, *wid);
+        }
+    }
+
+    for name in bound_values.iter() {
+        apply_bound_value(state, canvas_state, name, None);
+    }
+
+    for (window_id, ids) in shows.iter() {
+        if let Err(err) = process_show(state, window_id, ids) {
+            report_error(err);
+        }
+        for (id, _) in ids.iter() {
+            mirror_item(state, *id);
+        }
+    }
+
+    for (wid, item, value) in dataframes.iter() {
+        if let Err(err) = process_dataframe_update(state, wid, item, value) {
+            report_error(err);
+        }
+        mirror_item(state, *wid);
+    }
+
+    // transfer any widgets, containers or styles added during the session
+    transfer_new_items(state);
+
+}
+
+// A two way binding passes the widget's new value on
+// to the other widgets bound to the same value.
+fn sync_bindings(state: &mut IpgState, canvas_state: &mut IpgCanvasState, wid: usize) {
+    let two_way = access_bindings().two_way(wid);
+
+    for binding in two_way.iter() {
+        let item = match get_bound_item(state, wid, &binding.variant) {
+            Some(item) => item,
+            None => continue,
         };
+        let changed = access_bindings().set_from_item(binding, &item);
+        if changed {
+            apply_bound_value(state, canvas_state, &binding.name, Some(wid));
+        }
+    }
+}
 
-        let mut index: i32 = -1;
+// The widgets bound to the value are updated, except the
+// source widget whose change set the value.
+fn apply_bound_value(
+        state: &mut IpgState, 
+        canvas_state: &mut IpgCanvasState, 
+        name: &str, 
+        source: Option<usize>) 
+{
+    let (value, targets) = match access_bindings().targets(name, source) {
+        Some(targets) => targets,
+        None => return,
+    };
 
-        for (i, ipg_id) in ipg_ids.iter().enumerate() {
-            if ipg_id.id == *wid {
-                index = i as i32;
-                break;
-            }
+    for binding in targets.iter() {
+        let current = get_bound_item(state, binding.wid, &binding.variant);
+        let updated = binding.converter.to_item(&value, current.as_ref())
+            .and_then(item_to_py)
+            .and_then(|item| process_item_update(state, canvas_state, &binding.wid, &binding.param, &item));
+        if let Err(err) = updated {
+            report_error(err);
         }
+        mirror_item(state, binding.wid);
+    }
+}
+
+// The current value of the bound param, None when it can't be read back.
+fn get_bound_item(state: &IpgState, wid: usize, variant: &str) -> Option<IpgItemValue> {
+    let items = match state.widgets.get(&wid) {
+        Some(widget) => match_widget_items(widget).ok()?.1,
+        None => match_container_items(state.containers.get(&wid)?).ok()?.1,
+    };
+    items.into_iter().find(|(name, _)| *name == variant)?.1
+}
 
-        if index == -1 {
-            panic!("item with id {wid} could not be found to delete")
+// The batch is applied as a whole, when one of the updates fails the widgets,
+// containers and canvas changed by the others are put back and their window
+// actions are dropped.
+fn process_batch(
+        state: &mut IpgState, 
+        canvas_state: &mut IpgCanvasState, 
+        batch: &[(usize, PyObject, PyObject)]) 
+{
+    let mut saved_widgets: HashMap<usize, IpgWidgets> = HashMap::new();
+    let mut saved_containers: HashMap<usize, IpgContainers> = HashMap::new();
+    for (wid, _, _) in batch.iter() {
+        if let Some(widget) = state.widgets.get(wid) {
+            saved_widgets.entry(*wid).or_insert_with(|| widget.clone());
+        } else if let Some(container) = state.containers.get(wid) {
+            saved_containers.entry(*wid).or_insert_with(|| container.clone());
         }
+    }
+    let last_id = state.last_id;
+    // the canvas updates change the canvas state and the window updates queue actions
+    let saved_canvas_state = saved_containers.values()
+        .any(|container| matches!(container, IpgContainers::IpgCanvas(_)))
+        .then(|| canvas_state.clone());
+    let queued_actions = access_window_actions().queued();
+
+    let applied = batch.iter()
+        .try_for_each(|(wid, item, value)| process_item_update(state, canvas_state, wid, item, value));
+
+    if let Err(err) = applied {
+        report_error(err);
+        state.widgets.extend(saved_widgets);
+        state.containers.extend(saved_containers);
+        state.last_id = last_id;
+        if let Some(saved_canvas_state) = saved_canvas_state {
+            *canvas_state = saved_canvas_state;
+        }
+        access_window_actions().drop_queued_after(queued_actions);
+    }
+
+    for (wid, _, _) in batch.iter() {
+        mirror_item(state, *wid);
+    }
+}
 
-        ipg_ids.remove(index as usize);
+// The id of the widget or container the message is for.
+fn message_id(message: &Message) -> Option<usize> {
+    match message {
+        Message::Button(id, _) |
+        Message::Card(id, _) |
+        Message::CheckBox(id, _) |
+        Message::ColorPicker(id, _) |
+        Message::DatePicker(id, _) |
+        Message::Divider(id, _) |
+        Message::Heatmap(id, _) |
+        Message::Image(id, _) |
+        Message::Knob(id, _) |
+        Message::PickList(id, _) |
+        Message::Radio(id, _) |
+        Message::Scrolled(_, id) |
+        Message::SelectableText(id, _) |
+        Message::Slider(id, _) |
+        Message::Svg(id, _) |
+        Message::TableSync(_, id) |
+        Message::TableDividerChanged((id, _, _)) |
+        Message::TableDividerReleased(id) |
+        Message::TextInput(id, _) |
+        Message::Toggler(id, _) |
+        Message::Timer(id, _) |
+        Message::Tick(id) |
+        Message::CanvasTimer(id, _) |
+        Message::CanvasTick(id) => Some(*id),
+        _ => None,
+    }
+}
 
-        state.widgets.remove(wid);   
+// The window of the item the message is for, it is passed on to the IpgEvent.
+fn message_window(state: &IpgState, message: &Message) -> Option<String> {
+    let id = match message {
+        Message::EventWindow((iced_id, _)) |
+        Message::EventKeyboard(iced_id, _) |
+        Message::EventMouse(iced_id, _, _) |
+        Message::EventTouch(iced_id, _) |
+        Message::ShortcutKey(iced_id, _) |
+        Message::ShortcutFocused(iced_id, _, _) |
+        Message::TabKey(iced_id, _) |
+        Message::TabFocused(iced_id, _, _) => state.windows_iced_ipg_ids.get(iced_id).copied(),
+        Message::MouseAreaOnPress(id) |
+        Message::MouseAreaOnRelease(id) |
+        Message::MouseAreaOnRightPress(id) |
+        Message::MouseAreaOnRightRelease(id) |
+        Message::MouseAreaOnMiddlePress(id) |
+        Message::MouseAreaOnMiddleRelease(id) |
+        Message::MouseAreaOnEnter(id) |
+        Message::MouseAreaOnMove(_, id) |
+        Message::MouseAreaOnExit(id) |
+        Message::OpaqueOnPress(id) => Some(*id),
+        _ => message_id(message),
+    }?;
+
+    if let Some(container_id) = state.widget_container_ids.get(&id) {
+        return state.container_wnd_str_ids.get(container_id).cloned()
     }
-    all_updates.deletes = vec![];
+    // a container or the window itself
+    let window_uid = state.container_window_usize_ids.get(&id).copied().unwrap_or(id);
+    window_str_id(state, window_uid)
+}
 
-    // for moves
-    for (window_id, 
-        widget_id, 
-        target_container_str_id, 
-        move_after, 
-        move_before) in all_updates.moves.iter() {
+fn window_str_id(state: &IpgState, window_uid: usize) -> Option<String> {
+    state.windows_str_ids.iter()
+        .find(|(_, uid)| **uid == window_uid)
+        .map(|(window_id, _)| window_id.clone())
+}
 
-        let container_str_id_opt = state.container_str_ids.get(target_container_str_id);
+fn iced_window_id(state: &IpgState, ipg_id: usize) -> Option<window::Id> {
+    state.windows_iced_ipg_ids.iter()
+        .find(|(_, id)| **id == ipg_id)
+        .map(|(iced_id, _)| *iced_id)
+}
 
-        let container_usize_id = match container_str_id_opt {
-            Some(id) => *id,
-            None => panic!("move_widget: unable to find the target container id based on the id {}", target_container_str_id)
-        };
+fn iced_window_str_id(state: &IpgState, iced_id: window::Id) -> Option<String> {
+    state.windows_iced_ipg_ids.get(&iced_id)
+        .and_then(|window_uid| window_str_id(state, *window_uid))
+}
 
-        let window_id_usize_opt = state.windows_str_ids.get(window_id);
+// the text input the iced id was made from
+fn focused_text_input(state: &IpgState, focused: &iced::advanced::widget::Id) -> Option<usize> {
+    state.widgets.iter()
+        .find(|(id, widget)| 
+            matches!(widget, IpgWidgets::IpgTextInput(_)) && focus_id(**id) == *focused)
+        .map(|(id, _)| *id)
+}
 
-        let window_id_usize = match window_id_usize_opt {
-            Some(id) => *id,
-            None => panic!("move_widget: unable to find the window_id using the id {}", window_id)
-        };
+// The window the widget is in.
+fn widget_window(state: &IpgState, id: usize) -> Option<window::Id> {
+    state.ids.iter()
+        .find(|(_, ipg_ids)| ipg_ids.iter().any(|ipg_id| ipg_id.id == id))
+        .and_then(|(wnd_id, _)| iced_window_id(state, *wnd_id))
+}
 
-        let window_widget_ids_opt = state.ids.get_mut(&window_id_usize);
+// Each window keeps its own focus, only the one in the window is used.
+fn focused_in_window(state: &IpgState, 
+                    window_id: window::Id, 
+                    focused: Vec<iced::advanced::widget::Id>) 
+                    -> Option<iced::advanced::widget::Id> {
+    let ipg_ids = state.windows_iced_ipg_ids.get(&window_id)
+        .and_then(|wnd_id| state.ids.get(wnd_id))?;
+    focused.into_iter()
+        .find(|focused| ipg_ids.iter().any(|ipg_id| focus_id(ipg_id.id) == *focused))
+}
 
-        let window_widget_ids = match window_widget_ids_opt {
-            Some(ids) => ids,
-            None => panic!("move_widget: unable to find widget using window_id {}", window_id)    
-        };
+// the text inputs of the window in the order they were added
+fn window_text_inputs(state: &IpgState, window_id: window::Id) -> Vec<usize> {
+    state.windows_iced_ipg_ids.get(&window_id)
+        .and_then(|wnd_id| state.ids.get(wnd_id))
+        .map(|ipg_ids| ipg_ids.iter()
+            .map(|ipg_id| ipg_id.id)
+            .filter(|id| matches!(state.widgets.get(id), Some(IpgWidgets::IpgTextInput(_))))
+            .collect())
+        .unwrap_or_default()
+}
 
-        let mut before = false;
-        let pos_id = if move_after.is_some() {
-            move_after.unwrap()
-        } else if move_before.is_some() { 
-            before = true;
-            move_before.unwrap()
-        } else {
-            1_000_000
-        };
+// a hidden or disabled text input is passed over by Tab
+fn can_focus(state: &IpgState, id: usize) -> bool {
+    match state.widgets.get(&id) {
+        Some(IpgWidgets::IpgTextInput(input)) => input.show && input.enabled,
+        _ => false,
+    }
+}
 
-        //  set some large numbers to break early
-        let mut found_index = 1_000_000;
-        let mut target_index: usize = 1_000_000;
+fn widget_enabled(state: &IpgState, id: usize) -> bool {
+    match state.widgets.get(&id) {
+        Some(IpgWidgets::IpgButton(btn)) => btn.enabled,
+        Some(IpgWidgets::IpgCheckBox(chk)) => chk.enabled,
+        Some(IpgWidgets::IpgColorPicker(cp)) => cp.enabled,
+        Some(IpgWidgets::IpgDatePicker(dp)) => dp.enabled,
+        Some(IpgWidgets::IpgPickList(pick)) => pick.enabled,
+        Some(IpgWidgets::IpgRadio(radio)) => radio.enabled,
+        Some(IpgWidgets::IpgSlider(slider)) => slider.enabled,
+        Some(IpgWidgets::IpgTextInput(input)) => input.enabled,
+        Some(IpgWidgets::IpgToggler(tog)) => tog.enabled,
+        _ => true,
+    }
+}
 
-        for (i, ids) in window_widget_ids.iter_mut().enumerate() {
-            if ids.id == *widget_id {
-                ids.parent_uid = container_usize_id;
-                ids.parent_id = target_container_str_id.clone();
-                found_index = i;
-            }
-            if ids.id == pos_id {
-                target_index = i
-            }
-            if found_index != 1_000_000 && (target_index != 1_000_000 || pos_id == 1_000_000) {
-                break;
-            }
+fn process_delete(state: &mut IpgState, window_id: &str, wid: &usize) -> PyResult<()> {
+    let wnd_id = match state.windows_str_ids.get(window_id) {
+        Some(id) => *id,
+        None => return Err(IpgIdError::new_err(format!("Window_id {} not found in delete_item", window_id)))
+    };
+
+    if *wid == wnd_id {
+        return Err(IpgParamError::new_err(format!("Window {} can not be deleted with delete_item", window_id)))
+    }
+
+    let ipg_ids = match state.ids.get(&wnd_id) {
+        Some(ids) => ids,
+        None => return Err(IpgIdError::new_err(format!("Ids not found for window_id {} in delete_item", window_id)))
+    };
+
+    if !ipg_ids.iter().any(|ipg_id| ipg_id.id == *wid) {
+        // styles are not placed in a container
+        if state.widgets.remove(wid).is_some() {
+            remove_mirror_item(*wid);
+            return Ok(())
         }
-        
-        let move_ids = window_widget_ids.remove(found_index);
-        
-        if pos_id == 1_000_000 {
-            window_widget_ids.push(move_ids);
-        } else if before {
-            window_widget_ids.insert(target_index-1, move_ids);
-        } else {
-            window_widget_ids.insert(target_index, move_ids);
+        return Err(IpgIdError::new_err(format!("item with id {wid} could not be found to delete")))
+    }
+
+    let ids = get_subtree_ids(ipg_ids, *wid);
+    let style_ids = remove_running_items(state, wnd_id, &ids);
+
+    // the styles only used by the deleted items go with them
+    for style_id in style_ids {
+        let in_use = state.widgets.values().any(|widget| get_widget_style_ids(widget).contains(&style_id)) ||
+                        state.containers.values().any(|container| get_container_style_ids(container).contains(&style_id));
+        if !in_use {
+            state.widgets.remove(&style_id);
+            remove_mirror_item(style_id);
         }
-    }  
-    all_updates.moves = vec![];
+    }
+    Ok(())
+}
 
-    // for item updates
-    for ((wid, item, value)) in all_updates.updates.iter() {
-        let widget = state.widgets.get_mut(wid);
-        if let Some(w) = widget {
-            match_widget(w, item, value);
-        } else {
-            match state.containers.get_mut(wid) {
-                Some(cnt) => {
-                    let last_id = match_container(cnt, item, value, canvas_state, state.last_id);
-                    if last_id.is_some() {
-                        state.last_id = last_id.unwrap();
-                    }
-                },
-                None => panic!("Item_update: Widget, Container, or Window with id {wid} not found.")
-            }
-        }  
+// The children are removed but the styles are kept, the container 
+// is usually filled again with the same styles.
+fn process_clear(state: &mut IpgState, window_id: &str, container_id: &usize) -> PyResult<()> {
+    let wnd_id = match state.windows_str_ids.get(window_id) {
+        Some(id) => *id,
+        None => return Err(IpgIdError::new_err(format!("Window_id {} not found in clear_container", window_id)))
+    };
+
+    let ipg_ids = match state.ids.get(&wnd_id) {
+        Some(ids) => ids,
+        None => return Err(IpgIdError::new_err(format!("Ids not found for window_id {} in clear_container", window_id)))
+    };
+
+    if !state.containers.contains_key(container_id) {
+        return Err(IpgIdError::new_err(format!("container with id {container_id} could not be found to clear")))
     }
-    all_updates.updates = vec![];
 
-    // updates for shows
-    for (window_id, ids) in all_updates.shows.iter() {
-        let iced_id = match state.windows_str_ids.get(window_id) {
-            Some(id) => *id,
-            None => panic!("Window_id {} not found in hide_item", window_id)
-        };
+    let mut ids = get_subtree_ids(ipg_ids, *container_id);
+    ids.retain(|id| id != container_id);
+    remove_running_items(state, wnd_id, &ids);
+    Ok(())
+}
 
-        let ipg_ids = match state.ids.get_mut(&iced_id) {
-            Some(ids) => ids,
-            None => panic!("Ids not found for window_id {} in hide_item", window_id)
-        };
+// Removes the ids from the running state along with their callbacks and user data.
+// Returns the style ids used by the removed items.
+fn remove_running_items(state: &mut IpgState, wnd_id: usize, ids: &[usize]) -> Vec<usize> {
+    if let Some(ipg_ids) = state.ids.get_mut(&wnd_id) {
+        ipg_ids.retain(|ipg_id| !ids.contains(&ipg_id.id));
+    }
+    if let Some(container_ids) = state.container_ids.get_mut(&wnd_id) {
+        container_ids.retain(|id| !ids.contains(id));
+    }
+
+    let str_ids: Vec<String> = state.container_str_ids.iter()
+        .filter(|(_, id)| ids.contains(id))
+        .map(|(str_id, _)| str_id.clone())
+        .collect();
+    for str_id in str_ids.iter() {
+        state.container_str_ids.remove(str_id);
+        state.container_wnd_str_ids.remove(str_id);
+    }
+
+    let mut style_ids = vec![];
+    for id in ids.iter() {
+        if let Some(widget) = state.widgets.remove(id) {
+            style_ids.extend(get_widget_style_ids(&widget));
+        }
+        if let Some(container) = state.containers.remove(id) {
+            style_ids.extend(get_container_style_ids(&container));
+        }
+        state.container_window_usize_ids.remove(id);
+        // a deleted timer stops ticking
+        state.running_timers.remove(id);
+        state.running_canvas_timers.remove(id);
+        remove_mirror_item(*id);
+    }
+
+    remove_callbacks_and_user_data(ids);
+    style_ids
+}
+
+fn get_widget_style_ids(widget: &IpgWidgets) -> Vec<usize> {
+    let style_id = match widget {
+        IpgWidgets::IpgButton(btn) => btn.style_id,
+        IpgWidgets::IpgCard(crd) => crd.style_id,
+        IpgWidgets::IpgCheckBox(chk) => chk.style_id,
+        IpgWidgets::IpgColorPicker(cp) => cp.style_id,
+        IpgWidgets::IpgDatePicker(dp) => dp.button_style_id,
+        IpgWidgets::IpgDividerHorizontal(div) => div.style_id,
+        IpgWidgets::IpgDividerVertical(div) => div.style_id,
+        IpgWidgets::IpgPickList(pick) => pick.style_id,
+        IpgWidgets::IpgProgressBar(bar) => bar.style_id,
+        IpgWidgets::IpgRadio(radio) => radio.style_id,
+        IpgWidgets::IpgRule(rule) => rule.style_id,
+        IpgWidgets::IpgSeparator(sep) => sep.style_id,
+        IpgWidgets::IpgSlider(slider) => slider.style_id,
+        IpgWidgets::IpgTextInput(input) => input.style_id,
+        IpgWidgets::IpgTimer(timer) => timer.style_id,
+        IpgWidgets::IpgCanvasTimer(ctimer) => ctimer.style_id,
+        IpgWidgets::IpgToggler(tog) => tog.style_id,
+        _ => None,
+    };
+    style_id.into_iter().collect()
+}
+
+fn get_container_style_ids(container: &IpgContainers) -> Vec<usize> {
+    match container {
+        IpgContainers::IpgContainer(con) => con.style_id.into_iter().collect(),
+        IpgContainers::IpgMenu(menu) => menu.menu_bar_style_id.into_iter()
+                                            .chain(menu.menu_style_id).collect(),
+        IpgContainers::IpgOpaque(op) => op.style_id.into_iter().collect(),
+        IpgContainers::IpgScrollable(scroll) => scroll.style_id.into_iter().collect(),
+        IpgContainers::IpgTable(table) => table.style_id.into_iter().collect(),
+        IpgContainers::IpgToolTip(tool) => tool.style_id.into_iter().collect(),
+        _ => vec![],
+    }
+}
+
+fn process_move(
+        state: &mut IpgState, 
+        window_id: &str, 
+        widget_id: &usize, 
+        target_container_str_id: &str, 
+        move_after: Option<usize>, 
+        move_before: Option<usize>) 
+        -> PyResult<()>
+{
+    let container_str_id_opt = state.container_str_ids.get(target_container_str_id);
 
-        show_widget(state, ids);
+    let container_usize_id = match container_str_id_opt {
+        Some(id) => *id,
+        None => return Err(IpgIdError::new_err(format!("move_widget: unable to find the target container id based on the id {}", target_container_str_id)))
+    };
+
+    let window_id_usize_opt = state.windows_str_ids.get(window_id);
+
+    let window_id_usize = match window_id_usize_opt {
+        Some(id) => *id,
+        None => return Err(IpgIdError::new_err(format!("move_widget: unable to find the window_id using the id {}", window_id)))
+    };
+
+    let window_widget_ids_opt = state.ids.get_mut(&window_id_usize);
+
+    let window_widget_ids = match window_widget_ids_opt {
+        Some(ids) => ids,
+        None => return Err(IpgIdError::new_err(format!("move_widget: unable to find widget using window_id {}", window_id)))
+    };
+
+    if !window_widget_ids.iter().any(|ids| ids.id == *widget_id) {
+        return Err(IpgIdError::new_err(format!("move_widget: unable to find the widget with id {}", widget_id)))
+    }
+
+    let mut before = false;
+    let pos_id = if move_after.is_some() {
+        move_after.unwrap()
+    } else if move_before.is_some() { 
+        before = true;
+        move_before.unwrap()
+    } else {
+        1_000_000
+    };
+
+    //  set some large numbers to break early
+    let mut found_index = 1_000_000;
+    let mut target_index: usize = 1_000_000;
+
+    for (i, ids) in window_widget_ids.iter_mut().enumerate() {
+        if ids.id == *widget_id {
+            ids.parent_uid = container_usize_id;
+            ids.parent_id = target_container_str_id.to_string();
+            found_index = i;
+        }
+        if ids.id == pos_id {
+            target_index = i
+        }
+        if found_index != 1_000_000 && (target_index != 1_000_000 || pos_id == 1_000_000) {
+            break;
+        }
     }
     
-    all_updates.shows = vec![];
+    let move_ids = window_widget_ids.remove(found_index);
+    
+    if pos_id == 1_000_000 {
+        window_widget_ids.push(move_ids);
+    } else if before {
+        window_widget_ids.insert(target_index-1, move_ids);
+    } else {
+        window_widget_ids.insert(target_index, move_ids);
+    }
+    Ok(())
+}
 
-    // for DataFrame
-    for ((wid, item, value)) in all_updates.dataframes.iter() {
+fn process_item_update(
+        state: &mut IpgState, 
+        canvas_state: &mut IpgCanvasState, 
+        wid: &usize, 
+        item: &PyObject, 
+        value: &PyObject) 
+        -> PyResult<()>
+{
+    let widget = state.widgets.get_mut(wid);
+    if let Some(w) = widget {
+        match_widget(w, item, value)
+    } else {
         match state.containers.get_mut(wid) {
             Some(cnt) => {
-                match_container_for_df(cnt, item, value);
+                let last_id = match_container(cnt, item, value, canvas_state, state.last_id)?;
+                if let Some(last_id) = last_id {
+                    state.last_id = last_id;
+                }
+                Ok(())
             },
-            None => panic!("DataFrame_update: table with id {wid} not found.")
+            None => Err(IpgIdError::new_err(format!("Item_update: Widget, Container, or Window with id {wid} not found.")))
         }
     }
-    // transfer any widgets or containers over
-    let mut mutex_state = access_state();
-    let widgets = mutex_state.widgets.to_owned();
+}
 
-    for key in widgets.keys() {
-        let value = mutex_state.widgets.remove(key).unwrap();
-        let parent_id = get_widget_parent_id(&value);
-        set_state_of_widget_running_state(state, *key, parent_id);
-        state.widgets.insert(*key, value);
+fn process_show(state: &mut IpgState, window_id: &str, ids: &[(usize, bool)]) -> PyResult<()> {
+    let iced_id = match state.windows_str_ids.get(window_id) {
+        Some(id) => *id,
+        None => return Err(IpgIdError::new_err(format!("Window_id {} not found in hide_item", window_id)))
+    };
+
+    if !state.ids.contains_key(&iced_id) {
+        return Err(IpgIdError::new_err(format!("Ids not found for window_id {} in hide_item", window_id)))
     }
 
-    drop(mutex_state);
+    show_widget(state, ids)
+}
 
+fn process_dataframe_update(
+        state: &mut IpgState, 
+        wid: &usize, 
+        item: &PyObject, 
+        value: &PyDataFrame) 
+        -> PyResult<()>
+{
+    if let Some(widget) = state.widgets.get_mut(wid) {
+        return match_widget_for_df(widget, item, value);
+    }
+    match state.containers.get_mut(wid) {
+        Some(cnt) => match_container_for_df(cnt, item, value),
+        None => Err(IpgIdError::new_err(format!("DataFrame_update: table or chart with id {wid} not found.")))
+    }
 }
 
 fn process_canvas_updates(cs: &mut IpgCanvasState) {
     let mut canvas_items = access_canvas_update_items();
+    let updates = mem::take(&mut canvas_items.updates);
+    drop(canvas_items);
 
-    for ((wid, item, value)) in canvas_items.updates.iter() {
+    for ((wid, item, value)) in updates.iter() {
         let mut canvas_widget = if cs.curves.get_mut(wid).is_some(){
             cs.curves.get_mut(wid).unwrap()
         } else if cs.image_curves.get_mut(wid).is_some() {
@@ -1159,31 +1972,43 @@ fn process_canvas_updates(cs: &mut IpgCanvasState) {
         } else if cs.text_curves.get_mut(wid).is_some() {
             cs.text_curves.get_mut(wid).unwrap()
         } else {
-           panic!("canvas_item_update: canvas item with id, {} not found", wid);
+            report_error(IpgIdError::new_err(format!("canvas_item_update: canvas item with id, {} not found", wid)));
+            continue
         };
-        match_canvas_widget(canvas_widget, item, value);
+        if let Err(err) = match_canvas_widget(canvas_widget, item, value) {
+            report_error(err);
+        }
     }
-    canvas_items.updates = vec![];
 
 }
 
-fn show_widget(state: &mut IpgState, ids: &[(usize, bool)]) {
+fn show_widget(state: &mut IpgState, ids: &[(usize, bool)]) -> PyResult<()> {
     
     for (id, value) in ids.iter() {
         
-        let mut wid = state.widgets.get_mut(id);
-        let widget = if wid.is_some() {
-            wid.take().unwrap()
-        } else {
-            panic!("Show_items - unable to find id {}", id)
+        if let Some(container) = state.containers.get_mut(id) {
+            show_container(container, *value);
+            continue
+        }
+
+        let widget = match state.widgets.get_mut(id) {
+            Some(widget) => widget,
+            None => return Err(IpgIdError::new_err(format!("Show_items - unable to find id {}", id))),
         };
         match widget {
             IpgWidgets::IpgButton(ipg_button) => ipg_button.show= *value,
             IpgWidgets::IpgCard(ipg_card) => ipg_card.show= *value,
+            IpgWidgets::IpgChart(ipg_chart) => ipg_chart.show= *value,
             IpgWidgets::IpgCheckBox(ipg_check_box) => ipg_check_box.show= *value,
             IpgWidgets::IpgColorPicker(ipg_color_picker) => ipg_color_picker.show= *value,
             IpgWidgets::IpgDatePicker(ipg_date_picker) => ipg_date_picker.show= *value,
+            IpgWidgets::IpgDividerHorizontal(ipg_divider) => ipg_divider.show= *value,
+            IpgWidgets::IpgDividerVertical(ipg_divider) => ipg_divider.show= *value,
+            IpgWidgets::IpgGauge(ipg_gauge) => ipg_gauge.show= *value,
+            IpgWidgets::IpgHeatmap(ipg_heatmap) => ipg_heatmap.show= *value,
             IpgWidgets::IpgImage(ipg_image) => ipg_image.show= *value,
+            IpgWidgets::IpgKnob(ipg_knob) => ipg_knob.show= *value,
+            IpgWidgets::IpgMeter(ipg_meter) => ipg_meter.show= *value,
             IpgWidgets::IpgPickList(ipg_pick_list) => ipg_pick_list.show= *value,
             IpgWidgets::IpgProgressBar(ipg_progress_bar) => ipg_progress_bar.show= *value,
             IpgWidgets::IpgRadio(ipg_radio) => ipg_radio.show= *value,
@@ -1192,67 +2017,142 @@ fn show_widget(state: &mut IpgState, ids: &[(usize, bool)]) {
             IpgWidgets::IpgSeparator(ipg_separator) => ipg_separator.show= *value,
             IpgWidgets::IpgSlider(ipg_slider) => ipg_slider.show= *value,
             IpgWidgets::IpgSpace(ipg_space) => ipg_space.show= *value,
+            IpgWidgets::IpgStreamPlot(ipg_stream_plot) => ipg_stream_plot.show= *value,
             IpgWidgets::IpgSvg(ipg_svg) => ipg_svg.show= *value,
             IpgWidgets::IpgText(ipg_text) => ipg_text.show= *value,
             IpgWidgets::IpgTextInput(ipg_text_input) => ipg_text_input.show= *value,
             IpgWidgets::IpgTimer(ipg_timer) => ipg_timer.show= *value,
+            IpgWidgets::IpgCanvasTimer(ipg_canvas_timer) => ipg_canvas_timer.show= *value,
             IpgWidgets::IpgToggler(ipg_toggler) => ipg_toggler.show= *value,
             _ => (),
         }
     }
-    
+    Ok(())
 }
 
-fn get_widget_parent_id(widget: &IpgWidgets) -> String {
-    match widget {
-        IpgWidgets::IpgButton(ipg_button) => ipg_button.parent_id.clone(),
-        IpgWidgets::IpgButtonStyle(ipg_button_style) => todo!(),
-        IpgWidgets::IpgCard(ipg_card) => ipg_card.parent_id.clone(),
-        IpgWidgets::IpgCardStyle(ipg_card_style) => todo!(),
-        IpgWidgets::IpgCheckBox(ipg_check_box) => todo!(),
-        IpgWidgets::IpgCheckboxStyle(ipg_checkbox_style) => todo!(),
-        IpgWidgets::IpgColorPicker(ipg_color_picker) => todo!(),
-        IpgWidgets::IpgColorPickerStyle(ipg_color_picker_style) => todo!(),
-        IpgWidgets::IpgContainerStyle(ipg_container_style) => todo!(),
-        IpgWidgets::IpgDatePicker(ipg_date_picker) => todo!(),
-        IpgWidgets::IpgDividerHorizontal(_) => todo!(),
-        IpgWidgets::IpgDividerVertical(_) => todo!(),
-        IpgWidgets::IpgDividerStyle(_) => todo!(),
-        IpgWidgets::IpgImage(ipg_image) => todo!(),
-        IpgWidgets::IpgMenuStyle(ipg_menu_style) => todo!(),
-        IpgWidgets::IpgMenuBarStyle(ipg_menu_bar_style) => todo!(),
-        IpgWidgets::IpgOpaqueStyle(ipg_opaque_style) => todo!(),
-        IpgWidgets::IpgPickList(ipg_pick_list) => todo!(),
-        IpgWidgets::IpgPickListStyle(ipg_pick_list_style) => todo!(),
-        IpgWidgets::IpgProgressBar(ipg_progress_bar) => todo!(),
-        IpgWidgets::IpgProgressBarStyle(ipg_progress_bar_style) => todo!(),
-        IpgWidgets::IpgRadio(ipg_radio) => todo!(),
-        IpgWidgets::IpgRadioStyle(ipg_radio_style) => todo!(),
-        IpgWidgets::IpgRule(ipg_rule) => todo!(),
-        IpgWidgets::IpgRuleStyle(ipg_rule_style) => todo!(),
-        IpgWidgets::IpgScrollableStyle(ipg_scrollable_style) => todo!(),
-        IpgWidgets::IpgSelectableText(ipg_selectable_text) => todo!(),
-        IpgWidgets::IpgSeparator(ipg_separator) => todo!(),
-        IpgWidgets::IpgSeparatorStyle(ipg_separator_style) => todo!(),
-        IpgWidgets::IpgSlider(ipg_slider) => todo!(),
-        IpgWidgets::IpgSliderStyle(ipg_slider_style) => todo!(),
-        IpgWidgets::IpgSpace(ipg_space) => todo!(),
-        IpgWidgets::IpgSvg(ipg_svg) => todo!(),
-        IpgWidgets::IpgTableStyle(ipg_table_style) => todo!(),
-        IpgWidgets::IpgText(text) => text.parent_id.clone(),
-        // IpgWidgets::IpgRichText(r_text) => r_text.parent_id.clone(),
-        IpgWidgets::IpgTextInput(ipg_text_input) => todo!(),
-        IpgWidgets::IpgTextInputStyle(ipg_text_input_style) => todo!(),
-        IpgWidgets::IpgTimer(ipg_timer) => todo!(),
-        IpgWidgets::IpgTimerStyle(ipg_timer_style) => todo!(),
-        IpgWidgets::IpgCanvasTimer(ipg_canvas_timer) => todo!(),
-        IpgWidgets::IpgCanvasTimerStyle(ipg_canvas_timer_style) => todo!(),
-        IpgWidgets::IpgToggler(ipg_toggler) => todo!(),
-        IpgWidgets::IpgTogglerStyle(ipg_toggler_style) => todo!(),
-        IpgWidgets::IpgToolTipStyle(ipg_tool_tip) => todo!(),
+fn show_container(container: &mut IpgContainers, value: bool) {
+    match container {
+        IpgContainers::IpgCanvas(canvas) => canvas.show = value,
+        IpgContainers::IpgColumn(col) => col.show = value,
+        IpgContainers::IpgContainer(con) => con.show = value,
+        IpgContainers::IpgMenu(menu) => menu.show = value,
+        IpgContainers::IpgMouseArea(m_area) => m_area.show = value,
+        IpgContainers::IpgOpaque(op) => op.show = value,
+        IpgContainers::IpgStack(stack) => stack.show = value,
+        IpgContainers::IpgTable(table) => table.show = value,
+        IpgContainers::IpgRow(row) => row.show = value,
+        IpgContainers::IpgScrollable(scroll) => scroll.show = value,
+        IpgContainers::IpgToolTip(tool) => tool.show = value,
+        // windows are hidden with their mode
+        IpgContainers::IpgWindow(_) => (),
     }
 }
 
+// Widgets, containers and styles added after start_session are moved from
+// the mutex into the running state.  The ids are taken in the order they
+// were added so that containers are in place before their children.
+fn transfer_new_items(state: &mut IpgState) {
+    let mut mutex_state = access_state();
+    let mut widgets = mem::take(&mut *mutex_state.widgets);
+    let mut containers = mem::take(&mut *mutex_state.containers);
+
+    // the windows go in first since the new items may be in them
+    let mut new_windows: Vec<(Option<String>, Vec<IpgIds>, IpgWindow)> = vec![];
+    for wnd in mem::take(&mut mutex_state.windows) {
+        let window_id = mutex_state.windows_str_ids.iter()
+                            .find(|(_, id)| **id == wnd.id)
+                            .map(|(window_id, _)| window_id.clone());
+        let window_ids: Vec<IpgIds> = mutex_state.ids_ipd_ids.get(&wnd.id)
+                            .map(|ids| ids.iter().filter(|ipg_id| ipg_id.id == wnd.id).cloned().collect())
+                            .unwrap_or_default();
+        // the container has any update made since it was added
+        let wnd = match containers.remove(&wnd.id) {
+            Some(IpgContainers::IpgWindow(container)) => container,
+            _ => wnd,
+        };
+        new_windows.push((window_id, window_ids, wnd));
+    }
+
+    let mut new_ids: Vec<(usize, IpgIds)> = vec![];
+    if !widgets.is_empty() || !containers.is_empty() {
+        for (wnd_id, ipg_ids) in mutex_state.ids_ipd_ids.iter() {
+            for ipg_id in ipg_ids.iter() {
+                if widgets.contains_key(&ipg_id.id) || containers.contains_key(&ipg_id.id) {
+                    new_ids.push((*wnd_id, ipg_id.clone()));
+                }
+            }
+        }
+    }
+    drop(mutex_state);
+
+    for (window_id, window_ids, wnd) in new_windows {
+        let id = wnd.id;
+        if let Some(window_id) = window_id {
+            state.windows_str_ids.insert(window_id, id);
+        }
+        state.ids.insert(id, window_ids);
+        state.container_ids.insert(id, vec![id]);
+        // opened by get_tasks, the same as open_window
+        if wnd.mode != IpgWindowMode::Closed {
+            access_window_actions().open.push(id);
+        }
+        state.windows.push(wnd.clone());
+        state.containers.insert(id, IpgContainers::IpgWindow(wnd));
+        mirror_item(state, id);
+    }
+
+    for (wnd_id, ipg_id) in new_ids {
+        let id = ipg_id.id;
+        let widget = widgets.remove(&id);
+        let container = containers.remove(&id);
+
+        if let Err(err) = set_running_ids(state, wnd_id, ipg_id) {
+            report_error(err);
+            continue;
+        }
+
+        if let Some(widget) = widget {
+            state.widgets.insert(id, widget);
+        }
+        if let Some(container) = container {
+            state.containers.insert(id, container);
+        }
+        mirror_item(state, id);
+    }
+
+    // styles have no parent, they are only referenced by the widgets
+    for (id, widget) in widgets {
+        state.widgets.insert(id, widget);
+        mirror_item(state, id);
+    }
+}
+
+fn set_running_ids(state: &mut IpgState, wnd_id: usize, mut ipg_id: IpgIds) -> PyResult<()> {
+    let wnd_str_id = match state.windows_str_ids.iter().find(|(_, id)| **id == wnd_id) {
+        Some((wnd_str_id, _)) => wnd_str_id.clone(),
+        None => return Err(IpgIdError::new_err(format!("Window id {} not found for the item with id {}", wnd_id, ipg_id.id))),
+    };
+
+    let ipg_ids = match state.ids.get_mut(&wnd_id) {
+        Some(ids) => ids,
+        None => return Err(IpgIdError::new_err(format!("Ids not found for window_id {} when adding the item with id {}", wnd_str_id, ipg_id.id))),
+    };
+
+    ipg_id.parent_uid = find_parent_uid(ipg_ids, ipg_id.parent_id.clone())?;
+
+    if ipg_id.is_container {
+        if let Some(container_id) = ipg_id.container_id.clone() {
+            state.container_str_ids.insert(container_id.clone(), ipg_id.id);
+            state.container_wnd_str_ids.insert(container_id, wnd_str_id);
+        }
+        state.container_window_usize_ids.insert(ipg_id.id, wnd_id);
+        state.container_ids.entry(wnd_id).or_default().push(ipg_id.id);
+    }
+
+    ipg_ids.push(ipg_id);
+    Ok(())
+}
+
 fn clone_state(state: &mut IpgState) {
     let mut mutex_state = access_state();
     state.ids = mutex_state.ids_ipd_ids.to_owned();
@@ -1273,15 +2173,14 @@ fn clone_state(state: &mut IpgState) {
     
     state.keyboard_event_id_enabled = mutex_state.keyboard_event_id_enabled.to_owned();
     state.mouse_event_id_enabled = mutex_state.mouse_event_id_enabled.to_owned();
-    state.timer_event_id_enabled = mutex_state.timer_event_id_enabled.to_owned();
-    state.canvas_timer_event_id_enabled = mutex_state.canvas_timer_event_id_enabled.to_owned();
+    state.mouse_input = IpgMouseInput::new(mutex_state.mouse_click_interval_ms);
     state.window_event_id_enabled = mutex_state.window_event_id_enabled.to_owned();
     state.touch_event_id_enabled = mutex_state.touch_event_id_enabled.to_owned();
-    state.timer_duration = mutex_state.timer_duration.to_owned();
-    state.canvas_timer_duration = mutex_state.canvas_timer_duration.to_owned();
+    state.touch_gestures = IpgGestures::new(mutex_state.touch_long_press_ms);
 
     // zeroing out any unneeded vecs and hashmaps
     mutex_state.widgets = Lazy::new(||HashMap::new());
+    mutex_state.containers = Lazy::new(||HashMap::new());
     mutex_state.widget_container_ids = Lazy::new(||HashMap::new());
     mutex_state.windows = vec![];
     mutex_state.windows_iced_ipg_ids = Lazy::new(||HashMap::new());
@@ -1302,10 +2201,42 @@ fn clone_canvas_state(canvas_state: &mut IpgCanvasState) {
     canvas_state.border_width = mutex_cs.border_width;
     canvas_state.border_color = mutex_cs.border_color;
     canvas_state.selected_canvas_color = mutex_cs.background;
+    canvas_state.canvas_id = mutex_cs.canvas_id;
+    canvas_state.gestures = IpgGestures::new(mutex_cs.long_press_ms);
 
     // zeroing out any vecs and hashmaps
     mutex_cs.curves = Lazy::new(||HashMap::new());
     mutex_cs.text_curves = Lazy::new(||HashMap::new());
     mutex_cs.image_curves = Lazy::new(||HashMap::new());
     drop(mutex_cs);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_open_window_added_after_start() {
+    use crate::ipg_widgets::ipg_window::{IpgWindowLevel, IpgWindowTheme};
+
+    // the running state, cloned when the session started
+    let mut state = IpgState::default();
+    let mut ipg = crate::IPG { group_index: 0, theme: Theme::Dark };
+    let window_id = "test_window_added_after_start".to_string();
+    let id = ipg.add_window(window_id.clone(), "Added".to_string(), 300.0, 200.0,
+                            None, None, None, None, None, None, false, true, true, false,
+                            IpgWindowLevel::Normal, 1.0, IpgWindowTheme::Dark, false, None,
+                            IpgWindowMode::Windowed, false, None, None, None).unwrap();
+
+    transfer_new_items(&mut state);
+    assert_eq!(state.windows_str_ids.get(&window_id), Some(&id));
+    assert!(matches!(state.containers.get(&id), Some(IpgContainers::IpgWindow(_))));
+
+    let _ = get_tasks(&mut state);
+    let iced_id = iced_window_id(&state, id).expect("the window was not opened");
+    assert!(state.windows_opened.contains(&iced_id));
+    assert!(!state.windows_hidden.contains(&iced_id));
+
+    let mut mutex_state = access_state();
+    mutex_state.windows_str_ids.remove(&window_id);
+    mutex_state.ids_ipd_ids.remove(&id);
+    mutex_state.container_ids.remove(&id);
+    drop(mutex_state);
+    remove_mirror_item(id);
+}
@@ -29,6 +29,11 @@ pub fn get_mid_point(pt1: Point, pt2: Point) -> Point {
     Point {x: (pt1.x + pt2.x) / 2.0, y: (pt1.y + pt2.y) / 2.0 }
 }
 
+// angle in radians, clockwise from the x axis since y is down
+pub fn get_point_on_circle(center: Point, radius: f32, angle: f32) -> Point {
+    Point {x: center.x + radius * angle.cos(), y: center.y + radius * angle.sin() }
+}
+
 pub fn get_linear_regression(points: &[Point]) -> (f32, f32) {
     let mut sx: f64 = 0.0;
     let mut sy: f64 = 0.0;
@@ -178,6 +183,29 @@ pub fn to_radians(degrees: &f32) -> f32 {
     degrees * PI/180.0
 }
 
+// The measured length between two points, converted to user units
+pub fn get_scaled_distance(pt1: Point, pt2: Point, unit_scale: f32) -> f32 {
+    pt1.distance(pt2) * unit_scale
+}
+
+// The angle between the two arms, always the inner one (0-180 degrees)
+pub fn get_inner_angle_degrees(center: Point, p1: Point, p2: Point) -> f32 {
+    let degrees = to_degrees(&get_angle_of_vectors(center, p1, p2).0);
+    if degrees > 180.0 {
+        360.0 - degrees
+    } else {
+        degrees
+    }
+}
+
+pub fn format_measurement(value: f32, precision: usize, units: &str) -> String {
+    if units.is_empty() {
+        format!("{:.prec$}", value, prec = precision)
+    } else {
+        format!("{:.prec$} {}", value, units, prec = precision)
+    }
+}
+
 
 #[test]
 fn test_get_linear_regression() {
@@ -248,3 +276,25 @@ fn test_rotate_geometry() {
 }
 
 
+
+#[test]
+fn test_get_inner_angle_degrees() {
+    let center = Point::new(0.0, 0.0);
+    let degrees = get_inner_angle_degrees(center, Point::new(10.0, 0.0), Point::new(0.0, 10.0));
+    assert!((degrees - 90.0).abs() < 0.001);
+
+    // order of the arms does not matter
+    let degrees = get_inner_angle_degrees(center, Point::new(0.0, 10.0), Point::new(10.0, 0.0));
+    assert!((degrees - 90.0).abs() < 0.001);
+
+    let degrees = get_inner_angle_degrees(center, Point::new(10.0, 0.0), Point::new(-10.0, 10.0));
+    assert!((degrees - 135.0).abs() < 0.001);
+}
+
+#[test]
+fn test_scaled_distance_and_format() {
+    let length = get_scaled_distance(Point::new(0.0, 0.0), Point::new(30.0, 40.0), 0.5);
+    assert_eq!(25.0, length);
+    assert_eq!("25.00 mm", format_measurement(length, 2, "mm"));
+    assert_eq!("25.0", format_measurement(length, 1, ""));
+}
@@ -2,19 +2,22 @@
 // #![allow(clippy::unnecessary_unwrap)]
 use std::collections::HashMap;
 
-use iced::{alignment, mouse, Color, Length, Vector};
+use iced::{alignment, mouse, touch, Color, Length, Vector};
 use iced::widget::canvas::event::{self, Event};
 use iced::widget::canvas::{self, stroke, Canvas, Frame, Geometry, LineDash, Path, Stroke};
 use iced::{Element, Point, Renderer, Theme};
 use pyo3::pyclass;
 
 use crate::canvas::canvas_helpers::to_degrees;
+use crate::ipg_widgets::ipg_canvas::CanvasMessage;
+use crate::ipg_widgets::ipg_gestures::IpgGestures;
 use crate::canvas::path_builds::{build_arc_path, build_bezier_path, build_circle_path, 
     build_ellipse_path, build_free_hand_path, build_line_path, 
+    build_dimension_path, build_angle_measure_path, build_callout_path, 
     build_polygon_path, build_polyline_path, build_right_triangle_path, build_text_path};
 
 use super::canvas_helpers::to_radians;
-use super::geometries::{add_keypress, add_new_widget, check_if_text_widget, complete_new_widget, find_closest_point_index, find_closest_widget, get_del_key, get_widget_degrees, set_widget_mode_or_status_or_id, set_widget_point, update_edited_widget, update_rotated_widget, IpgArc, IpgBezier, IpgCanvasImage, IpgCanvasWidget, IpgCircle, IpgEllipse, IpgFreeHand, IpgLine, IpgPolyLine, IpgPolygon, IpgRectangle, IpgRightTriangle, IpgText};
+use super::geometries::{add_keypress, add_new_widget, check_if_text_widget, complete_new_widget, find_closest_point_index, find_closest_widget, get_del_key, get_widget_degrees, set_widget_mode_or_status_or_id, set_widget_point, update_edited_widget, update_rotated_widget, IpgArc, IpgBezier, IpgCanvasImage, IpgCanvasWidget, IpgCircle, IpgEllipse, IpgFreeHand, IpgLine, IpgPolyLine, IpgPolygon, IpgRectangle, IpgRightTriangle, IpgText, IpgDimension, IpgAngleMeasure, IpgCallout};
 
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -33,6 +36,9 @@ pub enum IpgWidget {
     RightTriangle(IpgRightTriangle),
     Text(IpgText),
     FreeHand(IpgFreeHand),
+    Dimension(IpgDimension),
+    AngleMeasure(IpgAngleMeasure),
+    Callout(IpgCallout),
 }
 
 
@@ -79,11 +85,15 @@ pub struct IpgCanvasState {
     pub selected_width: f32,
     pub selected_h_text_alignment: alignment::Horizontal,
     pub selected_v_text_alignment: alignment::Vertical,
+    pub selected_unit_scale: f32,
+    pub selected_units: String,
     pub timer_event_enabled: bool,
     pub timer_duration: u64,
     pub elapsed_time: u64,
     pub blink: bool,
     pub file_path: String,
+    pub canvas_id: usize,
+    pub gestures: IpgGestures,
 }
 
 impl Default for IpgCanvasState {
@@ -120,11 +130,52 @@ impl Default for IpgCanvasState {
             selected_width: 2.0,
             selected_h_text_alignment: alignment::Horizontal::Center,
             selected_v_text_alignment:alignment::Vertical::Center,
+            selected_unit_scale: 1.0,
+            selected_units: String::new(),
             timer_event_enabled: false,
             timer_duration: 750,
             elapsed_time: 0,
             blink: false,
             file_path: String::new(),
+            canvas_id: 0,
+            gestures: IpgGestures::new(500),
+        }
+    }
+}
+
+// The copy has new caches, so it's drawn again when it's put back.
+impl Clone for IpgCanvasState {
+    fn clone(&self) -> Self {
+        Self {
+            curves: self.curves.clone(),
+            text_curves: self.text_curves.clone(),
+            image_curves: self.image_curves.clone(),
+            draw_mode: self.draw_mode,
+            width: self.width,
+            height: self.height,
+            border_color: self.border_color,
+            border_width: self.border_width,
+            edit_widget_id: self.edit_widget_id,
+            escape_pressed: self.escape_pressed,
+            selected_widget: self.selected_widget,
+            selected_canvas_color: self.selected_canvas_color,
+            selected_draw_color: self.selected_draw_color,
+            selected_fill_color: self.selected_fill_color,
+            selected_poly_points: self.selected_poly_points,
+            selected_step_degrees: self.selected_step_degrees,
+            selected_width: self.selected_width,
+            selected_h_text_alignment: self.selected_h_text_alignment,
+            selected_v_text_alignment: self.selected_v_text_alignment,
+            selected_unit_scale: self.selected_unit_scale,
+            selected_units: self.selected_units.clone(),
+            timer_event_enabled: self.timer_event_enabled,
+            timer_duration: self.timer_duration,
+            elapsed_time: self.elapsed_time,
+            blink: self.blink,
+            file_path: self.file_path.clone(),
+            canvas_id: self.canvas_id,
+            gestures: self.gestures.clone(),
+            ..Default::default()
         }
     }
 }
@@ -134,7 +185,7 @@ impl IpgCanvasState {
                     curves: &'a HashMap<usize, IpgWidget>, 
                     text_curves: &'a HashMap<usize, IpgWidget>,
                     image_curves: &'a HashMap<usize, IpgWidget>,
-                    ) -> Element<'a, IpgWidget> {
+                    ) -> Element<'a, CanvasMessage> {
         Canvas::new(DrawPending {
             state: self,
             curves,
@@ -183,12 +234,10 @@ struct DrawPending<'a> {
     image_curves: &'a HashMap<usize, IpgWidget>,
 }
 
-impl canvas::Program<IpgWidget> for DrawPending<'_> {
-    type State = Option<Pending>;
-
-    fn update(
+impl DrawPending<'_> {
+    fn update_drawing(
         &self,
-        program_state: &mut Self::State,
+        program_state: &mut Option<Pending>,
         event: Event,
         bounds: iced::Rectangle,
         cursor: mouse::Cursor,
@@ -321,6 +370,8 @@ impl canvas::Program<IpgWidget> for DrawPending<'_> {
                                                 self.state.draw_mode,
                                                 self.state.selected_h_text_alignment,
                                                 self.state.selected_v_text_alignment,
+                                                self.state.selected_unit_scale,
+                                                self.state.selected_units.clone(),
                                             );
                                         
                                         let (widget, _) = 
@@ -504,7 +555,13 @@ impl canvas::Program<IpgWidget> for DrawPending<'_> {
                                                     *program_state = Some(Pending::New { 
                                                         widget: widget.clone(), 
                                                     });
-                                                    Some(widget)
+                                                    // The callout is drawn by the pending
+                                                    // until finished, so nothing to return.
+                                                    if matches!(widget, IpgWidget::Callout(_)) {
+                                                        None
+                                                    } else {
+                                                        Some(widget)
+                                                    }
                                                 } else {
                                                     *program_state = None;
                                                     Some(widget)
@@ -545,6 +602,46 @@ impl canvas::Program<IpgWidget> for DrawPending<'_> {
             _ => (event::Status::Ignored, None),
         }
     }
+}
+
+// Only a finger pressed outside of the canvas is left out, the
+// others may belong to a finger that was pressed inside.
+fn touch_in_canvas(touch_event: touch::Event, bounds: iced::Rectangle) -> Option<touch::Event> {
+    let offset = Vector::new(bounds.x, bounds.y);
+    match touch_event {
+        touch::Event::FingerPressed { id, position } if bounds.contains(position) => 
+            Some(touch::Event::FingerPressed { id, position: position - offset }),
+        touch::Event::FingerPressed { .. } => None,
+        touch::Event::FingerMoved { id, position } => 
+            Some(touch::Event::FingerMoved { id, position: position - offset }),
+        touch::Event::FingerLifted { id, position } => 
+            Some(touch::Event::FingerLifted { id, position: position - offset }),
+        touch::Event::FingerLost { id, position } => 
+            Some(touch::Event::FingerLost { id, position: position - offset }),
+    }
+}
+
+impl canvas::Program<CanvasMessage> for DrawPending<'_> {
+    type State = Option<Pending>;
+
+    fn update(
+        &self,
+        program_state: &mut Self::State,
+        event: Event,
+        bounds: iced::Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<CanvasMessage>) {
+        // the touches are sent for the gestures with their position in the canvas
+        if let Event::Touch(touch_event) = event {
+            return match touch_in_canvas(touch_event, bounds) {
+                Some(touch_event) => (event::Status::Captured, Some(CanvasMessage::Touch(touch_event))),
+                None => (event::Status::Ignored, None),
+            }
+        }
+
+        let (status, widget) = self.update_drawing(program_state, event, bounds, cursor);
+        (status, widget.map(CanvasMessage::WidgetDraw))
+    }
 
     fn draw(
         &self,
@@ -823,6 +920,64 @@ impl DrawCurve {
                         }
                     },
                     
+                    IpgWidget::Dimension(dim) => {
+                        // skip if being editied or rotated
+                        if dim.status == IpgDrawStatus::Inprogress {
+                            (None, None, None, 0, None)
+                        } else {
+                            let (path, text) = 
+                                build_dimension_path(
+                                    dim, 
+                                    dim.draw_mode, 
+                                    None, 
+                                    None, 
+                                    false,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (Some(path), Some(dim.color), Some(dim.width), 0, None)
+                        }
+                    },
+                    IpgWidget::AngleMeasure(ang) => {
+                        // skip if being editied or rotated
+                        if ang.status == IpgDrawStatus::Inprogress {
+                            (None, None, None, 0, None)
+                        } else {
+                            let (path, text) = 
+                                build_angle_measure_path(
+                                    ang, 
+                                    ang.draw_mode, 
+                                    None, 
+                                    None, 
+                                    false,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (Some(path), Some(ang.color), Some(ang.width), 0, None)
+                        }
+                    },
+                    IpgWidget::Callout(co) => {
+                        // skip if being editied or rotated
+                        if co.status == IpgDrawStatus::Inprogress {
+                            (None, None, None, 0, None)
+                        } else {
+                            let (path, text) = 
+                                build_callout_path(
+                                    co, 
+                                    co.draw_mode, 
+                                    None, 
+                                    None, 
+                                    false,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (Some(path), Some(co.color), Some(co.width), 0, None)
+                        }
+                    },
+                    
                     _ => (None, None, None, 0, None),
                 };
 
@@ -1080,6 +1235,48 @@ impl Pending {
                                 );
                             (path, fh.color, fh.width, None, None, None)
                         }
+                        IpgWidget::Dimension(dim) => {
+                            let (path, text) = 
+                                build_dimension_path(
+                                    dim, 
+                                    IpgDrawMode::New, 
+                                    Some(cursor),
+                                    None, 
+                                    false,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (path, dim.color, dim.width, None, None, None)
+                        },
+                        IpgWidget::AngleMeasure(ang) => {
+                            let (path, text) = 
+                                build_angle_measure_path(
+                                    ang, 
+                                    IpgDrawMode::New, 
+                                    Some(cursor),
+                                    None, 
+                                    false,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (path, ang.color, ang.width, None, None, None)
+                        },
+                        IpgWidget::Callout(co) => {
+                            let (path, text) = 
+                                build_callout_path(
+                                    co, 
+                                    IpgDrawMode::New, 
+                                    Some(cursor),
+                                    None, 
+                                    false,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (path, co.color, co.width, None, None, None)
+                        },
                         _ => {
                             (Path::new(|_| {}), Color::TRANSPARENT, 0.0, None, None, None)  
                         }
@@ -1246,6 +1443,48 @@ impl Pending {
                                 frame.fill_text(text);
                                 (path.unwrap(), txt.color, 2.0)
                             },
+                            IpgWidget::Dimension(dim) => {
+                                let (path, text) = 
+                                    build_dimension_path(
+                                        dim, 
+                                        IpgDrawMode::Edit, 
+                                        Some(cursor),
+                                        None, 
+                                        false,
+                                    );
+                                if let Some(text) = text {
+                                    frame.fill_text(text);
+                                }
+                                (path, dim.color, dim.width)
+                            },
+                            IpgWidget::AngleMeasure(ang) => {
+                                let (path, text) = 
+                                    build_angle_measure_path(
+                                        ang, 
+                                        IpgDrawMode::Edit, 
+                                        Some(cursor),
+                                        None, 
+                                        false,
+                                    );
+                                if let Some(text) = text {
+                                    frame.fill_text(text);
+                                }
+                                (path, ang.color, ang.width)
+                            },
+                            IpgWidget::Callout(co) => {
+                                let (path, text) = 
+                                    build_callout_path(
+                                        co, 
+                                        IpgDrawMode::Edit, 
+                                        Some(cursor),
+                                        None, 
+                                        false,
+                                    );
+                                if let Some(text) = text {
+                                    frame.fill_text(text);
+                                }
+                                (path, co.color, co.width)
+                            },
                             _ => {
                                 (Path::new(|_| {}), Color::TRANSPARENT, 0.0)
                             },
@@ -1399,6 +1638,48 @@ impl Pending {
                             frame.fill_text(text);
                             (path.unwrap(), Color::TRANSPARENT, 0.0, Point::default(), None, None)
                         },
+                        IpgWidget::Dimension(dim) => {
+                            let (path, text) = 
+                                build_dimension_path(
+                                    dim, 
+                                    IpgDrawMode::Edit, 
+                                    Some(cursor),
+                                    *edit_point_index, 
+                                    *edit_mid_point,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (path, dim.color, dim.width, Point::default(), None, None)
+                        },
+                        IpgWidget::AngleMeasure(ang) => {
+                            let (path, text) = 
+                                build_angle_measure_path(
+                                    ang, 
+                                    IpgDrawMode::Edit, 
+                                    Some(cursor),
+                                    *edit_point_index, 
+                                    *edit_mid_point,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (path, ang.color, ang.width, Point::default(), None, None)
+                        },
+                        IpgWidget::Callout(co) => {
+                            let (path, text) = 
+                                build_callout_path(
+                                    co, 
+                                    IpgDrawMode::Edit, 
+                                    Some(cursor),
+                                    *edit_point_index, 
+                                    *edit_mid_point,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (path, co.color, co.width, Point::default(), None, None)
+                        },
                         _ => {
                             (Path::new(|_| {}), Color::TRANSPARENT, 0.0, Point::default(), None, None)
                         },
@@ -1576,6 +1857,48 @@ impl Pending {
                             
                             (path.unwrap(), text.color, 2.0, Point::default(), None, None)
                         }
+                        IpgWidget::Dimension(dim) => {
+                            let (path, text) = 
+                                build_dimension_path(
+                                    dim, 
+                                    IpgDrawMode::Rotate, 
+                                    None,
+                                    None, 
+                                    false,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (path, dim.color, dim.width, dim.mid_point, None, Some(dim.rotation))
+                        },
+                        IpgWidget::AngleMeasure(ang) => {
+                            let (path, text) = 
+                                build_angle_measure_path(
+                                    ang, 
+                                    IpgDrawMode::Rotate, 
+                                    None,
+                                    None, 
+                                    false,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (path, ang.color, ang.width, ang.mid_point, None, Some(ang.rotation))
+                        },
+                        IpgWidget::Callout(co) => {
+                            let (path, text) = 
+                                build_callout_path(
+                                    co, 
+                                    IpgDrawMode::Rotate, 
+                                    None,
+                                    None, 
+                                    false,
+                                );
+                            if let Some(text) = text {
+                                frame.fill_text(text);
+                            }
+                            (path, co.color, co.width, co.mid_point, None, Some(co.rotation))
+                        },
                         _ => {
                             (Path::new(|_| {}), Color::TRANSPARENT, 0.0, Point::default(), None, None)
                         }
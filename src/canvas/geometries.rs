@@ -186,6 +186,54 @@ pub struct IpgText {
     pub status: IpgDrawStatus,
 }
 
+// Measures the distance between the two points.
+// The label is calculated when drawn so it stays correct after edits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpgDimension {
+    pub id: usize,
+    pub points: Vec<Point>,
+    pub mid_point: Point,
+    pub color: Color,
+    pub width: f32,
+    pub unit_scale: f32,
+    pub units: String,
+    pub precision: usize,
+    pub text_size: f32,
+    pub rotation: f32,
+    pub draw_mode: IpgDrawMode,
+    pub status: IpgDrawStatus,
+}
+
+// points[0] is the vertex, points[1] and points[2] are the arms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpgAngleMeasure {
+    pub id: usize,
+    pub points: Vec<Point>,
+    pub mid_point: Point,
+    pub color: Color,
+    pub width: f32,
+    pub precision: usize,
+    pub text_size: f32,
+    pub rotation: f32,
+    pub draw_mode: IpgDrawMode,
+    pub status: IpgDrawStatus,
+}
+
+// points[0] is the anchor, points[1] is the text position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpgCallout {
+    pub id: usize,
+    pub points: Vec<Point>,
+    pub mid_point: Point,
+    pub content: String,
+    pub color: Color,
+    pub width: f32,
+    pub text_size: f32,
+    pub rotation: f32,
+    pub draw_mode: IpgDrawMode,
+    pub status: IpgDrawStatus,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct IpgFreeHand {
     pub id: usize,
@@ -215,6 +263,9 @@ pub enum IpgCanvasWidget {
     RightTriangle,
     Text,
     FreeHand,
+    Dimension,
+    AngleMeasure,
+    Callout,
 }
 
 pub fn check_if_text_widget(canvas_widget: &IpgWidget) -> bool {
@@ -229,6 +280,8 @@ pub fn add_new_widget(widget: IpgCanvasWidget,
                         draw_mode: IpgDrawMode,
                         h_alignment: alignment::Horizontal,
                         v_alignment: alignment::Vertical,
+                        unit_scale: f32,
+                        units: String,
                         ) 
                         -> IpgWidget {
     match widget {
@@ -429,6 +482,56 @@ pub fn add_new_widget(widget: IpgCanvasWidget,
                 }
             )
         },
+        IpgCanvasWidget::Dimension => {
+            IpgWidget::Dimension(
+                IpgDimension {
+                    id: 0,
+                    points: vec![],
+                    mid_point: Point::default(),
+                    color,
+                    width,
+                    unit_scale,
+                    units,
+                    precision: 2,
+                    text_size: 14.0,
+                    rotation: 0.0,
+                    draw_mode,
+                    status: IpgDrawStatus::Inprogress,
+                }
+            )
+        },
+        IpgCanvasWidget::AngleMeasure => {
+            IpgWidget::AngleMeasure(
+                IpgAngleMeasure {
+                    id: 0,
+                    points: vec![],
+                    mid_point: Point::default(),
+                    color,
+                    width,
+                    precision: 1,
+                    text_size: 14.0,
+                    rotation: 0.0,
+                    draw_mode,
+                    status: IpgDrawStatus::Inprogress,
+                }
+            )
+        },
+        IpgCanvasWidget::Callout => {
+            IpgWidget::Callout(
+                IpgCallout {
+                    id: 0,
+                    points: vec![],
+                    mid_point: Point::default(),
+                    content: String::new(),
+                    color,
+                    width,
+                    text_size: 16.0,
+                    rotation: 0.0,
+                    draw_mode,
+                    status: IpgDrawStatus::Inprogress,
+                }
+            )
+        },
     }
 }
 
@@ -535,6 +638,21 @@ pub fn complete_new_widget(widget: IpgWidget, cursor: Point) -> Option<IpgWidget
             txt.status = IpgDrawStatus::Completed;
             Some(IpgWidget::Text(txt))
         },
+        IpgWidget::Dimension(mut dim) => {
+            dim.mid_point = get_mid_point(dim.points[0], dim.points[1]);
+            dim.rotation = get_horizontal_angle_of_vector(dim.points[0], dim.points[1]);
+            Some(IpgWidget::Dimension(dim))
+        },
+        IpgWidget::AngleMeasure(mut ang) => {
+            ang.mid_point = ang.points[0];
+            ang.rotation = get_horizontal_angle_of_vector(ang.points[0], ang.points[1]);
+            Some(IpgWidget::AngleMeasure(ang))
+        },
+        IpgWidget::Callout(mut co) => {
+            co.mid_point = get_mid_point(co.points[0], co.points[1]);
+            co.rotation = get_horizontal_angle_of_vector(co.points[0], co.points[1]);
+            Some(IpgWidget::Callout(co))
+        },
         _ => {
             None
         },
@@ -804,6 +922,57 @@ pub fn update_edited_widget(widget: IpgWidget,
             txt.status = status;
             IpgWidget::Text(txt)
         },
+        IpgWidget::Dimension(mut dim) => {
+            if index.is_some() {
+                dim.points[index.unwrap()] = cursor;
+                dim.mid_point = get_mid_point(dim.points[0], dim.points[1]);
+            } else if mid_point {
+                dim.points = 
+                    translate_geometry(
+                        &dim.points, 
+                        cursor,
+                        dim.mid_point, 
+                        );
+                dim.mid_point = cursor;
+            }
+            dim.rotation = get_horizontal_angle_of_vector(dim.points[0], dim.points[1]);
+            dim.status = status;
+            IpgWidget::Dimension(dim)
+        },
+        IpgWidget::AngleMeasure(mut ang) => {
+            // the vertex is the mid point so moving it translates the measure
+            if index.is_some() {
+                ang.points[index.unwrap()] = cursor;
+            } else if mid_point {
+                ang.points = 
+                    translate_geometry(
+                        &ang.points, 
+                        cursor,
+                        ang.mid_point, 
+                        );
+                ang.mid_point = cursor;
+            }
+            ang.rotation = get_horizontal_angle_of_vector(ang.points[0], ang.points[1]);
+            ang.status = status;
+            IpgWidget::AngleMeasure(ang)
+        },
+        IpgWidget::Callout(mut co) => {
+            if index.is_some() {
+                co.points[index.unwrap()] = cursor;
+                co.mid_point = get_mid_point(co.points[0], co.points[1]);
+            } else if mid_point {
+                co.points = 
+                    translate_geometry(
+                        &co.points, 
+                        cursor,
+                        co.mid_point, 
+                        );
+                co.mid_point = cursor;
+            }
+            co.rotation = get_horizontal_angle_of_vector(co.points[0], co.points[1]);
+            co.status = status;
+            IpgWidget::Callout(co)
+        },
         _ => {
             IpgWidget::None
         },
@@ -910,6 +1079,30 @@ pub fn update_rotated_widget(widget: &mut IpgWidget,
             }
             (IpgWidget::Text(txt.clone()), txt.rotation)
         },
+        IpgWidget::Dimension(dim) => {
+            dim.points = rotate_geometry(&dim.points, &dim.mid_point, &step_degrees, IpgCanvasWidget::Dimension);
+            dim.rotation = get_horizontal_angle_of_vector(dim.points[0], dim.points[1]);
+            if status.is_some() {
+                dim.status = status.unwrap();
+            }
+            (IpgWidget::Dimension(dim.clone()), dim.rotation)
+        },
+        IpgWidget::AngleMeasure(ang) => {
+            ang.points = rotate_geometry(&ang.points, &ang.mid_point, &step_degrees, IpgCanvasWidget::AngleMeasure);
+            ang.rotation = get_horizontal_angle_of_vector(ang.points[0], ang.points[1]);
+            if status.is_some() {
+                ang.status = status.unwrap();
+            }
+            (IpgWidget::AngleMeasure(ang.clone()), ang.rotation)
+        },
+        IpgWidget::Callout(co) => {
+            co.points = rotate_geometry(&co.points, &co.mid_point, &step_degrees, IpgCanvasWidget::Callout);
+            co.rotation = get_horizontal_angle_of_vector(co.points[0], co.points[1]);
+            if status.is_some() {
+                co.status = status.unwrap();
+            }
+            (IpgWidget::Callout(co.clone()), co.rotation)
+        },
         _ => (IpgWidget::None, 0.0),
     }
 }
@@ -960,6 +1153,39 @@ pub fn add_keypress(widget: &mut IpgWidget, modified: Key) -> (Option<IpgWidget>
            (Some(IpgWidget::FreeHand(fh.clone())), fh.completed)
             
         }
+        IpgWidget::Callout(co) => {
+            // Enter finishes the callout, escape cancels it
+            match modified.as_ref() {
+                Key::Named(named) => {
+                    match named {
+                        iced::keyboard::key::Named::Enter => {
+                            if co.points.len() == 2 {
+                                co.mid_point = get_mid_point(co.points[0], co.points[1]);
+                                co.rotation = get_horizontal_angle_of_vector(co.points[0], co.points[1]);
+                                return (Some(IpgWidget::Callout(co.clone())), true)
+                            }
+                        },
+                        iced::keyboard::key::Named::Space => {
+                            co.content.push(' ');
+                        },
+                        iced::keyboard::key::Named::Escape => escape = true,
+                        iced::keyboard::key::Named::Backspace => {
+                            co.content.pop();
+                        } 
+                        _ => ()
+                    }
+                },
+                Key::Character(c) => {
+                    co.content.push_str(c);
+                },
+                Key::Unidentified => (),
+            }
+            if escape {
+                (None, false)
+            } else {
+                (Some(IpgWidget::Callout(co.clone())), false)
+            }
+        },
         _ => (None, false)
     }
 }
@@ -1123,6 +1349,42 @@ pub fn set_widget_mode_or_status_or_id(widget: IpgWidget,
             }
             IpgWidget::Text(txt)
         },
+        IpgWidget::Dimension(mut dim) => {
+            if mode.is_some() {
+                dim.draw_mode = mode.unwrap();
+            }
+            if status.is_some() {
+                dim.status = status.unwrap();
+            }
+            if id.is_some() {
+                dim.id = id.unwrap();
+            }
+            IpgWidget::Dimension(dim)
+        },
+        IpgWidget::AngleMeasure(mut ang) => {
+            if mode.is_some() {
+                ang.draw_mode = mode.unwrap();
+            }
+            if status.is_some() {
+                ang.status = status.unwrap();
+            }
+            if id.is_some() {
+                ang.id = id.unwrap();
+            }
+            IpgWidget::AngleMeasure(ang)
+        },
+        IpgWidget::Callout(mut co) => {
+            if mode.is_some() {
+                co.draw_mode = mode.unwrap();
+            }
+            if status.is_some() {
+                co.status = status.unwrap();
+            }
+            if id.is_some() {
+                co.id = id.unwrap();
+            }
+            IpgWidget::Callout(co)
+        },
         IpgWidget::None => {
             IpgWidget::None
         },
@@ -1295,6 +1557,40 @@ pub fn set_widget_point(widget: &IpgWidget, cursor: Point) -> (IpgWidget, bool)
             
             (IpgWidget::Text(txt), finished)
         },
+        IpgWidget::Dimension(dim) => {
+            let mut dim = dim.clone();
+            dim.points.push(cursor);
+
+            let finished = if dim.points.len() == 2 {
+                dim.mid_point = get_mid_point(dim.points[0], dim.points[1]);
+                true
+            } else {
+                false
+            };
+            
+            (IpgWidget::Dimension(dim), finished)
+        },
+        IpgWidget::AngleMeasure(ang) => {
+            let mut ang = ang.clone();
+            ang.points.push(cursor);
+            ang.mid_point = ang.points[0];
+            let finished = ang.points.len() == 3;
+            
+            (IpgWidget::AngleMeasure(ang), finished)
+        },
+        IpgWidget::Callout(co) => {
+            // anchor, text position, then the text is typed
+            // and a third click or enter finishes.
+            let mut co = co.clone();
+            let finished = if co.points.len() < 2 {
+                co.points.push(cursor);
+                false
+            } else {
+                true
+            };
+            
+            (IpgWidget::Callout(co), finished)
+        },
         _ => (IpgWidget::None, true),
     }
 }
@@ -1495,6 +1791,61 @@ pub fn find_closest_point_index(widget: &IpgWidget,
             // just using the edit_other_point to indicate the position point
             (None, false, true)
         },
+        IpgWidget::Dimension(dim) => {
+            for (idx, point) in dim.points.iter().enumerate() {
+                let dist = cursor.distance(*point);
+                if  dist < point_dist {
+                    point_index = idx;
+                    point_dist = dist;
+                }
+            };
+            
+            let mid_dist = cursor.distance(dim.mid_point);
+
+            if mid_dist < point_dist {
+                (None, true, false)
+            } else {
+                (Some(point_index), false, false)
+            }
+        },
+        IpgWidget::AngleMeasure(ang) => {
+            for (idx, point) in ang.points.iter().enumerate() {
+                // skip first point since its the vertex and mid_point too.
+                if idx == 0 {
+                    continue;
+                }
+                let dist = cursor.distance(*point);
+                if  dist < point_dist {
+                    point_index = idx;
+                    point_dist = dist;
+                }
+            };
+            
+            let mid_dist = cursor.distance(ang.mid_point);
+
+            if mid_dist < point_dist {
+                (None, true, false)
+            } else {
+                (Some(point_index), false, false)
+            }
+        },
+        IpgWidget::Callout(co) => {
+            for (idx, point) in co.points.iter().enumerate() {
+                let dist = cursor.distance(*point);
+                if  dist < point_dist {
+                    point_index = idx;
+                    point_dist = dist;
+                }
+            };
+            
+            let mid_dist = cursor.distance(co.mid_point);
+
+            if mid_dist < point_dist {
+                (None, true, false)
+            } else {
+                (Some(point_index), false, false)
+            }
+        },
         _ => (None, false, false),
     }
     
@@ -1515,6 +1866,9 @@ pub fn get_widget_id(widget: &IpgWidget) -> usize {
         IpgWidget::RightTriangle(tr) => tr.id,
         IpgWidget::FreeHand(fh) => fh.id,
         IpgWidget::Text(txt) => txt.id,
+        IpgWidget::Dimension(dim) => dim.id,
+        IpgWidget::AngleMeasure(ang) => ang.id,
+        IpgWidget::Callout(co) => co.id,
         IpgWidget::None => 0,
     }
 }
@@ -1534,6 +1888,9 @@ pub fn get_widget_degrees(widget: &IpgWidget) -> Option<f32> {
         IpgWidget::RightTriangle(tr) => Some(tr.rotation),
         IpgWidget::FreeHand(_) => None,
         IpgWidget::Text(txt) => Some(txt.rotation),
+        IpgWidget::Dimension(dim) => Some(dim.rotation),
+        IpgWidget::AngleMeasure(ang) => Some(ang.rotation),
+        IpgWidget::Callout(co) => Some(co.rotation),
     }
 }
 
@@ -1552,6 +1909,9 @@ pub fn get_draw_mode_and_status(widget: &IpgWidget) -> (IpgDrawMode, IpgDrawStat
         IpgWidget::RightTriangle(tr) => (tr.draw_mode, tr.status),
         IpgWidget::FreeHand(fh) => (fh.draw_mode, fh.status),
         IpgWidget::Text(txt) => (txt.draw_mode, txt.status),
+        IpgWidget::Dimension(dim) => (dim.draw_mode, dim.status),
+        IpgWidget::AngleMeasure(ang) => (ang.draw_mode, ang.status),
+        IpgWidget::Callout(co) => (co.draw_mode, co.status),
     }
 }
 
@@ -1588,6 +1948,15 @@ pub fn get_distance_to_mid_point(widget: &IpgWidget, cursor: Point) -> f32 {
             IpgWidget::Text(txt) => {
                 cursor.distance(txt.position)
             },
+            IpgWidget::Dimension(dim) => {
+                cursor.distance(dim.mid_point)
+            },
+            IpgWidget::AngleMeasure(ang) => {
+                cursor.distance(ang.mid_point)
+            },
+            IpgWidget::Callout(co) => {
+                cursor.distance(co.mid_point)
+            },
             _ => f32::INFINITY,
         }
 
@@ -1639,6 +2008,16 @@ pub fn get_mid_geometry(pts: &[Point], curve_type: IpgCanvasWidget) -> Point {
         IpgCanvasWidget::Text => {
             pts[0]
         }
+        IpgCanvasWidget::Dimension => {
+            get_mid_point(pts[0], pts[1])
+        },
+        IpgCanvasWidget::AngleMeasure => {
+            // return the vertex
+            pts[0]
+        },
+        IpgCanvasWidget::Callout => {
+            get_mid_point(pts[0], pts[1])
+        },
         IpgCanvasWidget::None => Point::default(),
     }
     
@@ -1659,5 +2038,8 @@ fn match_ipg_widget(widget: &IpgWidget) -> IpgCanvasWidget {
         IpgWidget::RightTriangle(_) => IpgCanvasWidget::RightTriangle,
         IpgWidget::Text(_) => IpgCanvasWidget::Text,
         IpgWidget::FreeHand(_) => IpgCanvasWidget::FreeHand,
+        IpgWidget::Dimension(_) => IpgCanvasWidget::Dimension,
+        IpgWidget::AngleMeasure(_) => IpgCanvasWidget::AngleMeasure,
+        IpgWidget::Callout(_) => IpgCanvasWidget::Callout,
     }
 }
\ No newline at end of file
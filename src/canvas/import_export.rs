@@ -6,7 +6,7 @@ use iced::{alignment, widget::text::{LineHeight, Shaping}, Color, Font, Pixels,
 use serde::{Deserialize, Serialize};
 
 use super::{draw_canvas::{IpgDrawMode, IpgDrawStatus, IpgWidget}, 
-    geometries::{IpgArc, IpgBezier, IpgCanvasWidget, IpgCircle, IpgEllipse, IpgFreeHand, IpgLine, IpgPolyLine, IpgPolygon, IpgRectangle, IpgRightTriangle, IpgText}};
+    geometries::{IpgAngleMeasure, IpgArc, IpgBezier, IpgCallout, IpgCanvasWidget, IpgCircle, IpgDimension, IpgEllipse, IpgFreeHand, IpgLine, IpgPolyLine, IpgPolygon, IpgRectangle, IpgRightTriangle, IpgText}};
 
 
 pub fn save(path: impl AsRef<Path>, data: &impl Serialize) -> std::io::Result<()> {
@@ -115,6 +115,19 @@ pub struct ExportWidget {
     pub width: f32,
     pub horizontal_alignment: ExportHorizontal,
     pub vertical_alignment: ExportVertical,
+    // measurement fields, defaulted so older files still load
+    #[serde(default = "default_unit_scale")]
+    pub unit_scale: f32,
+    #[serde(default)]
+    pub units: String,
+    #[serde(default)]
+    pub precision: usize,
+    #[serde(default)]
+    pub text_size: f32,
+}
+
+fn default_unit_scale() -> f32 {
+    1.0
 }
 
 #[allow(clippy::redundant_closure)]
@@ -344,6 +357,56 @@ pub fn import_widgets(widgets: Vec<ExportWidget>, mut last_id: usize)
                 };
                 text_curves.insert(last_id, IpgWidget::Text(txt));
             }
+            IpgCanvasWidget::Dimension => {
+                last_id += 1;
+                let dim = IpgDimension {
+                    id: last_id,
+                    points,
+                    mid_point,
+                    color,
+                    width,
+                    unit_scale: widget.unit_scale,
+                    units: widget.units.clone(),
+                    precision: widget.precision,
+                    text_size: widget.text_size,
+                    rotation,
+                    draw_mode,
+                    status,
+                };
+                curves.insert(last_id, IpgWidget::Dimension(dim));
+            },
+            IpgCanvasWidget::AngleMeasure => {
+                last_id += 1;
+                let ang = IpgAngleMeasure {
+                    id: last_id,
+                    points,
+                    mid_point,
+                    color,
+                    width,
+                    precision: widget.precision,
+                    text_size: widget.text_size,
+                    rotation,
+                    draw_mode,
+                    status,
+                };
+                curves.insert(last_id, IpgWidget::AngleMeasure(ang));
+            },
+            IpgCanvasWidget::Callout => {
+                last_id += 1;
+                let co = IpgCallout {
+                    id: last_id,
+                    points,
+                    mid_point,
+                    content: widget.content.clone(),
+                    color,
+                    width,
+                    text_size: widget.text_size,
+                    rotation,
+                    draw_mode,
+                    status,
+                };
+                curves.insert(last_id, IpgWidget::Callout(co));
+            },
         }
     }
 
@@ -421,12 +484,32 @@ pub fn convert_to_export(widgets: &HashMap<usize, IpgWidget>,
                     txt.color, Some(Color::TRANSPARENT), 0.0, txt.content.clone(), 
                     convert_to_export_horizontal(txt.horizontal_alignment), convert_to_export_vertical(txt.vertical_alignment))
                 },
+                IpgWidget::Dimension(dim) => {
+                    (IpgCanvasWidget::Dimension, &dim.points, dim.mid_point, Point::default(), 0, dim.rotation, 0.0, 
+                    dim.color, Some(Color::TRANSPARENT), dim.width, String::new(), ExportHorizontal::None, ExportVertical::None)
+                },
+                IpgWidget::AngleMeasure(ang) => {
+                    (IpgCanvasWidget::AngleMeasure, &ang.points, ang.mid_point, Point::default(), 0, ang.rotation, 0.0, 
+                    ang.color, Some(Color::TRANSPARENT), ang.width, String::new(), ExportHorizontal::None, ExportVertical::None)
+                },
+                IpgWidget::Callout(co) => {
+                    (IpgCanvasWidget::Callout, &co.points, co.mid_point, Point::default(), 0, co.rotation, 0.0, 
+                    co.color, Some(Color::TRANSPARENT), co.width, co.content.clone(), ExportHorizontal::None, ExportVertical::None)
+                },
                 _ => {
                     (IpgCanvasWidget::None, &vec![], Point::default(), Point::default(), 0, 0.0, 0.0, 
                     Color::TRANSPARENT, Some(Color::TRANSPARENT), 0.0, String::new(), ExportHorizontal::None, ExportVertical::None)
                 },
         };
 
+        let (unit_scale, units, precision, text_size) = 
+            match widget {
+                IpgWidget::Dimension(dim) => (dim.unit_scale, dim.units.clone(), dim.precision, dim.text_size),
+                IpgWidget::AngleMeasure(ang) => (1.0, String::new(), ang.precision, ang.text_size),
+                IpgWidget::Callout(co) => (1.0, String::new(), 0, co.text_size),
+                _ => (1.0, String::new(), 0, 0.0),
+            };
+
         let x_color = ExportColor::from_rgba(&color);
         let x_fill_color = if let Some(color) = fill_color {
             ExportColor::from_rgba(&color)
@@ -455,7 +538,11 @@ pub fn convert_to_export(widgets: &HashMap<usize, IpgWidget>,
                 fill_color: x_fill_color, 
                 width,
                 horizontal_alignment,
-                vertical_alignment,  
+                vertical_alignment,
+                unit_scale,
+                units,
+                precision,
+                text_size,  
             })
     }
     
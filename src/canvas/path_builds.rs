@@ -2,13 +2,14 @@
 
 use std::f32::consts::PI;
 
-use iced::{widget::canvas::{self, path::arc::Elliptical, Path}, Point, Radians, Vector};
-use crate::canvas::geometries::{IpgArc, IpgBezier, IpgCircle, IpgEllipse, IpgFreeHand, 
-    IpgLine, IpgPolyLine, IpgPolygon, IpgRightTriangle, IpgText, IpgCanvasWidget};
+use iced::{alignment, widget::canvas::{self, path::arc::Elliptical, Path}, Point, Radians, Vector};
+use crate::canvas::geometries::{IpgAngleMeasure, IpgArc, IpgBezier, IpgCallout, IpgCircle, 
+    IpgDimension, IpgEllipse, IpgFreeHand, IpgLine, IpgPolyLine, IpgPolygon, 
+    IpgRightTriangle, IpgText, IpgCanvasWidget};
 use crate::{canvas::draw_canvas::IpgDrawMode, 
-canvas::canvas_helpers::{build_polygon, get_angle_of_vectors, 
-    get_horizontal_angle_of_vector, get_mid_point, rotate_geometry, to_degrees, 
-    translate_geometry}};
+canvas::canvas_helpers::{build_polygon, format_measurement, get_angle_of_vectors, 
+    get_horizontal_angle_of_vector, get_inner_angle_degrees, get_mid_point, 
+    get_point_on_circle, get_scaled_distance, rotate_geometry, to_degrees, translate_geometry}};
 
 use super::geometries::get_mid_geometry;
 
@@ -908,3 +909,316 @@ pub fn build_text_path (txt: &IpgText,
 
                   
 }
+
+// Returns the points as they should be drawn during the pending modes.
+fn get_pending_points(points: &[Point], 
+                    mid_point: Point,
+                    draw_mode: IpgDrawMode, 
+                    pending_cursor: Option<Point>,
+                    edit_point_index: Option<usize>, 
+                    edit_mid_point: bool,
+                    ) -> (Vec<Point>, Point) {
+    let mut pts = points.to_vec();
+    let mut mid_point = mid_point;
+    match draw_mode {
+        IpgDrawMode::New => {
+            if let Some(cursor) = pending_cursor {
+                pts.push(cursor);
+            }
+        },
+        IpgDrawMode::Edit => {
+            if edit_mid_point {
+                pts = translate_geometry(
+                    &pts, 
+                    pending_cursor.unwrap(),
+                    mid_point,
+                );
+                mid_point = pending_cursor.unwrap();
+            };
+            if edit_point_index.is_some() {
+                pts[edit_point_index.unwrap()] = pending_cursor.unwrap();
+            }
+        },
+        _ => (),
+    }
+    (pts, mid_point)
+}
+
+pub fn build_dimension_path(dim: &IpgDimension, 
+                    draw_mode: IpgDrawMode, 
+                    pending_cursor: Option<Point>,
+                    edit_point_index: Option<usize>, 
+                    edit_mid_point: bool,
+                    ) -> (Path, Option<canvas::Text>) {
+
+    let (pts, _) = 
+        get_pending_points(
+            &dim.points, 
+            dim.mid_point, 
+            draw_mode, 
+            pending_cursor, 
+            edit_point_index, 
+            edit_mid_point,
+        );
+
+    if pts.len() < 2 {
+        return (Path::new(|_| {}), None)
+    }
+
+    let mid_point = get_mid_point(pts[0], pts[1]);
+    let length = pts[0].distance(pts[1]);
+    // unit normal, pointing up so the label sits above the line
+    let mut normal = if length > 0.0 {
+        Vector::new(-(pts[1].y - pts[0].y) / length, (pts[1].x - pts[0].x) / length)
+    } else {
+        Vector::new(0.0, -1.0)
+    };
+    if normal.y > 0.0 {
+        normal = Vector::new(-normal.x, -normal.y);
+    }
+    let tick = Vector::new(normal.x * 6.0, normal.y * 6.0);
+
+    let path = Path::new(|p| {
+        p.move_to(pts[0]);
+        p.line_to(pts[1]);
+        for pt in pts.iter() {
+            p.move_to(*pt - tick);
+            p.line_to(*pt + tick);
+        }
+        if draw_mode == IpgDrawMode::Edit {
+            p.circle(pts[0], 3.0);
+            p.circle(pts[1], 3.0);
+            p.circle(mid_point, 3.0);
+        }
+        if draw_mode == IpgDrawMode::Rotate {
+            p.circle(mid_point, 3.0);
+        }
+    });
+
+    let value = get_scaled_distance(pts[0], pts[1], dim.unit_scale);
+    let text = canvas::Text {
+        content: format_measurement(value, dim.precision, &dim.units),
+        position: mid_point + Vector::new(normal.x * 12.0, normal.y * 12.0),
+        color: dim.color,
+        size: dim.text_size.into(),
+        horizontal_alignment: alignment::Horizontal::Center,
+        vertical_alignment: alignment::Vertical::Center,
+        ..canvas::Text::default()
+    };
+
+    (path, Some(text))
+}
+
+pub fn build_angle_measure_path(ang: &IpgAngleMeasure, 
+                    draw_mode: IpgDrawMode, 
+                    pending_cursor: Option<Point>,
+                    edit_point_index: Option<usize>, 
+                    edit_mid_point: bool,
+                    ) -> (Path, Option<canvas::Text>) {
+
+    let (pts, _) = 
+        get_pending_points(
+            &ang.points, 
+            ang.mid_point, 
+            draw_mode, 
+            pending_cursor, 
+            edit_point_index, 
+            edit_mid_point,
+        );
+
+    // only the first arm is known yet
+    if pts.len() < 3 {
+        let path = Path::new(|p| {
+            if pts.len() == 2 {
+                p.move_to(pts[0]);
+                p.line_to(pts[1]);
+            }
+        });
+        return (path, None)
+    }
+
+    let vertex = pts[0];
+    let start_angle = (pts[1].y - vertex.y).atan2(pts[1].x - vertex.x);
+    let mut sweep = (pts[2].y - vertex.y).atan2(pts[2].x - vertex.x) - start_angle;
+    // use the inner angle
+    if sweep > PI {
+        sweep -= 2.0 * PI;
+    } else if sweep < -PI {
+        sweep += 2.0 * PI;
+    }
+    let shortest_arm = vertex.distance(pts[1]).min(vertex.distance(pts[2]));
+    let radius = 25.0_f32.min(shortest_arm / 2.0);
+
+    let path = Path::new(|p| {
+        p.move_to(pts[1]);
+        p.line_to(vertex);
+        p.line_to(pts[2]);
+        if radius > 0.0 {
+            p.arc(canvas::path::Arc {
+                center: vertex,
+                radius,
+                start_angle: Radians(start_angle),
+                end_angle: Radians(start_angle + sweep),
+            });
+        }
+        if draw_mode == IpgDrawMode::Edit || draw_mode == IpgDrawMode::Rotate {
+            p.circle(vertex, 3.0);
+        }
+        if draw_mode == IpgDrawMode::Edit {
+            p.circle(pts[1], 3.0);
+            p.circle(pts[2], 3.0);
+        }
+    });
+
+    // the label sits on the bisector just outside the arc
+    let bisector = start_angle + sweep / 2.0;
+    let label_dist = radius + ang.text_size;
+    let degrees = get_inner_angle_degrees(vertex, pts[1], pts[2]);
+    let text = canvas::Text {
+        content: format_measurement(degrees, ang.precision, "°"),
+        position: Point::new(vertex.x + label_dist * bisector.cos(), 
+                            vertex.y + label_dist * bisector.sin()),
+        color: ang.color,
+        size: ang.text_size.into(),
+        horizontal_alignment: alignment::Horizontal::Center,
+        vertical_alignment: alignment::Vertical::Center,
+        ..canvas::Text::default()
+    };
+
+    (path, Some(text))
+}
+
+pub fn build_callout_path(co: &IpgCallout, 
+                    draw_mode: IpgDrawMode, 
+                    pending_cursor: Option<Point>,
+                    edit_point_index: Option<usize>, 
+                    edit_mid_point: bool,
+                    ) -> (Path, Option<canvas::Text>) {
+
+    // once the text position is set, the cursor is no longer followed
+    let cursor = if draw_mode == IpgDrawMode::New && co.points.len() == 2 {
+        None
+    } else {
+        pending_cursor
+    };
+
+    let (pts, mid_point) = 
+        get_pending_points(
+            &co.points, 
+            co.mid_point, 
+            draw_mode, 
+            cursor, 
+            edit_point_index, 
+            edit_mid_point,
+        );
+
+    let path = Path::new(|p| {
+        if pts.is_empty() {
+            return
+        }
+        p.circle(pts[0], 3.0);
+        if pts.len() > 1 {
+            p.move_to(pts[0]);
+            p.line_to(pts[1]);
+        }
+        if draw_mode == IpgDrawMode::Edit {
+            if pts.len() > 1 {
+                p.circle(pts[1], 3.0);
+            }
+            p.circle(mid_point, 3.0);
+        }
+        if draw_mode == IpgDrawMode::Rotate {
+            p.circle(mid_point, 3.0);
+        }
+    });
+
+    if pts.len() < 2 {
+        return (path, None)
+    }
+
+    let mut content = co.content.clone();
+    if draw_mode == IpgDrawMode::New {
+        content.push('|');
+    }
+
+    // place the text on the side away from the anchor
+    let (position, horizontal_alignment) = if pts[1].x >= pts[0].x {
+        (Point::new(pts[1].x + 6.0, pts[1].y), alignment::Horizontal::Left)
+    } else {
+        (Point::new(pts[1].x - 6.0, pts[1].y), alignment::Horizontal::Right)
+    };
+
+    let text = canvas::Text {
+        content,
+        position,
+        color: co.color,
+        size: co.text_size.into(),
+        horizontal_alignment,
+        vertical_alignment: alignment::Vertical::Center,
+        ..canvas::Text::default()
+    };
+
+    (path, Some(text))
+}
+
+// The gauge builders below are shared by the gauge, meter and knob widgets.
+// Angles are in radians, clockwise from the positive x axis.
+
+pub fn build_arc_band_path(center: Point, 
+                    inner_radius: f32, 
+                    outer_radius: f32, 
+                    start_angle: f32, 
+                    end_angle: f32,
+                    ) -> Path {
+
+    Path::new(|p| {
+        p.move_to(get_point_on_circle(center, outer_radius, start_angle));
+        p.arc(canvas::path::Arc{ 
+                center, 
+                radius: outer_radius, 
+                start_angle: Radians(start_angle), 
+                end_angle: Radians(end_angle), 
+            });
+        p.line_to(get_point_on_circle(center, inner_radius, end_angle));
+        p.arc(canvas::path::Arc{ 
+                center, 
+                radius: inner_radius, 
+                start_angle: Radians(end_angle), 
+                end_angle: Radians(start_angle), 
+            });
+        p.close();
+    })
+}
+
+pub fn build_radial_ticks_path(center: Point, 
+                    inner_radius: f32, 
+                    outer_radius: f32, 
+                    angles: &[f32],
+                    ) -> Path {
+
+    Path::new(|p| {
+        for angle in angles.iter() {
+            p.move_to(get_point_on_circle(center, inner_radius, *angle));
+            p.line_to(get_point_on_circle(center, outer_radius, *angle));
+        }
+    })
+}
+
+pub fn build_needle_path(center: Point, 
+                    length: f32, 
+                    base_width: f32, 
+                    angle: f32,
+                    ) -> Path {
+
+    let tip = get_point_on_circle(center, length, angle);
+    let left = get_point_on_circle(center, base_width / 2.0, angle - PI / 2.0);
+    let right = get_point_on_circle(center, base_width / 2.0, angle + PI / 2.0);
+
+    Path::new(|p| {
+        p.move_to(left);
+        p.line_to(tip);
+        p.line_to(right);
+        p.close();
+        p.circle(center, base_width * 0.75);
+    })
+}
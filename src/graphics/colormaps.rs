@@ -0,0 +1,106 @@
+//! Colormaps for mapping values to colors
+//!
+//! The stops are sampled from the matplotlib and ColorBrewer maps and
+//! interpolated in linear rgb.
+use iced::Color;
+use palette::{LinSrgb, Mix, Srgb};
+use pyo3::pyclass;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[pyclass(eq, eq_int)]
+pub enum IpgColorMap {
+    Viridis,
+    Magma,
+    Plasma,
+    Inferno,
+    Greys,
+    // diverging
+    CoolWarm,
+    RdBu,
+    RdYlGn,
+}
+
+impl IpgColorMap {
+    fn stops(&self) -> &'static [u32] {
+        match self {
+            IpgColorMap::Viridis => &[0x440154, 0x482878, 0x3e4989, 0x31688e, 0x26828e,
+                                    0x1f9e89, 0x35b779, 0x6ece58, 0xb5de2b, 0xfde725],
+            IpgColorMap::Magma => &[0x000004, 0x180f3d, 0x440f76, 0x721f81, 0x9e2f7f,
+                                    0xcd4071, 0xf1605d, 0xfd9668, 0xfeca8d, 0xfcfdbf],
+            IpgColorMap::Plasma => &[0x0d0887, 0x46039f, 0x7201a8, 0x9c179e, 0xbd3786,
+                                    0xd8576b, 0xed7953, 0xfb9f3a, 0xfdca26, 0xf0f921],
+            IpgColorMap::Inferno => &[0x000004, 0x1b0c41, 0x4a0c6b, 0x781c6d, 0xa52c60,
+                                    0xcf4446, 0xed6925, 0xfb9b06, 0xf7d13d, 0xfcffa4],
+            IpgColorMap::Greys => &[0xffffff, 0xd9d9d9, 0xbdbdbd, 0x969696, 0x737373,
+                                    0x525252, 0x252525, 0x000000],
+            IpgColorMap::CoolWarm => &[0x3b4cc0, 0x688aef, 0x99baff, 0xc9d8ef, 0xedd1c2,
+                                    0xf7a889, 0xe26952, 0xb40426],
+            IpgColorMap::RdBu => &[0x67001f, 0xb2182b, 0xd6604d, 0xf4a582, 0xfddbc7, 0xf7f7f7,
+                                    0xd1e5f0, 0x92c5de, 0x4393c3, 0x2166ac, 0x053061],
+            IpgColorMap::RdYlGn => &[0xa50026, 0xd73027, 0xf46d43, 0xfdae61, 0xfee08b, 0xffffbf,
+                                    0xd9ef8b, 0xa6d96a, 0x66bd63, 0x1a9850, 0x006837],
+        }
+    }
+
+    pub fn is_diverging(&self) -> bool {
+        matches!(self, IpgColorMap::CoolWarm | IpgColorMap::RdBu | IpgColorMap::RdYlGn)
+    }
+}
+
+fn hex_to_linear(hex: u32) -> LinSrgb {
+    Srgb::new(
+        ((hex >> 16) & 0xff) as f32 / 255.0,
+        ((hex >> 8) & 0xff) as f32 / 255.0,
+        (hex & 0xff) as f32 / 255.0,
+    ).into_linear()
+}
+
+/// Returns the color at position t, 0.0 to 1.0, of the colormap.
+pub fn get_colormap_color(colormap: IpgColorMap, t: f32) -> Color {
+    let stops = colormap.stops();
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let scaled = t * (stops.len() - 1) as f32;
+    let index = (scaled.floor() as usize).min(stops.len() - 2);
+    let fraction = scaled - index as f32;
+
+    let mixed = hex_to_linear(stops[index]).mix(hex_to_linear(stops[index + 1]), fraction);
+
+    Color::from(Srgb::from_linear(mixed))
+}
+
+/// Returns the position of the value in the range for the colormap.
+/// When a center is given, the range is made symmetric around it,
+/// which keeps the center on the middle color of a diverging map.
+pub fn get_colormap_position(value: f64, min: f64, max: f64, center: Option<f64>) -> f32 {
+    let (min, max) = match center {
+        Some(center) => {
+            let half = (max - center).abs().max((center - min).abs());
+            (center - half, center + half)
+        },
+        None => (min, max),
+    };
+    if max <= min {
+        return 0.5
+    }
+    ((value - min) / (max - min)) as f32
+}
+
+
+#[test]
+fn test_colormap_ends() {
+    let low = get_colormap_color(IpgColorMap::Greys, 0.0);
+    let high = get_colormap_color(IpgColorMap::Greys, 1.0);
+    assert!((low.r - 1.0).abs() < 1e-4);
+    assert!(high.r.abs() < 1e-4);
+
+    // out of range values are clamped
+    assert_eq!(high, get_colormap_color(IpgColorMap::Greys, 3.0));
+}
+
+#[test]
+fn test_colormap_position_centered() {
+    assert_eq!(0.5, get_colormap_position(0.0, -0.2, 1.0, Some(0.0)));
+    assert_eq!(0.0, get_colormap_position(-1.0, -0.2, 1.0, Some(0.0)));
+    assert_eq!(0.25, get_colormap_position(2.5, 0.0, 10.0, None));
+}
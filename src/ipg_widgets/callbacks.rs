@@ -1,13 +1,283 @@
 //! callbacks
 #![allow(dead_code)]
-use crate::IpgState;
+use crate::{access_callbacks, access_events, access_user_data1, access_user_data2, mirror_item, IpgState};
 use super::ipg_enums::IpgContainers;
+use super::ipg_errors::report_callback_error;
 use super::{helpers::{format_date, MONTH_NAMES}, ipg_enums::IpgWidgets, ipg_radio::Choice};
 
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use iced::keyboard::Modifiers;
 use iced::widget::scrollable;
 use iced::{Color, Point};
 
-use pyo3::PyObject;
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+
+// The callback and the user data are cloned while the locks are held
+// and the locks are released before python is called.  The callback can
+// then use any of the IPG methods, even those taking the same locks.
+// The locks are only taken with the GIL held, a python thread holding
+// the GIL never waits on a lock held by the gui thread.
+
+/// The callback of the widget's event and the widget's user data.
+pub fn get_callback(id: usize, event_name: &str) -> Option<(PyObject, Option<PyObject>)> {
+    Python::with_gil(|py| {
+        let callback = access_callbacks().callbacks
+                            .get(&(id, event_name.to_string()))
+                            .map(|cb| cb.clone_ref(py))?;
+
+        let user_data = access_user_data1().user_data
+                            .get(&id)
+                            .map(|ud| ud.clone_ref(py));
+        let user_data = user_data.or_else(|| access_user_data2().user_data
+                            .get(&id)
+                            .map(|ud| ud.clone_ref(py)));
+
+        Some((callback, user_data))
+    })
+}
+
+/// The callback of the event and the user data, the window
+/// events keep their user data under the window id.
+pub fn get_event_callback(event_id: usize, event_name: &str, user_data_id: usize) 
+                            -> Option<(PyObject, Option<PyObject>)> 
+{
+    Python::with_gil(|py| {
+        let callback = access_events().events
+                            .get(&(event_id, event_name.to_string()))
+                            .map(|cb| cb.clone_ref(py))?;
+
+        let user_data = access_user_data1().user_data
+                            .get(&user_data_id)
+                            .map(|ud| ud.clone_ref(py));
+
+        Some((callback, user_data))
+    })
+}
+
+/// Calls the callback of the widget's event, the user data is
+/// added as the last arg when the widget has some.
+pub fn dispatch_callback<A>(name: &str, id: usize, event_name: &str, args: A)
+where
+    A: for<'py> IntoPyObject<'py, Target = PyTuple, Output = Bound<'py, PyTuple>, Error = PyErr>,
+{
+    dispatch_callback_at(name, id, event_name, None, args);
+}
+
+/// The same as dispatch_callback for an event with a mouse position.
+pub fn dispatch_callback_at<A>(name: &str, id: usize, event_name: &str, position: Option<Point>, args: A)
+where
+    A: for<'py> IntoPyObject<'py, Target = PyTuple, Output = Bound<'py, PyTuple>, Error = PyErr>,
+{
+    if let Some((callback, user_data)) = get_callback(id, event_name) {
+        let source = IpgEventSource::new(name, id, event_name).position(position);
+        call_callback(source, callback, user_data, args);
+    }
+}
+
+/// Calls the callback of the event, the same as dispatch_callback
+/// except for the user data being kept under its own id.
+pub fn dispatch_event<A>(name: &str, event_id: usize, event_name: &str, user_data_id: usize, args: A)
+where
+    A: for<'py> IntoPyObject<'py, Target = PyTuple, Output = Bound<'py, PyTuple>, Error = PyErr>,
+{
+    dispatch_event_at(name, event_id, event_name, user_data_id, None, args);
+}
+
+/// The same as dispatch_event for an event with a mouse position.
+pub fn dispatch_event_at<A>(name: &str, 
+                            event_id: usize, 
+                            event_name: &str, 
+                            user_data_id: usize, 
+                            position: Option<Point>, 
+                            args: A)
+where
+    A: for<'py> IntoPyObject<'py, Target = PyTuple, Output = Bound<'py, PyTuple>, Error = PyErr>,
+{
+    if let Some((callback, user_data)) = get_event_callback(event_id, event_name, user_data_id) {
+        let source = IpgEventSource::new(name, event_id, event_name).position(position);
+        call_callback(source, callback, user_data, args);
+    }
+}
+
+/// Calls the callback with the args and the user data, or with an IpgEvent
+/// when the event objects are used.  A python error is reported under
+/// the source's name.  No lock may be held.
+pub fn call_callback<A>(source: IpgEventSource, callback: PyObject, user_data: Option<PyObject>, args: A)
+where
+    A: for<'py> IntoPyObject<'py, Target = PyTuple, Output = Bound<'py, PyTuple>, Error = PyErr>,
+{
+    call_callback_returning(source, callback, user_data, args);
+}
+
+/// The same as call_callback, the value returned by the callback
+/// is given back, None when it raised an error.
+pub fn call_callback_returning<A>(source: IpgEventSource, 
+                                callback: PyObject, 
+                                user_data: Option<PyObject>, 
+                                args: A) 
+                                -> Option<PyObject>
+where
+    A: for<'py> IntoPyObject<'py, Target = PyTuple, Output = Bound<'py, PyTuple>, Error = PyErr>,
+{
+    let name = source.name;
+    Python::with_gil(|py| {
+        let result = args.into_pyobject(py).and_then(|args| {
+            if event_objects() {
+                let event = source.into_event(py, &args, user_data);
+                return callback.call1(py, (event,))
+            }
+            match user_data {
+                Some(user_data) => {
+                    let mut args: Vec<Bound<'_, PyAny>> = args.iter().collect();
+                    args.push(user_data.into_bound(py));
+                    callback.call1(py, PyTuple::new(py, args)?)
+                },
+                None => callback.call1(py, args),
+            }
+        });
+
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                report_callback_error(name, err);
+                None
+            },
+        }
+    })
+}
+
+
+// Set by IPG(event_objects=True), every callback is then called
+// with a single IpgEvent in place of its own args.
+static EVENT_OBJECTS: AtomicBool = AtomicBool::new(false);
+
+pub fn set_event_objects(enabled: bool) {
+    EVENT_OBJECTS.store(enabled, Ordering::Relaxed);
+}
+
+pub fn event_objects() -> bool {
+    EVENT_OBJECTS.load(Ordering::Relaxed)
+}
+
+thread_local! {
+    // The window of the item whose message is being handled and the
+    // keyboard modifiers last seen, both are set by App on the gui thread.
+    static EVENT_WINDOW: RefCell<Option<String>> = const { RefCell::new(None) };
+    static EVENT_MODIFIERS: Cell<Modifiers> = const { Cell::new(Modifiers::empty()) };
+}
+
+pub fn set_event_window(window_id: Option<String>) {
+    EVENT_WINDOW.with(|window| *window.borrow_mut() = window_id);
+}
+
+pub fn set_event_modifiers(modifiers: Modifiers) {
+    EVENT_MODIFIERS.with(|current| current.set(modifiers));
+}
+
+/// Where a callback comes from, the IpgEvent is made from it.
+pub struct IpgEventSource<'a> {
+    // the name the errors are reported under
+    pub name: &'a str,
+    pub widget_id: usize,
+    pub kind: &'a str,
+    pub position: Option<Point>,
+    // the args before the value, the id and for a window event its name
+    pub value_start: usize,
+}
+
+impl<'a> IpgEventSource<'a> {
+    pub fn new(name: &'a str, widget_id: usize, kind: &'a str) -> Self {
+        Self {
+            name,
+            widget_id,
+            kind,
+            position: None,
+            value_start: 1,
+        }
+    }
+
+    pub fn position(mut self, position: Option<Point>) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn value_start(mut self, value_start: usize) -> Self {
+        self.value_start = value_start;
+        self
+    }
+
+    // The value is the args following the id, a single arg is
+    // used as is and several are kept together as a tuple.
+    fn into_event(self, py: Python<'_>, args: &Bound<'_, PyTuple>, user_data: Option<PyObject>) -> IpgEvent {
+        let value = match args.len().saturating_sub(self.value_start) {
+            0 => py.None(),
+            1 => args.get_item(self.value_start)
+                    .map(|value| value.unbind())
+                    .unwrap_or_else(|_| py.None()),
+            _ => args.get_slice(self.value_start, args.len()).into_any().unbind(),
+        };
+
+        let timestamp = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .map(|duration| duration.as_secs_f64())
+                            .unwrap_or_default();
+
+        IpgEvent {
+            widget_id: self.widget_id,
+            kind: self.kind.to_string(),
+            window_id: EVENT_WINDOW.with(|window| window.borrow().clone()),
+            value,
+            position: self.position.map(|point| (point.x, point.y)),
+            modifiers: EVENT_MODIFIERS.with(|modifiers| modifier_names(modifiers.get())),
+            timestamp,
+            user_data: user_data.unwrap_or_else(|| py.None()),
+        }
+    }
+}
+
+pub fn modifier_names(modifiers: Modifiers) -> Vec<String> {
+    let names = [
+        (modifiers.shift(), "Shift"),
+        (modifiers.control(), "Control"),
+        (modifiers.alt(), "Alt"),
+        (modifiers.logo(), "Logo"),
+    ];
+    names.iter()
+        .filter(|(held, _)| *held)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// The single arg of every callback when IPG(event_objects=True) is used.
+#[pyclass(get_all)]
+pub struct IpgEvent {
+    pub widget_id: usize,
+    pub kind: String,
+    pub window_id: Option<String>,
+    pub value: PyObject,
+    pub position: Option<(f32, f32)>,
+    pub modifiers: Vec<String>,
+    pub timestamp: f64,
+    pub user_data: PyObject,
+}
+
+#[pymethods]
+impl IpgEvent {
+    fn __repr__(&self, py: Python<'_>) -> String {
+        format!("IpgEvent(widget_id={}, kind={:?}, window_id={:?}, value={}, position={:?}, modifiers={:?})",
+                self.widget_id, 
+                self.kind, 
+                self.window_id, 
+                self.value.bind(py).repr().map(|repr| repr.to_string()).unwrap_or_default(), 
+                self.position, 
+                self.modifiers)
+    }
+}
 
 
 #[derive(Default, Debug)]
@@ -80,7 +350,15 @@ pub struct WidgetCallbackOut {
 
 impl WidgetCallbackOut{}
 
-pub fn set_or_get_widget_callback_data(state: &mut IpgState, wci: WidgetCallbackIn) -> WidgetCallbackOut                                     
+pub fn set_or_get_widget_callback_data(state: &mut IpgState, wci: WidgetCallbackIn) -> WidgetCallbackOut {
+    let id = wci.id;
+    let wco = set_or_get_widget_data(state, wci);
+    // the python callback may read the widget back with get_item
+    mirror_item(state, id);
+    wco
+}
+
+fn set_or_get_widget_data(state: &mut IpgState, wci: WidgetCallbackIn) -> WidgetCallbackOut                                     
 {
     let widget_opt = state.widgets.get_mut(&wci.id);
 
@@ -103,6 +381,9 @@ pub fn set_or_get_widget_callback_data(state: &mut IpgState, wci: WidgetCallback
             IpgWidgets::IpgCardStyle(_) => {
                 return WidgetCallbackOut::default();
             },
+            IpgWidgets::IpgChart(_) => {
+                return WidgetCallbackOut::default();
+            },
             IpgWidgets::IpgCheckBox(cbox) => {
                 cbox.is_checked = match wci.on_toggle {
                     Some(data) => data,
@@ -212,9 +493,27 @@ pub fn set_or_get_widget_callback_data(state: &mut IpgState, wci: WidgetCallback
                     ..Default::default()
                 }
             },
+            IpgWidgets::IpgGauge(_) => {
+                return WidgetCallbackOut::default();
+            },
+            IpgWidgets::IpgHeatmap(_) => {
+                return WidgetCallbackOut::default();
+            },
             IpgWidgets::IpgImage(_) => {
                 return WidgetCallbackOut::default();
             },
+            IpgWidgets::IpgKnob(knob) => {
+                if let Some(value) = wci.value_f64 {
+                    knob.value = value as f32;
+                }
+                return WidgetCallbackOut{
+                    value_f32: Some(knob.value),
+                    ..Default::default()
+                }
+            },
+            IpgWidgets::IpgMeter(_) => {
+                return WidgetCallbackOut::default();
+            },
             IpgWidgets::IpgOpaqueStyle(_) => {
                 return WidgetCallbackOut::default();
             },
@@ -273,6 +572,9 @@ pub fn set_or_get_widget_callback_data(state: &mut IpgState, wci: WidgetCallback
             IpgWidgets::IpgSpace(_) => {
                 return WidgetCallbackOut::default();
             },
+            IpgWidgets::IpgStreamPlot(_) => {
+                return WidgetCallbackOut::default();
+            },
             IpgWidgets::IpgSvg(_) => {
                 return WidgetCallbackOut::default();
             },
@@ -296,12 +598,13 @@ pub fn set_or_get_widget_callback_data(state: &mut IpgState, wci: WidgetCallback
                 return WidgetCallbackOut::default();
             },
             IpgWidgets::IpgTimer(tim) => {
-                tim.counter += 1;
-                // value_str is set when a tick occurs
-                // so no value_bool present
-                if wci.value_str.is_none() {
-                    tim.started = wci.value_bool.unwrap();
+                // the ticks are counted in tick_callback
+                if wci.value_bool.unwrap() {
+                    tim.start();
+                } else {
+                    tim.stop();
                 }
+                tim.counter = tim.ticks();
                 return WidgetCallbackOut{
                     counter: Some(tim.counter),
                     duration: Some(tim.duration_ms),
@@ -422,6 +725,13 @@ pub fn set_or_get_widget_callback_data(state: &mut IpgState, wci: WidgetCallback
 
 
 pub fn container_callback_data(state: &mut IpgState, wci: WidgetCallbackIn) -> WidgetCallbackOut {
+    let id = wci.id;
+    let wco = container_data(state, wci);
+    mirror_item(state, id);
+    wco
+}
+
+fn container_data(state: &mut IpgState, wci: WidgetCallbackIn) -> WidgetCallbackOut {
 
     let container_type_opt = state.containers.get_mut(&wci.id);
 
@@ -449,3 +759,202 @@ pub fn container_callback_data(state: &mut IpgState, wci: WidgetCallbackIn) -> W
     }
         
 }
+
+
+// The callbacks below use the IPG update_item, delete_item and add methods,
+// these take the same locks as dispatch_callback.  A dispatch that still
+// held a lock would never return, so each one is given a few seconds.
+// The tests share the globals, each one removes what it added.
+#[cfg(test)]
+fn test_callback<F>(py: Python<'_>, f: F) -> PyObject
+where
+    F: Fn(&Bound<'_, PyTuple>) + Send + 'static,
+{
+    use pyo3::types::{PyCFunction, PyDict};
+
+    PyCFunction::new_closure(py, None, None,
+        move |args: &Bound<'_, PyTuple>, _kwargs: Option<&Bound<'_, PyDict>>| f(args))
+        .unwrap()
+        .into_any()
+        .unbind()
+}
+
+#[cfg(test)]
+fn set_test_callback<F>(id: usize, event_name: &str, user_data: Option<&str>, f: F)
+where
+    F: Fn(&Bound<'_, PyTuple>) + Send + 'static,
+{
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        access_callbacks().callbacks.insert((id, event_name.to_string()), test_callback(py, f));
+
+        if let Some(user_data) = user_data {
+            let user_data = user_data.into_pyobject(py).unwrap().into_any().unbind();
+            access_user_data1().user_data.insert(id, user_data);
+        }
+    });
+}
+
+#[cfg(test)]
+fn test_ipg() -> crate::IPG {
+    crate::IPG { group_index: 0, theme: iced::Theme::Dark }
+}
+
+// A window with a column, the widgets are added to the column
+// whose container id is test_column_id.
+#[cfg(test)]
+fn add_test_window(window_id: &str) -> usize {
+    use super::ipg_enums::IpgAlignment;
+    use super::ipg_window::{IpgWindowLevel, IpgWindowMode, IpgWindowTheme};
+
+    pyo3::prepare_freethreaded_python();
+    let mut ipg = test_ipg();
+    ipg.add_window(window_id.to_string(), "Test".to_string(), 300.0, 200.0,
+                    None, None, None, None, None, None, false, true, true, false,
+                    IpgWindowLevel::Normal, 1.0, IpgWindowTheme::Dark, false, None,
+                    IpgWindowMode::Windowed, false, None, None, None).unwrap();
+    ipg.add_column(window_id.to_string(), test_column_id(window_id), None, IpgAlignment::Start,
+                    None, None, false, false, f32::INFINITY, vec![0.0], 10.0, false, true).unwrap()
+}
+
+#[cfg(test)]
+fn test_column_id(window_id: &str) -> String {
+    format!("{window_id}_column")
+}
+
+#[cfg(test)]
+fn remove_test_window(window_id: &str, column_id: usize) {
+    // the column is still pending, its widgets, callbacks and user data go with it
+    test_ipg().delete_item(window_id.to_string(), column_id);
+
+    let mut state = crate::access_state();
+    if let Some(id) = state.windows_str_ids.remove(window_id) {
+        state.ids_ipd_ids.remove(&id);
+        state.container_ids.remove(&id);
+        state.containers.remove(&id);
+        state.windows.retain(|window| window.id != id);
+    }
+}
+
+#[cfg(test)]
+fn add_test_button(parent_id: &str, on_press: Option<PyObject>) -> usize {
+    use super::ipg_enums::{IpgHorizontalAlignment, IpgVerticalAlignment};
+
+    let user_data = Python::with_gil(|py| "data".into_pyobject(py).unwrap().into_any().unbind());
+    test_ipg().add_button(parent_id.to_string(), "Test".to_string(), None, on_press,
+                    None, None, false, false, vec![5.0],
+                    IpgHorizontalAlignment::Center, IpgVerticalAlignment::Center,
+                    16.0, false, None, None, None, None, Some(user_data), true, true).unwrap()
+}
+
+#[cfg(test)]
+fn dispatch_returns(id: usize, event_name: &'static str) -> bool {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        dispatch_callback("Test", id, event_name, (id,));
+        let _ = sender.send(());
+    });
+    receiver.recv_timeout(std::time::Duration::from_secs(5)).is_ok()
+}
+
+#[test]
+fn test_callback_reads_user_data() {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    set_test_callback(1001, "on_press", Some("data"), move |args| {
+        // the lock get_callback takes for the user data
+        let has_user_data = access_user_data1().user_data.contains_key(&1001);
+        let last: String = args.get_item(args.len() - 1).unwrap().extract().unwrap();
+        let _ = sender.send((args.len(), last, has_user_data));
+    });
+
+    assert!(dispatch_returns(1001, "on_press"));
+    assert_eq!(receiver.try_recv(), Ok((2, "data".to_string(), true)));
+    crate::remove_callbacks_and_user_data(&[1001]);
+}
+
+#[test]
+fn test_callback_updates_and_adds_items() {
+    use super::ipg_button::IpgButtonParam;
+
+    let window_id = "test_callback_updates_and_adds_items";
+    let column_id = add_test_window(window_id);
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let on_press = Python::with_gil(|py| test_callback(py, move |args| {
+        let py = args.py();
+        let id: usize = args.get_item(0).unwrap().extract().unwrap();
+        let param = Py::new(py, IpgButtonParam::Label).unwrap().into_any();
+        let value = "Pressed".into_pyobject(py).unwrap().into_any().unbind();
+        test_ipg().update_item(id, param, value).unwrap();
+        let _ = sender.send(add_test_button(&test_column_id(window_id), Some(py.None())));
+    }));
+    let id = add_test_button(&test_column_id(window_id), Some(on_press));
+
+    assert!(dispatch_returns(id, "on_press"));
+    let added = receiver.try_recv().unwrap();
+    assert!(get_callback(added, "on_press").is_some());
+
+    let mut all_updates = crate::access_update_items();
+    assert!(all_updates.updates.iter().flatten().any(|(wid, _, _)| *wid == id));
+    all_updates.updates.retain(|updates| updates.iter().all(|(wid, _, _)| *wid != id));
+    drop(all_updates);
+
+    remove_test_window(window_id, column_id);
+    assert!(get_callback(id, "on_press").is_none());
+    assert!(get_callback(added, "on_press").is_none());
+}
+
+#[test]
+fn test_callback_deletes_itself() {
+    let window_id = "test_callback_deletes_itself";
+    let column_id = add_test_window(window_id);
+
+    let on_press = Python::with_gil(|py| test_callback(py, move |args| {
+        let id: usize = args.get_item(0).unwrap().extract().unwrap();
+        test_ipg().delete_item(window_id.to_string(), id);
+    }));
+    let id = add_test_button(&test_column_id(window_id), Some(on_press));
+
+    assert!(dispatch_returns(id, "on_press"));
+    assert!(get_callback(id, "on_press").is_none());
+    assert!(!access_user_data1().user_data.contains_key(&id));
+    assert!(!access_user_data2().user_data.contains_key(&id));
+
+    remove_test_window(window_id, column_id);
+}
+
+#[test]
+fn test_nested_callbacks() {
+    set_test_callback(1006, "on_toggle", Some("inner"), |_args| {
+        access_callbacks().callbacks.retain(|_, _| true);
+        access_user_data1().user_data.retain(|_, _| true);
+    });
+    set_test_callback(1005, "on_press", Some("outer"), |_args| {
+        dispatch_callback("Test", 1006, "on_toggle", (1006,));
+    });
+
+    assert!(dispatch_returns(1005, "on_press"));
+    crate::remove_callbacks_and_user_data(&[1005, 1006]);
+}
+
+#[test]
+fn test_event_value() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let args = PyTuple::new(py, [3.into_pyobject(py).unwrap().into_any(), 
+                                    "resized".into_pyobject(py).unwrap().into_any(),
+                                    1.5.into_pyobject(py).unwrap().into_any()]).unwrap();
+
+        let event = IpgEventSource::new("Test", 3, "resized").into_event(py, &args, None);
+        let value: (String, f64) = event.value.extract(py).unwrap();
+        assert_eq!(value, ("resized".to_string(), 1.5));
+        assert!(event.user_data.is_none(py));
+
+        let event = IpgEventSource::new("Test", 3, "resized")
+                        .value_start(2)
+                        .position(Some(Point::new(1.0, 2.0)))
+                        .into_event(py, &args, None);
+        assert_eq!(event.value.extract::<f64>(py).unwrap(), 1.5);
+        assert_eq!(event.position, Some((1.0, 2.0)));
+    });
+}
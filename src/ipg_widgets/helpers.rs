@@ -5,30 +5,36 @@ use crate::graphics::colors::IpgColor;
 use crate::style::styling::IpgStyleStandard;
 use crate::access_state;
 use iced::border::Radius;
-use iced::{window, Alignment, Pixels};
+use iced::{window, Alignment, Color, Element, Pixels};
 use iced::{alignment::{Horizontal, Vertical}, Length, Padding};
 use iced::widget::text::{Shaping, LineHeight};
+use iced::widget::{opaque, Space, Stack};
 
-use pyo3::{PyObject, Python};
+use polars::frame::DataFrame;
+use pyo3::prelude::*;
+use pyo3::{BoundObject, PyClass, PyClassInitializer};
+use pyo3_polars::PyDataFrame;
 
 use super::ipg_enums::{IpgAlignment, IpgHorizontalAlignment, IpgVerticalAlignment};
+use super::ipg_errors::{IpgIdError, IpgParamError};
 
-pub fn check_for_dup_container_ids(id: usize, container_id: Option<String>) {
+pub fn check_for_dup_container_ids(id: usize, container_id: Option<String>) -> PyResult<()> {
 
     let state = access_state();
     
     let parents = match state.ids_ipd_ids.get(&id) {
         Some(ids) => ids,
-        None => panic!("Ids in check_for_dup_container_ids not found")
+        None => return Err(IpgIdError::new_err("Ids in check_for_dup_container_ids not found"))
     };
 
     for parent in parents {
         if container_id == parent.container_id {
-            panic!("Container Id {:?} is not unique", container_id);
+            return Err(IpgIdError::new_err(format!("Container Id {:?} is not unique", container_id)));
         }
     }
     
     drop(state);
+    Ok(())
 }
 
 pub fn find_key_for_value(ids: HashMap<window::Id, usize>, value: usize) -> window::Id {
@@ -75,6 +81,13 @@ pub fn get_vertical_alignment(v_align: &IpgVerticalAlignment) -> Vertical {
     }
 }
 
+// Covers a widget that has no disabled state of its own,
+// the clicks land on the cover and the widget is left alone.
+pub fn block_input<'a, Message: 'a>(content: Element<'a, Message>) -> Element<'a, Message> {
+    Stack::with_children(vec![content, opaque(Space::new(Length::Fill, Length::Fill))])
+        .into()
+}
+
 // Standard method for Length using Width
 pub fn get_width(width: Option<f32>, width_fill: bool)-> Length {
     // width overrides width_fill
@@ -104,45 +117,45 @@ pub fn get_height(height: Option<f32>, height_fill: bool)-> Length {
 }
 
 // Standard method for padding
-pub fn get_padding_f64(padding: Vec<f64>)-> Padding {
+pub fn get_padding_f64(padding: Vec<f64>)-> PyResult<Padding> {
     let len = padding.len();
     match len {
-    0 => panic!("Padding must have at List of at least 1, 2 or 4 items"),
-    1 => Padding::from(padding[0] as f32),
-    2 => Padding::from(vec_to_array2_f64(&padding)),
-    3 => panic!("Padding must have a List of 1, 2, or 4 items"),
+    0 => Err(IpgParamError::new_err("Padding must have at List of at least 1, 2 or 4 items")),
+    1 => Ok(Padding::from(padding[0] as f32)),
+    2 => Ok(Padding::from(vec_to_array2_f64(&padding))),
+    3 => Err(IpgParamError::new_err("Padding must have a List of 1, 2, or 4 items")),
     4 => {
         let mut pad = Padding::default();
         pad = pad.top(padding[0] as f32);
         pad = pad.right(padding[1] as f32);
         pad = pad.bottom(padding[2] as f32);
         pad = pad.left(padding[3] as f32);
-        pad
+        Ok(pad)
     },
-    _ => panic!("Padding must have a List of less than 4 items"),
+    _ => Err(IpgParamError::new_err("Padding must have a List of less than 4 items")),
     }
 }
 
-pub fn get_padding_f32(padding: Option<Vec<f32>>)-> Padding {
+pub fn get_padding_f32(padding: Option<Vec<f32>>)-> PyResult<Padding> {
     let padding = match padding {
-        None => return Padding::ZERO,
+        None => return Ok(Padding::ZERO),
         Some(p) => p,
     };
     let len = padding.len();
     match len {
-    0 => panic!("Padding must have at List of at least 1, 2 or 4 items"),
-    1 => Padding::from(padding[0]),
-    2 => Padding::from(vec_to_array2_f32(&padding)),
-    3 => panic!("Padding must have a List of 1, 2, or 4 items"),
+    0 => Err(IpgParamError::new_err("Padding must have at List of at least 1, 2 or 4 items")),
+    1 => Ok(Padding::from(padding[0])),
+    2 => Ok(Padding::from(vec_to_array2_f32(&padding))),
+    3 => Err(IpgParamError::new_err("Padding must have a List of 1, 2, or 4 items")),
     4 => {
         let mut pad = Padding::default();
         pad = pad.top(padding[0]);
         pad = pad.right(padding[1]);
         pad = pad.bottom(padding[2]);
         pad = pad.left(padding[3]);
-        pad
+        Ok(pad)
     },
-    _ => panic!("Padding must have a List of less than 4 items"),
+    _ => Err(IpgParamError::new_err("Padding must have a List of less than 4 items")),
     }
 }
 
@@ -169,11 +182,11 @@ fn vec_to_array2_f32(arr: &[f32]) -> [f32; 2] {
     [arr[0], arr[1]]
 }
 
-pub fn get_shaping(shape: String) -> Shaping {
+pub fn get_shaping(shape: String) -> PyResult<Shaping> {
     match shape.as_str() {
-        "basic" => Shaping::Basic,
-        "advanced" => Shaping::Advanced,
-        _ => panic!("The shape {shape} is not allowed, used either 'basic' or 'advanced'")
+        "basic" => Ok(Shaping::Basic),
+        "advanced" => Ok(Shaping::Advanced),
+        _ => Err(IpgParamError::new_err(format!("The shape {shape} is not allowed, used either 'basic' or 'advanced'")))
     }
 }
 
@@ -229,32 +242,32 @@ fn convert_to_len_two(value: usize) -> String {
     }
 }
 
-pub fn try_extract_i64(value: &PyObject, name: String) -> i64 {
+pub fn try_extract_i64(value: &PyObject, name: String) -> PyResult<i64> {
     Python::with_gil(|py| {
         let res = value.extract::<i64>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python integer", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python integer", name))),
         }
     })  
 }
 
-pub fn try_extract_f64(value: &PyObject, name: String) -> f64 {
+pub fn try_extract_f64(value: &PyObject, name: String) -> PyResult<f64> {
     Python::with_gil(|py| {
         let res = value.extract::<f64>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python float", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python float", name))),
         }
     })  
 }
 
-pub fn try_extract_f32(value: &PyObject, name: String) -> f32 {
+pub fn try_extract_f32(value: &PyObject, name: String) -> PyResult<f32> {
     Python::with_gil(|py| {
         let res = value.extract::<f32>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python float", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python float", name))),
         }
     })  
 }
@@ -270,32 +283,32 @@ pub fn try_extract_i64_option(value: &PyObject) -> Option<i64> {
     })  
 }
 
-pub fn try_extract_u16(value: &PyObject, name: String) -> u16 {
+pub fn try_extract_u16(value: &PyObject, name: String) -> PyResult<u16> {
     Python::with_gil(|py| {
         let res = value.extract::<u16>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract u16", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract u16", name))),
         }
     })  
 }
 
-pub fn try_extract_u64(value: &PyObject, name: String) -> u64 {
+pub fn try_extract_u64(value: &PyObject, name: String) -> PyResult<u64> {
     Python::with_gil(|py| {
         let res = value.extract::<u64>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract u64", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract u64", name))),
         }
     })  
 }
 
-pub fn try_extract_usize(value: &PyObject, name: String) -> usize {
+pub fn try_extract_usize(value: &PyObject, name: String) -> PyResult<usize> {
     Python::with_gil(|py| {
         let res = value.extract::<usize>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract usize", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract usize", name))),
         }
     })  
 }
@@ -310,83 +323,83 @@ pub fn try_extract_f64_option(value: &PyObject) -> Option<f64> {
     })  
 }
 
-pub fn try_extract_vec_f64(value: &PyObject, name: String) -> Vec<f64> {
+pub fn try_extract_vec_f64(value: &PyObject, name: String) -> PyResult<Vec<f64>> {
     Python::with_gil(|py| {
         let res = value.extract::<Vec<f64>>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python list[float]", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python list[float]", name))),
         }
     })  
 }
 
-pub fn try_extract_vec_f32(value: &PyObject, name: String) -> Vec<f32> {
+pub fn try_extract_vec_f32(value: &PyObject, name: String) -> PyResult<Vec<f32>> {
     Python::with_gil(|py| {
         let res = value.extract::<Vec<f32>>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python list[float]", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python list[float]", name))),
         }
     })  
 }
 
-pub fn try_extract_vec_u16(value: &PyObject, name: String) -> Vec<u16> {
+pub fn try_extract_vec_u16(value: &PyObject, name: String) -> PyResult<Vec<u16>> {
     Python::with_gil(|py| {
         let res = value.extract::<Vec<u16>>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python list[uint]", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python list[uint]", name))),
         }
     })  
 }
 
-pub fn try_extract_vec_usize(value: &PyObject, name: String) -> Vec<usize> {
+pub fn try_extract_vec_usize(value: &PyObject, name: String) -> PyResult<Vec<usize>> {
     Python::with_gil(|py| {
         let res = value.extract::<Vec<usize>>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python list[uint]", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python list[uint]", name))),
         }
     })  
 }
 
-pub fn try_extract_array_2(value: &PyObject, name: String) -> [f32; 2] {
+pub fn try_extract_array_2(value: &PyObject, name: String) -> PyResult<[f32; 2]> {
     Python::with_gil(|py| {
 
         let res = value.extract::<[f32; 2]>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python object for 2 item list", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python object for 2 item list", name))),
         }
     })
 }
 
-pub fn try_extract_string(value: &PyObject, name: String) -> String {
+pub fn try_extract_string(value: &PyObject, name: String) -> PyResult<String> {
     Python::with_gil(|py| {
         let res = value.extract::<String>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python str", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python str", name))),
         }
     })  
 }
 
-pub fn try_extract_vec_str(value: &PyObject, name: String) -> Vec<String> {
+pub fn try_extract_vec_str(value: &PyObject, name: String) -> PyResult<Vec<String>> {
     Python::with_gil(|py| {
         let res = value.extract::<Vec<String>>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python list[str]", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python list[str]", name))),
         }
     })  
 }
 
-pub fn try_extract_boolean(value: &PyObject, name: String) -> bool {
+pub fn try_extract_boolean(value: &PyObject, name: String) -> PyResult<bool> {
     Python::with_gil(|py| {
         let res = value.extract::<bool>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python bool", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python bool", name))),
         }
     })  
 }
@@ -425,47 +438,231 @@ pub fn try_extract_ipg_alignment(value: &PyObject) -> Option<IpgAlignment> {
     })
 }
 
-pub fn try_extract_style_standard(value: &PyObject, name: String) -> IpgStyleStandard {
+pub fn try_extract_style_standard(value: &PyObject, name: String) -> PyResult<IpgStyleStandard> {
     Python::with_gil(|py| {
 
         let res = value.extract::<IpgStyleStandard>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python object for StyleStandard", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python object for StyleStandard", name))),
         }
     })
 }
 
-pub fn try_extract_ipg_color(value: &PyObject, name: String) -> IpgColor {
+pub fn try_extract_ipg_color(value: &PyObject, name: String) -> PyResult<IpgColor> {
     Python::with_gil(|py| {
 
         let res = value.extract::<IpgColor>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python object for IpgColor", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python object for IpgColor", name))),
         }
     })
 }
 
-pub fn try_extract_rgba_color(value: &PyObject, name: String) -> [f32; 4] {
+pub fn try_extract_rgba_color(value: &PyObject, name: String) -> PyResult<[f32; 4]> {
     Python::with_gil(|py| {
 
         let res = value.extract::<[f32; 4]>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python object for RGBA color", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python object for RGBA color", name))),
         }
     })
 }
 
-pub fn try_extract_point(value: &PyObject, name: String) -> [f32; 2] {
+pub fn try_extract_point(value: &PyObject, name: String) -> PyResult<[f32; 2]> {
     Python::with_gil(|py| {
 
         let res = value.extract::<[f32; 2]>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract python object for Point", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract python object for Point", name))),
         }
     })
 }
 
+
+// The values read back with get_item.  Each widget lists its values
+// under the names of its Param enum, None is returned as a python None.
+#[derive(Debug, IntoPyObject)]
+pub enum IpgItemValue {
+    Bool(bool),
+    Float(f32),
+    Int(usize),
+    Str(String),
+    Floats(Vec<f32>),
+    Strs(Vec<String>),
+    Object(PyObject),
+}
+
+pub type IpgItems = Vec<(&'static str, Option<IpgItemValue>)>;
+
+impl From<bool> for IpgItemValue {
+    fn from(value: bool) -> Self {
+        IpgItemValue::Bool(value)
+    }
+}
+
+impl From<f32> for IpgItemValue {
+    fn from(value: f32) -> Self {
+        IpgItemValue::Float(value)
+    }
+}
+
+impl From<usize> for IpgItemValue {
+    fn from(value: usize) -> Self {
+        IpgItemValue::Int(value)
+    }
+}
+
+impl From<String> for IpgItemValue {
+    fn from(value: String) -> Self {
+        IpgItemValue::Str(value)
+    }
+}
+
+impl From<Vec<f32>> for IpgItemValue {
+    fn from(value: Vec<f32>) -> Self {
+        IpgItemValue::Floats(value)
+    }
+}
+
+impl From<Vec<String>> for IpgItemValue {
+    fn from(value: Vec<String>) -> Self {
+        IpgItemValue::Strs(value)
+    }
+}
+
+// The fixed size, a fill or shrink length has none.
+pub fn item_length(length: Length) -> Option<IpgItemValue> {
+    match length {
+        Length::Fixed(value) => Some(value.into()),
+        _ => None,
+    }
+}
+
+pub fn item_fill(length: Length) -> Option<IpgItemValue> {
+    Some((length == Length::Fill).into())
+}
+
+pub fn item_padding(padding: Padding) -> Option<IpgItemValue> {
+    Some(vec![padding.top, padding.right, padding.bottom, padding.left].into())
+}
+
+pub fn item_color(color: Option<Color>) -> Option<IpgItemValue> {
+    color.map(|c| vec![c.r, c.g, c.b, c.a].into())
+}
+
+pub fn item_line_height(line_height: LineHeight) -> Option<IpgItemValue> {
+    match line_height {
+        LineHeight::Relative(value) => Some(value.into()),
+        LineHeight::Absolute(pixels) => Some(pixels.0.into()),
+    }
+}
+
+pub fn item_shaping(shaping: Shaping) -> Option<IpgItemValue> {
+    match shaping {
+        Shaping::Basic => Some("basic".to_string().into()),
+        Shaping::Advanced => Some("advanced".to_string().into()),
+    }
+}
+
+// Returns the python class, like an IpgAlignment, for the value.
+pub fn item_object<T>(value: T) -> PyResult<Option<IpgItemValue>> 
+    where T: PyClass + Into<PyClassInitializer<T>>
+{
+    Python::with_gil(|py| {
+        match Py::new(py, value) {
+            Ok(obj) => Ok(Some(IpgItemValue::Object(obj.into_any()))),
+            Err(err) => Err(IpgParamError::new_err(format!("get_item: unable to convert the value, {err}"))),
+        }
+    })
+}
+
+// Same as item_object for an optional value, None stays None.
+pub fn item_object_opt<T>(value: Option<T>) -> PyResult<Option<IpgItemValue>> 
+    where T: PyClass + Into<PyClassInitializer<T>>
+{
+    value.map(item_object).transpose().map(Option::flatten)
+}
+
+// Returns a value which has no IpgItemValue, like a list of rows, as a python object.
+pub fn item_value<T>(value: T) -> PyResult<Option<IpgItemValue>> 
+    where T: for<'py> IntoPyObject<'py>
+{
+    Python::with_gil(|py| {
+        match value.into_pyobject(py) {
+            Ok(obj) => Ok(Some(IpgItemValue::Object(obj.into_any().unbind()))),
+            Err(err) => Err(IpgParamError::new_err(
+                format!("get_item: unable to convert the value, {}", Into::<PyErr>::into(err)))),
+        }
+    })
+}
+
+// Returns a copy of the dataframe as a polars DataFrame.
+pub fn item_dataframe(df: &DataFrame) -> PyResult<Option<IpgItemValue>> {
+    item_value(PyDataFrame(df.clone()))
+}
+
+pub fn item_alignment(align: Alignment) -> PyResult<Option<IpgItemValue>> {
+    match align {
+        Alignment::Start => item_object(IpgAlignment::Start),
+        Alignment::Center => item_object(IpgAlignment::Center),
+        Alignment::End => item_object(IpgAlignment::End),
+    }
+}
+
+pub fn item_horizontal_alignment(h_align: Horizontal) -> PyResult<Option<IpgItemValue>> {
+    match h_align {
+        Horizontal::Left => item_object(IpgHorizontalAlignment::Left),
+        Horizontal::Center => item_object(IpgHorizontalAlignment::Center),
+        Horizontal::Right => item_object(IpgHorizontalAlignment::Right),
+    }
+}
+
+pub fn item_vertical_alignment(v_align: Vertical) -> PyResult<Option<IpgItemValue>> {
+    match v_align {
+        Vertical::Top => item_object(IpgVerticalAlignment::Top),
+        Vertical::Center => item_object(IpgVerticalAlignment::Center),
+        Vertical::Bottom => item_object(IpgVerticalAlignment::Bottom),
+    }
+}
+
+/// The class name and the variant of a param, 
+/// for example, IpgButtonParam and Label.
+pub fn get_param_names(param: &PyObject) -> PyResult<(String, String)> {
+    Python::with_gil(|py| {
+        let param = param.bind(py);
+        let class_name = param.get_type().name()?.to_string();
+        // the repr of a param is like IpgButtonParam.Label
+        let repr = param.repr()?.to_string();
+        let variant = repr.rsplit('.').next().unwrap_or_default().to_string();
+        Ok((class_name, variant))
+    })
+}
+
+/// Finds the value of the param, the param must be of the
+/// param class of the item, for example, IpgButtonParam.Label.
+pub fn get_item_value(
+        items: IpgItems, 
+        param_class: &str, 
+        param: &PyObject) 
+        -> PyResult<Option<IpgItemValue>> 
+{
+    let (class_name, variant) = get_param_names(param)?;
+
+    if param_class.is_empty() {
+        return Err(IpgParamError::new_err("get_item: the item has no parameters to read back"))
+    }
+    if class_name != param_class {
+        return Err(IpgParamError::new_err(
+            format!("get_item: {class_name} can not be used for this item, expected {param_class}")))
+    }
+
+    match items.into_iter().find(|(name, _)| *name == variant) {
+        Some((_, value)) => Ok(value),
+        None => Err(IpgParamError::new_err(
+            format!("get_item: {class_name}.{variant} can not be read back"))),
+    }
+}
@@ -0,0 +1,170 @@
+//! ipg_async
+//! Runs the async def callbacks and the run_task coroutines on an
+//! asyncio loop owned by the session.  The loop runs in its own python
+//! thread, the results are posted back to the gui thread.
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyTuple};
+
+use crate::{access_task_results, wake_up};
+
+use super::ipg_errors::{report_callback_error, report_error, IpgParamError};
+
+
+static SESSION_LOOP: GILOnceCell<PyObject> = GILOnceCell::new();
+
+// Starts the loop in a daemon thread the first time it is needed.
+fn get_session_loop(py: Python<'_>) -> PyResult<&Bound<'_, PyAny>> {
+    let event_loop = SESSION_LOOP.get_or_try_init(py, || -> PyResult<PyObject> {
+        let asyncio = py.import("asyncio")?;
+        let event_loop = asyncio.call_method0("new_event_loop")?;
+
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("target", event_loop.getattr("run_forever")?)?;
+        kwargs.set_item("name", "ipg_session_loop")?;
+        kwargs.set_item("daemon", true)?;
+        let thread = py.import("threading")?
+            .getattr("Thread")?
+            .call((), Some(&kwargs))?;
+        thread.call_method0("start")?;
+
+        Ok(event_loop.unbind())
+    })?;
+    Ok(event_loop.bind(py))
+}
+
+/// Stops the session loop, if one was started, when the gui exits.
+pub fn stop_session_loop(py: Python<'_>) {
+    if let Some(event_loop) = SESSION_LOOP.get(py) {
+        let event_loop = event_loop.bind(py);
+        let stop = event_loop.getattr("stop")
+            .and_then(|stop| event_loop.call_method1("call_soon_threadsafe", (stop,)));
+        if let Err(err) = stop {
+            report_error(err);
+        }
+    }
+}
+
+/// Schedules the coroutine on the session loop and returns the task id.
+/// When finished, the result is queued for the gui thread and the gui is woken up.
+pub fn run_coroutine(
+        py: Python<'_>,
+        coro: PyObject,
+        on_done: Option<PyObject>,
+        user_data: Option<PyObject>)
+        -> PyResult<usize>
+{
+    let asyncio = py.import("asyncio")?;
+    if !asyncio.call_method1("iscoroutine", (&coro,))?.is_truthy()? {
+        return Err(IpgParamError::new_err(
+            "run_task: a coroutine is required, call the async function to get one"))
+    }
+
+    let mut tasks = access_task_results();
+    tasks.last_id += 1;
+    let task_id = tasks.last_id;
+    drop(tasks);
+
+    let event_loop = get_session_loop(py)?;
+    let future = asyncio.call_method1("run_coroutine_threadsafe", (coro, event_loop))?;
+    let done = IpgTaskDone {
+        task_id,
+        on_done: on_done.map(wrap_async_callback).transpose()?,
+        user_data,
+    };
+    future.call_method1("add_done_callback", (Py::new(py, done)?,))?;
+
+    Ok(task_id)
+}
+
+/// Returns a callable which schedules the coroutine on the session loop
+/// when the callback is an async def function, otherwise the callback itself.
+pub fn wrap_async_callback(callback: PyObject) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let is_async = py.import("inspect")
+            .and_then(|inspect| inspect.call_method1("iscoroutinefunction", (&callback,)))
+            .and_then(|res| res.is_truthy());
+
+        match is_async {
+            Ok(true) => match Py::new(py, IpgAsyncCallback { callback }) {
+                Ok(wrapped) => Ok(wrapped.into_any()),
+                Err(err) => Err(IpgParamError::new_err(format!("Unable to wrap the async callback, {err}"))),
+            },
+            Ok(false) => Ok(callback),
+            Err(err) => Err(IpgParamError::new_err(format!("Unable to inspect the callback, {err}"))),
+        }
+    })
+}
+
+/// Calls the on_done callbacks of the finished tasks on the gui thread.
+pub fn process_task_results() {
+    let mut tasks = access_task_results();
+    if tasks.results.is_empty() {
+        return
+    }
+    let results = std::mem::take(&mut tasks.results);
+    drop(tasks);
+
+    for (task_id, on_done, user_data, result) in results {
+        Python::with_gil(|py| {
+            let value = match result {
+                Ok(value) => value,
+                Err(err) => return report_callback_error(&format!("Async task {task_id}"), err),
+            };
+            let on_done = match on_done {
+                Some(cb) => cb,
+                None => return,
+            };
+            let res = match user_data {
+                Some(user_data) => on_done.call1(py, (task_id, value, user_data)),
+                None => on_done.call1(py, (task_id, value)),
+            };
+            if let Err(err) = res {
+                return report_callback_error("run_task on_done", err);
+            }
+        });
+    }
+}
+
+// Stands in for an async def callback, the widgets call it like any
+// other callback and the coroutine is sent to the session loop.
+#[pyclass]
+pub struct IpgAsyncCallback {
+    callback: PyObject,
+}
+
+#[pymethods]
+impl IpgAsyncCallback {
+    #[pyo3(signature = (*args))]
+    fn __call__(&self, py: Python<'_>, args: &Bound<'_, PyTuple>) -> PyResult<()> {
+        let coro = self.callback.bind(py).call1(args)?;
+        run_coroutine(py, coro.unbind(), None, None)?;
+        Ok(())
+    }
+}
+
+// The done callback of the concurrent future, runs in the loop thread.
+#[pyclass]
+pub struct IpgTaskDone {
+    task_id: usize,
+    on_done: Option<PyObject>,
+    user_data: Option<PyObject>,
+}
+
+#[pymethods]
+impl IpgTaskDone {
+    fn __call__(&self, py: Python<'_>, future: &Bound<'_, PyAny>) {
+        let result = future.call_method0("result").map(|value| value.unbind());
+
+        let mut tasks = access_task_results();
+        tasks.results.push((
+            self.task_id,
+            self.on_done.as_ref().map(|cb| cb.clone_ref(py)),
+            self.user_data.as_ref().map(|ud| ud.clone_ref(py)),
+            result,
+        ));
+        drop(tasks);
+
+        wake_up();
+    }
+}
@@ -0,0 +1,70 @@
+//! ipg_batch
+//! Collects the update_item calls made inside of a `with ipg.batch():`
+//! block.  The updates are queued together when the block ends, so they
+//! are applied in one pass and drawn together.
+use std::cell::RefCell;
+
+use pyo3::prelude::*;
+
+use crate::queue_updates;
+
+
+thread_local! {
+    // the open batches of this thread, the last one is the innermost
+    static BATCHES: RefCell<Vec<Vec<(usize, PyObject, PyObject)>>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Adds the updates to the innermost open batch of this thread.
+/// The updates are handed back when no batch is open.
+pub fn add_to_batch(updates: Vec<(usize, PyObject, PyObject)>)
+                    -> Option<Vec<(usize, PyObject, PyObject)>>
+{
+    BATCHES.with(|batches| {
+        match batches.borrow_mut().last_mut() {
+            Some(batch) => {
+                batch.extend(updates);
+                None
+            },
+            None => Some(updates),
+        }
+    })
+}
+
+pub fn in_batch() -> bool {
+    BATCHES.with(|batches| !batches.borrow().is_empty())
+}
+
+#[pyclass]
+pub struct IpgBatch {}
+
+#[pymethods]
+impl IpgBatch {
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        BATCHES.with(|batches| batches.borrow_mut().push(vec![]));
+        slf
+    }
+
+    #[pyo3(signature = (exc_type, _exc_value, _traceback))]
+    fn __exit__(&self,
+                exc_type: Option<PyObject>,
+                _exc_value: Option<PyObject>,
+                _traceback: Option<PyObject>)
+                -> PyResult<bool>
+    {
+        let batch = BATCHES.with(|batches| batches.borrow_mut().pop())
+                                .unwrap_or_default();
+
+        // an exception inside of the block drops the whole batch
+        // and is raised as usual
+        if exc_type.is_some() {
+            return Ok(false)
+        }
+
+        // a nested batch becomes part of the outer one
+        if let Some(batch) = add_to_batch(batch) {
+            queue_updates(batch)?;
+        }
+        Ok(false)
+    }
+}
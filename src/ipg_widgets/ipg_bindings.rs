@@ -0,0 +1,248 @@
+//! ipg_bindings
+//! Named values shared by widgets.  A widget param bound to a value
+//! follows it, a two way binding also changes the value when the widget
+//! is used.  The values are passed on to the other widgets inside of
+//! App::update, no python callback is needed.
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use once_cell::sync::Lazy;
+use pyo3::prelude::*;
+
+use super::helpers::IpgItemValue;
+use super::ipg_errors::IpgParamError;
+
+
+#[derive(Debug, Clone, PartialEq, IntoPyObject)]
+pub enum IpgBindValue {
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+impl IpgBindValue {
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            IpgBindValue::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+            IpgBindValue::Number(n) => Some(*n),
+            IpgBindValue::Text(s) => s.trim().parse::<f64>().ok(),
+        }
+    }
+
+    fn as_bool(&self) -> bool {
+        match self {
+            IpgBindValue::Bool(b) => *b,
+            IpgBindValue::Number(n) => *n != 0.0,
+            IpgBindValue::Text(s) => !s.is_empty(),
+        }
+    }
+}
+
+// The value is changed on its way to the widget by the scale and
+// offset, numbers only, then by the format when the param is text.
+// A two way binding reverses the scale and offset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpgConverter {
+    pub format: Option<String>,
+    pub scale: f64,
+    pub offset: f64,
+}
+
+impl IpgConverter {
+    /// The value for the widget, of the same type as the
+    /// widget's current value when it has one.
+    pub fn to_item(&self, value: &IpgBindValue, current: Option<&IpgItemValue>) -> PyResult<IpgItemValue> {
+        let number = value.as_number().map(|n| n * self.scale + self.offset);
+        let get_number = || match number {
+            Some(n) => Ok(n),
+            None => Err(IpgParamError::new_err(format!("Binding: the value {:?} is not a number", value))),
+        };
+
+        let item = match current {
+            Some(IpgItemValue::Float(_)) => IpgItemValue::Float(get_number()? as f32),
+            Some(IpgItemValue::Int(_)) => {
+                let n = get_number()?.round();
+                if !(0.0..=usize::MAX as f64).contains(&n) {
+                    return Err(IpgParamError::new_err(
+                        format!("Binding: the value {n} is out of range for a whole number param")))
+                }
+                IpgItemValue::Int(n as usize)
+            },
+            Some(IpgItemValue::Bool(_)) => IpgItemValue::Bool(value.as_bool()),
+            Some(IpgItemValue::Str(_)) => IpgItemValue::Str(self.format_value(value)),
+            _ => {
+                if self.format.is_some() {
+                    return Ok(IpgItemValue::Str(self.format_value(value)))
+                }
+                match value {
+                    IpgBindValue::Bool(b) => IpgItemValue::Bool(*b),
+                    IpgBindValue::Number(_) => IpgItemValue::Float(get_number()? as f32),
+                    IpgBindValue::Text(s) => IpgItemValue::Str(s.clone()),
+                }
+            },
+        };
+        Ok(item)
+    }
+
+    /// The widget's value as the type of the shared value.  None when it
+    /// can't be converted, like a number half typed into a text input.
+    pub fn from_item(&self, item: &IpgItemValue, shared: &IpgBindValue) -> Option<IpgBindValue> {
+        let unscale = |n: f64| (n - self.offset) / self.scale;
+        match (shared, item) {
+            (IpgBindValue::Number(_), IpgItemValue::Float(f)) => Some(IpgBindValue::Number(unscale(*f as f64))),
+            (IpgBindValue::Number(_), IpgItemValue::Int(i)) => Some(IpgBindValue::Number(unscale(*i as f64))),
+            (IpgBindValue::Number(_), IpgItemValue::Bool(b)) => Some(IpgBindValue::Number(if *b { 1.0 } else { 0.0 })),
+            (IpgBindValue::Number(_), IpgItemValue::Str(s)) => s.trim().parse::<f64>().ok()
+                                                                .map(|n| IpgBindValue::Number(unscale(n))),
+            (IpgBindValue::Bool(_), IpgItemValue::Bool(b)) => Some(IpgBindValue::Bool(*b)),
+            (IpgBindValue::Bool(_), IpgItemValue::Float(f)) => Some(IpgBindValue::Bool(*f != 0.0)),
+            (IpgBindValue::Bool(_), IpgItemValue::Int(i)) => Some(IpgBindValue::Bool(*i != 0)),
+            (IpgBindValue::Text(_), IpgItemValue::Str(s)) => Some(IpgBindValue::Text(s.clone())),
+            (IpgBindValue::Text(_), IpgItemValue::Float(f)) => Some(IpgBindValue::Text(f.to_string())),
+            (IpgBindValue::Text(_), IpgItemValue::Int(i)) => Some(IpgBindValue::Text(i.to_string())),
+            (IpgBindValue::Text(_), IpgItemValue::Bool(b)) => Some(IpgBindValue::Text(b.to_string())),
+            _ => None,
+        }
+    }
+
+    // The first {} of the format is replaced by the value,
+    // {:.2} gives a number with 2 decimals.
+    fn format_value(&self, value: &IpgBindValue) -> String {
+        let text = match value {
+            IpgBindValue::Number(n) => (n * self.scale + self.offset).to_string(),
+            IpgBindValue::Bool(b) => b.to_string(),
+            IpgBindValue::Text(s) => s.clone(),
+        };
+
+        let format = match &self.format {
+            Some(format) => format,
+            None => return text,
+        };
+
+        let (start, end) = match format.find('{')
+                                .and_then(|start| format[start..].find('}')
+                                .map(|end| (start, start + end))) {
+            Some(span) => span,
+            None => return format.clone(),
+        };
+
+        let spec = &format[start+1..end];
+        let precision = spec.strip_prefix(":.").and_then(|p| p.parse::<usize>().ok());
+        let text = match (precision, value) {
+            (Some(precision), IpgBindValue::Number(n)) =>
+                format!("{:.*}", precision, n * self.scale + self.offset),
+            _ => text,
+        };
+
+        format!("{}{}{}", &format[..start], text, &format[end+1..])
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IpgBinding {
+    pub wid: usize,
+    pub param: Arc<PyObject>,
+    // the name of the param variant, Value for IpgSliderParam.Value
+    pub variant: String,
+    pub name: String,
+    pub two_way: bool,
+    pub converter: IpgConverter,
+}
+
+#[derive(Debug)]
+pub struct Bindings {
+    pub values: Lazy<HashMap<String, IpgBindValue>>,
+    pub bindings: Vec<IpgBinding>,
+}
+
+pub static BINDINGS: Mutex<Bindings> = Mutex::new(Bindings {
+    values: Lazy::new(||HashMap::new()),
+    bindings: vec![],
+});
+
+pub fn access_bindings() -> MutexGuard<'static, Bindings> {
+    BINDINGS.lock().unwrap()
+}
+
+impl Bindings {
+    /// The two way bindings of the widget.
+    pub fn two_way(&self, wid: usize) -> Vec<IpgBinding> {
+        self.bindings.iter()
+            .filter(|binding| binding.wid == wid && binding.two_way)
+            .cloned()
+            .collect()
+    }
+
+    /// The value and the bindings that follow it, except those of the source widget.
+    pub fn targets(&self, name: &str, source: Option<usize>) -> Option<(IpgBindValue, Vec<IpgBinding>)> {
+        let value = self.values.get(name)?.clone();
+        let targets = self.bindings.iter()
+            .filter(|binding| binding.name == name && Some(binding.wid) != source)
+            .cloned()
+            .collect();
+        Some((value, targets))
+    }
+
+    /// Sets the value from the widget, returns true when it changed.
+    pub fn set_from_item(&mut self, binding: &IpgBinding, item: &IpgItemValue) -> bool {
+        let shared = match self.values.get_mut(&binding.name) {
+            Some(shared) => shared,
+            None => return false,
+        };
+        match binding.converter.from_item(item, shared) {
+            Some(value) if value != *shared => {
+                *shared = value;
+                true
+            },
+            _ => false,
+        }
+    }
+}
+
+pub fn try_extract_bind_value(value: &PyObject) -> PyResult<IpgBindValue> {
+    Python::with_gil(|py| {
+        // a python bool is also an int so it is tried first
+        if let Ok(b) = value.extract::<bool>(py) {
+            Ok(IpgBindValue::Bool(b))
+        } else if let Ok(n) = value.extract::<f64>(py) {
+            Ok(IpgBindValue::Number(n))
+        } else if let Ok(s) = value.extract::<String>(py) {
+            Ok(IpgBindValue::Text(s))
+        } else {
+            Err(IpgParamError::new_err("The value must be a bool, a number or a str"))
+        }
+    })
+}
+
+pub fn item_to_py(item: IpgItemValue) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        match item.into_pyobject(py) {
+            Ok(value) => Ok(value.unbind()),
+            Err(e) => Err(IpgParamError::new_err(format!("Binding: unable to convert the value, {e}"))),
+        }
+    })
+}
+
+
+#[test]
+fn test_binding_converter() {
+    let converter = IpgConverter { format: Some("{:.1}%".to_string()), scale: 100.0, offset: 0.0 };
+    let text = converter.to_item(&IpgBindValue::Number(0.256), Some(&IpgItemValue::Str(String::new()))).unwrap();
+    assert!(matches!(text, IpgItemValue::Str(s) if s == "25.6%"));
+
+    // a whole number param can't be negative, a text that's not a number can't be scaled
+    let unit = IpgConverter { format: None, scale: 1.0, offset: 0.0 };
+    let index = unit.to_item(&IpgBindValue::Number(2.4), Some(&IpgItemValue::Int(0))).unwrap();
+    assert!(matches!(index, IpgItemValue::Int(2)));
+    assert!(unit.to_item(&IpgBindValue::Number(-1.0), Some(&IpgItemValue::Int(0))).is_err());
+    assert!(unit.to_item(&IpgBindValue::Text("a".to_string()), Some(&IpgItemValue::Float(0.0))).is_err());
+
+    let value = converter.from_item(&IpgItemValue::Float(50.0), &IpgBindValue::Number(0.0));
+    assert_eq!(value, Some(IpgBindValue::Number(0.5)));
+
+    let value = converter.from_item(&IpgItemValue::Str("4.".to_string()), &IpgBindValue::Number(0.0));
+    assert_eq!(value, Some(IpgBindValue::Number(0.04)));
+
+    let value = converter.from_item(&IpgItemValue::Str("-".to_string()), &IpgBindValue::Number(0.0));
+    assert_eq!(value, None);
+}
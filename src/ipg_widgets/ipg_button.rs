@@ -2,14 +2,18 @@
 
 use crate::graphics::colors::get_color;
 use crate::style::styling::IpgStyleStandard;
-use crate::{access_callbacks, access_user_data2, access_user_data1, app};
-use super::helpers::{get_height, get_horizontal_alignment, get_padding_f64, get_radius, get_vertical_alignment, get_width, try_extract_boolean, try_extract_f32, try_extract_f64, try_extract_ipg_color, try_extract_ipg_horizontal_alignment, try_extract_ipg_vertical_alignment, try_extract_rgba_color, try_extract_string, try_extract_style_standard, try_extract_vec_f32, try_extract_vec_f64};
+use crate::app;
+use super::callbacks::dispatch_callback;
+use super::helpers::{get_height, get_horizontal_alignment, get_padding_f64, get_radius, get_vertical_alignment, get_width, try_extract_boolean, try_extract_f32, try_extract_f64, try_extract_ipg_color, try_extract_ipg_horizontal_alignment, try_extract_ipg_vertical_alignment, try_extract_rgba_color, try_extract_string, try_extract_style_standard, try_extract_vec_f32, try_extract_vec_f64, 
+    item_fill, item_horizontal_alignment, item_length, item_object_opt, item_padding, item_vertical_alignment, IpgItems};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_shortcuts::{accelerator_text, parse_accelerator};
+use super::ipg_errors::IpgParamError;
 
 use iced::widget::button::{self, Status, Style};
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
-use iced::widget::{text, Button, Text};
+use iced::widget::{text, Button, Row, Text};
 use iced::{alignment, Border, Color, Element, Length, Padding, Shadow, Theme, Vector };
 
 use crate::graphics::bootstrap::{self, icon_to_char, icon_to_string};
@@ -20,6 +24,7 @@ pub struct IpgButton {
     pub id: usize,
     pub parent_id: String,
     pub show: bool,
+    pub enabled: bool,
 
     pub label: String,
     pub width: Length,
@@ -32,6 +37,8 @@ pub struct IpgButton {
     pub style_id: Option<usize>,
     pub style_standard: Option<IpgStyleStandard>,
     pub style_arrow: Option<IpgButtonArrow>,
+    // the shortcut text shown after the label, as in a menu
+    pub accelerator: Option<String>,
 }
 
 impl IpgButton {
@@ -39,6 +46,7 @@ impl IpgButton {
         id: usize,
         parent_id: String,
         show: bool,
+        enabled: bool,
 
         label: String,
         width: Length,
@@ -51,11 +59,13 @@ impl IpgButton {
         style_id: Option<usize>,
         style_standard: Option<IpgStyleStandard>,
         style_arrow: Option<IpgButtonArrow>,
+        accelerator: Option<String>,
         ) -> Self {
         Self {
             id,
             parent_id,
             show,
+            enabled,
             label,
             width,
             height,
@@ -67,6 +77,7 @@ impl IpgButton {
             style_id,
             style_standard,
             style_arrow,
+            accelerator,
         }
     }
 }
@@ -142,11 +153,24 @@ pub fn construct_button<'a>(btn: &'a IpgButton,
         label = Text::new(arrow).font(iced::Font::with_name("bootstrap-icons"));
     }
     
-    let ipg_btn: Element<BTNMessage> = Button::new(label)
+    let content: Element<BTNMessage> = match &btn.accelerator {
+        Some(accelerator) => {
+            // a filled button, like a menu item, has the accelerator at the right
+            let label_width = if btn.width == Length::Fill { Length::Fill } else { Length::Shrink };
+            Row::new()
+                .push(label.width(label_width))
+                .push(text(accelerator.clone()).size(btn.text_size).align_y(btn.text_align_y))
+                .spacing(20.0)
+                .into()
+        },
+        None => label.into(),
+    };
+
+    let ipg_btn: Element<BTNMessage> = Button::new(content)
                                 .height(btn.height)
                                 .padding(btn.padding)
                                 .width(btn.width)
-                                .on_press(BTNMessage::OnPress)
+                                .on_press_maybe(btn.enabled.then_some(BTNMessage::OnPress))
                                 .clip(btn.clip)
                                 .style(move|theme: &Theme, status| {   
                                     get_styling(theme, status,
@@ -175,60 +199,21 @@ pub fn process_callback(
         id: usize, 
         event_name: String) 
 {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, user_data)) {
-                panic!("Button callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, user_data)) {
-                panic!("Button callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with only the id
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id,)) {
-            panic!("Button callback error: {err}");
-        }
-    });
+    dispatch_callback("Button", id, &event_name, (id,));
 }
 
 
 #[derive(Debug, Clone, PartialEq)]
 #[pyclass(eq, eq_int)]
 pub enum IpgButtonParam {
+    Accelerator,
     ArrowStyle,
     Height,
     HeightFill,
     Label,
     Padding,
     Clip,
+    Enabled,
     Show,
     StyleId,
     StyleStandard,
@@ -243,61 +228,98 @@ pub enum IpgButtonParam {
 pub fn button_item_update(btn: &mut IpgButton,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_button_update(item);
+    let update = try_extract_button_update(item)?;
     let name = "Button".to_string();
     match update {
+        IpgButtonParam::Accelerator => {
+            btn.accelerator = if Python::with_gil(|py| value.is_none(py)) {
+                None
+            } else {
+                let accelerator = try_extract_string(value, name)?;
+                match parse_accelerator(&accelerator) {
+                    Ok(strokes) => Some(accelerator_text(&strokes)),
+                    Err(err) => return Err(IpgParamError::new_err(format!("Button: Accelerator, {err}"))),
+                }
+            };
+        },
        IpgButtonParam::ArrowStyle => {
-            btn.style_arrow = Some(try_extract_button_arrow(value));
+            btn.style_arrow = Some(try_extract_button_arrow(value)?);
         },
         IpgButtonParam::Label => {
-            btn.label = try_extract_string(value, name);
+            btn.label = try_extract_string(value, name)?;
         },
         IpgButtonParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             btn.height = get_height(Some(val as f32), false);
         },
         IpgButtonParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             btn.height = get_height(None, val);
         },
         IpgButtonParam::Padding => {
-            btn.padding =  get_padding_f64(try_extract_vec_f64(value, name));
+            btn.padding =  get_padding_f64(try_extract_vec_f64(value, name)?)?;
         },
         IpgButtonParam::Clip => {
-            btn.clip = try_extract_boolean(value, name);
+            btn.clip = try_extract_boolean(value, name)?;
         }
+        IpgButtonParam::Enabled => {
+            btn.enabled = try_extract_boolean(value, name)?;
+        },
         IpgButtonParam::Show => {
-            btn.show = try_extract_boolean(value, name);
+            btn.show = try_extract_boolean(value, name)?;
         },
         IpgButtonParam::StyleId => {
-            btn.style_id = Some(try_extract_f64(value, name) as usize);
+            btn.style_id = Some(try_extract_f64(value, name)? as usize);
         },
         IpgButtonParam::StyleStandard => {
-            btn.style_standard = Some(try_extract_style_standard(value, name));
+            btn.style_standard = Some(try_extract_style_standard(value, name)?);
         },
         IpgButtonParam::TextAlignX => {
-            let h_align = try_extract_ipg_horizontal_alignment(value).unwrap();
+            let h_align = try_extract_ipg_horizontal_alignment(value)
+                .ok_or_else(|| IpgParamError::new_err(format!("{}-Unable to extract python object for IpgHorizontalAlignment", name)))?;
             btn.text_align_x = get_horizontal_alignment(&h_align);
         },
         IpgButtonParam::TextAlignY => {
-            let v_align = try_extract_ipg_vertical_alignment(value).unwrap();
+            let v_align = try_extract_ipg_vertical_alignment(value)
+                .ok_or_else(|| IpgParamError::new_err(format!("{}-Unable to extract python object for IpgVerticalAlignment", name)))?;
             btn.text_align_y= get_vertical_alignment(&v_align);
         },
         IpgButtonParam::TextSize => {
-            btn.text_size = try_extract_f32(value, name);
+            btn.text_size = try_extract_f32(value, name)?;
         },
         IpgButtonParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             btn.width = get_width(Some(val as f32), false);
         },
         IpgButtonParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             btn.width = get_width(None, val);
         },
     }
+    Ok(())
+}
 
+pub fn button_get_items(btn: &IpgButton) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Accelerator", btn.accelerator.clone().map(Into::into)),
+        ("ArrowStyle", item_object_opt(btn.style_arrow.clone())?),
+        ("Clip", Some(btn.clip.into())),
+        ("Height", item_length(btn.height)),
+        ("HeightFill", item_fill(btn.height)),
+        ("Label", Some(btn.label.clone().into())),
+        ("Padding", item_padding(btn.padding)),
+        ("Enabled", Some(btn.enabled.into())),
+        ("Show", Some(btn.show.into())),
+        ("StyleId", btn.style_id.map(Into::into)),
+        ("StyleStandard", item_object_opt(btn.style_standard.clone())?),
+        ("TextAlignX", item_horizontal_alignment(btn.text_align_x)?),
+        ("TextAlignY", item_vertical_alignment(btn.text_align_y)?),
+        ("TextSize", Some(btn.text_size.into())),
+        ("Width", item_length(btn.width)),
+        ("WidthFill", item_fill(btn.width)),
+    ])
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -322,62 +344,63 @@ pub enum IpgButtonStyleParam {
 
 pub fn button_style_update_item(style: &mut IpgButtonStyle,
                                 item: &PyObject,
-                                value: &PyObject,) 
+                                value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_button_style_update(item);
+    let update = try_extract_button_style_update(item)?;
     let name = "ButtonStyle".to_string();
     match update {
         IpgButtonStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgButtonStyleParam::BackgroundRbgaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgButtonStyleParam::BackgroundIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgButtonStyleParam::BackgroundIpgRgbaHovered => {
-            style.background_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgButtonStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgButtonStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgButtonStyleParam::BorderRadius => {
-            style.border_radius = try_extract_vec_f32(value, name);
+            style.border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgButtonStyleParam::BorderWidth => {
-            style.border_width = try_extract_f64(value, name) as f32;
+            style.border_width = try_extract_f64(value, name)? as f32;
         },
         IpgButtonStyleParam::ShadowIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.shadow_color = get_color(None, Some(color), 1.0, false);
         },
         IpgButtonStyleParam::ShadowRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgButtonStyleParam::ShadowOffsetX => {
-            style.shadow_offset_x = try_extract_f64(value, name) as f32;
+            style.shadow_offset_x = try_extract_f64(value, name)? as f32;
         },
         IpgButtonStyleParam::ShadowOffsetY => {
-            style.shadow_offset_y = try_extract_f64(value, name) as f32;
+            style.shadow_offset_y = try_extract_f64(value, name)? as f32;
         },
         IpgButtonStyleParam::ShadowBlurRadius => {
-            style.shadow_blur_radius = try_extract_f64(value, name) as f32;
+            style.shadow_blur_radius = try_extract_f64(value, name)? as f32;
         },
         IpgButtonStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgButtonStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
 pub fn get_btn_style(style: Option<&IpgWidgets>) -> Option<IpgButtonStyle>{
@@ -521,35 +544,35 @@ fn disabled(style: Style) -> Style {
     }
 }
 
-pub fn try_extract_button_update(update_obj: &PyObject) -> IpgButtonParam {
+pub fn try_extract_button_update(update_obj: &PyObject) -> PyResult<IpgButtonParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgButtonParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Button update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Button update extraction failed")),
         }
     })
 }
 
-pub fn try_extract_button_arrow(update_obj: &PyObject) -> IpgButtonArrow {
+pub fn try_extract_button_arrow(update_obj: &PyObject) -> PyResult<IpgButtonArrow> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgButtonArrow>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Button arrow extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Button arrow extraction failed")),
         }
     })
 }
 
-pub fn try_extract_button_style_update(update_obj: &PyObject) -> IpgButtonStyleParam {
+pub fn try_extract_button_style_update(update_obj: &PyObject) -> PyResult<IpgButtonStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgButtonStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Button style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Button style update extraction failed")),
         }
     })
 }
@@ -3,12 +3,14 @@
 // #![allow(dead_code)]
 use std::fs;
 use std::path::Path;
+use std::time::Instant;
 
 use iced::widget::container;
-use iced::{Color, Element, Point, Radians};
-use pyo3::{pyclass, PyObject, Python};
+use iced::{touch, Color, Element, Point, Radians};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use crate::app::Message;
+use crate::canvas::canvas_helpers::{rotate_geometry, translate_geometry};
 use crate::canvas::draw_canvas::{IpgCanvasState, IpgDrawMode, 
     IpgDrawStatus, IpgWidget};
 use crate::canvas::geometries::{
@@ -18,19 +20,23 @@ use crate::canvas::geometries::{
 use crate::canvas::import_export::{convert_to_export, import_widgets, save};
 use crate::IpgState;
 
+use super::callbacks::dispatch_callback;
+use super::ipg_gestures::IpgGesture;
 use super::helpers::{
     get_horizontal_alignment, get_vertical_alignment, try_extract_f64, try_extract_ipg_horizontal_alignment,
     try_extract_ipg_vertical_alignment, try_extract_point, try_extract_rgba_color, try_extract_string,
 };
+use super::ipg_errors::IpgParamError;
 
 #[derive(Debug, Clone)]
 pub struct IpgCanvas {
     pub id: usize,
+    pub show: bool,
 }
 
 impl IpgCanvas {
-    pub fn new(id: usize) -> Self {
-        Self { id }
+    pub fn new(id: usize, show: bool) -> Self {
+        Self { id, show }
     }
 }
 
@@ -42,7 +48,6 @@ pub fn construct_canvas(canvas_state: &IpgCanvasState) -> Element<Message> {
                 &canvas_state.text_curves,
                 &canvas_state.image_curves,
             )
-            .map(CanvasMessage::WidgetDraw),
     )
     .into();
     draw.map(move |message| Message::Canvas(message))
@@ -51,10 +56,15 @@ pub fn construct_canvas(canvas_state: &IpgCanvasState) -> Element<Message> {
 #[derive(Debug, Clone)]
 pub enum CanvasMessage {
     WidgetDraw(IpgWidget),
+    Touch(touch::Event),
 }
 
 pub fn canvas_callback(canvas_message: CanvasMessage, app_state: &mut IpgState, canvas_state: &mut IpgCanvasState) {
     match canvas_message {
+        CanvasMessage::Touch(event) => {
+            let found = canvas_state.gestures.process(&event, Instant::now());
+            process_gesture_callbacks(canvas_state.canvas_id, found);
+        },
         CanvasMessage::WidgetDraw(mut widget) => {
             // Since the text widget may have a blinking cursor, the only way to use a timer
             // is to use the main subscription one at this time, canvas lacks a time event.
@@ -130,6 +140,21 @@ pub fn canvas_callback(canvas_message: CanvasMessage, app_state: &mut IpgState,
     }
 }
 
+/// Sends the long press of a finger held still on the canvas, from the time tick.
+pub fn canvas_long_press(canvas_state: &mut IpgCanvasState) {
+    let found = canvas_state.gestures.long_press(Instant::now());
+    process_gesture_callbacks(canvas_state.canvas_id, found.into_iter().collect());
+}
+
+// the callbacks are named after the gestures, on_pinch, on_long_press, ...
+fn process_gesture_callbacks(id: usize, found: Vec<IpgGesture>) {
+    for gesture in found {
+        let (event_name, hmap_s_v) = gesture.values();
+        let callback_name = format!("on_{}", event_name.replace(' ', "_"));
+        dispatch_callback("Canvas", id, &callback_name, (id, hmap_s_v));
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[pyclass(eq, eq_int)]
 pub enum IpgCanvasParam {
@@ -145,6 +170,8 @@ pub enum IpgCanvasParam {
     Load,
     Save,
     TextAlignment,
+    UnitScale,
+    Units,
 }
 
 // update only the canvas, not the propterties of the canvas widgets.
@@ -153,62 +180,64 @@ pub fn canvas_item_update(canvas_state: &mut IpgCanvasState,
                             item: &PyObject, 
                             value: &PyObject,
                             mut last_id: usize,) 
-                            -> Option<usize> 
+                            -> PyResult<Option<usize>> 
 {
-    let update = try_extract_canvas_update(item);
+    let update = try_extract_canvas_update(item)?;
     let name = "Canvas".to_string();
     match update {
         IpgCanvasParam::Clear => {
             canvas_state.clear_curves();
-            None
+            Ok(None)
         }
         IpgCanvasParam::CanvasColor => {
-            let rgba = try_extract_rgba_color(value, name);
+            let rgba = try_extract_rgba_color(value, name)?;
             canvas_state.selected_canvas_color = Some(Color::from(rgba));
             canvas_state.clear_background_cache();
-            None
+            Ok(None)
         }
         IpgCanvasParam::DrawColor => {
-            let rgba = try_extract_rgba_color(value, name);
+            let rgba = try_extract_rgba_color(value, name)?;
             canvas_state.selected_draw_color = Color::from(rgba);
-            None
+            Ok(None)
         }
         IpgCanvasParam::FilePath => {
-            canvas_state.file_path = try_extract_string(value, name);
-            None
+            canvas_state.file_path = try_extract_string(value, name)?;
+            Ok(None)
         }
         IpgCanvasParam::FillColor => {
-            let rgba = try_extract_rgba_color(value, name);
+            let rgba = try_extract_rgba_color(value, name)?;
             canvas_state.selected_fill_color = Some(Color::from(rgba));
-            None
+            Ok(None)
         }
         IpgCanvasParam::DrawWidth => {
-            let width = try_extract_f64(value, name) as f32;
+            let width = try_extract_f64(value, name)? as f32;
             canvas_state.selected_width = width;
-            None
+            Ok(None)
         }
         IpgCanvasParam::Mode => {
-            canvas_state.draw_mode = try_extract_mode(value);
-            None
+            canvas_state.draw_mode = try_extract_mode(value)?;
+            Ok(None)
         }
         IpgCanvasParam::PolyPoints => {
-            let input = try_extract_string(value, name);
+            let input = try_extract_string(value, name)?;
             canvas_state.selected_poly_points = match input.parse::<usize>() {
                 Ok(int) => int,
-                Err(e) => panic!("PolyPoint input must be an integer, {}", e),
+                Err(e) => return Err(IpgParamError::new_err(format!("PolyPoint input must be an integer, {}", e))),
             };
-            None
+            Ok(None)
         }
         IpgCanvasParam::Load => {
             let path = Path::new(&canvas_state.file_path);
-            let data = fs::read_to_string(path).expect("Unable to read file");
-            let widgets = serde_json::from_str(&data).expect("Unable to parse file");
+            let data = fs::read_to_string(path)
+                .map_err(|e| IpgParamError::new_err(format!("Canvas: unable to read the file, {}", e)))?;
+            let widgets = serde_json::from_str(&data)
+                .map_err(|e| IpgParamError::new_err(format!("Canvas: unable to parse the file, {}", e)))?;
             canvas_state.clear_curves();
             (canvas_state.curves, canvas_state.text_curves, last_id) =
                 import_widgets(widgets, last_id);
             canvas_state.request_redraw();
             canvas_state.request_text_redraw();
-            Some(last_id)
+            Ok(Some(last_id))
         }
         IpgCanvasParam::Save => {
             let path = Path::new(&canvas_state.file_path);
@@ -218,7 +247,7 @@ pub fn canvas_item_update(canvas_state: &mut IpgCanvasState,
                 Ok(_) => (),
                 Err(e) => println!("Unable to save file, {}", e),
             }
-            None
+            Ok(None)
         }
         IpgCanvasParam::TextAlignment => {
             let align = try_extract_ipg_horizontal_alignment(value);
@@ -229,43 +258,66 @@ pub fn canvas_item_update(canvas_state: &mut IpgCanvasState,
             if align.is_some() {
                 canvas_state.selected_v_text_alignment = get_vertical_alignment(&align.unwrap());
             }
-            None
+            Ok(None)
+        }
+        IpgCanvasParam::UnitScale => {
+            let scale = try_extract_f64(value, name)? as f32;
+            canvas_state.selected_unit_scale = scale;
+            // the existing dimensions follow the new scale
+            for widget in canvas_state.curves.values_mut() {
+                if let IpgWidget::Dimension(dim) = widget {
+                    dim.unit_scale = scale;
+                }
+            }
+            canvas_state.request_redraw();
+            Ok(None)
+        }
+        IpgCanvasParam::Units => {
+            let units = try_extract_string(value, name)?;
+            for widget in canvas_state.curves.values_mut() {
+                if let IpgWidget::Dimension(dim) = widget {
+                    dim.units = units.clone();
+                }
+            }
+            canvas_state.selected_units = units;
+            canvas_state.request_redraw();
+            Ok(None)
         }
         IpgCanvasParam::Widget => {
-            let selected_widget = Some(try_extract_widget(value));
+            let selected_widget = Some(try_extract_widget(value)?);
             canvas_state.selected_widget = selected_widget;
             canvas_state.timer_event_enabled = selected_widget == Some(IpgCanvasWidget::Text);
-            None
+            Ok(None)
         }
     }
 }
 
-pub fn try_extract_canvas_update(update_obj: &PyObject) -> IpgCanvasParam {
+pub fn try_extract_canvas_update(update_obj: &PyObject) -> PyResult<IpgCanvasParam> {
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgCanvasParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Canvas update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Canvas update extraction failed")),
         }
     })
 }
 
-fn try_extract_mode(update_obj: &PyObject) -> IpgDrawMode {
+fn try_extract_mode(update_obj: &PyObject) -> PyResult<IpgDrawMode> {
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgDrawMode>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Canvas mode update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Canvas mode update extraction failed")),
         }
     })
 }
 
-fn try_extract_widget(update_obj: &PyObject) -> IpgCanvasWidget {
+fn try_extract_widget(update_obj: &PyObject) -> PyResult<IpgCanvasWidget> {
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgCanvasWidget>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Canvas widget update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Canvas widget update extraction failed")),
         }
     })
 }
@@ -277,136 +329,173 @@ pub enum IpgCanvasGeometryParam {
     Rotation,
 }
 
-pub fn match_canvas_widget(widget: &mut IpgWidget, item: &PyObject, value: &PyObject) {
-    let update_item = try_extract_geometry_update(item);
+pub fn match_canvas_widget(widget: &mut IpgWidget, item: &PyObject, value: &PyObject) -> PyResult<()> {
+    let update_item = try_extract_geometry_update(item)?;
     let name = "CanvasGeometry".to_string();
     match widget {
         IpgWidget::None => (),
         IpgWidget::Arc(arc) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 arc.mid_point = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                panic!("Arc has no rotation property")
+                return Err(IpgParamError::new_err("Arc has no rotation property"))
             }
         },
         IpgWidget::Bezier(bz) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 bz.mid_point = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                let val = try_extract_f64(value, name) as f32;
+                let val = try_extract_f64(value, name)? as f32;
                 bz.rotation = val;
             }
         },
         IpgWidget::Circle(cir) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 cir.center = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                panic!("Circle update has no rotation property")
+                return Err(IpgParamError::new_err("Circle update has no rotation property"))
             }
         },
         IpgWidget::Ellipse(ell) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 ell.center = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                let val = try_extract_f64(value, name) as f32;
+                let val = try_extract_f64(value, name)? as f32;
                 ell.rotation = Radians(val);
             }
         },
         IpgWidget::Image(img) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 img.position = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                let val = try_extract_f64(value, name) as f32;
+                let val = try_extract_f64(value, name)? as f32;
                 img.rotation = val;
             }
         },
         IpgWidget::Line(line) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 line.mid_point = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                let val = try_extract_f64(value, name) as f32;
+                let val = try_extract_f64(value, name)? as f32;
                 line.rotation = val;
             }
         },
         IpgWidget::PolyLine(pl) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 pl.mid_point = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                let val = try_extract_f64(value, name) as f32;
+                let val = try_extract_f64(value, name)? as f32;
                 pl.rotation = val;
             }
         },
         IpgWidget::Polygon(pg) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 pg.mid_point = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                let val = try_extract_f64(value, name) as f32;
+                let val = try_extract_f64(value, name)? as f32;
                 pg.rotation = val;
             }
         },
         IpgWidget::Rectangle(rect) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 rect.mid_point = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                panic!("Rectangle has no rotation property use polygon with 4 sides")
+                return Err(IpgParamError::new_err("Rectangle has no rotation property use polygon with 4 sides"))
             }
         },
         IpgWidget::RightTriangle(tr) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 tr.mid_point = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                let val = try_extract_f64(value, name) as f32;
+                let val = try_extract_f64(value, name)? as f32;
                 tr.rotation = val;
             }
         },
         IpgWidget::Text(txt) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 txt.position = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                let val = try_extract_f64(value, name) as f32;
+                let val = try_extract_f64(value, name)? as f32;
                 txt.rotation = val;
             }
         },
         IpgWidget::FreeHand(fh) => match update_item {
             IpgCanvasGeometryParam::Position => {
-                let val = try_extract_point(value, name);
+                let val = try_extract_point(value, name)?;
                 fh.points[0] = Point::from(val);
             }
             IpgCanvasGeometryParam::Rotation => {
-                panic!("Freehand geometry has no rotation property")
+                return Err(IpgParamError::new_err("Freehand geometry has no rotation property"))
+            }
+        },
+        IpgWidget::Dimension(dim) => match update_item {
+            IpgCanvasGeometryParam::Position => {
+                let val = Point::from(try_extract_point(value, name)?);
+                dim.points = translate_geometry(&dim.points, val, dim.mid_point);
+                dim.mid_point = val;
+            }
+            IpgCanvasGeometryParam::Rotation => {
+                let val = try_extract_f64(value, name)? as f32;
+                dim.points = rotate_geometry(&dim.points, &dim.mid_point, &(val - dim.rotation), IpgCanvasWidget::Dimension);
+                dim.rotation = val;
+            }
+        },
+        IpgWidget::AngleMeasure(ang) => match update_item {
+            IpgCanvasGeometryParam::Position => {
+                let val = Point::from(try_extract_point(value, name)?);
+                ang.points = translate_geometry(&ang.points, val, ang.mid_point);
+                ang.mid_point = val;
+            }
+            IpgCanvasGeometryParam::Rotation => {
+                let val = try_extract_f64(value, name)? as f32;
+                ang.points = rotate_geometry(&ang.points, &ang.mid_point, &(val - ang.rotation), IpgCanvasWidget::AngleMeasure);
+                ang.rotation = val;
+            }
+        },
+        IpgWidget::Callout(co) => match update_item {
+            IpgCanvasGeometryParam::Position => {
+                let val = Point::from(try_extract_point(value, name)?);
+                co.points = translate_geometry(&co.points, val, co.mid_point);
+                co.mid_point = val;
+            }
+            IpgCanvasGeometryParam::Rotation => {
+                let val = try_extract_f64(value, name)? as f32;
+                co.points = rotate_geometry(&co.points, &co.mid_point, &(val - co.rotation), IpgCanvasWidget::Callout);
+                co.rotation = val;
             }
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_geometry_update(update_obj: &PyObject) -> IpgCanvasGeometryParam {
+pub fn try_extract_geometry_update(update_obj: &PyObject) -> PyResult<IpgCanvasGeometryParam> {
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgCanvasGeometryParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Canvas update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Canvas update extraction failed")),
         }
     })
 }
@@ -1,18 +1,20 @@
 //! ipg_card
 use crate::app::Message;
 use crate::graphics::colors::get_color;
-use crate::ipg_widgets::helpers::{try_extract_boolean, try_extract_string};
-use crate::{access_callbacks, access_user_data1, access_user_data2, IpgState};
+use crate::ipg_widgets::helpers::{try_extract_boolean, try_extract_string, IpgItems};
+use crate::IpgState;
+use super::callbacks::dispatch_callback;
 use super::callbacks::WidgetCallbackIn;
 use super::helpers::{try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, try_extract_u64};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use iced::{Color, Element, Length, Padding};
 use iced::widget::{Column, Space, Text};
 
 use crate::iced_aw_widgets::card::{self, Card, CardStyles};
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -200,48 +202,7 @@ pub fn card_callback(_state: &mut IpgState, id: usize, message: CardMessage) {
 }
 
 pub fn process_callback(id: usize, event_name: String) {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, user_data)) {
-                panic!("Card callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, user_data)) {
-                panic!("Card callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with only the id
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id,)) {
-            panic!("Card callback error: {err}");
-        }
-    });
+    dispatch_callback("Card", id, &event_name, (id,));
 }
 
 
@@ -260,30 +221,42 @@ pub enum IpgCardParam {
 pub fn card_item_update(crd: &mut IpgCard,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_card_update(item);
+    let update = try_extract_card_update(item)?;
     let name = "Card".to_string();
     match update {
         IpgCardParam::Body => {
-            crd.body = try_extract_string(value, name);
+            crd.body = try_extract_string(value, name)?;
         },
         IpgCardParam::Foot => {
-            crd.foot = Some(try_extract_string(value, name));
+            crd.foot = Some(try_extract_string(value, name)?);
         },
         IpgCardParam::Head => {
-            crd.head = try_extract_string(value, name);
+            crd.head = try_extract_string(value, name)?;
         },
         IpgCardParam::IsOpen => {
-            crd.is_open = try_extract_boolean(value, name);
+            crd.is_open = try_extract_boolean(value, name)?;
         },
         IpgCardParam::StyleId => {
-            crd.style_id = Some(try_extract_u64(value, name) as usize);
+            crd.style_id = Some(try_extract_u64(value, name)? as usize);
         },
         IpgCardParam::Show => {
-            crd.show = try_extract_boolean(value, name);
+            crd.show = try_extract_boolean(value, name)?;
         },
     }
+    Ok(())
+}
+
+pub fn card_get_items(crd: &IpgCard) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Body", Some(crd.body.clone().into())),
+        ("Foot", crd.foot.clone().map(Into::into)),
+        ("Head", Some(crd.head.clone().into())),
+        ("IsOpen", Some(crd.is_open.into())),
+        ("Show", Some(crd.show.into())),
+        ("StyleId", crd.style_id.map(Into::into)),
+    ])
 }
 
 pub fn get_card_style(style: Option<&IpgWidgets>) -> Option<IpgCardStyle>{
@@ -348,12 +321,12 @@ fn custom_style(ipg_style_opt: Option<IpgCardStyle>) -> CardStyles {
 }
 
 
-pub fn try_extract_card_update(update_obj: &PyObject) -> IpgCardParam {
+pub fn try_extract_card_update(update_obj: &PyObject) -> PyResult<IpgCardParam> {
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgCardParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Card update extraction failed."),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Card update extraction failed.")),
         }
     })
 }
@@ -385,91 +358,92 @@ pub enum IpgCardStyleParam {
 
 pub fn card_style_update(style: &mut IpgCardStyle,
                         item: &PyObject,
-                        value: &PyObject,) {
+                        value: &PyObject,) -> PyResult<()> {
 
-    let update = try_extract_card_style_update(item);
+    let update = try_extract_card_style_update(item)?;
     let name = "Card Style".to_string();
     
     match update {
         IpgCardStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background = get_color(None, Some(color), 1.0, false);
         },
         IpgCardStyleParam::BackgroundRgbaColor => {
-            style.background = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCardStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCardStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCardStyleParam::BorderRadius => {
-            style.border_radius = try_extract_f64(value, name) as f32;
+            style.border_radius = try_extract_f64(value, name)? as f32;
         },
         IpgCardStyleParam::BorderWidth => {
-            style.border_width = try_extract_f64(value, name) as f32;
+            style.border_width = try_extract_f64(value, name)? as f32;
         },
         IpgCardStyleParam::HeadBackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.head_background = get_color(None, Some(color), 1.0, false);
         },
         IpgCardStyleParam::HeadBackgroundRgbaColor => {
-             style.head_background = Some(Color::from(try_extract_rgba_color(value, name)));
+             style.head_background = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCardStyleParam::HeadTextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.head_text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCardStyleParam::HeadTextRgbaColor => {
-             style.head_text_color= Some(Color::from(try_extract_rgba_color(value, name)));
+             style.head_text_color= Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCardStyleParam::BodyBackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.body_background = get_color(None, Some(color), 1.0, false);
         },
         IpgCardStyleParam::BodyBackgroundRgbaColor => {
-             style.body_background = Some(Color::from(try_extract_rgba_color(value, name)));
+             style.body_background = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCardStyleParam::BodyTextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.body_text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCardStyleParam::BodyTextRgbaColor => {
-             style.body_text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+             style.body_text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCardStyleParam::FootBackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.foot_background = get_color(None, Some(color), 1.0, false);
         },
         IpgCardStyleParam::FootBackgroundRgbaColor => {
-             style.foot_background = Some(Color::from(try_extract_rgba_color(value, name)));
+             style.foot_background = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCardStyleParam::FootTextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.foot_text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCardStyleParam::FootTextRgbaColor => {
-             style.foot_text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+             style.foot_text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCardStyleParam::CloseIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.close_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCardStyleParam::CloseRgbaColor => {
-             style.close_color = Some(Color::from(try_extract_rgba_color(value, name)));
+             style.close_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
-fn try_extract_card_style_update(update_obj: &PyObject) -> IpgCardStyleParam {
+fn try_extract_card_style_update(update_obj: &PyObject) -> PyResult<IpgCardStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgCardStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Card style parameter update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Card style parameter update extraction failed")),
         }
     })
 }
@@ -0,0 +1,957 @@
+//! ipg_chart
+use std::cell::Cell;
+
+use iced::mouse;
+use iced::widget::canvas::event::{self, Event};
+use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
+use iced::{alignment, Color, Element, Length, Point, Rectangle, Renderer, Size, Theme};
+use polars::frame::DataFrame;
+use polars::prelude::DataType;
+use pyo3::{pyclass, PyObject, Python, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use crate::app;
+
+use super::helpers::{get_height, get_width, try_extract_boolean, try_extract_f64,
+    try_extract_string, try_extract_vec_str, 
+    item_dataframe, item_fill, item_length, item_object, IpgItems};
+use super::ipg_errors::IpgParamError;
+
+
+#[derive(Debug, Clone)]
+pub struct IpgChart {
+    pub id: usize,
+    pub parent_id: String,
+    pub show: bool,
+
+    pub df: DataFrame,
+    pub x_column: String,
+    pub y_columns: Vec<String>,
+    pub kind: IpgChartKind,
+    pub series_kinds: Option<Vec<IpgChartKind>>,
+    pub series_colors: Option<Vec<Color>>,
+    pub title: Option<String>,
+    pub x_label: Option<String>,
+    pub y_label: Option<String>,
+    pub show_legend: bool,
+    pub text_size: f32,
+    pub width: Length,
+    pub height: Length,
+    // built from the dataframe, rebuilt when the data or columns change
+    pub series: Vec<IpgChartSeries>,
+    pub x_categories: Option<Vec<String>>,
+    // bumped on every data change so the drawing cache is refreshed
+    pub version: u64,
+}
+
+impl IpgChart {
+    pub fn new(
+        id: usize,
+        parent_id: String,
+        show: bool,
+        df: DataFrame,
+        x_column: String,
+        y_columns: Vec<String>,
+        kind: IpgChartKind,
+        series_kinds: Option<Vec<IpgChartKind>>,
+        series_colors: Option<Vec<Color>>,
+        title: Option<String>,
+        x_label: Option<String>,
+        y_label: Option<String>,
+        show_legend: bool,
+        text_size: f32,
+        width: Length,
+        height: Length,
+    ) -> Result<Self, String> {
+        let mut chart = Self {
+            id,
+            parent_id,
+            show,
+            df,
+            x_column,
+            y_columns,
+            kind,
+            series_kinds,
+            series_colors,
+            title,
+            x_label,
+            y_label,
+            show_legend,
+            text_size,
+            width,
+            height,
+            series: vec![],
+            x_categories: None,
+            version: 0,
+        };
+        chart.build_series()?;
+        Ok(chart)
+    }
+
+    // Converts the dataframe columns into the points drawn.
+    // Null values are skipped.
+    pub fn build_series(&mut self) -> Result<(), String> {
+        let x_series = match self.df.column(&self.x_column) {
+            Ok(col) => col.as_materialized_series().clone(),
+            Err(_) => return Err(format!("Chart: x column {} not found in the dataframe", self.x_column)),
+        };
+
+        let (x_values, x_categories): (Vec<Option<f64>>, Option<Vec<String>>) =
+            if x_series.dtype() == &DataType::String {
+                let categories: Vec<String> = x_series.str()
+                    .map_err(|e| format!("Chart: unable to read the x column {} as strings, {}", self.x_column, e))?
+                    .into_iter()
+                    .map(|s| s.unwrap_or("").to_string())
+                    .collect();
+                ((0..categories.len()).map(|i| Some(i as f64)).collect(), Some(categories))
+            } else {
+                (get_column_values(&x_series, &self.x_column)?, None)
+            };
+
+        let mut series = vec![];
+        for (i, name) in self.y_columns.iter().enumerate() {
+            let y_series = match self.df.column(name) {
+                Ok(col) => col.as_materialized_series().clone(),
+                Err(_) => return Err(format!("Chart: y column {} not found in the dataframe", name)),
+            };
+            let y_values = get_column_values(&y_series, name)?;
+
+            let points: Vec<(f64, f64)> = x_values.iter()
+                .zip(y_values.iter())
+                .filter_map(|(x, y)| match (x, y) {
+                    (Some(x), Some(y)) => Some((*x, *y)),
+                    _ => None,
+                })
+                .collect();
+
+            let kind = match &self.series_kinds {
+                Some(kinds) if i < kinds.len() => kinds[i].clone(),
+                _ => self.kind.clone(),
+            };
+
+            let color = match &self.series_colors {
+                Some(colors) if i < colors.len() => colors[i],
+                _ => CHART_PALETTE[i % CHART_PALETTE.len()],
+            };
+
+            series.push(IpgChartSeries { name: name.clone(), kind, color, points });
+        }
+
+        self.series = series;
+        self.x_categories = x_categories;
+        self.version += 1;
+        Ok(())
+    }
+}
+
+// Numbers, booleans and dates can be drawn, a string cast to a float is null.
+pub fn is_plottable(dtype: &DataType) -> bool {
+    dtype.is_primitive_numeric() || dtype.is_bool() || dtype.is_temporal()
+}
+
+fn get_column_values(series: &polars::prelude::Series, name: &str) -> Result<Vec<Option<f64>>, String> {
+    if !is_plottable(series.dtype()) {
+        return Err(format!("Chart: column {} is not numeric, found {}", name, series.dtype()))
+    }
+    let cast = match series.cast(&DataType::Float64) {
+        Ok(s) => s,
+        Err(e) => return Err(format!("Chart: column {} could not be converted to a float, {}", name, e)),
+    };
+    match cast.f64() {
+        Ok(ca) => Ok(ca.into_iter().collect()),
+        Err(e) => Err(format!("Chart: column {} could not be converted to a float, {}", name, e)),
+    }
+}
+
+// The series are rebuilt after the change, the chart is put back
+// as it was when the new columns or data can't be drawn.
+fn update_series(chart: &mut IpgChart, change: impl FnOnce(&mut IpgChart)) -> PyResult<()> {
+    let saved = chart.clone();
+    change(chart);
+    if let Err(err) = chart.build_series() {
+        *chart = saved;
+        return Err(IpgParamError::new_err(err))
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpgChartSeries {
+    pub name: String,
+    pub kind: IpgChartKind,
+    pub color: Color,
+    pub points: Vec<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass(eq, eq_int)]
+pub enum IpgChartKind {
+    Line,
+    Scatter,
+    Area,
+    Bar,
+}
+
+const CHART_PALETTE: [Color; 8] = [
+    Color::from_rgb(0.122, 0.467, 0.706),
+    Color::from_rgb(1.0, 0.498, 0.055),
+    Color::from_rgb(0.173, 0.627, 0.173),
+    Color::from_rgb(0.839, 0.153, 0.157),
+    Color::from_rgb(0.580, 0.404, 0.741),
+    Color::from_rgb(0.549, 0.337, 0.294),
+    Color::from_rgb(0.890, 0.467, 0.761),
+    Color::from_rgb(0.498, 0.498, 0.498),
+];
+
+pub fn construct_chart(chart: &IpgChart) -> Option<Element<app::Message>> {
+
+    if !chart.show {
+        return None
+    }
+
+    Some(Canvas::new(chart)
+            .width(chart.width)
+            .height(chart.height)
+            .into())
+}
+
+// The zoom and pan are kept in the canvas state so they survive
+// a data refresh, a right click resets them.
+#[derive(Default)]
+pub struct ChartState {
+    cache: canvas::Cache,
+    version: Cell<u64>,
+    x_range: Option<(f64, f64)>,
+    drag_start: Option<(f32, (f64, f64))>,
+}
+
+impl canvas::Program<app::Message> for IpgChart {
+    type State = ChartState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<app::Message>) {
+
+        let plot = get_plot_area(self, bounds.size());
+
+        let Event::Mouse(mouse_event) = event else {
+            return (event::Status::Ignored, None)
+        };
+
+        match mouse_event {
+            mouse::Event::WheelScrolled { delta } => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None)
+                };
+                if !plot.contains(position) {
+                    return (event::Status::Ignored, None)
+                }
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 20.0,
+                };
+                let full = get_full_x_range(self);
+                let (x0, x1) = state.x_range.unwrap_or(full);
+                let factor = if y > 0.0 { 0.8 } else { 1.25 };
+                let center = x0 + (position.x - plot.x) as f64 / plot.width as f64 * (x1 - x0);
+                let new_range = (center - (center - x0) * factor, center + (x1 - center) * factor);
+                // zoomed all the way out goes back to auto
+                state.x_range = if new_range.1 - new_range.0 >= full.1 - full.0 {
+                    None
+                } else {
+                    Some(new_range)
+                };
+                state.cache.clear();
+                (event::Status::Captured, None)
+            },
+            mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                match cursor.position_in(bounds) {
+                    Some(position) if plot.contains(position) => {
+                        let range = state.x_range.unwrap_or(get_full_x_range(self));
+                        state.drag_start = Some((position.x, range));
+                        (event::Status::Captured, None)
+                    },
+                    _ => (event::Status::Ignored, None),
+                }
+            },
+            mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                if state.drag_start.take().is_some() {
+                    (event::Status::Captured, None)
+                } else {
+                    (event::Status::Ignored, None)
+                }
+            },
+            mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                if cursor.is_over(bounds) && state.x_range.is_some() {
+                    state.x_range = None;
+                    state.cache.clear();
+                    (event::Status::Captured, None)
+                } else {
+                    (event::Status::Ignored, None)
+                }
+            },
+            mouse::Event::CursorMoved { .. } => {
+                if let (Some((start_x, (x0, x1))), Some(position)) =
+                    (state.drag_start, cursor.position_in(bounds)) {
+                    let shift = (start_x - position.x) as f64 / plot.width as f64 * (x1 - x0);
+                    state.x_range = Some((x0 + shift, x1 + shift));
+                    state.cache.clear();
+                    return (event::Status::Captured, None)
+                }
+                (event::Status::Ignored, None)
+            },
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+
+        if state.version.get() != self.version {
+            state.version.set(self.version);
+            state.cache.clear();
+        }
+
+        let view = get_chart_view(self, state.x_range, bounds.size());
+        let text_color = theme.palette().text;
+
+        let content = state.cache.draw(renderer, bounds.size(), |frame| {
+            draw_axes(self, &view, frame, text_color);
+            draw_series(self, &view, frame);
+            if self.show_legend && !self.series.is_empty() {
+                draw_legend(self, &view, frame, text_color);
+            }
+        });
+
+        let mut geometries = vec![content];
+
+        if let Some(position) = cursor.position_in(bounds) {
+            if view.plot.contains(position) && state.drag_start.is_none() {
+                let mut frame = Frame::new(renderer, bounds.size());
+                draw_tooltip(self, &view, position, &mut frame, theme);
+                geometries.push(frame.into_geometry());
+            }
+        }
+
+        geometries
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if state.drag_start.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+// The data ranges and the plot area used to convert to pixels
+struct ChartView {
+    plot: Rectangle,
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+}
+
+impl ChartView {
+    fn to_pixel(&self, x: f64, y: f64) -> Point {
+        let (x0, x1) = self.x_range;
+        let (y0, y1) = self.y_range;
+        Point::new(
+            self.plot.x + ((x - x0) / (x1 - x0)) as f32 * self.plot.width,
+            self.plot.y + self.plot.height - ((y - y0) / (y1 - y0)) as f32 * self.plot.height,
+        )
+    }
+}
+
+fn get_plot_area(chart: &IpgChart, size: Size) -> Rectangle {
+    let mut top = if chart.title.is_some() { chart.text_size * 2.0 } else { chart.text_size };
+    // the y label is drawn on a line of its own above the y axis
+    if chart.y_label.is_some() {
+        top += chart.text_size * 1.4;
+    }
+    let bottom = if chart.x_label.is_some() { chart.text_size * 3.2 } else { chart.text_size * 2.0 };
+    let left = chart.text_size * 3.8;
+    let right = chart.text_size;
+
+    Rectangle {
+        x: left,
+        y: top,
+        width: (size.width - left - right).max(1.0),
+        height: (size.height - top - bottom).max(1.0),
+    }
+}
+
+fn has_bars(chart: &IpgChart) -> bool {
+    chart.series.iter().any(|s| s.kind == IpgChartKind::Bar)
+}
+
+fn get_full_x_range(chart: &IpgChart) -> (f64, f64) {
+    let mut x0 = f64::INFINITY;
+    let mut x1 = f64::NEG_INFINITY;
+    for series in chart.series.iter() {
+        for (x, _) in series.points.iter() {
+            x0 = x0.min(*x);
+            x1 = x1.max(*x);
+        }
+    }
+    if !x0.is_finite() {
+        return (0.0, 1.0)
+    }
+    // bars and categories need a half step on each side
+    if has_bars(chart) || chart.x_categories.is_some() {
+        let step = get_min_x_spacing(chart).unwrap_or(1.0);
+        x0 -= step / 2.0;
+        x1 += step / 2.0;
+    }
+    if x1 <= x0 {
+        (x0 - 0.5, x1 + 0.5)
+    } else {
+        (x0, x1)
+    }
+}
+
+fn get_min_x_spacing(chart: &IpgChart) -> Option<f64> {
+    let mut xs: Vec<f64> = chart.series.iter()
+        .flat_map(|s| s.points.iter().map(|(x, _)| *x))
+        .collect();
+    xs.sort_by(|a, b| a.total_cmp(b));
+    xs.dedup();
+    xs.windows(2)
+        .map(|w| w[1] - w[0])
+        .filter(|d| *d > 0.0)
+        .min_by(|a, b| a.total_cmp(b))
+}
+
+fn get_chart_view(chart: &IpgChart, x_range: Option<(f64, f64)>, size: Size) -> ChartView {
+    let x_range = x_range.unwrap_or(get_full_x_range(chart));
+
+    // y only covers the visible points
+    let mut y0 = f64::INFINITY;
+    let mut y1 = f64::NEG_INFINITY;
+    for series in chart.series.iter() {
+        for (x, y) in series.points.iter() {
+            if *x >= x_range.0 && *x <= x_range.1 {
+                y0 = y0.min(*y);
+                y1 = y1.max(*y);
+            }
+        }
+    }
+    if !y0.is_finite() {
+        y0 = 0.0;
+        y1 = 1.0;
+    }
+    if chart.series.iter().any(|s| s.kind == IpgChartKind::Bar || s.kind == IpgChartKind::Area) {
+        y0 = y0.min(0.0);
+        y1 = y1.max(0.0);
+    }
+    let pad = if y1 > y0 { (y1 - y0) * 0.05 } else { 0.5 };
+
+    ChartView {
+        plot: get_plot_area(chart, size),
+        x_range,
+        y_range: (y0 - pad, y1 + pad),
+    }
+}
+
+// Tick values on multiples of 1, 2 or 5 times a power of ten
+pub fn get_nice_ticks(min: f64, max: f64, max_ticks: usize) -> (Vec<f64>, f64) {
+    if max <= min || max_ticks == 0 {
+        return (vec![min], 1.0)
+    }
+    let raw_step = (max - min) / max_ticks as f64;
+    let magnitude = 10_f64.powf(raw_step.log10().floor());
+    let normalized = raw_step / magnitude;
+    let step = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    } * magnitude;
+
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+    let ticks = (first..=last).map(|i| i as f64 * step).collect();
+
+    (ticks, step)
+}
+
+pub fn format_tick(value: f64, step: f64) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    // avoids showing -0
+    let value = if value.abs() < step * 1e-9 { 0.0 } else { value };
+    format!("{:.prec$}", value, prec = decimals)
+}
+
+fn draw_axes(chart: &IpgChart, view: &ChartView, frame: &mut Frame, text_color: Color) {
+    let plot = view.plot;
+    let grid_color = Color { a: 0.15, ..text_color };
+    let axis_color = Color { a: 0.6, ..text_color };
+    let tick_size = chart.text_size * 0.85;
+
+    // y ticks and grid
+    let max_y_ticks = (plot.height / (tick_size * 3.0)).max(2.0) as usize;
+    let (y_ticks, y_step) = get_nice_ticks(view.y_range.0, view.y_range.1, max_y_ticks);
+    for tick in y_ticks.iter() {
+        let y = view.to_pixel(view.x_range.0, *tick).y;
+        frame.stroke(
+            &Path::line(Point::new(plot.x, y), Point::new(plot.x + plot.width, y)),
+            Stroke::default().with_width(1.0).with_color(grid_color),
+        );
+        frame.fill_text(canvas::Text {
+            content: format_tick(*tick, y_step),
+            position: Point::new(plot.x - 6.0, y),
+            color: text_color,
+            size: tick_size.into(),
+            horizontal_alignment: alignment::Horizontal::Right,
+            vertical_alignment: alignment::Vertical::Center,
+            ..canvas::Text::default()
+        });
+    }
+
+    // x ticks, either the categories or the numeric values
+    let max_x_ticks = (plot.width / (tick_size * 6.0)).max(2.0) as usize;
+    let x_bottom = plot.y + plot.height;
+    let x_ticks: Vec<(f64, String)> = match &chart.x_categories {
+        Some(categories) => {
+            let visible: Vec<usize> = (0..categories.len())
+                .filter(|i| *i as f64 >= view.x_range.0 && *i as f64 <= view.x_range.1)
+                .collect();
+            let skip = (visible.len() / max_x_ticks).max(1);
+            visible.iter().step_by(skip).map(|i| (*i as f64, categories[*i].clone())).collect()
+        },
+        None => {
+            let (ticks, step) = get_nice_ticks(view.x_range.0, view.x_range.1, max_x_ticks);
+            ticks.iter().map(|t| (*t, format_tick(*t, step))).collect()
+        },
+    };
+    for (tick, label) in x_ticks.iter() {
+        let x = view.to_pixel(*tick, view.y_range.0).x;
+        frame.stroke(
+            &Path::line(Point::new(x, x_bottom), Point::new(x, x_bottom + 4.0)),
+            Stroke::default().with_width(1.0).with_color(axis_color),
+        );
+        frame.fill_text(canvas::Text {
+            content: label.clone(),
+            position: Point::new(x, x_bottom + 6.0),
+            color: text_color,
+            size: tick_size.into(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Top,
+            ..canvas::Text::default()
+        });
+    }
+
+    // the axis lines
+    frame.stroke(
+        &Path::new(|p| {
+            p.move_to(Point::new(plot.x, plot.y));
+            p.line_to(Point::new(plot.x, x_bottom));
+            p.line_to(Point::new(plot.x + plot.width, x_bottom));
+        }),
+        Stroke::default().with_width(1.0).with_color(axis_color),
+    );
+
+    if let Some(title) = &chart.title {
+        frame.fill_text(canvas::Text {
+            content: title.clone(),
+            position: Point::new(plot.x + plot.width / 2.0, chart.text_size),
+            color: text_color,
+            size: (chart.text_size * 1.2).into(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            ..canvas::Text::default()
+        });
+    }
+
+    if let Some(x_label) = &chart.x_label {
+        frame.fill_text(canvas::Text {
+            content: x_label.clone(),
+            position: Point::new(plot.x + plot.width / 2.0, x_bottom + chart.text_size * 2.0),
+            color: text_color,
+            size: chart.text_size.into(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Top,
+            ..canvas::Text::default()
+        });
+    }
+
+    // the canvas text ignores the frame rotation, so the y label
+    // is written horizontally over the y tick labels
+    if let Some(y_label) = &chart.y_label {
+        frame.fill_text(canvas::Text {
+            content: y_label.clone(),
+            position: Point::new(chart.text_size * 0.5, plot.y - tick_size * 0.8),
+            color: text_color,
+            size: chart.text_size.into(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Bottom,
+            ..canvas::Text::default()
+        });
+    }
+}
+
+fn draw_series(chart: &IpgChart, view: &ChartView, frame: &mut Frame) {
+    let plot = view.plot;
+    let bar_series: Vec<usize> = chart.series.iter()
+        .enumerate()
+        .filter(|(_, s)| s.kind == IpgChartKind::Bar)
+        .map(|(i, _)| i)
+        .collect();
+
+    let spacing = get_min_x_spacing(chart).unwrap_or(1.0);
+    let group_width = (view.to_pixel(view.x_range.0 + spacing, 0.0).x - plot.x) * 0.8;
+    let bar_width = group_width / bar_series.len().max(1) as f32;
+    let baseline = view.to_pixel(view.x_range.0, 0.0_f64.clamp(view.y_range.0, view.y_range.1)).y;
+
+    frame.with_clip(plot, |frame| {
+        // the clipped frame starts at the plot origin
+        frame.translate(iced::Vector::new(-plot.x, -plot.y));
+        for series in chart.series.iter() {
+            let points: Vec<Point> = series.points.iter()
+                .map(|(x, y)| view.to_pixel(*x, *y))
+                .collect();
+
+            match series.kind {
+                IpgChartKind::Line => {
+                    if points.len() > 1 {
+                        frame.stroke(
+                            &build_polyline(&points),
+                            Stroke::default().with_width(2.0).with_color(series.color),
+                        );
+                    }
+                },
+                IpgChartKind::Scatter => {
+                    let path = Path::new(|p| {
+                        for point in points.iter() {
+                            p.circle(*point, 3.0);
+                        }
+                    });
+                    frame.fill(&path, series.color);
+                },
+                IpgChartKind::Area => {
+                    if points.len() > 1 {
+                        let area = Path::new(|p| {
+                            p.move_to(Point::new(points[0].x, baseline));
+                            for point in points.iter() {
+                                p.line_to(*point);
+                            }
+                            p.line_to(Point::new(points[points.len()-1].x, baseline));
+                            p.close();
+                        });
+                        frame.fill(&area, Color { a: 0.3, ..series.color });
+                        frame.stroke(
+                            &build_polyline(&points),
+                            Stroke::default().with_width(2.0).with_color(series.color),
+                        );
+                    }
+                },
+                IpgChartKind::Bar => {
+                    let index = bar_series.iter()
+                        .position(|i| chart.series[*i].name == series.name)
+                        .unwrap_or(0);
+                    let offset = -group_width / 2.0 + bar_width * index as f32;
+                    let path = Path::new(|p| {
+                        for point in points.iter() {
+                            let top = point.y.min(baseline);
+                            let height = (point.y - baseline).abs();
+                            p.rectangle(Point::new(point.x + offset, top), Size::new(bar_width, height));
+                        }
+                    });
+                    frame.fill(&path, series.color);
+                },
+            }
+        }
+    });
+}
+
+fn build_polyline(points: &[Point]) -> Path {
+    Path::new(|p| {
+        p.move_to(points[0]);
+        for point in points.iter().skip(1) {
+            p.line_to(*point);
+        }
+    })
+}
+
+fn draw_legend(chart: &IpgChart, view: &ChartView, frame: &mut Frame, text_color: Color) {
+    let line_height = chart.text_size * 1.4;
+    let longest = chart.series.iter().map(|s| s.name.chars().count()).max().unwrap_or(0);
+    let width = longest as f32 * chart.text_size * 0.6 + chart.text_size * 2.5;
+    let height = line_height * chart.series.len() as f32 + 6.0;
+    let top_left = Point::new(view.plot.x + view.plot.width - width - 8.0, view.plot.y + 8.0);
+
+    let background = Path::rectangle(top_left, Size::new(width, height));
+    frame.fill(&background, Color { a: 0.08, ..text_color });
+
+    for (i, series) in chart.series.iter().enumerate() {
+        let y = top_left.y + 3.0 + line_height * (i as f32 + 0.5);
+        let swatch = Path::rectangle(
+            Point::new(top_left.x + 6.0, y - chart.text_size * 0.35),
+            Size::new(chart.text_size * 0.7, chart.text_size * 0.7));
+        frame.fill(&swatch, series.color);
+        frame.fill_text(canvas::Text {
+            content: series.name.clone(),
+            position: Point::new(top_left.x + chart.text_size * 1.5, y),
+            color: text_color,
+            size: chart.text_size.into(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            ..canvas::Text::default()
+        });
+    }
+}
+
+fn draw_tooltip(chart: &IpgChart, view: &ChartView, cursor: Point, frame: &mut Frame, theme: &Theme) {
+    // the closest point to the cursor over all series
+    let mut closest: Option<(f32, &IpgChartSeries, (f64, f64))> = None;
+    for series in chart.series.iter() {
+        for (x, y) in series.points.iter() {
+            let distance = view.to_pixel(*x, *y).distance(cursor);
+            if closest.map_or(true, |(d, _, _)| distance < d) {
+                closest = Some((distance, series, (*x, *y)));
+            }
+        }
+    }
+
+    let Some((distance, series, (x, y))) = closest else {
+        return
+    };
+    if distance > 30.0 {
+        return
+    }
+
+    let point = view.to_pixel(x, y);
+    let palette = theme.extended_palette();
+
+    frame.stroke(
+        &Path::line(Point::new(point.x, view.plot.y), Point::new(point.x, view.plot.y + view.plot.height)),
+        Stroke::default().with_width(1.0).with_color(Color { a: 0.4, ..palette.background.base.text }),
+    );
+    frame.fill(&Path::circle(point, 4.0), series.color);
+
+    let x_text = match &chart.x_categories {
+        Some(categories) => categories.get(x as usize).cloned().unwrap_or_default(),
+        None => format!("{}", x),
+    };
+    let content = format!("{}\n{}: {}", x_text, series.name, y);
+    let lines = 2.0;
+    let longest = content.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+    let size = Size::new(
+        longest as f32 * chart.text_size * 0.6 + 12.0,
+        chart.text_size * 1.3 * lines + 8.0);
+
+    // keep the tooltip inside the plot
+    let mut position = Point::new(point.x + 10.0, point.y - size.height - 10.0);
+    if position.x + size.width > view.plot.x + view.plot.width {
+        position.x = point.x - size.width - 10.0;
+    }
+    if position.y < view.plot.y {
+        position.y = point.y + 10.0;
+    }
+
+    frame.fill(&Path::rectangle(position, size), palette.background.strong.color);
+    frame.fill_text(canvas::Text {
+        content,
+        position: Point::new(position.x + 6.0, position.y + 4.0),
+        color: palette.background.strong.text,
+        size: chart.text_size.into(),
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        ..canvas::Text::default()
+    });
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass(eq, eq_int)]
+pub enum IpgChartParam {
+    Height,
+    HeightFill,
+    Kind,
+    PolarsDf,
+    Show,
+    ShowLegend,
+    Title,
+    Width,
+    WidthFill,
+    XColumn,
+    XLabel,
+    YColumns,
+    YLabel,
+}
+
+pub fn chart_item_update(chart: &mut IpgChart,
+                        item: &PyObject,
+                        value: &PyObject,
+                        ) -> PyResult<()>
+{
+    let update = try_extract_chart_update(item)?;
+    let name = "Chart".to_string();
+    match update {
+        IpgChartParam::Height => {
+            let val = try_extract_f64(value, name)?;
+            chart.height = get_height(Some(val as f32), false);
+        },
+        IpgChartParam::HeightFill => {
+            let val = try_extract_boolean(value, name)?;
+            chart.height = get_height(None, val);
+        },
+        IpgChartParam::Kind => {
+            let kind = try_extract_chart_kind(value)?;
+            update_series(chart, |chart| {
+                chart.kind = kind;
+                chart.series_kinds = None;
+            })?;
+        },
+        IpgChartParam::PolarsDf => {
+            return Err(IpgParamError::new_err("Chart: use update_dataframe to change the dataframe"))
+        },
+        IpgChartParam::Show => {
+            chart.show = try_extract_boolean(value, name)?;
+        },
+        IpgChartParam::ShowLegend => {
+            chart.show_legend = try_extract_boolean(value, name)?;
+            chart.version += 1;
+        },
+        IpgChartParam::Title => {
+            chart.title = Some(try_extract_string(value, name)?);
+            chart.version += 1;
+        },
+        IpgChartParam::Width => {
+            let val = try_extract_f64(value, name)?;
+            chart.width = get_width(Some(val as f32), false);
+        },
+        IpgChartParam::WidthFill => {
+            let val = try_extract_boolean(value, name)?;
+            chart.width = get_width(None, val);
+        },
+        IpgChartParam::XColumn => {
+            let x_column = try_extract_string(value, name)?;
+            update_series(chart, |chart| chart.x_column = x_column)?;
+        },
+        IpgChartParam::XLabel => {
+            chart.x_label = Some(try_extract_string(value, name)?);
+            chart.version += 1;
+        },
+        IpgChartParam::YColumns => {
+            let y_columns = try_extract_vec_str(value, name)?;
+            update_series(chart, |chart| chart.y_columns = y_columns)?;
+        },
+        IpgChartParam::YLabel => {
+            chart.y_label = Some(try_extract_string(value, name)?);
+            chart.version += 1;
+        },
+    }
+    Ok(())
+}
+
+pub fn chart_get_items(chart: &IpgChart) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Height", item_length(chart.height)),
+        ("HeightFill", item_fill(chart.height)),
+        ("Kind", item_object(chart.kind.clone())?),
+        ("PolarsDf", item_dataframe(&chart.df)?),
+        ("Show", Some(chart.show.into())),
+        ("ShowLegend", Some(chart.show_legend.into())),
+        ("Title", chart.title.clone().map(Into::into)),
+        ("Width", item_length(chart.width)),
+        ("WidthFill", item_fill(chart.width)),
+        ("XColumn", Some(chart.x_column.clone().into())),
+        ("XLabel", chart.x_label.clone().map(Into::into)),
+        ("YColumns", Some(chart.y_columns.clone().into())),
+        ("YLabel", chart.y_label.clone().map(Into::into)),
+    ])
+}
+
+pub fn chart_dataframe_update(chart: &mut IpgChart,
+                            item: &PyObject,
+                            value: &PyDataFrame,
+                            ) -> PyResult<()>
+{
+    let update = try_extract_chart_update(item)?;
+    if update == IpgChartParam::PolarsDf {
+        let df = Into::<DataFrame>::into(value.clone());
+        update_series(chart, |chart| chart.df = df)?;
+    }
+    Ok(())
+}
+
+pub fn try_extract_chart_update(update_obj: &PyObject) -> PyResult<IpgChartParam> {
+
+    Python::with_gil(|py| {
+        let res = update_obj.extract::<IpgChartParam>(py);
+        match res {
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Chart update extraction failed")),
+        }
+    })
+}
+
+fn try_extract_chart_kind(value: &PyObject) -> PyResult<IpgChartKind> {
+
+    Python::with_gil(|py| {
+        let res = value.extract::<IpgChartKind>(py);
+        match res {
+            Ok(kind) => Ok(kind),
+            Err(_) => Err(IpgParamError::new_err("Chart kind extraction failed")),
+        }
+    })
+}
+
+
+#[test]
+fn test_get_nice_ticks() {
+    let (ticks, step) = get_nice_ticks(0.0, 100.0, 5);
+    assert_eq!(20.0, step);
+    assert_eq!(vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0], ticks);
+
+    let (ticks, step) = get_nice_ticks(-0.33, 0.92, 8);
+    assert_eq!(2.0, (step * 10.0).round());
+    assert_eq!(vec![-0.2, 0.0, 0.2, 0.4, 0.6, 0.8],
+        ticks.iter().map(|t| (t * 10.0).round() / 10.0).collect::<Vec<f64>>());
+    assert_eq!("-0.2", format_tick(ticks[0], step));
+    assert_eq!("0.0", format_tick(ticks[1], step));
+}
+
+#[test]
+fn test_chart_columns() {
+    let df = polars::df!(
+        "x" => [1.0, 2.0, 3.0],
+        "y" => [4.0, 5.0, 6.0],
+        "name" => ["a", "b", "c"]).unwrap();
+    let new_chart = |x: &str, y: &str| IpgChart::new(0, "col".to_string(), true, df.clone(),
+        x.to_string(), vec![y.to_string()], IpgChartKind::Line, None, None, None, None, None,
+        true, 12.0, Length::Fill, Length::Fill);
+
+    let mut chart = new_chart("x", "y").unwrap();
+    assert_eq!(vec![(1.0, 4.0), (2.0, 5.0), (3.0, 6.0)], chart.series[0].points);
+    // a string x column is drawn as categories
+    assert!(new_chart("name", "y").unwrap().x_categories.is_some());
+    assert!(new_chart("x", "missing").is_err());
+    assert!(new_chart("x", "name").is_err());
+
+    // a failed update leaves the chart as it was
+    assert!(update_series(&mut chart, |chart| chart.y_columns = vec!["missing".to_string()]).is_err());
+    assert_eq!(vec!["y".to_string()], chart.y_columns);
+    assert_eq!(3, chart.series[0].points.len());
+}
@@ -1,13 +1,16 @@
 //! ipg_checkbox
 use crate::graphics::colors::get_color;
 use crate::style::styling::IpgStyleStandard;
-use crate::{access_callbacks, access_user_data1, access_user_data2, IpgState};
+use crate::IpgState;
+use super::callbacks::dispatch_callback;
 use crate::app;
 use super::helpers::{get_radius, get_shaping, get_width, try_extract_boolean, 
     try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, 
-    try_extract_string, try_extract_style_standard, try_extract_vec_f32};
+    try_extract_string, try_extract_style_standard, try_extract_vec_f32, 
+    item_fill, item_length, item_line_height, item_object_opt, item_shaping, IpgItems};
 use super::callbacks::{set_or_get_widget_callback_data, WidgetCallbackIn};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use crate::graphics::BOOTSTRAP_FONT;
 use crate::graphics::bootstrap_icon::{Icon, icon_to_char};
@@ -18,7 +21,7 @@ use iced::widget::text::{LineHeight, Shaping};
 use iced::widget::Checkbox;
 use iced::widget::checkbox::{self, Status};
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -26,6 +29,7 @@ pub struct IpgCheckBox {
     pub id: usize,
     pub parent_id: String,
     pub show: bool,
+    pub enabled: bool,
     pub is_checked: bool,
     pub label: String,
     pub width: Length,
@@ -46,6 +50,7 @@ impl IpgCheckBox {
         id: usize,
         parent_id: String,
         show: bool,
+        enabled: bool,
         is_checked: bool,
         label: String,
         width: Length,
@@ -63,6 +68,7 @@ impl IpgCheckBox {
                 id,
                 parent_id,
                 show,
+                enabled,
                 is_checked,
                 label,
                 width,
@@ -92,6 +98,9 @@ pub struct IpgCheckboxStyle {
     pub border_width: f32,
     pub icon_color: Option<Color>,
     pub text_color: Option<Color>,
+    pub background_color_disabled: Option<Color>,
+    pub icon_color_disabled: Option<Color>,
+    pub text_color_disabled: Option<Color>,
 }
 
 impl IpgCheckboxStyle {
@@ -106,6 +115,9 @@ impl IpgCheckboxStyle {
         border_width: f32,
         icon_color: Option<Color>,
         text: Option<Color>,
+        background_color_disabled: Option<Color>,
+        icon_color_disabled: Option<Color>,
+        text_color_disabled: Option<Color>,
     ) -> Self {
         Self {
             id,
@@ -118,6 +130,9 @@ impl IpgCheckboxStyle {
             border_width,
             icon_color,
             text_color: text,
+            background_color_disabled,
+            icon_color_disabled,
+            text_color_disabled,
         }
     }
 }
@@ -139,7 +154,7 @@ pub fn construct_checkbox<'a>(chk: &'a IpgCheckBox,
 
     let ipg_chk: Element<'_, CHKMessage> = Checkbox::new(chk.label.clone(), 
                             chk.is_checked)
-                            .on_toggle(CHKMessage::OnToggle)
+                            .on_toggle_maybe(chk.enabled.then_some(CHKMessage::OnToggle))
                             .size(chk.size)
                             .spacing(chk.spacing)
                             .text_line_height(chk.text_line_height)
@@ -194,48 +209,7 @@ pub fn process_callback(
         is_checked: bool, 
         event_name: String) 
 {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, is_checked, user_data)) {
-                panic!("Checkbox callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, is_checked, user_data)) {
-                panic!("Checkbox callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with only the id and is_checked
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id, is_checked)) {
-            panic!("Checkbox callback error: {err}");
-        }
-    });
+    dispatch_callback("Checkbox", id, &event_name, (id, is_checked));
 }
 
 
@@ -246,6 +220,7 @@ pub enum IpgCheckboxParam {
     IconX,
     IsChecked,
     Label,
+    Enabled,
     Show,
     Size,
     Spacing,
@@ -261,59 +236,83 @@ pub enum IpgCheckboxParam {
 pub fn checkbox_item_update(chk: &mut IpgCheckBox,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_checkbox_update(item);
+    let update = try_extract_checkbox_update(item)?;
     let name = "Checkbox".to_string();
     match update {
         IpgCheckboxParam::IconSize => {
-            chk.icon_size = try_extract_f64(value, name) as f32;
+            chk.icon_size = try_extract_f64(value, name)? as f32;
         },
         IpgCheckboxParam::IconX => {
-            chk.icon_x = try_extract_boolean(value, name);
+            chk.icon_x = try_extract_boolean(value, name)?;
         },
         IpgCheckboxParam::IsChecked => {
-            chk.is_checked = try_extract_boolean(value, name);
+            chk.is_checked = try_extract_boolean(value, name)?;
         },
         IpgCheckboxParam::Label => {
-            chk.label = try_extract_string(value, name);
+            chk.label = try_extract_string(value, name)?;
+        },
+        IpgCheckboxParam::Enabled => {
+            chk.enabled = try_extract_boolean(value, name)?;
         },
         IpgCheckboxParam::Show => {
-            chk.show = try_extract_boolean(value, name);
+            chk.show = try_extract_boolean(value, name)?;
         },
         IpgCheckboxParam::Size => {
-            chk.size = try_extract_f64(value, name) as f32;
+            chk.size = try_extract_f64(value, name)? as f32;
         },
         IpgCheckboxParam::Spacing => {
-            chk.spacing = try_extract_f64(value, name) as f32;
+            chk.spacing = try_extract_f64(value, name)? as f32;
         },
         IpgCheckboxParam::TextLineHeight => {
-            let tlh = try_extract_f64(value, name);
+            let tlh = try_extract_f64(value, name)?;
             chk.text_line_height =  LineHeight::Relative(tlh as f32);
         },
         IpgCheckboxParam::TextShaping => {
-            let ts =try_extract_string(value, name);
-            chk.text_shaping = get_shaping(ts); 
+            let ts =try_extract_string(value, name)?;
+            chk.text_shaping = get_shaping(ts)?; 
         },
         IpgCheckboxParam::TextSize => {
-            chk.text_size = try_extract_f64(value, name) as f32;
+            chk.text_size = try_extract_f64(value, name)? as f32;
         },
         IpgCheckboxParam::Style => {
-            chk.style_id = Some(try_extract_f64(value, name) as usize)
+            chk.style_id = Some(try_extract_f64(value, name)? as usize)
         },
         IpgCheckboxParam::StyleStandard => {
-            let val = try_extract_style_standard(value, name);
+            let val = try_extract_style_standard(value, name)?;
             chk.style_standard = Some(val)
         },
         IpgCheckboxParam::Width => {
-            let wd = try_extract_f64(value, name);
+            let wd = try_extract_f64(value, name)?;
             chk.width =  get_width(Some(wd as f32), false);
         },
         IpgCheckboxParam::WidthFill => {
-            let wd = try_extract_boolean(value, name);
+            let wd = try_extract_boolean(value, name)?;
             chk.width =  get_width(None, wd);
         },
     }
+    Ok(())
+}
+
+pub fn checkbox_get_items(chk: &IpgCheckBox) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("IconSize", Some(chk.icon_size.into())),
+        ("IconX", Some(chk.icon_x.into())),
+        ("IsChecked", Some(chk.is_checked.into())),
+        ("Label", Some(chk.label.clone().into())),
+        ("Enabled", Some(chk.enabled.into())),
+        ("Show", Some(chk.show.into())),
+        ("Size", Some(chk.size.into())),
+        ("Spacing", Some(chk.spacing.into())),
+        ("Style", chk.style_id.map(Into::into)),
+        ("StyleStandard", item_object_opt(chk.style_standard.clone())?),
+        ("TextLineHeight", item_line_height(chk.text_line_height)),
+        ("TextShaping", item_shaping(chk.text_shaping)),
+        ("TextSize", Some(chk.text_size.into())),
+        ("Width", item_length(chk.width)),
+        ("WidthFill", item_fill(chk.width)),
+    ])
 }
 
 
@@ -336,81 +335,109 @@ pub enum IpgCheckboxStyleParam {
     IconRgbaColor,
     TextIpgColor,
     TextRgbaColor,
+    BackgroundIpgColorDisabled,
+    BackgroundRgbaColorDisabled,
+    IconIpgColorDisabled,
+    IconRgbaColorDisabled,
+    TextIpgColorDisabled,
+    TextRgbaColorDisabled,
 }
 
 pub fn checkbox_style_update_item(style: &mut IpgCheckboxStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_checkbox_style_update(item);
+    let update = try_extract_checkbox_style_update(item)?;
     let name = "CheckboxStyle".to_string();
     match update {
         IpgCheckboxStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCheckboxStyleParam::BackgroundRgbaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCheckboxStyleParam::BackgroundIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgCheckboxStyleParam::BackgroundRgbaColorHovered => {
-            style.background_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCheckboxStyleParam::AccentIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.accent_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCheckboxStyleParam::AccentRgbaColor => {
-            style.accent_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.accent_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCheckboxStyleParam::AccentIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.accent_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgCheckboxStyleParam::AccentRgbaColorHovered => {
-            style.accent_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.accent_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCheckboxStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCheckboxStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCheckboxStyleParam::BorderRadius => {
-            style.border_radius = try_extract_vec_f32(value, name);
+            style.border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgCheckboxStyleParam::BorderWidth => {
-            style.border_width = try_extract_f64(value, name) as f32;
+            style.border_width = try_extract_f64(value, name)? as f32;
         },
         IpgCheckboxStyleParam::IconIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.icon_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCheckboxStyleParam::IconRgbaColor => {
-            style.icon_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.icon_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCheckboxStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCheckboxStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
+        },
+        IpgCheckboxStyleParam::BackgroundIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.background_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgCheckboxStyleParam::BackgroundRgbaColorDisabled => {
+            style.background_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
+        },
+        IpgCheckboxStyleParam::IconIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.icon_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgCheckboxStyleParam::IconRgbaColorDisabled => {
+            style.icon_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
+        },
+        IpgCheckboxStyleParam::TextIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.text_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgCheckboxStyleParam::TextRgbaColorDisabled => {
+            style.text_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_checkbox_update(update_obj: &PyObject) -> IpgCheckboxParam {
+pub fn try_extract_checkbox_update(update_obj: &PyObject) -> PyResult<IpgCheckboxParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgCheckboxParam>(py);
 
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Checkbox update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Checkbox update extraction failed")),
         }
     })
 }
@@ -505,19 +532,36 @@ pub fn get_styling(theme: &Theme, status: Status,
             hovered_style
         },
         Status::Disabled { is_checked } => {
-            checkbox::danger(theme, Status::Disabled { is_checked })
+            let active_style = get_styling(theme, Status::Active { is_checked }, 
+                                            Some(style.clone()), None, is_checked);
+            disabled(active_style, &style)
         },
     }
     
 }
 
-pub fn try_extract_checkbox_style_update(update_obj: &PyObject) -> IpgCheckboxStyleParam {
+// The disabled colors of the style, the active ones are faded when not set.
+fn disabled(style: checkbox::Style, ipg_style: &IpgCheckboxStyle) -> checkbox::Style {
+    checkbox::Style {
+        background: match ipg_style.background_color_disabled {
+            Some(color) => iced::Background::Color(color),
+            None => style.background.scale_alpha(0.5),
+        },
+        icon_color: ipg_style.icon_color_disabled
+                        .unwrap_or_else(|| style.icon_color.scale_alpha(0.5)),
+        text_color: ipg_style.text_color_disabled
+                        .or_else(|| style.text_color.map(|color| color.scale_alpha(0.5))),
+        ..style
+    }
+}
+
+pub fn try_extract_checkbox_style_update(update_obj: &PyObject) -> PyResult<IpgCheckboxStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgCheckboxStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Checkbox style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Checkbox style update extraction failed")),
         }
     })
 }
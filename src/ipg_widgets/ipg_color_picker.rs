@@ -1,18 +1,21 @@
 //! ipg_color_picker
 use crate::graphics::colors::get_color;
-use crate::{access_callbacks, access_user_data1, access_user_data2, IpgState};
+use crate::IpgState;
+use super::callbacks::dispatch_callback;
 use crate::app::Message;
 use crate::style::styling::IpgStyleStandard;
-use super::helpers::{get_height, get_padding_f64, get_radius, get_width, try_extract_boolean, try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, try_extract_string, try_extract_style_standard, try_extract_vec_f32, try_extract_vec_f64};
+use super::helpers::{get_height, get_padding_f64, get_radius, get_width, try_extract_boolean, try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, try_extract_string, try_extract_style_standard, try_extract_vec_f32, try_extract_vec_f64, 
+    item_color, item_fill, item_length, item_object_opt, item_padding, IpgItems};
 use super::ipg_button::{get_standard_style, try_extract_button_arrow, IpgButtonArrow};
 use super::callbacks::{set_or_get_widget_callback_data, WidgetCallbackIn};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use iced::widget::{button, text, Button};
 use iced::{Border, Color, Element, Length, Padding, Shadow, Theme, Vector};
 use iced_aw::ColorPicker;
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -20,6 +23,7 @@ pub struct IpgColorPicker {
     pub id: usize,
     pub parent_id: String,
     pub show: bool,
+    pub enabled: bool,
     pub color: Color,
     //button related
     pub label: String,
@@ -37,6 +41,7 @@ impl IpgColorPicker {
         id: usize,
         parent_id: String,
         show: bool,
+        enabled: bool,
         color: Color,
         // button related
         label: String,
@@ -52,6 +57,7 @@ impl IpgColorPicker {
             id,
             parent_id,
             show,
+            enabled,
             color,
             // button related
             label,
@@ -132,7 +138,7 @@ pub fn construct_color_picker<'a>(cp: &'a IpgColorPicker,
                                     .height(cp.height)
                                     .padding(cp.padding)
                                     .width(cp.width)
-                                    .on_press(ColPikMessage::OnPress)
+                                    .on_press_maybe(cp.enabled.then_some(ColPikMessage::OnPress))
                                     .style(move|theme: &Theme, status| {   
                                         get_styling(theme, status,
                                             style.clone(),
@@ -184,69 +190,11 @@ pub fn color_picker_callback(state: &mut IpgState, id: usize, message: ColPikMes
 
 pub fn process_callback(id: usize, event_name: String, color: Option<Vec<f64>>) 
 {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name.clone())) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if event_name == "on_submit".to_string() {
-                if let Err(err) = callback.call1(py, (id, color, user_data)) {
-                    panic!("ColorPicker callback error: {err}");
-                }
-            } else {
-                if let Err(err) = callback.call1(py, (id, user_data)) {
-                    panic!("ColorPicker callback error: {err}");
-                }
-            }
-            
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if event_name == "on_submit".to_string() {
-                if let Err(err) = callback.call1(py, (id, color, user_data)) {
-                    panic!("ColorPicker callback error: {err}");
-                }
-            } else {
-                if let Err(err) = callback.call1(py, (id, user_data)) {
-                    panic!("ColorPicker callback error: {err}");
-                }
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
+    if event_name == "on_submit" {
+        dispatch_callback("ColorPicker", id, &event_name, (id, color));
+    } else {
+        dispatch_callback("ColorPicker", id, &event_name, (id,));
     }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the 
-    // callback with only the id and color except for on_pressed
-    // which has only an id.
-    Python::with_gil(|py| {
-        if event_name == "on_submit".to_string() {
-            if let Err(err) = callback.call1(py, (id, color)) {
-                panic!("ColorPicker callback error: {err}");
-            }
-        } else {
-            if let Err(err) = callback.call1(py, (id,)) {
-                panic!("ColorPicker callback error: {err}");
-            }
-        }
-    });
 }
 
 
@@ -260,6 +208,7 @@ pub enum IpgColorPickerParam {
     HeightFill,
     Label,
     Padding,
+    Enabled,
     Show,
     StyleId,
     StyleStandard,
@@ -270,65 +219,87 @@ pub enum IpgColorPickerParam {
 pub fn color_picker_update(cp: &mut IpgColorPicker,
                             item: &PyObject,
                             value: &PyObject,
-                            ) 
+                            ) -> PyResult<()> 
 {
-    let update = try_extract_cp_update(item);
+    let update = try_extract_cp_update(item)?;
     let name = "ColorPicker".to_string();
     match update {
        IpgColorPickerParam::ArrowStyle => {
-            cp.style_arrow = Some(try_extract_button_arrow(value));
+            cp.style_arrow = Some(try_extract_button_arrow(value)?);
         },
         IpgColorPickerParam::Color => {
-            let rgba = try_extract_rgba_color(value, name);
+            let rgba = try_extract_rgba_color(value, name)?;
             cp.color = Color::from(rgba);
         },
         IpgColorPickerParam::Label => {
-            cp.label = try_extract_string(value, name);
+            cp.label = try_extract_string(value, name)?;
         },
         IpgColorPickerParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             cp.height = get_height(Some(val as f32), false);
         },
         IpgColorPickerParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             cp.height = get_height(None, val);
         },
         IpgColorPickerParam::Padding => {
-            let val = try_extract_vec_f64(value, name);
-            cp.padding =  get_padding_f64(val);
+            let val = try_extract_vec_f64(value, name)?;
+            cp.padding =  get_padding_f64(val)?;
         },
         IpgColorPickerParam::Clip => {
-            cp.clip = try_extract_boolean(value, name);
+            cp.clip = try_extract_boolean(value, name)?;
         }
+        IpgColorPickerParam::Enabled => {
+            cp.enabled = try_extract_boolean(value, name)?;
+        },
         IpgColorPickerParam::Show => {
-            cp.show = try_extract_boolean(value, name);
+            cp.show = try_extract_boolean(value, name)?;
         },
         IpgColorPickerParam::StyleId => {
-            let val = try_extract_f64(value, name) as usize;
+            let val = try_extract_f64(value, name)? as usize;
             cp.style_id = Some(val);
         },
         IpgColorPickerParam::StyleStandard => {
-            let val = try_extract_style_standard(value, name);
+            let val = try_extract_style_standard(value, name)?;
             cp.style_standard = Some(val);
         },
         IpgColorPickerParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             cp.width = get_width(Some(val as f32), false);
         },
         IpgColorPickerParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             cp.width = get_width(None, val);
         },
     }
+    Ok(())
+}
+
+pub fn color_picker_get_items(cp: &IpgColorPicker) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("ArrowStyle", item_object_opt(cp.style_arrow.clone())?),
+        ("Clip", Some(cp.clip.into())),
+        ("Color", item_color(Some(cp.color))),
+        ("Height", item_length(cp.height)),
+        ("HeightFill", item_fill(cp.height)),
+        ("Label", Some(cp.label.clone().into())),
+        ("Padding", item_padding(cp.padding)),
+        ("Enabled", Some(cp.enabled.into())),
+        ("Show", Some(cp.show.into())),
+        ("StyleId", cp.style_id.map(Into::into)),
+        ("StyleStandard", item_object_opt(cp.style_standard.clone())?),
+        ("Width", item_length(cp.width)),
+        ("WidthFill", item_fill(cp.width)),
+    ])
 }
 
-pub fn try_extract_cp_update(update_obj: &PyObject) -> IpgColorPickerParam {
+pub fn try_extract_cp_update(update_obj: &PyObject) -> PyResult<IpgColorPickerParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgColorPickerParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Color Picker update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Color Picker update extraction failed")),
         }
     })
 }
@@ -355,72 +326,73 @@ pub enum IpgColorPickerStyleParam {
 
 pub fn color_picker_style_update_item(style: &mut IpgColorPickerStyle,
                                         item: &PyObject,
-                                        value: &PyObject,) 
+                                        value: &PyObject,) -> PyResult<()> 
 {
 
-    let update = try_extract_color_picker_style_update(item);
+    let update = try_extract_color_picker_style_update(item)?;
     let name = "ColorPickerStyle".to_string();
     match update {
         IpgColorPickerStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgColorPickerStyleParam::BackgroundRbga => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgColorPickerStyleParam::BackgroundIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgColorPickerStyleParam::BackgroundIpgRgbaHovered => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgColorPickerStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgColorPickerStyleParam::BorderRgba => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgColorPickerStyleParam::BorderRadius => {
-            style.border_radius = try_extract_vec_f32(value, name);
+            style.border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgColorPickerStyleParam::BorderWidth => {
-            style.border_width = try_extract_f64(value, name) as f32;
+            style.border_width = try_extract_f64(value, name)? as f32;
         },
         IpgColorPickerStyleParam::ShadowIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.shadow_color = get_color(None, Some(color), 1.0, false);
         },
         IpgColorPickerStyleParam::ShadowRgba => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgColorPickerStyleParam::ShadowOffsetX => {
-            style.shadow_offset_x = try_extract_f64(value, name) as f32;
+            style.shadow_offset_x = try_extract_f64(value, name)? as f32;
         },
         IpgColorPickerStyleParam::ShadowOffsetY => {
-            style.shadow_offset_y = try_extract_f64(value, name) as f32;
+            style.shadow_offset_y = try_extract_f64(value, name)? as f32;
         },
         IpgColorPickerStyleParam::ShadowBlurRadius => {
-            style.shadow_blur_radius = try_extract_f64(value, name) as f32;
+            style.shadow_blur_radius = try_extract_f64(value, name)? as f32;
         },
         IpgColorPickerStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgColorPickerStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_color_picker_style_update(update_obj: &PyObject) -> IpgColorPickerStyleParam {
+pub fn try_extract_color_picker_style_update(update_obj: &PyObject) -> PyResult<IpgColorPickerStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgColorPickerStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Color Picker style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Color Picker style update extraction failed")),
         }
     })
 }
@@ -1,12 +1,14 @@
 //! ipg_column
 use iced::{Element, Length, Padding};
 use iced::widget::Column;
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use crate::app::Message;
 
-use super::helpers::{get_alignment, get_height, get_padding_f64, get_width, try_extract_boolean, try_extract_f64, try_extract_ipg_alignment, try_extract_vec_f64};
+use super::helpers::{get_alignment, get_height, get_padding_f64, get_width, try_extract_boolean, try_extract_f64, try_extract_ipg_alignment, try_extract_vec_f64, 
+    item_fill, item_length, item_object, item_padding, IpgItems};
 use super::ipg_enums::IpgAlignment;
+use super::ipg_errors::IpgParamError;
 
 
 #[derive(Debug, Clone)]
@@ -79,49 +81,64 @@ pub fn construct_column<'a>(col: &IpgColumn, content: Vec<Element<'a, Message>>
 pub fn column_item_update(col: &mut IpgColumn,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_column_update(item);
+    let update = try_extract_column_update(item)?;
     let name = "Column".to_string();
     match update {
         IpgColumnParam::Align => {
-            col.align = try_extract_ipg_alignment(value).unwrap();
+            col.align = try_extract_ipg_alignment(value)
+                .ok_or_else(|| IpgParamError::new_err(format!("{}-Unable to extract python object for IpgAlignment", name)))?;
         },
         IpgColumnParam::Clip => {
-            col.clip = try_extract_boolean(value, name);
+            col.clip = try_extract_boolean(value, name)?;
         },
         IpgColumnParam::Padding => {
-            col.padding =  get_padding_f64(try_extract_vec_f64(value, name));
+            col.padding =  get_padding_f64(try_extract_vec_f64(value, name)?)?;
         },
         IpgColumnParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             col.width = get_width(Some(val as f32), false);
         },
         IpgColumnParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             col.width = get_width(None, val);
         },
         IpgColumnParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             col.height = get_height(Some(val as f32), false);
         },
         IpgColumnParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             col.height = get_height(None, val);
         },
         IpgColumnParam::Spacing => {
-            col.spacing = try_extract_f64(value, name) as f32;
+            col.spacing = try_extract_f64(value, name)? as f32;
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_column_update(update_obj: &PyObject) -> IpgColumnParam {
+pub fn column_get_items(col: &IpgColumn) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Align", item_object(col.align.clone())?),
+        ("Clip", Some(col.clip.into())),
+        ("Padding", item_padding(col.padding)),
+        ("Width", item_length(col.width)),
+        ("WidthFill", item_fill(col.width)),
+        ("Height", item_length(col.height)),
+        ("HeightFill", item_fill(col.height)),
+        ("Spacing", Some(col.spacing.into())),
+    ])
+}
+
+pub fn try_extract_column_update(update_obj: &PyObject) -> PyResult<IpgColumnParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgColumnParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Column update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Column update extraction failed")),
         }
     })
 }
@@ -1,13 +1,15 @@
 //! ipg_container
 use iced::{Border, Color, Element, Length, Padding, Shadow, Theme, Vector};
 use iced::widget::{container, horizontal_space, Container};
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use crate::app::Message;
 use crate::graphics::colors::get_color;
 
-use super::helpers::{get_height, get_horizontal_alignment, get_padding_f64, get_radius, get_vertical_alignment, get_width, try_extract_array_2, try_extract_boolean, try_extract_f64, try_extract_ipg_color, try_extract_ipg_horizontal_alignment, try_extract_ipg_vertical_alignment, try_extract_rgba_color, try_extract_vec_f32, try_extract_vec_f64};
+use super::helpers::{get_height, get_horizontal_alignment, get_padding_f64, get_radius, get_vertical_alignment, get_width, try_extract_array_2, try_extract_boolean, try_extract_f64, try_extract_ipg_color, try_extract_ipg_horizontal_alignment, try_extract_ipg_vertical_alignment, try_extract_rgba_color, try_extract_vec_f32, try_extract_vec_f64, 
+    item_fill, item_length, item_object, item_padding, IpgItems};
 use super::ipg_enums::{IpgHorizontalAlignment, IpgVerticalAlignment, IpgWidgets};
+use super::ipg_errors::IpgParamError;
 
 
 #[derive(Debug, Clone)]
@@ -149,19 +151,21 @@ pub enum IpgContainerParam {
 pub fn container_item_update(cont: &mut IpgContainer,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_container_update(item);
+    let update = try_extract_container_update(item)?;
     let name = "Container".to_string();
     match update {
         IpgContainerParam::AlignX => {
-            cont.align_x = try_extract_ipg_horizontal_alignment(value).unwrap();
+            cont.align_x = try_extract_ipg_horizontal_alignment(value)
+                .ok_or_else(|| IpgParamError::new_err(format!("{}-Unable to extract python object for IpgHorizontalAlignment", name)))?;
         },
         IpgContainerParam::AlignY => {
-            cont.align_y = try_extract_ipg_vertical_alignment(value).unwrap();
+            cont.align_y = try_extract_ipg_vertical_alignment(value)
+                .ok_or_else(|| IpgParamError::new_err(format!("{}-Unable to extract python object for IpgVerticalAlignment", name)))?;
         },
         IpgContainerParam::Centered => {
-            let centered = try_extract_boolean(value, name);
+            let centered = try_extract_boolean(value, name)?;
             if centered {
                 cont.align_x = IpgHorizontalAlignment::Center;
                 cont.align_y = IpgVerticalAlignment::Center;
@@ -171,39 +175,57 @@ pub fn container_item_update(cont: &mut IpgContainer,
             }
         },
         IpgContainerParam::Padding => {
-            let pad = try_extract_vec_f64(value, name);
-            cont.padding = get_padding_f64(pad);
+            let pad = try_extract_vec_f64(value, name)?;
+            cont.padding = get_padding_f64(pad)?;
         },
         IpgContainerParam::Width => {
-            let w = Some(try_extract_f64(value, name) as f32);
+            let w = Some(try_extract_f64(value, name)? as f32);
             cont.width = get_width(w, false)
         },
         IpgContainerParam::WidthFill => {
-            cont.width = get_width(None, try_extract_boolean(value, name));
+            cont.width = get_width(None, try_extract_boolean(value, name)?);
         },
         IpgContainerParam::Height => {
-            let h = Some(try_extract_f64(value, name) as f32);
+            let h = Some(try_extract_f64(value, name)? as f32);
             cont.height = get_height(h, false)
         },
         IpgContainerParam::HeightFill => {
-            cont.height = get_height(None, try_extract_boolean(value, name));
+            cont.height = get_height(None, try_extract_boolean(value, name)?);
         },
         IpgContainerParam::Clip => {
-            cont.clip = try_extract_boolean(value, name);
+            cont.clip = try_extract_boolean(value, name)?;
         },
         IpgContainerParam::Show => {
-            cont.show = try_extract_boolean(value, name);
+            cont.show = try_extract_boolean(value, name)?;
         }
     }
+    Ok(())
 }
 
-pub fn try_extract_container_update(update_obj: &PyObject) -> IpgContainerParam {
+pub fn container_get_items(cont: &IpgContainer) -> PyResult<IpgItems> {
+    let centered = cont.align_x == IpgHorizontalAlignment::Center
+        && cont.align_y == IpgVerticalAlignment::Center;
+    Ok(vec![
+        ("AlignX", item_object(cont.align_x.clone())?),
+        ("AlignY", item_object(cont.align_y.clone())?),
+        ("Centered", Some(centered.into())),
+        ("Padding", item_padding(cont.padding)),
+        ("Width", item_length(cont.width)),
+        ("WidthFill", item_fill(cont.width)),
+        ("Height", item_length(cont.height)),
+        ("HeightFill", item_fill(cont.height)),
+        ("Clip", Some(cont.clip.into())),
+        ("Show", Some(cont.show.into())),
+    ])
+}
+
+pub fn try_extract_container_update(update_obj: &PyObject) -> PyResult<IpgContainerParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgContainerParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Container update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Container update extraction failed")),
         }
     })
 }
@@ -278,62 +300,62 @@ pub enum IpgContainerStyleParam {
 
 pub fn container_style_update_item(style: &mut IpgContainerStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_container_style_update(item);
+    let update = try_extract_container_style_update(item)?;
     let name = "ContainerStyle".to_string();
     match update {
         IpgContainerStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgContainerStyleParam::BackgroundRgbaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgContainerStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgContainerStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgContainerStyleParam::BorderRadius => {
-            style.border_radius = try_extract_vec_f32(value, name);
+            style.border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgContainerStyleParam::BorderWidth => {
-            style.border_width = try_extract_f64(value, name) as f32;
+            style.border_width = try_extract_f64(value, name)? as f32;
         },
         IpgContainerStyleParam::ShadowIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.shadow_color = get_color(None, Some(color), 1.0, false);
         },
         IpgContainerStyleParam::ShadowRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgContainerStyleParam::ShadowOffsetXY => {
-            style.shadow_offset_xy = try_extract_array_2(value, name);
+            style.shadow_offset_xy = try_extract_array_2(value, name)?;
         },
         IpgContainerStyleParam::ShadowBlurRadius => {
-            style.shadow_blur_radius = try_extract_f64(value, name) as f32;
+            style.shadow_blur_radius = try_extract_f64(value, name)? as f32;
         },
         IpgContainerStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgContainerStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
-
+    Ok(())
 }
 
-pub fn try_extract_container_style_update(update_obj: &PyObject) -> IpgContainerStyleParam {
+pub fn try_extract_container_style_update(update_obj: &PyObject) -> PyResult<IpgContainerStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgContainerStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Container style parameter update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Container style parameter update extraction failed")),
         }
     })
 }
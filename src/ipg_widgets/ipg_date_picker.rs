@@ -1,14 +1,17 @@
 //! ipg_date_picker
 use crate::app::{Message, self};
-use crate::{access_callbacks, access_user_data1, access_user_data2, IpgState};
+use crate::IpgState;
+use super::callbacks::dispatch_callback;
 use crate::style::styling::IpgStyleStandard;
 use super::callbacks::{set_or_get_widget_callback_data, WidgetCallbackIn};
 use super::ipg_enums::IpgWidgets;
 use crate::ICON_FONT_BOOT;
 use super::helpers::{get_padding_f64, try_extract_boolean, 
     try_extract_f64, try_extract_string, try_extract_vec_f64, 
-    DATE_FORMATS, DAYS, MONTH_NAMES, WEEKDAYS};
+    DATE_FORMATS, DAYS, MONTH_NAMES, WEEKDAYS, 
+    item_padding, IpgItems};
 use super::ipg_button::{self, get_standard_style, IpgButtonStyle};
+use super::ipg_errors::IpgParamError;
 
 use iced::advanced::graphics::core::Element;
 use iced::widget::{button, text};
@@ -17,7 +20,7 @@ use iced::alignment::{self, Alignment};
 use iced::widget::{Button, Column, Container, PickList, Row, Space, Text};
 
 use chrono::prelude::*;
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -28,6 +31,7 @@ pub struct IpgDatePicker {
     pub size_factor: f32,
     pub padding: Padding,
     pub show: bool,
+    pub enabled: bool,
     pub show_calendar: bool,
     
     pub selected_format: String,
@@ -54,6 +58,7 @@ impl IpgDatePicker {
         size_factor: f32,
         padding: Padding,
         show: bool,
+        enabled: bool,
         show_calendar: bool,
         button_style_standard: Option<IpgStyleStandard>,
         button_style_id: Option<usize>,
@@ -65,6 +70,7 @@ impl IpgDatePicker {
             size_factor,
             padding,
             show,
+            enabled,
             show_calendar,
 
             selected_format: "YYYY-mm-dd".to_string(),
@@ -215,7 +221,7 @@ fn calendar_show_button<'a>(dp: &'a IpgDatePicker,
 
     let show_btn: Element<DPMessage, Theme, Renderer> = 
                     Button::new(text(dp.label.clone()))
-                                    .on_press(DPMessage::ShowModal)
+                                    .on_press_maybe(dp.enabled.then_some(DPMessage::ShowModal))
                                     .height(Length::Shrink)
                                     .width(Length::Shrink)
                                     .style(move|theme, status|
@@ -542,66 +548,10 @@ pub fn date_picker_update(state: &mut IpgState, id: usize, message: DPMessage) {
 
 
 pub fn process_callback(id: usize, event_name: String, selected_date: Option<String>) {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Some(date) = &selected_date {
-                if let Err(err) = callback.call1(py, (id, date.clone(), user_data)) {
-                    panic!("DatePicker callback error: {err}");
-                }
-            } else {
-                if let Err(err) = callback.call1(py, (id, user_data)) {
-                    panic!("DatePicker callback error: {err}");
-                }
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
+    match selected_date {
+        Some(date) => dispatch_callback("DatePicker", id, &event_name, (id, date)),
+        None => dispatch_callback("DatePicker", id, &event_name, (id,)),
     }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Some(date) = &selected_date {
-                if let Err(err) = callback.call1(py, (id, date.clone(), user_data)) {
-                    panic!("DatePicker callback error: {err}");
-                }
-            } else {
-                if let Err(err) = callback.call1(py, (id, user_data)) {
-                    panic!("DatePicker callback error: {err}");
-                }
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with only id and selected_date
-    Python::with_gil(|py| {
-        if let Some(date) = selected_date {
-            if let Err(err) = callback.call1(py, (id, date)) {
-                panic!("DatePicker callback error: {err}");
-            }
-        } else {
-            if let Err(err) = callback.call1(py, (id,)) {
-                panic!("DatePicker callback error: {err}");
-            }
-        }
-    });
 }      
 
 
@@ -611,40 +561,59 @@ pub enum IpgDatePickerParam {
     Label,
     Padding,
     SizeFactor,
+    Enabled,
     Show,
 }
 
 pub fn date_picker_item_update(dp: &mut IpgDatePicker,
                                 item: &PyObject,
                                 value: &PyObject,
-                                )
+                                ) -> PyResult<()>
 {
-    let update = try_extract_date_picker_update(item);
+    let update = try_extract_date_picker_update(item)?;
     let name = "DatePicker".to_string();
     match update {
         IpgDatePickerParam::Label => {
-            dp.label = try_extract_string(value, name);
+            dp.label = try_extract_string(value, name)?;
         },
         IpgDatePickerParam::Padding => {
-            let pd = try_extract_vec_f64(value, name);
-            dp.padding = get_padding_f64(pd);
+            let pd = try_extract_vec_f64(value, name)?;
+            dp.padding = get_padding_f64(pd)?;
         },
         IpgDatePickerParam::SizeFactor => {
-                dp.size_factor = try_extract_f64(value, name) as f32;
+                dp.size_factor = try_extract_f64(value, name)? as f32;
+        },
+        IpgDatePickerParam::Enabled => {
+            dp.enabled = try_extract_boolean(value, name)?;
+            // an open calendar is closed so it can't be used while disabled
+            if !dp.enabled {
+                dp.show_calendar = false;
+            }
         },
         IpgDatePickerParam::Show => {
-            dp.show = try_extract_boolean(value, name);
+            dp.show = try_extract_boolean(value, name)?;
         },
     }
+    Ok(())
+}
+
+pub fn date_picker_get_items(dp: &IpgDatePicker) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Label", Some(dp.label.clone().into())),
+        ("Padding", item_padding(dp.padding)),
+        ("SizeFactor", Some(dp.size_factor.into())),
+        ("Enabled", Some(dp.enabled.into())),
+        ("Show", Some(dp.show.into())),
+    ])
 }
 
-pub fn try_extract_date_picker_update(update_obj: &PyObject) -> IpgDatePickerParam {
+pub fn try_extract_date_picker_update(update_obj: &PyObject) -> PyResult<IpgDatePickerParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgDatePickerParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("DatePicker update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("DatePicker update extraction failed")),
         }
     })
 }
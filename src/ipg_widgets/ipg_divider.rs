@@ -1,15 +1,17 @@
 //! ipg_divider
 
 use iced::{Background, Color, Element, Length, Theme};
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
-use crate::{access_callbacks, access_user_data1, access_user_data2, app, graphics::colors::get_color, IpgState};
+use crate::{app, graphics::colors::get_color, IpgState};
+use super::callbacks::dispatch_callback;
 
 use super::{callbacks::{set_or_get_widget_callback_data, WidgetCallbackIn}, 
 divider::{self, divider_horizontal, divider_vertical, Direction, Status, Style}, helpers::{ 
     get_radius, try_extract_boolean, try_extract_f32, 
-    try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, try_extract_vec_f32}, 
+    try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, try_extract_vec_f32, IpgItems}, 
     ipg_enums::IpgWidgets};
+use super::ipg_errors::IpgParamError;
 
 
 #[derive(Debug, Clone, PartialEq)]
@@ -275,51 +277,7 @@ pub fn divider_callback(state: &mut IpgState, id: usize, message: DivMessage) {
 }
 
 pub fn process_callback(id: usize, event_name: String, index: usize, value: f32) {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            let res = callback.call1(py, (id, index, value, user_data));
-            if let Err(err) = res {
-                panic!("Divider callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            let res = callback.call1(py, (id, index, value, user_data));
-            if let Err(err) = res {
-                panic!("Divider callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with only id, index, and value
-    Python::with_gil(|py| {
-        let res = callback.call1(py, (id, index, value));
-        if let Err(err) = res {
-            panic!("Divider callback error: {err}");
-        }
-    });
+    dispatch_callback("Divider", id, &event_name, (id, index, value));
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -336,72 +294,94 @@ pub enum IpgDividerParam {
 pub fn divider_horizontal_item_update(
         divider: &mut IpgDividerHorizontal, 
         item: &PyObject, 
-        value: &PyObject) {
+        value: &PyObject) -> PyResult<()> {
 
-    let update = try_extract_divider_update(item);
+    let update = try_extract_divider_update(item)?;
     let name = "Divider".to_string();
     match update {
         IpgDividerParam::HandleWidth => {
-            divider.handle_width = try_extract_f32(value, name);
+            divider.handle_width = try_extract_f32(value, name)?;
         },
         IpgDividerParam::HandleHeight => {
-            divider.handle_height = try_extract_f32(value, name);
+            divider.handle_height = try_extract_f32(value, name)?;
         },
         IpgDividerParam::Widths => {
-            divider.widths = try_extract_vec_f32(value, name);
+            divider.widths = try_extract_vec_f32(value, name)?;
         },
         IpgDividerParam::Heights => {
-            panic!("Horizontal Divider must use the Widths not Heights");
+            return Err(IpgParamError::new_err("Horizontal Divider must use the Widths not Heights"));
         },
         IpgDividerParam::StyleId => {
-            divider.style_id = Some(try_extract_f64(value, name) as usize);
+            divider.style_id = Some(try_extract_f64(value, name)? as usize);
         },
         IpgDividerParam::Show => {
-            divider.show = try_extract_boolean(value, name);
+            divider.show = try_extract_boolean(value, name)?;
         },
         
     }
+    Ok(())
 }
 
 pub fn divider_vertical_item_update(
         divider: &mut IpgDividerVertical, 
         item: &PyObject, 
-        value: &PyObject) {
+        value: &PyObject) -> PyResult<()> {
 
-    let update = try_extract_divider_update(item);
+    let update = try_extract_divider_update(item)?;
     let name = "Divider".to_string();
     match update {
         IpgDividerParam::HandleWidth => {
-            divider.handle_width = try_extract_f32(value, name);
+            divider.handle_width = try_extract_f32(value, name)?;
         },
         IpgDividerParam::HandleHeight => {
-            divider.handle_height = try_extract_f32(value, name);
+            divider.handle_height = try_extract_f32(value, name)?;
             
         },
         IpgDividerParam::Widths => {
-            panic!("Vertical Divider must use the Heights not Widths");
+            return Err(IpgParamError::new_err("Vertical Divider must use the Heights not Widths"));
         },
         IpgDividerParam::Heights => {
-            divider.heights = try_extract_vec_f32(value, name);
+            divider.heights = try_extract_vec_f32(value, name)?;
         },
         IpgDividerParam::StyleId => {
-            divider.style_id = Some(try_extract_f64(value, name) as usize);
+            divider.style_id = Some(try_extract_f64(value, name)? as usize);
         },
         IpgDividerParam::Show => {
-            divider.show = try_extract_boolean(value, name);
+            divider.show = try_extract_boolean(value, name)?;
         },
         
     }
+    Ok(())
+}
+
+pub fn divider_horizontal_get_items(divider: &IpgDividerHorizontal) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("HandleWidth", Some(divider.handle_width.into())),
+        ("HandleHeight", Some(divider.handle_height.into())),
+        ("Widths", Some(divider.widths.clone().into())),
+        ("StyleId", divider.style_id.map(Into::into)),
+        ("Show", Some(divider.show.into())),
+    ])
+}
+
+pub fn divider_vertical_get_items(divider: &IpgDividerVertical) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("HandleWidth", Some(divider.handle_width.into())),
+        ("HandleHeight", Some(divider.handle_height.into())),
+        ("Heights", Some(divider.heights.clone().into())),
+        ("StyleId", divider.style_id.map(Into::into)),
+        ("Show", Some(divider.show.into())),
+    ])
 }
 
 
-fn try_extract_divider_update(update_obj: &PyObject) -> IpgDividerParam {
+fn try_extract_divider_update(update_obj: &PyObject) -> PyResult<IpgDividerParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgDividerParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Divider update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Divider update extraction failed")),
         }
     })
 }
@@ -467,44 +447,45 @@ pub enum IpgDividerStyleParam {
 pub fn divider_style_update_item(
         style: &mut IpgDividerStyle,
         item: &PyObject,
-        value: &PyObject,) 
+        value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_divider_style_update(item);
+    let update = try_extract_divider_style_update(item)?;
     let name = "DividerStyle".to_string();
     match update {
         IpgDividerStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background = get_color(None, Some(color), 1.0, false);
         },
         IpgDividerStyleParam::BackgroundRgbaColor => {
-            style.background = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgDividerStyleParam::BackgroundTransparent => {
-            style.background_transparent = try_extract_boolean(value, name)
+            style.background_transparent = try_extract_boolean(value, name)?
         }
         IpgDividerStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgDividerStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgDividerStyleParam::BorderWidth => {
-            style.border_width = try_extract_f32(value, name);
+            style.border_width = try_extract_f32(value, name)?;
         },
         IpgDividerStyleParam::BorderRadius =>  {
-             style.border_radius = try_extract_f32(value, name);
+             style.border_radius = try_extract_f32(value, name)?;
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_divider_style_update(update_obj: &PyObject) -> IpgDividerStyleParam {
+pub fn try_extract_divider_style_update(update_obj: &PyObject) -> PyResult<IpgDividerStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgDividerStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Divider style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Divider style update extraction failed")),
         }
     })
 }
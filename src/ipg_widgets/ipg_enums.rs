@@ -5,6 +5,7 @@ use pyo3::pyclass;
 use super::ipg_button::{IpgButton, IpgButtonStyle};
 use super::ipg_canvas::IpgCanvas;
 use super::ipg_card::{IpgCard, IpgCardStyle};
+use super::ipg_chart::IpgChart;
 use super::ipg_checkbox::{IpgCheckBox, IpgCheckboxStyle};
 use super::ipg_color_picker::{IpgColorPicker, IpgColorPickerStyle};
 // use super::ipg_color_picker::IpgColorPicker;
@@ -12,6 +13,8 @@ use super::ipg_container::{IpgContainer, IpgContainerStyle};
 use super::ipg_column::IpgColumn;
 use super::ipg_date_picker::IpgDatePicker;
 use super::ipg_divider::{IpgDividerHorizontal, IpgDividerVertical, IpgDividerStyle};
+use super::ipg_gauge::{IpgGauge, IpgKnob, IpgMeter};
+use super::ipg_heatmap::IpgHeatmap;
 use super::ipg_image::IpgImage;
 use super::ipg_menu::{IpgMenu, IpgMenuBarStyle, IpgMenuStyle};
 // use super::ipg_modal::IpgModal;
@@ -29,6 +32,7 @@ use super::ipg_separator::{IpgSeparator, IpgSeparatorStyle};
 use super::ipg_slider::{IpgSlider, IpgSliderStyle};
 use super::ipg_space::IpgSpace;
 use super::ipg_stack::IpgStack;
+use super::ipg_stream_plot::IpgStreamPlot;
 use super::ipg_svg::IpgSvg;
 use super::ipg_table::{IpgTable, IpgTableStyle};
 use super::ipg_text::IpgText;
@@ -67,6 +71,7 @@ pub enum IpgWidgets {
     IpgButtonStyle(IpgButtonStyle),
     IpgCard(IpgCard),
     IpgCardStyle(IpgCardStyle),
+    IpgChart(IpgChart),
     IpgCheckBox(IpgCheckBox),
     IpgCheckboxStyle(IpgCheckboxStyle),
     IpgColorPicker(IpgColorPicker),
@@ -76,7 +81,11 @@ pub enum IpgWidgets {
     IpgDividerVertical(IpgDividerVertical),
     IpgDividerStyle(IpgDividerStyle),
     IpgDatePicker(IpgDatePicker),
+    IpgGauge(IpgGauge),
+    IpgHeatmap(IpgHeatmap),
     IpgImage(IpgImage),
+    IpgKnob(IpgKnob),
+    IpgMeter(IpgMeter),
     IpgMenuStyle(IpgMenuStyle),
     IpgMenuBarStyle(IpgMenuBarStyle),
     IpgOpaqueStyle(IpgOpaqueStyle),
@@ -95,6 +104,7 @@ pub enum IpgWidgets {
     IpgSlider(IpgSlider),
     IpgSliderStyle(IpgSliderStyle),
     IpgSpace(IpgSpace),
+    IpgStreamPlot(IpgStreamPlot),
     IpgSvg(IpgSvg),
     IpgTableStyle(IpgTableStyle),
     IpgText(IpgText),
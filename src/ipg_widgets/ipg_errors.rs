@@ -0,0 +1,49 @@
+//! ipg_errors
+//! The python exceptions raised by IPG.  Errors found while adding widgets
+//! are raised by the IPG methods.  Errors found while the gui is running
+//! are sent to the on_error handler, given in start_session, or printed
+//! when there is none, so that the gui keeps running.
+use std::sync::Mutex;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+
+
+create_exception!(icedpygui, IpgError, PyException, "Base class of the IPG errors.");
+create_exception!(icedpygui, IpgIdError, IpgError, "A widget, container or window id was not found.");
+create_exception!(icedpygui, IpgParamError, IpgError, "A parameter or its value is not valid.");
+create_exception!(icedpygui, IpgCallbackError, IpgError, "A callback raised an error or has the wrong parameters.");
+
+static ON_ERROR: Mutex<Option<PyObject>> = Mutex::new(None);
+
+pub fn set_error_handler(on_error: Option<PyObject>) {
+    *ON_ERROR.lock().unwrap() = on_error;
+}
+
+/// Sends the error to the on_error handler or prints it.
+pub fn report_error(err: PyErr) {
+    let on_error = ON_ERROR.lock().unwrap();
+    let handler = Python::with_gil(|py| on_error.as_ref().map(|cb| cb.clone_ref(py)));
+    drop(on_error);
+
+    Python::with_gil(|py| {
+        match handler {
+            Some(handler) => {
+                if let Err(handler_err) = handler.call1(py, (err.value(py),)) {
+                    err.print(py);
+                    handler_err.print(py);
+                }
+            },
+            None => err.print(py),
+        }
+    });
+}
+
+/// Reports a python error raised by a callback, the original
+/// error is kept as the cause.
+pub fn report_callback_error(name: &str, err: PyErr) {
+    let callback_err = IpgCallbackError::new_err(format!("{name} callback error: {err}"));
+    Python::with_gil(|py| callback_err.set_cause(py, Some(err)));
+    report_error(callback_err);
+}
@@ -2,19 +2,26 @@
 #![allow(unused_assignments)]
 #![allow(clippy::enum_variant_names)]
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
-use crate::{access_events, access_user_data1, access_window_actions, IpgState};
+use crate::{access_window_actions, IpgState};
+use super::callbacks::{call_callback_returning, dispatch_event, dispatch_event_at, event_objects, get_event_callback, modifier_names, IpgEventSource};
+use super::helpers::IpgItemValue;
+use super::ipg_gestures::{IpgGesture, IpgGestures};
+use super::ipg_window::{forget_window, hide_window, set_window_geometry};
 
-use iced::event::Event;
+use iced::event::{Event, Status};
 use iced::keyboard::Event::{KeyPressed, KeyReleased, ModifiersChanged};
 use iced::keyboard::{Key, Location, Modifiers};
 use iced::mouse::Event::{ButtonPressed, ButtonReleased, CursorEntered, 
                         CursorLeft, CursorMoved, WheelScrolled};
 use iced::mouse::Button::{Left, Right, Middle, Back, Forward, Other,};
-use iced::mouse::ScrollDelta;
+use iced::mouse::{self, ScrollDelta};
 
 use iced::window;
-use pyo3::Python;
+use iced::Point;
+
+use pyo3::{PyObject, Python};
 
 
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
@@ -78,167 +85,219 @@ pub enum IpgEvents {
     Window(IpgWindowEvent),
 }
 
-pub fn process_keyboard_events(event: Event, event_id: usize) 
+pub fn process_keyboard_events(event: Event, event_id: usize, window_id: String) 
 {   
-    match event {    
+    let (event_name, key, location, modifiers, text) = match event {
         Event::Keyboard(KeyPressed { key, 
                                     location, 
                                     modifiers, 
-                                    text: _ ,
+                                    text,
                                     physical_key: _, 
                                     modified_key: _ }) => {
-
-            let event_name = "key pressed".to_string();
-            
-            let key_str: String = process_key(key.as_ref());
-            
-            let mod_key = process_modifier(modifiers);
-
-            let location_str: String = process_location(location);
-
-            let hmap_s_s: HashMap<String, String> = 
-                HashMap::from([
-                    ("name".to_string(), event_name.clone()),
-                    ("key".to_string(), key_str),
-                    ("modifier".to_string(), mod_key),
-                    ("location".to_string(), location_str)
-                ]);
-
-            process_keyboard_callback(event_id, 
-                                        event_name,
-                                        hmap_s_s, 
-                                        );
-            
+            ("key pressed", key, location, modifiers, text)
         },
         Event::Keyboard(KeyReleased { key, location, modifiers, }) => {
+            ("key released", key, location, modifiers, None)
+        },
+        // ModifiersChanged occurs when command keys are pressed but these 
+        // also show up under key pressed and release so not sure any advantage on using this.
+        _ => return,
+    };
 
-            let event_name = "key released".to_string();
-            
-            let key_str: String = process_key(key.as_ref());
+    let key_str: String = process_key(key.as_ref());
+    let mod_key = process_modifier(modifiers);
+    let location_str: String = process_location(location);
+
+    // the text typed, a composed or unicode character may take
+    // several keys, Enter, Backspace and Ctrl+key have none
+    let text = text.map(|text| text.to_string())
+        .filter(|text| !text.chars().all(char::is_control));
+
+    let hmap_s_v = |name: &str| {
+        let mut hmap_s_v: HashMap<String, IpgItemValue> = 
+            HashMap::from([
+                ("name".to_string(), name.to_string().into()),
+                ("key".to_string(), key_str.clone().into()),
+                ("modifier".to_string(), mod_key.clone().into()),
+                ("modifiers".to_string(), modifier_names(modifiers).into()),
+                ("location".to_string(), location_str.clone().into()),
+                ("window_id".to_string(), window_id.clone().into()),
+            ]);
+        if let Some(text) = &text {
+            hmap_s_v.insert("text".to_string(), text.clone().into());
+        }
+        hmap_s_v
+    };
 
-            let mod_key = process_modifier(modifiers);
+    process_keyboard_callback(event_id, event_name.to_string(), hmap_s_v(event_name));
 
-            let location_str: String = process_location(location);
+    if text.is_some() {
+        process_keyboard_callback(event_id, "text input".to_string(), hmap_s_v("text input"));
+    }
+}
 
-            let hmap_s_s: HashMap<String, String> = HashMap::from([
-                ("name".to_string(), event_name.clone()),
-                ("key".to_string(), key_str),
-                ("modifier".to_string(), mod_key),
-                ("location".to_string(), location_str)
-            ]);
+// clicks further apart than this are not a double click
+const CLICK_DISTANCE: f32 = 4.0;
+
+/// The cursor position, modifiers and clicks followed for the mouse events.
+#[derive(Debug, Clone)]
+pub struct IpgMouseInput {
+    pub positions: HashMap<window::Id, Point>,
+    pub modifiers: Modifiers,
+    pub click_interval: Duration,
+    last_click: Option<(Instant, window::Id, mouse::Button, Point)>,
+    clicks: u8,
+}
 
-            process_keyboard_callback(event_id,
-                                        event_name, 
-                                        hmap_s_s, 
-                                        );
-            
-        },
-        // This event occurs when command keys are pressed but these 
-        // also show up under key pressed and release so not sure any advantage on using this.
-        Event::Keyboard(ModifiersChanged(_)) => (),
-        Event::Mouse(_) => (),
-        Event::Window(_) => (),
-        Event::Touch(_) => (),
+impl IpgMouseInput {
+    pub fn new(click_interval_ms: u64) -> Self {
+        Self {
+            positions: HashMap::new(),
+            modifiers: Modifiers::empty(),
+            click_interval: Duration::from_millis(click_interval_ms),
+            last_click: None,
+            clicks: 0,
+        }
     }
-}
 
+    /// Counts the presses of the same button in the same place,
+    /// 2 is a double click and 3 a triple click, after which
+    /// the count starts over.
+    pub fn click(&mut self, at: Instant, window_id: window::Id, button: mouse::Button) -> u8 {
+        let position = self.positions.get(&window_id).copied().unwrap_or(Point::ORIGIN);
+
+        let repeated = match self.last_click {
+            Some((last_at, last_window_id, last_button, last_position)) => {
+                last_window_id == window_id && 
+                last_button == button &&
+                at.duration_since(last_at) <= self.click_interval &&
+                last_position.distance(position) <= CLICK_DISTANCE
+            },
+            None => false,
+        };
+
+        self.clicks = if repeated && self.clicks < 3 { self.clicks + 1 } else { 1 };
+        self.last_click = Some((at, window_id, button, position));
+        self.clicks
+    }
+}
 
-pub fn process_mouse_events(event: Event, event_id: usize)
+pub fn process_mouse_events(input: &mut IpgMouseInput,
+                            event_id: usize,
+                            event: Event,
+                            status: Status,
+                            window_id: window::Id,
+                            window_str_id: String)
 {
-    let mut hmap_s_f: Option<HashMap<String, f32>> = None;
+    let m_event = match event {
+        Event::Mouse(m_event) => m_event,
+        Event::Keyboard(ModifiersChanged(modifiers)) => {
+            input.modifiers = modifiers;
+            return
+        },
+        _ => return,
+    };
+
+    if let CursorMoved { position } = m_event {
+        input.positions.insert(window_id, position);
+    }
+    // a captured event, like a press on a button, only moves the cursor
+    if status == Status::Captured {
+        return
+    }
+
+    // the last position, there is none until the cursor moves in the window
+    let position = input.positions.get(&window_id).copied();
+
+    let modifiers = input.modifiers;
+    let hmap_s_v = || {
+        let mut hmap_s_v: HashMap<String, IpgItemValue> = HashMap::from([
+            ("window_id".to_string(), window_str_id.clone().into()),
+            ("modifiers".to_string(), modifier_names(modifiers).into()),
+        ]);
+        if let Some(position) = position {
+            hmap_s_v.insert("position".to_string(), vec![position.x, position.y].into());
+        }
+        hmap_s_v
+    };
+    let mut event_hmap = hmap_s_v();
+
+    let mut clicks = 0;
+    let mut button = "";
     
-    match event {
-        Event::Mouse(m_event) => {
-            let event_name = match m_event {
-                CursorEntered => {
-                    "enter window".to_string()
-                },
-                CursorLeft => {
-                    "exit window".to_string()
+    let event_name = match m_event {
+        CursorEntered => {
+            "enter window".to_string()
+        },
+        CursorLeft => {
+            "exit window".to_string()
+        },
+        CursorMoved { position } => {
+            event_hmap.insert("x".to_string(), position.x.into());
+            event_hmap.insert("y".to_string(), position.y.into());
+            "move".to_string()
+        },
+        ButtonPressed(btn) => {
+            clicks = input.click(Instant::now(), window_id, btn);
+            button = process_button(btn, &mut event_hmap);
+            format!("{button} press")
+        },
+        ButtonReleased(btn) => {
+            format!("{} release", process_button(btn, &mut event_hmap))
+        },
+        WheelScrolled { delta } => {
+            match delta {
+                ScrollDelta::Lines { x, y } => {
+                    event_hmap.insert("x".to_string(), x.into());
+                    event_hmap.insert("y".to_string(), y.into());
+                    "middle scroll line".to_string()
                 },
-                CursorMoved { position } => {
-                    hmap_s_f = Some(HashMap::from([("x".to_string(), position.x),
-                                    ("y".to_string(), position.y)]));
-                    "move".to_string()
+                ScrollDelta::Pixels { x, y } => {
+                    event_hmap.insert("x".to_string(), x.into());
+                    event_hmap.insert("y".to_string(), y.into());
+                    "middle scroll pixel".to_string()
                 },
-                ButtonPressed(btn) => {
-                    match btn {
-                        Left => {
-                            "left press".to_string()
-                        },
-                        Right => {
-                            "right press".to_string()
-                        },
-                        Middle => {
-                            "middle press".to_string()
-                        },
-                        Back => {
-                            "back press".to_string()
-                        },
-                        Forward => {
-                            "forward press".to_string()
-                        },
-                        Other(other) => {
-                            hmap_s_f = Some(HashMap::from([("other".to_string(), other as f32)]));
-                            "other press".to_string()
-                        },
-                    }
-                    },
-                    ButtonReleased(btn) => {
-                        match btn {
-                            Left => {
-                                "left release".to_string()
-                            },
-                            Right => {
-                                "right release".to_string()
-                            },
-                            Middle => {
-                                "middle release".to_string()
-                            },
-                            Back => {
-                                "back release".to_string()
-                            },
-                            Forward => {
-                                "forward release".to_string()
-                            },
-                            Other(other) => {
-                                hmap_s_f = Some(HashMap::from([("other".to_string(), other as f32)]));
-                                "other release".to_string()
-                            },
-                        }
-                    },
-                    WheelScrolled { delta } => {
-                        match delta {
-                            ScrollDelta::Lines { x, y } => {
-                                hmap_s_f = Some(HashMap::from([("x".to_string(), x),
-                                                                ("y".to_string(), y)]));
-                                "middle scroll line".to_string()
-                            },
-                            ScrollDelta::Pixels { x, y } => {
-                                hmap_s_f = Some(HashMap::from([("x".to_string(), x),
-                                                                ("y".to_string(), y)]));
-                                "middle scroll pixel".to_string()
-                            },
-                        }
-                    }
-            };
+            }
+        }
+    };
+
+    process_mouse_callback(event_id, event_name, position, event_hmap);
 
-            process_mouse_callback(event_id,
-                                    event_name,
-                                    hmap_s_f,
-                                    )
+    // the press is sent before the double or triple click
+    let click_name = match clicks {
+        2 => "double click",
+        3 => "triple click",
+        _ => return,
+    };
+    let mut click_hmap = hmap_s_v();
+    click_hmap.insert("button".to_string(), button.to_string().into());
+    process_mouse_callback(event_id, click_name.to_string(), position, click_hmap);
+}
+
+fn process_button(btn: mouse::Button, hmap_s_v: &mut HashMap<String, IpgItemValue>) -> &'static str {
+    match btn {
+        Left => "left",
+        Right => "right",
+        Middle => "middle",
+        Back => "back",
+        Forward => "forward",
+        Other(other) => {
+            hmap_s_v.insert("other".to_string(), (other as f32).into());
+            "other"
         },
-        Event::Keyboard(_) => (),
-        Event::Window(_) => (),
-        Event::Touch(_) => (),
     }
-        
 }
 
-pub fn process_touch_events(event: Event, event_id: usize) {
+/// The raw finger events are sent as they come and are
+/// also followed by the gestures.
+pub fn process_touch_events(gestures: &mut IpgGestures, 
+                            event_id: usize,
+                            event: Event, 
+                            window_str_id: String) {
     let mut event_name = "".to_string();
     match event {
         Event::Touch(tch) => {
+            let found = gestures.process(&tch, Instant::now());
             let (hmap_s_fg, hmap_s_pt) = match tch {
                 iced::touch::Event::FingerPressed { id, position } => {
                     let hmap_s_fg = HashMap::from([("finger".to_string(), id.0)]);
@@ -269,8 +328,8 @@ pub fn process_touch_events(event: Event, event_id: usize) {
                                 event_name,
                                 hmap_s_fg,
                                 hmap_s_pt,
-                                )
-            
+                                );
+            process_gesture_callbacks(event_id, found, &window_str_id);
         },
         Event::Window(_) => (),
         Event::Keyboard(_) => (),
@@ -278,6 +337,20 @@ pub fn process_touch_events(event: Event, event_id: usize) {
     }
 }
 
+/// Sends the long press of a finger held still, from the time tick.
+pub fn process_long_press(gestures: &mut IpgGestures, event_id: usize, window_str_id: String) {
+    let found = gestures.long_press(Instant::now());
+    process_gesture_callbacks(event_id, found.into_iter().collect(), &window_str_id);
+}
+
+fn process_gesture_callbacks(event_id: usize, found: Vec<IpgGesture>, window_str_id: &str) {
+    for gesture in found {
+        let (event_name, mut hmap_s_v) = gesture.values();
+        hmap_s_v.insert("window_id".to_string(), window_str_id.to_string().into());
+        dispatch_event("Touch Event", event_id, event_name, event_id, (event_id, hmap_s_v));
+    }
+}
+
 
 pub fn process_window_event(state: &mut IpgState,
                             event: Event,
@@ -295,20 +368,16 @@ pub fn process_window_event(state: &mut IpgState,
     };
 
     let event_name: Option<String> = match event {
-        Event::Window(window::Event::Opened { position: _, size: _ } )=> {
+        Event::Window(window::Event::Opened { position, size } )=> {
+            set_window_geometry(state, ipg_id, position, Some(size));
             Some("opened".to_string())
         },
         Event::Window(window::Event::Closed) => {
-            let mut actions = access_window_actions();
-                actions.mode.push((ipg_id, window::Mode::Hidden));
-                drop(actions);
-
-            if !state.windows_hidden.contains(&window_id){
-                state.windows_hidden.push(window_id);
-            }
+            forget_window(state, ipg_id, window_id);
             Some("closed".to_string())
         },
         Event::Window(window::Event::Moved(point)) => {
+            set_window_geometry(state, ipg_id, Some(point), None);
             hmap_s_f = Some(HashMap::from([
                             ("x".to_string(), point.x),
                             ("y".to_string(), point.y),
@@ -316,6 +385,7 @@ pub fn process_window_event(state: &mut IpgState,
             Some("moved".to_string())
         },
         Event::Window(window::Event::Resized (size)) => {
+            set_window_geometry(state, ipg_id, None, Some(size));
             hmap_s_f = Some(HashMap::from([
                             ("width".to_string(), size.width),
                             ("height".to_string(), size.height),
@@ -326,13 +396,7 @@ pub fn process_window_event(state: &mut IpgState,
             Some("redraw requested".to_string())
         },
         Event::Window(window::Event::CloseRequested ) => {
-            let mut actions = access_window_actions();
-                actions.mode.push((ipg_id, window::Mode::Hidden));
-                drop(actions);
-
-            if !state.windows_hidden.contains(&window_id){
-                state.windows_hidden.push(window_id);
-            }
+            // hidden after the callback, which can keep the window open
             Some("close requested".to_string())
         },
         Event::Window(window::Event::Focused) => {
@@ -364,70 +428,56 @@ pub fn process_window_event(state: &mut IpgState,
         Event::Touch(_) => None,
     };
 
-    if event_name.is_some() {
-        process_window_callback(
-            ipg_id,
-            event_id,
-            event_name.unwrap(), 
-            hmap_s_f,
-            hmap_s_s,
-            );
+    let event_name = match event_name {
+        Some(name) => name,
+        None => return,
+    };
+    let close_requested = event_name == "close requested";
+
+    let returned = process_window_callback(
+                        ipg_id,
+                        event_id,
+                        event_name, 
+                        hmap_s_f,
+                        hmap_s_s,
+                        );
+
+    // the on_close_requested callback keeps the window open by returning False
+    if close_requested && !close_vetoed(returned) {
+        hide_window(state, &mut access_window_actions(), ipg_id, window_id);
     }
         
 }
 
+fn close_vetoed(returned: Option<PyObject>) -> bool {
+    match returned {
+        Some(value) => Python::with_gil(|py| matches!(value.extract::<bool>(py), Ok(false))),
+        None => false,
+    }
+}
+
 fn process_window_callback(
     win_id: usize,
     event_id: usize,
     name: String,
     hmap_s_f: Option<HashMap<String, f32>>,
     hmap_s_s: Option<HashMap<String, String>>,) 
+    -> Option<PyObject>
 {
-    let ud = access_user_data1();
-    let user_data_opt = ud.user_data.get(&win_id);
-
-    let app_event = access_events();
-    let event = match app_event.events.get(&(event_id, name.clone())) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_event);
-
-    Python::with_gil(|py| {
-        let res = match (user_data_opt, hmap_s_f, hmap_s_s) {
-            (Some(user_data), Some(hmap_f), Some(hmap_s)) => {
-                event.call1(py, (win_id, name, hmap_f, hmap_s, user_data))
-            }
-            (Some(user_data), Some(hmap_f), None) => {
-                event.call1(py, (win_id, name, hmap_f, user_data))
-            }
-            (Some(user_data), None, Some(hmap_s)) => {
-                event.call1(py, (win_id, name, hmap_s, user_data))
-            }
-            (Some(user_data), None, None) => {
-                event.call1(py, (win_id, name, user_data))
-            }
-            (None, Some(hmap_f), Some(hmap_s)) => {
-                event.call1(py, (win_id, name, hmap_f, hmap_s))
-            }
-            (None, Some(hmap_f), None) => {
-                event.call1(py, (win_id, name, hmap_f))
-            }
-            (None, None, Some(hmap_s)) => {
-                event.call1(py, (win_id, name, hmap_s))
-            }
-            (None, None, None) => {
-                event.call1(py, (win_id, name,))
-            }
-        };
-
-        if let Err(err) = res {
-            panic!("Window Event callback error: {err}");
-        }
-    });
-
-    drop(ud);
+    let (event, user_data) = get_event_callback(event_id, &name, win_id)?;
+
+    // the event name follows the window id in the args
+    let source = IpgEventSource::new("Window Event", win_id, &name).value_start(2);
+    match (hmap_s_f, hmap_s_s) {
+        (Some(hmap_f), Some(hmap_s)) => 
+            call_callback_returning(source, event, user_data, (win_id, &name, hmap_f, hmap_s)),
+        (Some(hmap_f), None) => 
+            call_callback_returning(source, event, user_data, (win_id, &name, hmap_f)),
+        (None, Some(hmap_s)) => 
+            call_callback_returning(source, event, user_data, (win_id, &name, hmap_s)),
+        (None, None) => 
+            call_callback_returning(source, event, user_data, (win_id, &name)),
+    }
 }
 
 
@@ -475,71 +525,25 @@ fn process_location(location: Location) -> String {
 fn process_keyboard_callback(
         id: usize,
         event_name: String, 
-        hmap_s_s: HashMap<String, String>,) 
-{   
-    let ud = access_user_data1();
-    let app_event = access_events();
-    
-    let user_data_opt = ud.user_data.get(&id);
-    
-    let event = 
-        match app_event.events.get(&(id, event_name)) {
-            Some(cb) => cb,
-            None => return,
-        };
-    
-    let cb = 
-        Python::with_gil(|py| {
-            event.clone_ref(py)
-        });
-    
-    drop(app_event);
-    
-    Python::with_gil(|py| {
-        let res = match user_data_opt {
-            Some(user_data) => cb.call1(py, (id, hmap_s_s, user_data)),
-            None => cb.call1(py, (id, hmap_s_s)),
-        };
-
-        if let Err(err) = res {
-            panic!("Keyboard Event callback error: {err}");
-        }
-    });
-    
-    drop(ud);      
-
+        hmap_s_v: HashMap<String, IpgItemValue>,) 
+{
+    dispatch_event("Keyboard Event", id, &event_name, id, (id, hmap_s_v));
 }
 
 fn process_mouse_callback(
         id: usize,
         event_name: String,
-        hmap_s_f: Option<HashMap<String, f32>>,) 
+        position: Option<Point>,
+        hmap_s_v: HashMap<String, IpgItemValue>,) 
 {
-    let ud = access_user_data1();
-    let user_data_opt = ud.user_data.get(&id);
-
-    let app_event = access_events();
-    let event = match app_event.events.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_event);
-
-    Python::with_gil(|py| {
-        let res = match (user_data_opt, hmap_s_f) {
-            (Some(user_data), Some(hmap)) => event.call1(py, (id, hmap, user_data)),
-            (None, Some(hmap)) => event.call1(py, (id, hmap)),
-            (Some(user_data), None) => event.call1(py, (id, user_data)),
-            (None, None) => event.call1(py, (id,)),
-        };
-
-        if let Err(err) = res {
-            panic!("Mouse Event callback error: {err}");
-        }
-    });
-
-    drop(ud);
+    // the enter, exit, press and release callbacks only get the dict
+    // with the event objects, the same as before the dict was added
+    let has_values = ["x", "other", "button"].iter().any(|key| hmap_s_v.contains_key(*key));
+    if has_values || event_objects() {
+        dispatch_event_at("Mouse Event", id, &event_name, id, position, (id, hmap_s_v));
+    } else {
+        dispatch_event_at("Mouse Event", id, &event_name, id, position, (id,));
+    }
 }
 
 fn process_touch_callback(
@@ -548,27 +552,27 @@ fn process_touch_callback(
     hmap_s_fg: HashMap<String, u64>,
     hmap_s_pt: HashMap<String, (f32, f32)>,) 
 {
-    let ud = access_user_data1();
-    let user_data_opt = ud.user_data.get(&id);
-
-    let app_event = access_events();
-    let event = match app_event.events.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_event);
-
-    Python::with_gil(|py| {
-        let res = match user_data_opt {
-            Some(user_data) => event.call1(py, (id, hmap_s_fg, hmap_s_pt, user_data)),
-            None => event.call1(py, (id, hmap_s_fg, hmap_s_pt)),
-        };
+    dispatch_event("Touch Event", id, &event_name, id, (id, hmap_s_fg, hmap_s_pt));
+}
 
-        if let Err(err) = res {
-            panic!("Touch Event callback error: {err}");
-        }
-    });
 
-    drop(ud);
-}
\ No newline at end of file
+#[test]
+fn test_double_and_triple_clicks() {
+    let window_id = window::Id::unique();
+    let mut input = IpgMouseInput::new(500);
+    input.positions.insert(window_id, Point::new(10.0, 10.0));
+
+    let start = Instant::now();
+    let at = |ms| start + Duration::from_millis(ms);
+    assert_eq!(1, input.click(at(0), window_id, mouse::Button::Left));
+    assert_eq!(2, input.click(at(200), window_id, mouse::Button::Left));
+    assert_eq!(3, input.click(at(400), window_id, mouse::Button::Left));
+    // counted from the start again after a triple click
+    assert_eq!(1, input.click(at(600), window_id, mouse::Button::Left));
+    // too slow, another button, or moved away
+    assert_eq!(1, input.click(at(1200), window_id, mouse::Button::Left));
+    assert_eq!(1, input.click(at(1300), window_id, mouse::Button::Right));
+    input.positions.insert(window_id, Point::new(30.0, 10.0));
+    assert_eq!(1, input.click(at(1400), window_id, mouse::Button::Right));
+    assert_eq!(2, input.click(at(1500), window_id, mouse::Button::Right));
+}
@@ -0,0 +1,131 @@
+//! ipg_focus
+//! The focusable widgets are given an iced id made from their ipg id
+//! so that the focused widget can be found with a widget operation.
+//! The tab order of each window and the text inputs with the
+//! on_focus and on_blur callbacks are kept here.
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, MutexGuard};
+
+use iced::advanced::widget::operation::{Focusable, Outcome};
+use iced::advanced::widget::{operate, Id, Operation};
+use iced::widget::text_input;
+use iced::{Rectangle, Task};
+use once_cell::sync::Lazy;
+
+
+#[derive(Debug)]
+pub struct Focus {
+    pub tab_orders: Lazy<HashMap<String, Vec<usize>>>, // <window_id, text input ids>
+    pub watched: Lazy<HashSet<usize>>,
+}
+
+pub static FOCUS: Mutex<Focus> = Mutex::new(Focus {
+    tab_orders: Lazy::new(||HashMap::new()),
+    watched: Lazy::new(||HashSet::new()),
+});
+
+pub fn access_focus() -> MutexGuard<'static, Focus> {
+    FOCUS.lock().unwrap()
+}
+
+/// An empty order goes back to the order of the widgets.
+pub fn set_tab_order(window_id: String, ids: Vec<usize>) {
+    let mut focus = access_focus();
+    if ids.is_empty() {
+        focus.tab_orders.remove(&window_id);
+    } else {
+        focus.tab_orders.insert(window_id, ids);
+    }
+}
+
+pub fn tab_order(window_id: &str) -> Option<Vec<usize>> {
+    access_focus().tab_orders.get(window_id).cloned()
+}
+
+/// The focus is only followed when a text input has on_focus or on_blur.
+pub fn watch_focus(id: usize) {
+    access_focus().watched.insert(id);
+}
+
+pub fn watching_focus() -> bool {
+    !access_focus().watched.is_empty()
+}
+
+/// Focuses the text input with the ipg id.
+pub fn focus_text_input<T: Send + 'static>(id: usize) -> Task<T> {
+    text_input::focus(id.to_string())
+}
+
+/// The next text input in the tab order after the focused one, going
+/// around at the end.  The focused one may not be in the order, then
+/// it starts at the first one, or the last one going backward.
+pub fn next_in_tab_order(order: &[usize], 
+                        focused: Option<usize>, 
+                        backward: bool, 
+                        can_focus: impl Fn(usize) -> bool) 
+                        -> Option<usize> {
+    let count = order.len();
+    let position = focused.and_then(|id| order.iter().position(|ord| *ord == id));
+    (1..=count)
+        .map(|step| match (position, backward) {
+            (Some(pos), false) => order[(pos + step) % count],
+            (Some(pos), true) => order[(pos + count - step) % count],
+            (None, false) => order[step - 1],
+            (None, true) => order[count - step],
+        })
+        .find(|id| Some(*id) != focused && can_focus(*id))
+}
+
+
+/// The iced id of a focusable widget.
+pub fn focus_id(id: usize) -> Id {
+    Id::new(id.to_string())
+}
+
+/// Finds the focused widgets, each window keeps its own focus so
+/// there can be one in each of them.
+pub fn find_focused() -> Task<Vec<Id>> {
+    operate(FindFocused { focused: vec![] })
+}
+
+struct FindFocused {
+    focused: Vec<Id>,
+}
+
+impl Operation<Vec<Id>> for FindFocused {
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        if let (true, Some(id)) = (state.is_focused(), id) {
+            self.focused.push(id.clone());
+        }
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Vec<Id>>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn finish(&self) -> Outcome<Vec<Id>> {
+        Outcome::Some(self.focused.clone())
+    }
+}
+
+
+#[test]
+fn test_next_in_tab_order() {
+    let order = [4, 2, 9];
+    let all = |_| true;
+    assert_eq!(Some(9), next_in_tab_order(&order, Some(2), false, all));
+    assert_eq!(Some(4), next_in_tab_order(&order, Some(9), false, all));
+    assert_eq!(Some(9), next_in_tab_order(&order, Some(4), true, all));
+    // nothing focused, or a widget that's not in the order
+    assert_eq!(Some(4), next_in_tab_order(&order, None, false, all));
+    assert_eq!(Some(9), next_in_tab_order(&order, Some(7), true, all));
+    // the hidden or disabled ones are skipped
+    assert_eq!(Some(4), next_in_tab_order(&order, Some(2), false, |id| id != 9));
+    assert_eq!(None, next_in_tab_order(&order, Some(2), false, |id| id == 2));
+    assert_eq!(None, next_in_tab_order(&[], None, false, all));
+}
@@ -0,0 +1,1048 @@
+//! ipg_gauge
+use std::f32::consts::PI;
+use std::time::Instant;
+
+use iced::mouse;
+use iced::widget::canvas::event::{self, Event};
+use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
+use iced::{alignment, Color, Element, Length, Point, Rectangle, Renderer, Size, Theme};
+use pyo3::{pyclass, PyObject, Python, PyResult};
+
+use crate::canvas::canvas_helpers::get_point_on_circle;
+use crate::canvas::path_builds::{build_arc_band_path, build_needle_path,
+    build_radial_ticks_path};
+use crate::graphics::colors::get_color;
+use crate::{app, IpgState};
+use super::callbacks::dispatch_callback;
+
+use super::callbacks::{set_or_get_widget_callback_data, WidgetCallbackIn};
+use super::helpers::{get_height, get_width, try_extract_boolean, try_extract_f64,
+    try_extract_string, try_extract_u64, try_extract_usize, 
+    item_fill, item_length, item_value, IpgItemValue, IpgItems};
+use super::ipg_chart::{format_tick, get_nice_ticks};
+use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
+
+// The radial gauge and knob sweep clockwise from the lower left
+// to the lower right, leaving the bottom open.
+const START_ANGLE: f32 = 0.75 * PI;
+const SWEEP_ANGLE: f32 = 1.5 * PI;
+
+
+#[derive(Debug, Clone)]
+pub struct IpgGauge {
+    pub id: usize,
+    pub parent_id: String,
+    pub show: bool,
+
+    pub min: f32,
+    pub max: f32,
+    pub value: f32,
+    pub bands: Vec<IpgGaugeBand>,
+    pub tick_count: usize,
+    pub minor_ticks: usize,
+    pub label: Option<String>,
+    pub units: String,
+    pub precision: usize,
+    pub needle_color: Option<Color>,
+    pub text_size: f32,
+    pub size: f32,
+    pub needle: IpgNeedleAnimation,
+}
+
+impl IpgGauge {
+    pub fn new(
+        id: usize,
+        parent_id: String,
+        show: bool,
+        min: f32,
+        max: f32,
+        value: f32,
+        bands: Vec<IpgGaugeBand>,
+        tick_count: usize,
+        minor_ticks: usize,
+        label: Option<String>,
+        units: String,
+        precision: usize,
+        needle_color: Option<Color>,
+        text_size: f32,
+        size: f32,
+        animation_ms: u64,
+    ) -> Self {
+        Self {
+            id,
+            parent_id,
+            show,
+            min,
+            max,
+            value,
+            bands,
+            tick_count,
+            minor_ticks,
+            label,
+            units,
+            precision,
+            needle_color,
+            text_size,
+            size,
+            needle: IpgNeedleAnimation::new(value, animation_ms),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IpgMeter {
+    pub id: usize,
+    pub parent_id: String,
+    pub show: bool,
+
+    pub min: f32,
+    pub max: f32,
+    pub value: f32,
+    pub bands: Vec<IpgGaugeBand>,
+    pub tick_count: usize,
+    pub minor_ticks: usize,
+    pub label: Option<String>,
+    pub units: String,
+    pub precision: usize,
+    pub bar_color: Option<Color>,
+    pub text_size: f32,
+    pub vertical: bool,
+    pub width: Length,
+    pub height: Length,
+    pub needle: IpgNeedleAnimation,
+}
+
+impl IpgMeter {
+    pub fn new(
+        id: usize,
+        parent_id: String,
+        show: bool,
+        min: f32,
+        max: f32,
+        value: f32,
+        bands: Vec<IpgGaugeBand>,
+        tick_count: usize,
+        minor_ticks: usize,
+        label: Option<String>,
+        units: String,
+        precision: usize,
+        bar_color: Option<Color>,
+        text_size: f32,
+        vertical: bool,
+        width: Length,
+        height: Length,
+        animation_ms: u64,
+    ) -> Self {
+        Self {
+            id,
+            parent_id,
+            show,
+            min,
+            max,
+            value,
+            bands,
+            tick_count,
+            minor_ticks,
+            label,
+            units,
+            precision,
+            bar_color,
+            text_size,
+            vertical,
+            width,
+            height,
+            needle: IpgNeedleAnimation::new(value, animation_ms),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IpgKnob {
+    pub id: usize,
+    pub parent_id: String,
+    pub show: bool,
+
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    pub value: f32,
+    pub bands: Vec<IpgGaugeBand>,
+    pub tick_count: usize,
+    pub label: Option<String>,
+    pub units: String,
+    pub precision: usize,
+    pub knob_color: Option<Color>,
+    pub text_size: f32,
+    pub size: f32,
+}
+
+impl IpgKnob {
+    pub fn new(
+        id: usize,
+        parent_id: String,
+        show: bool,
+        min: f32,
+        max: f32,
+        step: f32,
+        value: f32,
+        bands: Vec<IpgGaugeBand>,
+        tick_count: usize,
+        label: Option<String>,
+        units: String,
+        precision: usize,
+        knob_color: Option<Color>,
+        text_size: f32,
+        size: f32,
+    ) -> Self {
+        Self {
+            id,
+            parent_id,
+            show,
+            min,
+            max,
+            step,
+            value,
+            bands,
+            tick_count,
+            label,
+            units,
+            precision,
+            knob_color,
+            text_size,
+            size,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpgGaugeBand {
+    pub from: f32,
+    pub to: f32,
+    pub color: Color,
+}
+
+pub fn get_gauge_bands(bands: Option<Vec<(f32, f32, [f32; 4])>>) -> Vec<IpgGaugeBand> {
+    bands.unwrap_or_default()
+        .into_iter()
+        .map(|(from, to, rgba)| IpgGaugeBand {
+            from,
+            to,
+            color: get_color(Some(rgba), None, 1.0, false).unwrap(),
+        })
+        .collect()
+}
+
+// Moves the displayed value towards the value over the duration,
+// easing out so the needle settles rather than stops abruptly.
+#[derive(Debug, Clone)]
+pub struct IpgNeedleAnimation {
+    pub duration_ms: u64,
+    pub display_value: f32,
+    from_value: f32,
+    started: Option<Instant>,
+}
+
+impl IpgNeedleAnimation {
+    pub fn new(value: f32, duration_ms: u64) -> Self {
+        Self {
+            duration_ms,
+            display_value: value,
+            from_value: value,
+            started: None,
+        }
+    }
+
+    pub fn start(&mut self, value: f32, now: Instant) {
+        if self.duration_ms == 0 {
+            self.display_value = value;
+            self.started = None;
+        } else {
+            self.from_value = self.display_value;
+            self.started = Some(now);
+        }
+    }
+
+    pub fn step(&mut self, value: f32, now: Instant) {
+        let Some(started) = self.started else {
+            return
+        };
+        let t = (now.saturating_duration_since(started).as_millis() as f32
+                    / self.duration_ms as f32).min(1.0);
+        let eased = 1.0 - (1.0 - t).powi(3);
+        self.display_value = self.from_value + (value - self.from_value) * eased;
+        if t >= 1.0 {
+            self.display_value = value;
+            self.started = None;
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+}
+
+// Used by the subscription to only request frames while a needle is moving.
+pub fn gauges_animating(state: &IpgState) -> bool {
+    state.widgets.values().any(|widget| match widget {
+        IpgWidgets::IpgGauge(gauge) => gauge.needle.is_running(),
+        IpgWidgets::IpgMeter(meter) => meter.needle.is_running(),
+        _ => false,
+    })
+}
+
+pub fn animate_gauges(state: &mut IpgState, now: Instant) {
+    for widget in state.widgets.values_mut() {
+        match widget {
+            IpgWidgets::IpgGauge(gauge) => gauge.needle.step(gauge.value, now),
+            IpgWidgets::IpgMeter(meter) => meter.needle.step(meter.value, now),
+            _ => (),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum KnobMessage {
+    OnChange(f32),
+    OnRelease,
+}
+
+pub fn construct_gauge(gauge: &IpgGauge) -> Option<Element<app::Message>> {
+
+    if !gauge.show {
+        return None
+    }
+
+    Some(Canvas::new(gauge)
+            .width(gauge.size)
+            .height(gauge.size)
+            .into())
+}
+
+pub fn construct_meter(meter: &IpgMeter) -> Option<Element<app::Message>> {
+
+    if !meter.show {
+        return None
+    }
+
+    Some(Canvas::new(meter)
+            .width(meter.width)
+            .height(meter.height)
+            .into())
+}
+
+pub fn construct_knob(knob: &IpgKnob) -> Option<Element<app::Message>> {
+
+    if !knob.show {
+        return None
+    }
+
+    Some(Canvas::new(knob)
+            .width(knob.size)
+            .height(knob.size)
+            .into())
+}
+
+fn get_fraction(value: f32, min: f32, max: f32) -> f32 {
+    if max <= min {
+        return 0.0
+    }
+    ((value - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+fn get_angle(value: f32, min: f32, max: f32) -> f32 {
+    START_ANGLE + get_fraction(value, min, max) * SWEEP_ANGLE
+}
+
+// The values of the major ticks followed by the minor ones
+fn get_tick_values(min: f32, max: f32, tick_count: usize, minor_ticks: usize) -> (Vec<f32>, Vec<f32>) {
+    if tick_count < 2 {
+        return (vec![], vec![])
+    }
+    let step = (max - min) / (tick_count - 1) as f32;
+    let major: Vec<f32> = (0..tick_count).map(|i| min + step * i as f32).collect();
+    let minor_step = step / (minor_ticks + 1) as f32;
+    let minor = major.iter()
+        .take(tick_count - 1)
+        .flat_map(|m| (1..=minor_ticks).map(move |j| m + minor_step * j as f32))
+        .collect();
+    (major, minor)
+}
+
+fn get_band_color(bands: &[IpgGaugeBand], value: f32) -> Option<Color> {
+    bands.iter()
+        .find(|band| value >= band.from && value <= band.to)
+        .map(|band| band.color)
+}
+
+fn format_value(value: f32, precision: usize, units: &str) -> String {
+    if units.is_empty() {
+        format!("{:.prec$}", value, prec = precision)
+    } else {
+        format!("{:.prec$} {}", value, units, prec = precision)
+    }
+}
+
+fn get_tick_label(value: f32, min: f32, max: f32, tick_count: usize) -> String {
+    let step = ((max - min) / (tick_count.max(2) - 1) as f32) as f64;
+    format_tick(value as f64, step)
+}
+
+fn fill_label(frame: &mut Frame, content: String, position: Point, size: f32, color: Color) {
+    frame.fill_text(canvas::Text {
+        content,
+        position,
+        color,
+        size: size.into(),
+        horizontal_alignment: alignment::Horizontal::Center,
+        vertical_alignment: alignment::Vertical::Center,
+        ..canvas::Text::default()
+    });
+}
+
+impl canvas::Program<app::Message> for IpgGauge {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let text_color = theme.palette().text;
+        let radius = bounds.width.min(bounds.height) / 2.0 * 0.95;
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0 + radius * 0.05);
+
+        // the track with the threshold bands over it
+        frame.fill(
+            &build_arc_band_path(center, radius * 0.88, radius, START_ANGLE, START_ANGLE + SWEEP_ANGLE),
+            Color { a: 0.12, ..text_color });
+        for band in self.bands.iter() {
+            frame.fill(
+                &build_arc_band_path(center, radius * 0.88, radius,
+                    get_angle(band.from, self.min, self.max),
+                    get_angle(band.to, self.min, self.max)),
+                band.color);
+        }
+
+        let (major, minor) = get_tick_values(self.min, self.max, self.tick_count, self.minor_ticks);
+        let major_angles: Vec<f32> = major.iter().map(|v| get_angle(*v, self.min, self.max)).collect();
+        let minor_angles: Vec<f32> = minor.iter().map(|v| get_angle(*v, self.min, self.max)).collect();
+        frame.stroke(
+            &build_radial_ticks_path(center, radius * 0.74, radius * 0.86, &major_angles),
+            Stroke::default().with_width(2.0).with_color(text_color));
+        frame.stroke(
+            &build_radial_ticks_path(center, radius * 0.80, radius * 0.86, &minor_angles),
+            Stroke::default().with_width(1.0).with_color(text_color));
+
+        let tick_size = self.text_size * 0.85;
+        for (value, angle) in major.iter().zip(major_angles.iter()) {
+            fill_label(&mut frame,
+                get_tick_label(*value, self.min, self.max, self.tick_count),
+                get_point_on_circle(center, radius * 0.74 - tick_size, *angle),
+                tick_size,
+                text_color);
+        }
+
+        fill_label(&mut frame,
+            format_value(self.value, self.precision, &self.units),
+            Point::new(center.x, center.y + radius * 0.45),
+            self.text_size * 1.3,
+            text_color);
+
+        if let Some(label) = &self.label {
+            fill_label(&mut frame,
+                label.clone(),
+                Point::new(center.x, center.y + radius * 0.75),
+                self.text_size,
+                text_color);
+        }
+
+        let needle_color = self.needle_color.unwrap_or(theme.palette().primary);
+        frame.fill(
+            &build_needle_path(center, radius * 0.78, radius * 0.07,
+                get_angle(self.needle.display_value, self.min, self.max)),
+            needle_color);
+
+        vec![frame.into_geometry()]
+    }
+}
+
+impl canvas::Program<app::Message> for IpgMeter {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let text_color = theme.palette().text;
+        let tick_size = self.text_size * 0.85;
+        let label_space = if self.label.is_some() { self.text_size * 1.6 } else { 0.0 };
+
+        // the track runs along the long side, the ticks and labels beside it
+        let (track, along) = if self.vertical {
+            let thickness = (bounds.width * 0.3).min(24.0);
+            let track = Rectangle::new(
+                Point::new(tick_size * 4.0, tick_size),
+                Size::new(thickness, (bounds.height - tick_size * 2.0 - label_space).max(1.0)));
+            (track, track.height)
+        } else {
+            let thickness = (bounds.height * 0.3).min(24.0);
+            let track = Rectangle::new(
+                Point::new(tick_size * 2.0, label_space + tick_size * 0.5),
+                Size::new((bounds.width - tick_size * 4.0).max(1.0), thickness));
+            (track, track.width)
+        };
+
+        // position along the track for a value
+        let to_offset = |value: f32| get_fraction(value, self.min, self.max) * along;
+        let get_segment = |from: f32, to: f32| -> Rectangle {
+            let (a, b) = (to_offset(from), to_offset(to));
+            if self.vertical {
+                Rectangle::new(Point::new(track.x, track.y + track.height - b), Size::new(track.width, b - a))
+            } else {
+                Rectangle::new(Point::new(track.x + a, track.y), Size::new(b - a, track.height))
+            }
+        };
+
+        frame.fill(&Path::rectangle(track.position(), track.size()), Color { a: 0.12, ..text_color });
+        for band in self.bands.iter() {
+            let segment = get_segment(band.from, band.to);
+            frame.fill(&Path::rectangle(segment.position(), segment.size()), Color { a: 0.35, ..band.color });
+        }
+
+        let bar_color = get_band_color(&self.bands, self.needle.display_value)
+            .or(self.bar_color)
+            .unwrap_or(theme.palette().primary);
+        let bar = get_segment(self.min, self.needle.display_value.clamp(self.min, self.max));
+        frame.fill(&Path::rectangle(bar.position(), bar.size()), bar_color);
+
+        let (major, minor) = get_tick_values(self.min, self.max, self.tick_count, self.minor_ticks);
+        let tick_path = |values: &[f32], length: f32| Path::new(|p| {
+            for value in values.iter() {
+                let offset = to_offset(*value);
+                if self.vertical {
+                    let y = track.y + track.height - offset;
+                    p.move_to(Point::new(track.x + track.width, y));
+                    p.line_to(Point::new(track.x + track.width + length, y));
+                } else {
+                    let x = track.x + offset;
+                    p.move_to(Point::new(x, track.y + track.height));
+                    p.line_to(Point::new(x, track.y + track.height + length));
+                }
+            }
+        });
+        frame.stroke(&tick_path(&major, 8.0), Stroke::default().with_width(2.0).with_color(text_color));
+        frame.stroke(&tick_path(&minor, 4.0), Stroke::default().with_width(1.0).with_color(text_color));
+
+        for value in major.iter() {
+            let offset = to_offset(*value);
+            let position = if self.vertical {
+                Point::new(track.x + track.width + 10.0 + tick_size * 1.5, track.y + track.height - offset)
+            } else {
+                Point::new(track.x + offset, track.y + track.height + 10.0 + tick_size * 0.5)
+            };
+            fill_label(&mut frame,
+                get_tick_label(*value, self.min, self.max, self.tick_count),
+                position,
+                tick_size,
+                text_color);
+        }
+
+        if let Some(label) = &self.label {
+            let content = format!("{} {}", label, format_value(self.value, self.precision, &self.units));
+            let position = if self.vertical {
+                Point::new(bounds.width / 2.0, bounds.height - label_space / 2.0)
+            } else {
+                Point::new(bounds.width / 2.0, label_space / 2.0)
+            };
+            fill_label(&mut frame, content, position, self.text_size, text_color);
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+#[derive(Default)]
+pub struct KnobState {
+    // cursor y and the value when the drag started
+    drag: Option<(f32, f32)>,
+}
+
+impl IpgKnob {
+    fn snap(&self, value: f32) -> f32 {
+        let value = value.clamp(self.min, self.max);
+        if self.step > 0.0 {
+            (self.min + ((value - self.min) / self.step).round() * self.step).clamp(self.min, self.max)
+        } else {
+            value
+        }
+    }
+}
+
+impl canvas::Program<app::Message> for IpgKnob {
+    type State = KnobState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<app::Message>) {
+
+        let Event::Mouse(mouse_event) = event else {
+            return (event::Status::Ignored, None)
+        };
+
+        match mouse_event {
+            mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                match cursor.position_in(bounds) {
+                    Some(position) => {
+                        state.drag = Some((position.y, self.value));
+                        (event::Status::Captured, None)
+                    },
+                    None => (event::Status::Ignored, None),
+                }
+            },
+            mouse::Event::CursorMoved { position } => {
+                let Some((start_y, start_value)) = state.drag else {
+                    return (event::Status::Ignored, None)
+                };
+                // dragging up by 200 pixels covers the full range
+                let delta = (start_y - (position.y - bounds.y)) / 200.0 * (self.max - self.min);
+                let value = self.snap(start_value + delta);
+                if value != self.value {
+                    (event::Status::Captured,
+                        Some(app::Message::Knob(self.id, KnobMessage::OnChange(value))))
+                } else {
+                    (event::Status::Captured, None)
+                }
+            },
+            mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                if state.drag.take().is_some() {
+                    (event::Status::Captured, Some(app::Message::Knob(self.id, KnobMessage::OnRelease)))
+                } else {
+                    (event::Status::Ignored, None)
+                }
+            },
+            mouse::Event::WheelScrolled { delta } => {
+                if !cursor.is_over(bounds) {
+                    return (event::Status::Ignored, None)
+                }
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y.signum(),
+                    mouse::ScrollDelta::Pixels { y, .. } => y.signum(),
+                };
+                let increment = if self.step > 0.0 { self.step } else { (self.max - self.min) / 100.0 };
+                let value = self.snap(self.value + y * increment);
+                if value != self.value {
+                    (event::Status::Captured,
+                        Some(app::Message::Knob(self.id, KnobMessage::OnChange(value))))
+                } else {
+                    (event::Status::Captured, None)
+                }
+            },
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let palette = theme.extended_palette();
+        let text_color = theme.palette().text;
+        let radius = bounds.width.min(bounds.height) / 2.0 * 0.95;
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+        let angle = get_angle(self.value, self.min, self.max);
+
+        frame.fill(
+            &build_arc_band_path(center, radius * 0.86, radius, START_ANGLE, START_ANGLE + SWEEP_ANGLE),
+            Color { a: 0.12, ..text_color });
+        for band in self.bands.iter() {
+            frame.fill(
+                &build_arc_band_path(center, radius * 0.86, radius,
+                    get_angle(band.from, self.min, self.max),
+                    get_angle(band.to, self.min, self.max)),
+                Color { a: 0.35, ..band.color });
+        }
+        let value_color = get_band_color(&self.bands, self.value)
+            .unwrap_or(theme.palette().primary);
+        frame.fill(
+            &build_arc_band_path(center, radius * 0.86, radius, START_ANGLE, angle),
+            value_color);
+
+        let (major, _) = get_tick_values(self.min, self.max, self.tick_count, 0);
+        let major_angles: Vec<f32> = major.iter().map(|v| get_angle(*v, self.min, self.max)).collect();
+        frame.stroke(
+            &build_radial_ticks_path(center, radius * 0.74, radius * 0.82, &major_angles),
+            Stroke::default().with_width(1.5).with_color(text_color));
+
+        let body_color = self.knob_color.unwrap_or(palette.background.strong.color);
+        frame.fill(&Path::circle(center, radius * 0.68), body_color);
+        frame.stroke(
+            &Path::line(
+                get_point_on_circle(center, radius * 0.3, angle),
+                get_point_on_circle(center, radius * 0.62, angle)),
+            Stroke::default().with_width(3.0).with_color(palette.background.strong.text));
+
+        fill_label(&mut frame,
+            format_value(self.value, self.precision, &self.units),
+            Point::new(center.x, center.y + radius * 0.85),
+            self.text_size,
+            text_color);
+
+        if let Some(label) = &self.label {
+            fill_label(&mut frame,
+                label.clone(),
+                center,
+                self.text_size * 0.9,
+                palette.background.strong.text);
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+pub fn knob_callback(state: &mut IpgState, id: usize, message: KnobMessage) {
+
+    let mut wci: WidgetCallbackIn = WidgetCallbackIn{id, ..Default::default()};
+
+    match message {
+        KnobMessage::OnChange(value) => {
+            wci.value_f64 = Some(value as f64);
+            let _ = set_or_get_widget_callback_data(state, wci);
+            process_callback(id, "on_change".to_string(), value);
+        },
+        KnobMessage::OnRelease => {
+            let wco = set_or_get_widget_callback_data(state, wci);
+            process_callback(id, "on_release".to_string(), wco.value_f32.unwrap());
+        },
+    }
+}
+
+fn process_callback(
+        id: usize,
+        event_name: String,
+        value: f32)
+{
+    dispatch_callback("Knob", id, &event_name, (id, value));
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass(eq, eq_int)]
+pub enum IpgGaugeParam {
+    AnimationMs,
+    Bands,
+    Height,
+    HeightFill,
+    Label,
+    Max,
+    Min,
+    MinorTicks,
+    Precision,
+    Show,
+    Size,
+    Step,
+    TickCount,
+    Units,
+    Value,
+    Width,
+    WidthFill,
+}
+
+pub fn gauge_item_update(gauge: &mut IpgGauge,
+                        item: &PyObject,
+                        value: &PyObject,
+                        ) -> PyResult<()>
+{
+    let update = try_extract_gauge_update(item)?;
+    let name = "Gauge".to_string();
+    match update {
+        IpgGaugeParam::AnimationMs => {
+            gauge.needle.duration_ms = try_extract_u64(value, name)?;
+        },
+        IpgGaugeParam::Bands => {
+            gauge.bands = try_extract_gauge_bands(value)?;
+        },
+        IpgGaugeParam::Label => {
+            gauge.label = Some(try_extract_string(value, name)?);
+        },
+        IpgGaugeParam::Max => {
+            gauge.max = try_extract_f64(value, name)? as f32;
+        },
+        IpgGaugeParam::Min => {
+            gauge.min = try_extract_f64(value, name)? as f32;
+        },
+        IpgGaugeParam::MinorTicks => {
+            gauge.minor_ticks = try_extract_usize(value, name)?;
+        },
+        IpgGaugeParam::Precision => {
+            gauge.precision = try_extract_usize(value, name)?;
+        },
+        IpgGaugeParam::Show => {
+            gauge.show = try_extract_boolean(value, name)?;
+        },
+        IpgGaugeParam::Size => {
+            gauge.size = try_extract_f64(value, name)? as f32;
+        },
+        IpgGaugeParam::TickCount => {
+            gauge.tick_count = try_extract_usize(value, name)?;
+        },
+        IpgGaugeParam::Units => {
+            gauge.units = try_extract_string(value, name)?;
+        },
+        IpgGaugeParam::Value => {
+            gauge.value = try_extract_f64(value, name)? as f32;
+            gauge.needle.start(gauge.value, Instant::now());
+        },
+        _ => return Err(IpgParamError::new_err(format!("Gauge: {:?} is not a gauge parameter", update))),
+    }
+    Ok(())
+}
+
+pub fn meter_item_update(meter: &mut IpgMeter,
+                        item: &PyObject,
+                        value: &PyObject,
+                        ) -> PyResult<()>
+{
+    let update = try_extract_gauge_update(item)?;
+    let name = "Meter".to_string();
+    match update {
+        IpgGaugeParam::AnimationMs => {
+            meter.needle.duration_ms = try_extract_u64(value, name)?;
+        },
+        IpgGaugeParam::Bands => {
+            meter.bands = try_extract_gauge_bands(value)?;
+        },
+        IpgGaugeParam::Height => {
+            let val = try_extract_f64(value, name)?;
+            meter.height = get_height(Some(val as f32), false);
+        },
+        IpgGaugeParam::HeightFill => {
+            let val = try_extract_boolean(value, name)?;
+            meter.height = get_height(None, val);
+        },
+        IpgGaugeParam::Label => {
+            meter.label = Some(try_extract_string(value, name)?);
+        },
+        IpgGaugeParam::Max => {
+            meter.max = try_extract_f64(value, name)? as f32;
+        },
+        IpgGaugeParam::Min => {
+            meter.min = try_extract_f64(value, name)? as f32;
+        },
+        IpgGaugeParam::MinorTicks => {
+            meter.minor_ticks = try_extract_usize(value, name)?;
+        },
+        IpgGaugeParam::Precision => {
+            meter.precision = try_extract_usize(value, name)?;
+        },
+        IpgGaugeParam::Show => {
+            meter.show = try_extract_boolean(value, name)?;
+        },
+        IpgGaugeParam::TickCount => {
+            meter.tick_count = try_extract_usize(value, name)?;
+        },
+        IpgGaugeParam::Units => {
+            meter.units = try_extract_string(value, name)?;
+        },
+        IpgGaugeParam::Value => {
+            meter.value = try_extract_f64(value, name)? as f32;
+            meter.needle.start(meter.value, Instant::now());
+        },
+        IpgGaugeParam::Width => {
+            let val = try_extract_f64(value, name)?;
+            meter.width = get_width(Some(val as f32), false);
+        },
+        IpgGaugeParam::WidthFill => {
+            let val = try_extract_boolean(value, name)?;
+            meter.width = get_width(None, val);
+        },
+        _ => return Err(IpgParamError::new_err(format!("Meter: {:?} is not a meter parameter", update))),
+    }
+    Ok(())
+}
+
+pub fn knob_item_update(knob: &mut IpgKnob,
+                        item: &PyObject,
+                        value: &PyObject,
+                        ) -> PyResult<()>
+{
+    let update = try_extract_gauge_update(item)?;
+    let name = "Knob".to_string();
+    match update {
+        IpgGaugeParam::Bands => {
+            knob.bands = try_extract_gauge_bands(value)?;
+        },
+        IpgGaugeParam::Label => {
+            knob.label = Some(try_extract_string(value, name)?);
+        },
+        IpgGaugeParam::Max => {
+            knob.max = try_extract_f64(value, name)? as f32;
+        },
+        IpgGaugeParam::Min => {
+            knob.min = try_extract_f64(value, name)? as f32;
+        },
+        IpgGaugeParam::Precision => {
+            knob.precision = try_extract_usize(value, name)?;
+        },
+        IpgGaugeParam::Show => {
+            knob.show = try_extract_boolean(value, name)?;
+        },
+        IpgGaugeParam::Size => {
+            knob.size = try_extract_f64(value, name)? as f32;
+        },
+        IpgGaugeParam::Step => {
+            knob.step = try_extract_f64(value, name)? as f32;
+        },
+        IpgGaugeParam::TickCount => {
+            knob.tick_count = try_extract_usize(value, name)?;
+        },
+        IpgGaugeParam::Units => {
+            knob.units = try_extract_string(value, name)?;
+        },
+        IpgGaugeParam::Value => {
+            knob.value = try_extract_f64(value, name)? as f32;
+        },
+        _ => return Err(IpgParamError::new_err(format!("Knob: {:?} is not a knob parameter", update))),
+    }
+    Ok(())
+}
+
+pub fn gauge_get_items(gauge: &IpgGauge) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("AnimationMs", Some((gauge.needle.duration_ms as usize).into())),
+        ("Bands", item_gauge_bands(&gauge.bands)?),
+        ("Label", gauge.label.clone().map(Into::into)),
+        ("Max", Some(gauge.max.into())),
+        ("Min", Some(gauge.min.into())),
+        ("MinorTicks", Some(gauge.minor_ticks.into())),
+        ("Precision", Some(gauge.precision.into())),
+        ("Show", Some(gauge.show.into())),
+        ("Size", Some(gauge.size.into())),
+        ("TickCount", Some(gauge.tick_count.into())),
+        ("Units", Some(gauge.units.clone().into())),
+        ("Value", Some(gauge.value.into())),
+    ])
+}
+
+pub fn meter_get_items(meter: &IpgMeter) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("AnimationMs", Some((meter.needle.duration_ms as usize).into())),
+        ("Bands", item_gauge_bands(&meter.bands)?),
+        ("Height", item_length(meter.height)),
+        ("HeightFill", item_fill(meter.height)),
+        ("Label", meter.label.clone().map(Into::into)),
+        ("Max", Some(meter.max.into())),
+        ("Min", Some(meter.min.into())),
+        ("MinorTicks", Some(meter.minor_ticks.into())),
+        ("Precision", Some(meter.precision.into())),
+        ("Show", Some(meter.show.into())),
+        ("TickCount", Some(meter.tick_count.into())),
+        ("Units", Some(meter.units.clone().into())),
+        ("Value", Some(meter.value.into())),
+        ("Width", item_length(meter.width)),
+        ("WidthFill", item_fill(meter.width)),
+    ])
+}
+
+pub fn knob_get_items(knob: &IpgKnob) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Bands", item_gauge_bands(&knob.bands)?),
+        ("Label", knob.label.clone().map(Into::into)),
+        ("Max", Some(knob.max.into())),
+        ("Min", Some(knob.min.into())),
+        ("Precision", Some(knob.precision.into())),
+        ("Show", Some(knob.show.into())),
+        ("Size", Some(knob.size.into())),
+        ("Step", Some(knob.step.into())),
+        ("TickCount", Some(knob.tick_count.into())),
+        ("Units", Some(knob.units.clone().into())),
+        ("Value", Some(knob.value.into())),
+    ])
+}
+
+// The bands as given, a list of (from, to, rgba).
+fn item_gauge_bands(bands: &[IpgGaugeBand]) -> PyResult<Option<IpgItemValue>> {
+    let bands: Vec<(f32, f32, [f32; 4])> = bands.iter()
+        .map(|band| (band.from, band.to, [band.color.r, band.color.g, band.color.b, band.color.a]))
+        .collect();
+    item_value(bands)
+}
+
+pub fn try_extract_gauge_update(update_obj: &PyObject) -> PyResult<IpgGaugeParam> {
+
+    Python::with_gil(|py| {
+        let res = update_obj.extract::<IpgGaugeParam>(py);
+        match res {
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Gauge update extraction failed")),
+        }
+    })
+}
+
+fn try_extract_gauge_bands(value: &PyObject) -> PyResult<Vec<IpgGaugeBand>> {
+
+    Python::with_gil(|py| {
+        let res = value.extract::<Vec<(f32, f32, [f32; 4])>>(py);
+        match res {
+            Ok(bands) => Ok(get_gauge_bands(Some(bands))),
+            Err(_) => Err(IpgParamError::new_err("Gauge bands extraction failed, expected a list of (from, to, rgba)")),
+        }
+    })
+}
+
+
+#[test]
+fn test_needle_animation_settles() {
+    let start = Instant::now();
+    let mut needle = IpgNeedleAnimation::new(0.0, 100);
+    needle.start(10.0, start);
+    assert!(needle.is_running());
+
+    needle.step(10.0, start + std::time::Duration::from_millis(50));
+    // eased out, so more than half way at the half time
+    assert!(needle.display_value > 5.0 && needle.display_value < 10.0);
+
+    needle.step(10.0, start + std::time::Duration::from_millis(150));
+    assert_eq!(10.0, needle.display_value);
+    assert!(!needle.is_running());
+}
+
+#[test]
+fn test_get_tick_values() {
+    let (major, minor) = get_tick_values(0.0, 100.0, 5, 1);
+    assert_eq!(vec![0.0, 25.0, 50.0, 75.0, 100.0], major);
+    assert_eq!(vec![12.5, 37.5, 62.5, 87.5], minor);
+}
@@ -0,0 +1,386 @@
+//! ipg_gestures
+//! Recognizes pinch, rotate, pan, swipe and long press gestures from
+//! the raw touch events.  The recognizer only follows the fingers,
+//! it is used by the touch events and by the canvas.
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use iced::touch::{self, Finger};
+use iced::{Point, Vector};
+
+use super::helpers::IpgItemValue;
+
+
+// a finger has to move this far before it pans or stops a long press
+const MOVE_TOLERANCE: f32 = 10.0;
+// a lifted finger swipes when it went this far this fast
+const SWIPE_DISTANCE: f32 = 50.0;
+const SWIPE_SPEED: f32 = 0.3; // pixels per ms
+// the fingers have to spread or turn this much before it's a pinch or rotate
+const PINCH_TOLERANCE: f32 = 0.05;
+const ROTATE_TOLERANCE: f32 = 5.0; // degrees
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IpgSwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl IpgSwipeDirection {
+    pub fn name(&self) -> &'static str {
+        match self {
+            IpgSwipeDirection::Left => "left",
+            IpgSwipeDirection::Right => "right",
+            IpgSwipeDirection::Up => "up",
+            IpgSwipeDirection::Down => "down",
+        }
+    }
+}
+
+/// The scale, degrees and pan are the totals since the fingers
+/// last changed, the center is the middle of the fingers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IpgGesture {
+    Pinch { scale: f32, center: Point },
+    Rotate { degrees: f32, center: Point },
+    Pan { translation: Vector, center: Point, fingers: usize },
+    Swipe { direction: IpgSwipeDirection, speed: f32, position: Point },
+    LongPress { finger: u64, position: Point },
+}
+
+impl IpgGesture {
+    /// The event name and the dictionary sent to python.
+    pub fn values(&self) -> (&'static str, HashMap<String, IpgItemValue>) {
+        let mut values: HashMap<String, IpgItemValue> = HashMap::new();
+        let (name, position) = match self {
+            IpgGesture::Pinch { scale, center } => {
+                values.insert("scale".to_string(), (*scale).into());
+                ("pinch", center)
+            },
+            IpgGesture::Rotate { degrees, center } => {
+                values.insert("degrees".to_string(), (*degrees).into());
+                ("rotate", center)
+            },
+            IpgGesture::Pan { translation, center, fingers } => {
+                values.insert("dx".to_string(), translation.x.into());
+                values.insert("dy".to_string(), translation.y.into());
+                values.insert("fingers".to_string(), (*fingers).into());
+                ("pan", center)
+            },
+            IpgGesture::Swipe { direction, speed, position } => {
+                values.insert("direction".to_string(), direction.name().to_string().into());
+                values.insert("speed".to_string(), (*speed).into());
+                ("swipe", position)
+            },
+            IpgGesture::LongPress { finger, position } => {
+                values.insert("finger".to_string(), (*finger as usize).into());
+                ("long press", position)
+            },
+        };
+        values.insert("x".to_string(), position.x.into());
+        values.insert("y".to_string(), position.y.into());
+        (name, values)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IpgFinger {
+    start: Point,
+    position: Point,
+    pressed_at: Instant,
+    moved: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct IpgGestureStart {
+    center: Point,
+    distance: f32,
+    angle: f32,
+    rotation: f32,
+    panning: bool,
+    pinching: bool,
+    rotating: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct IpgGestures {
+    pub long_press_ms: u64,
+    fingers: HashMap<Finger, IpgFinger>,
+    start: IpgGestureStart,
+    // once two fingers touch or a long press fires, there's
+    // no swipe or long press until all of the fingers are up
+    multi: bool,
+    long_pressed: bool,
+}
+
+impl IpgGestures {
+    pub fn new(long_press_ms: u64) -> Self {
+        Self {
+            long_press_ms,
+            fingers: HashMap::new(),
+            start: IpgGestureStart::default(),
+            multi: false,
+            long_pressed: false,
+        }
+    }
+
+    /// Follows the fingers and returns any gestures they made.
+    pub fn process(&mut self, event: &touch::Event, now: Instant) -> Vec<IpgGesture> {
+        let mut gestures = vec![];
+        match *event {
+            touch::Event::FingerPressed { id, position } => {
+                self.fingers.insert(id, IpgFinger {
+                    start: position,
+                    position,
+                    pressed_at: now,
+                    moved: false,
+                });
+                if self.fingers.len() > 1 {
+                    self.multi = true;
+                }
+                self.restart();
+            },
+            touch::Event::FingerMoved { id, position } => {
+                let Some(finger) = self.fingers.get_mut(&id) else {
+                    return gestures
+                };
+                finger.position = position;
+                if finger.start.distance(position) > MOVE_TOLERANCE {
+                    finger.moved = true;
+                }
+                gestures.extend(self.long_press(now));
+                gestures.extend(self.moved());
+            },
+            touch::Event::FingerLifted { id, position } => {
+                let Some(finger) = self.fingers.remove(&id) else {
+                    return gestures
+                };
+                gestures.extend(self.long_press(now));
+                if self.fingers.is_empty() && !self.multi && !self.long_pressed {
+                    gestures.extend(swipe(&finger, position, now));
+                }
+                self.lifted();
+            },
+            touch::Event::FingerLost { id, .. } => {
+                if self.fingers.remove(&id).is_some() {
+                    self.lifted();
+                }
+            },
+        }
+        gestures
+    }
+
+    /// A single finger held still long enough, checked by
+    /// the events and by a time tick while it's pending.
+    pub fn long_press(&mut self, now: Instant) -> Option<IpgGesture> {
+        if !self.long_press_pending() {
+            return None
+        }
+        let (id, finger) = self.fingers.iter().next()?;
+        if now.duration_since(finger.pressed_at) < Duration::from_millis(self.long_press_ms) {
+            return None
+        }
+        self.long_pressed = true;
+        Some(IpgGesture::LongPress { finger: id.0, position: finger.position })
+    }
+
+    pub fn long_press_pending(&self) -> bool {
+        !self.multi && !self.long_pressed && self.fingers.len() == 1 &&
+            self.fingers.values().all(|finger| !finger.moved)
+    }
+
+    fn moved(&mut self) -> Vec<IpgGesture> {
+        let mut gestures = vec![];
+        let center = self.center();
+        let translation = center - self.start.center;
+        let fingers = self.fingers.len();
+
+        if !self.start.panning {
+            self.start.panning = translation.x.hypot(translation.y) > MOVE_TOLERANCE;
+        }
+        if self.start.panning {
+            gestures.push(IpgGesture::Pan { translation, center, fingers });
+        }
+
+        if let Some((distance, angle)) = self.spread() {
+            let scale = if self.start.distance > 0.0 {
+                distance / self.start.distance
+            } else {
+                1.0
+            };
+            // the turns are added up so going past 180 doesn't flip
+            self.start.rotation += angle_between(self.start.angle, angle);
+            self.start.angle = angle;
+
+            if !self.start.pinching {
+                self.start.pinching = (scale - 1.0).abs() > PINCH_TOLERANCE;
+            }
+            if self.start.pinching {
+                gestures.push(IpgGesture::Pinch { scale, center });
+            }
+            if !self.start.rotating {
+                self.start.rotating = self.start.rotation.abs() > ROTATE_TOLERANCE;
+            }
+            if self.start.rotating {
+                gestures.push(IpgGesture::Rotate { degrees: self.start.rotation, center });
+            }
+        }
+        gestures
+    }
+
+    fn lifted(&mut self) {
+        if self.fingers.is_empty() {
+            self.multi = false;
+            self.long_pressed = false;
+        }
+        self.restart();
+    }
+
+    // the totals start over whenever a finger is added or lifted
+    fn restart(&mut self) {
+        let (distance, angle) = self.spread().unwrap_or_default();
+        self.start = IpgGestureStart {
+            center: self.center(),
+            distance,
+            angle,
+            ..Default::default()
+        };
+        for finger in self.fingers.values_mut() {
+            finger.start = finger.position;
+        }
+    }
+
+    fn center(&self) -> Point {
+        if self.fingers.is_empty() {
+            return Point::ORIGIN
+        }
+        let count = self.fingers.len() as f32;
+        let (x, y) = self.fingers.values()
+            .fold((0.0, 0.0), |(x, y), finger| (x + finger.position.x, y + finger.position.y));
+        Point::new(x / count, y / count)
+    }
+
+    // the distance and angle between two fingers, a pinch
+    // or rotate needs exactly two
+    fn spread(&self) -> Option<(f32, f32)> {
+        if self.fingers.len() != 2 {
+            return None
+        }
+        let mut fingers: Vec<(&Finger, &IpgFinger)> = self.fingers.iter().collect();
+        fingers.sort_by_key(|(id, _)| id.0);
+        let delta = fingers[1].1.position - fingers[0].1.position;
+        Some((delta.x.hypot(delta.y), delta.y.atan2(delta.x).to_degrees()))
+    }
+}
+
+fn swipe(finger: &IpgFinger, position: Point, now: Instant) -> Option<IpgGesture> {
+    let delta = position - finger.start;
+    let distance = delta.x.hypot(delta.y);
+    let ms = now.duration_since(finger.pressed_at).as_secs_f32() * 1000.0;
+    let speed = distance / ms.max(1.0);
+    if distance < SWIPE_DISTANCE || speed < SWIPE_SPEED {
+        return None
+    }
+    let direction = if delta.x.abs() > delta.y.abs() {
+        if delta.x > 0.0 { IpgSwipeDirection::Right } else { IpgSwipeDirection::Left }
+    } else if delta.y > 0.0 {
+        IpgSwipeDirection::Down
+    } else {
+        IpgSwipeDirection::Up
+    };
+    Some(IpgGesture::Swipe { direction, speed, position })
+}
+
+// the smallest turn from one angle to the other, in degrees
+fn angle_between(from: f32, to: f32) -> f32 {
+    let mut degrees = (to - from) % 360.0;
+    if degrees > 180.0 {
+        degrees -= 360.0;
+    } else if degrees < -180.0 {
+        degrees += 360.0;
+    }
+    degrees
+}
+
+
+#[test]
+fn test_pinch_rotate_and_pan() {
+    let now = Instant::now();
+    let mut gestures = IpgGestures::new(500);
+    let (a, b) = (Finger(1), Finger(2));
+    gestures.process(&touch::Event::FingerPressed { id: a, position: Point::new(100.0, 100.0) }, now);
+    gestures.process(&touch::Event::FingerPressed { id: b, position: Point::new(200.0, 100.0) }, now);
+
+    // spreading the fingers apart is a pinch, the center ends up back in place
+    gestures.process(&touch::Event::FingerMoved { id: a, position: Point::new(50.0, 100.0) }, now);
+    let found = gestures.process(&touch::Event::FingerMoved { id: b, position: Point::new(250.0, 100.0) }, now);
+    assert!(found.contains(&IpgGesture::Pinch { scale: 2.0, center: Point::new(150.0, 100.0) }));
+    assert!(found.contains(&IpgGesture::Pan {
+        translation: Vector::ZERO, center: Point::new(150.0, 100.0), fingers: 2 }));
+    assert!(!found.iter().any(|g| matches!(g, IpgGesture::Rotate { .. })));
+
+    // turning a quarter around the center
+    gestures.process(&touch::Event::FingerMoved { id: a, position: Point::new(150.0, 0.0) }, now);
+    let found = gestures.process(&touch::Event::FingerMoved { id: b, position: Point::new(150.0, 200.0) }, now);
+    let degrees = found.iter().find_map(|g| match g {
+        IpgGesture::Rotate { degrees, .. } => Some(*degrees),
+        _ => None,
+    });
+    assert!((degrees.unwrap() - 90.0).abs() < 0.001);
+
+    // both fingers going down is a two finger pan
+    gestures.process(&touch::Event::FingerMoved { id: a, position: Point::new(150.0, 30.0) }, now);
+    let found = gestures.process(&touch::Event::FingerMoved { id: b, position: Point::new(150.0, 230.0) }, now);
+    assert!(found.contains(&IpgGesture::Pan {
+        translation: Vector::new(0.0, 30.0), center: Point::new(150.0, 130.0), fingers: 2 }));
+
+    // lifting one finger starts the totals over and is never a swipe
+    let found = gestures.process(&touch::Event::FingerLifted { id: b, position: Point::new(150.0, 230.0) }, now);
+    assert!(found.is_empty());
+    let found = gestures.process(&touch::Event::FingerLifted { id: a, position: Point::new(400.0, 30.0) }, now);
+    assert!(found.is_empty());
+}
+
+#[test]
+fn test_swipe_and_long_press() {
+    let now = Instant::now();
+    let at = |ms| now + Duration::from_millis(ms);
+    let mut gestures = IpgGestures::new(500);
+    let a = Finger(1);
+
+    // fast and far is a swipe
+    gestures.process(&touch::Event::FingerPressed { id: a, position: Point::new(100.0, 100.0) }, at(0));
+    gestures.process(&touch::Event::FingerMoved { id: a, position: Point::new(60.0, 110.0) }, at(50));
+    let found = gestures.process(&touch::Event::FingerLifted { id: a, position: Point::new(20.0, 110.0) }, at(100));
+    assert!(matches!(found.as_slice(),
+        [IpgGesture::Swipe { direction: IpgSwipeDirection::Left, .. }]));
+
+    // too slow is only a pan
+    gestures.process(&touch::Event::FingerPressed { id: a, position: Point::new(100.0, 100.0) }, at(1000));
+    let found = gestures.process(&touch::Event::FingerMoved { id: a, position: Point::new(100.0, 200.0) }, at(1400));
+    assert!(matches!(found.as_slice(), [IpgGesture::Pan { fingers: 1, .. }]));
+    let found = gestures.process(&touch::Event::FingerLifted { id: a, position: Point::new(100.0, 200.0) }, at(2000));
+    assert!(found.is_empty());
+
+    // held still, the long press fires once from the time tick
+    gestures.process(&touch::Event::FingerPressed { id: a, position: Point::new(100.0, 100.0) }, at(3000));
+    gestures.process(&touch::Event::FingerMoved { id: a, position: Point::new(104.0, 103.0) }, at(3100));
+    assert!(gestures.long_press_pending());
+    assert_eq!(None, gestures.long_press(at(3400)));
+    assert_eq!(Some(IpgGesture::LongPress { finger: 1, position: Point::new(104.0, 103.0) }),
+                gestures.long_press(at(3500)));
+    assert_eq!(None, gestures.long_press(at(3600)));
+    assert!(!gestures.long_press_pending());
+    // and the lift after it isn't a swipe
+    let found = gestures.process(&touch::Event::FingerLifted { id: a, position: Point::new(300.0, 103.0) }, at(3650));
+    assert!(found.is_empty());
+
+    // moving away stops the long press
+    gestures.process(&touch::Event::FingerPressed { id: a, position: Point::new(100.0, 100.0) }, at(5000));
+    gestures.process(&touch::Event::FingerMoved { id: a, position: Point::new(120.0, 100.0) }, at(5100));
+    assert!(!gestures.long_press_pending());
+    assert_eq!(None, gestures.long_press(at(6000)));
+}
@@ -0,0 +1,603 @@
+//! ipg_heatmap
+use std::cell::Cell;
+
+use iced::mouse;
+use iced::widget::canvas::event::{self, Event};
+use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
+use iced::{alignment, Color, Element, Length, Point, Rectangle, Renderer, Size, Theme};
+use polars::frame::DataFrame;
+use polars::prelude::DataType;
+use pyo3::{pyclass, PyObject, Python, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use crate::graphics::colormaps::{get_colormap_color, get_colormap_position, IpgColorMap};
+use crate::style::styling::readable;
+use crate::app;
+use super::callbacks::dispatch_callback;
+
+use super::helpers::{get_height, get_width, try_extract_boolean, try_extract_f64,
+    try_extract_f64_option, try_extract_usize, try_extract_vec_str, 
+    item_fill, item_length, item_object, item_value, IpgItems};
+use super::ipg_chart::{format_tick, get_nice_ticks, is_plottable};
+use super::ipg_errors::IpgParamError;
+
+
+#[derive(Debug, Clone)]
+pub struct IpgHeatmap {
+    pub id: usize,
+    pub parent_id: String,
+    pub show: bool,
+
+    // row major, missing values are NaN and left blank
+    pub values: Vec<Vec<f64>>,
+    pub row_labels: Option<Vec<String>>,
+    pub column_labels: Option<Vec<String>>,
+    pub row_label_column: Option<String>,
+    pub colormap: IpgColorMap,
+    pub vmin: Option<f64>,
+    pub vmax: Option<f64>,
+    pub center: Option<f64>,
+    pub show_values: bool,
+    pub precision: usize,
+    pub show_colorbar: bool,
+    pub text_size: f32,
+    pub width: Length,
+    pub height: Length,
+    // bumped on every change so the drawing cache is refreshed
+    pub version: u64,
+}
+
+impl IpgHeatmap {
+    pub fn new(
+        id: usize,
+        parent_id: String,
+        show: bool,
+        values: Vec<Vec<f64>>,
+        row_labels: Option<Vec<String>>,
+        column_labels: Option<Vec<String>>,
+        row_label_column: Option<String>,
+        colormap: IpgColorMap,
+        vmin: Option<f64>,
+        vmax: Option<f64>,
+        center: Option<f64>,
+        show_values: bool,
+        precision: usize,
+        show_colorbar: bool,
+        text_size: f32,
+        width: Length,
+        height: Length,
+    ) -> Self {
+        Self {
+            id,
+            parent_id,
+            show,
+            values,
+            row_labels,
+            column_labels,
+            row_label_column,
+            colormap,
+            vmin,
+            vmax,
+            center,
+            show_values,
+            precision,
+            show_colorbar,
+            text_size,
+            width,
+            height,
+            version: 0,
+        }
+    }
+
+    pub fn set_dataframe(&mut self, df: &DataFrame) -> Result<(), String> {
+        let (values, row_labels, column_labels) =
+            get_heatmap_values_from_df(df, self.row_label_column.as_deref())?;
+        self.values = values;
+        if row_labels.is_some() {
+            self.row_labels = row_labels;
+        }
+        self.column_labels = Some(column_labels);
+        self.version += 1;
+        Ok(())
+    }
+
+    // The range used for the colors, a diverging map is centered
+    // on zero when the values cross it and no center is given.
+    fn get_range(&self) -> (f64, f64, Option<f64>) {
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for value in self.values.iter().flatten() {
+            if value.is_finite() {
+                min = min.min(*value);
+                max = max.max(*value);
+            }
+        }
+        if !min.is_finite() {
+            min = 0.0;
+            max = 1.0;
+        }
+        let min = self.vmin.unwrap_or(min);
+        let max = self.vmax.unwrap_or(max);
+        let center = match self.center {
+            Some(center) => Some(center),
+            None if self.colormap.is_diverging() && min < 0.0 && max > 0.0 => Some(0.0),
+            None => None,
+        };
+        (min, max, center)
+    }
+
+    fn get_shape(&self) -> (usize, usize) {
+        let rows = self.values.len();
+        let columns = self.values.iter().map(|row| row.len()).max().unwrap_or(0);
+        (rows, columns)
+    }
+}
+
+// Each numeric column becomes a column of the grid, a string column
+// named by row_label_column supplies the row labels.
+pub fn get_heatmap_values_from_df(df: &DataFrame, row_label_column: Option<&str>)
+    -> Result<(Vec<Vec<f64>>, Option<Vec<String>>, Vec<String>), String> {
+
+    let mut columns: Vec<Vec<f64>> = vec![];
+    let mut column_labels = vec![];
+    let mut row_labels = None;
+
+    for column in df.get_columns() {
+        let series = column.as_materialized_series();
+        let name = series.name().to_string();
+
+        if Some(name.as_str()) == row_label_column {
+            let labels = match series.cast(&DataType::String) {
+                Ok(s) => s.str()
+                        .map_err(|e| format!("Heatmap: row label column {} could not be read, {}", name, e))?
+                        .into_iter()
+                        .map(|s| s.unwrap_or("").to_string())
+                        .collect(),
+                Err(e) => return Err(format!("Heatmap: row label column {} could not be read, {}", name, e)),
+            };
+            row_labels = Some(labels);
+            continue;
+        }
+
+        if !is_plottable(series.dtype()) {
+            return Err(format!("Heatmap: column {} is not numeric, found {}", name, series.dtype()))
+        }
+        let cast = match series.cast(&DataType::Float64) {
+            Ok(s) => s,
+            Err(e) => return Err(format!("Heatmap: column {} could not be converted to a float, {}", name, e)),
+        };
+        let values: Vec<f64> = match cast.f64() {
+            Ok(ca) => ca.into_iter().map(|v| v.unwrap_or(f64::NAN)).collect(),
+            Err(e) => return Err(format!("Heatmap: column {} could not be converted to a float, {}", name, e)),
+        };
+        columns.push(values);
+        column_labels.push(name);
+    }
+
+    // the dataframe is column major, the grid is row major
+    let rows = df.height();
+    let values = (0..rows)
+        .map(|r| columns.iter().map(|column| column[r]).collect())
+        .collect();
+
+    Ok((values, row_labels, column_labels))
+}
+
+#[derive(Debug, Clone)]
+pub enum HeatmapMessage {
+    OnHover(usize, usize, f64),
+}
+
+pub fn construct_heatmap(heatmap: &IpgHeatmap) -> Option<Element<app::Message>> {
+
+    if !heatmap.show {
+        return None
+    }
+
+    Some(Canvas::new(heatmap)
+            .width(heatmap.width)
+            .height(heatmap.height)
+            .into())
+}
+
+struct HeatmapLayout {
+    grid: Rectangle,
+    colorbar: Rectangle,
+    cell: Size,
+}
+
+fn get_layout(heatmap: &IpgHeatmap, size: Size) -> HeatmapLayout {
+    let (rows, columns) = heatmap.get_shape();
+    let char_width = heatmap.text_size * 0.6;
+
+    let left = match &heatmap.row_labels {
+        Some(labels) => labels.iter().map(|l| l.chars().count()).max().unwrap_or(0) as f32
+                        * char_width + 8.0,
+        None => 4.0,
+    };
+    let top = if heatmap.column_labels.is_some() { heatmap.text_size * 1.6 } else { 4.0 };
+    let right = if heatmap.show_colorbar { 24.0 + heatmap.text_size * 4.0 } else { 4.0 };
+
+    let grid = Rectangle::new(
+        Point::new(left, top),
+        Size::new((size.width - left - right).max(1.0), (size.height - top - 4.0).max(1.0)));
+
+    let colorbar = Rectangle::new(
+        Point::new(grid.x + grid.width + 12.0, grid.y),
+        Size::new(14.0, grid.height));
+
+    HeatmapLayout {
+        grid,
+        colorbar,
+        cell: Size::new(grid.width / columns.max(1) as f32, grid.height / rows.max(1) as f32),
+    }
+}
+
+#[derive(Default)]
+pub struct HeatmapState {
+    cache: canvas::Cache,
+    version: Cell<u64>,
+    hovered: Option<(usize, usize)>,
+}
+
+impl canvas::Program<app::Message> for IpgHeatmap {
+    type State = HeatmapState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<app::Message>) {
+
+        let Event::Mouse(mouse::Event::CursorMoved { .. }) = event else {
+            return (event::Status::Ignored, None)
+        };
+
+        let layout = get_layout(self, bounds.size());
+        let (rows, columns) = self.get_shape();
+
+        let hovered = match cursor.position_in(bounds) {
+            Some(position) if layout.grid.contains(position) => {
+                let row = (((position.y - layout.grid.y) / layout.cell.height) as usize).min(rows.max(1) - 1);
+                let column = (((position.x - layout.grid.x) / layout.cell.width) as usize).min(columns.max(1) - 1);
+                Some((row, column))
+            },
+            _ => None,
+        };
+
+        if hovered == state.hovered {
+            return (event::Status::Ignored, None)
+        }
+        state.hovered = hovered;
+
+        match hovered {
+            Some((row, column)) => {
+                let value = self.values.get(row)
+                    .and_then(|r| r.get(column))
+                    .copied()
+                    .unwrap_or(f64::NAN);
+                (event::Status::Ignored,
+                    Some(app::Message::Heatmap(self.id, HeatmapMessage::OnHover(row, column, value))))
+            },
+            None => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+
+        if state.version.get() != self.version {
+            state.version.set(self.version);
+            state.cache.clear();
+        }
+
+        let layout = get_layout(self, bounds.size());
+        let text_color = theme.palette().text;
+
+        let content = state.cache.draw(renderer, bounds.size(), |frame| {
+            draw_heatmap(self, &layout, frame, text_color);
+        });
+
+        let mut geometries = vec![content];
+
+        if let Some((row, column)) = state.hovered {
+            let mut frame = Frame::new(renderer, bounds.size());
+            let position = Point::new(
+                layout.grid.x + column as f32 * layout.cell.width,
+                layout.grid.y + row as f32 * layout.cell.height);
+            frame.stroke(
+                &Path::rectangle(position, layout.cell),
+                Stroke::default().with_width(2.0).with_color(text_color));
+            geometries.push(frame.into_geometry());
+        }
+
+        geometries
+    }
+}
+
+fn fill_label(frame: &mut Frame, content: String, position: Point, size: f32, color: Color,
+                horizontal_alignment: alignment::Horizontal) {
+    frame.fill_text(canvas::Text {
+        content,
+        position,
+        color,
+        size: size.into(),
+        horizontal_alignment,
+        vertical_alignment: alignment::Vertical::Center,
+        ..canvas::Text::default()
+    });
+}
+
+fn draw_heatmap(heatmap: &IpgHeatmap, layout: &HeatmapLayout, frame: &mut Frame, text_color: Color) {
+    let (min, max, center) = heatmap.get_range();
+    let grid = layout.grid;
+    let cell = layout.cell;
+    let cell_text_size = heatmap.text_size.min(cell.height * 0.6);
+
+    for (r, row) in heatmap.values.iter().enumerate() {
+        for (c, value) in row.iter().enumerate() {
+            if !value.is_finite() {
+                continue;
+            }
+            let color = get_colormap_color(heatmap.colormap,
+                get_colormap_position(*value, min, max, center));
+            let position = Point::new(grid.x + c as f32 * cell.width, grid.y + r as f32 * cell.height);
+            frame.fill_rectangle(position, cell, color);
+
+            if heatmap.show_values {
+                fill_label(frame,
+                    format!("{:.prec$}", value, prec = heatmap.precision),
+                    Point::new(position.x + cell.width / 2.0, position.y + cell.height / 2.0),
+                    cell_text_size,
+                    readable(color, Color::WHITE),
+                    alignment::Horizontal::Center);
+            }
+        }
+    }
+
+    if let Some(labels) = &heatmap.row_labels {
+        for (r, label) in labels.iter().enumerate() {
+            fill_label(frame,
+                label.clone(),
+                Point::new(grid.x - 6.0, grid.y + (r as f32 + 0.5) * cell.height),
+                heatmap.text_size,
+                text_color,
+                alignment::Horizontal::Right);
+        }
+    }
+
+    if let Some(labels) = &heatmap.column_labels {
+        for (c, label) in labels.iter().enumerate() {
+            fill_label(frame,
+                label.clone(),
+                Point::new(grid.x + (c as f32 + 0.5) * cell.width, grid.y - heatmap.text_size * 0.8),
+                heatmap.text_size,
+                text_color,
+                alignment::Horizontal::Center);
+        }
+    }
+
+    if heatmap.show_colorbar {
+        draw_colorbar(heatmap, layout.colorbar, (min, max, center), frame, text_color);
+    }
+}
+
+fn draw_colorbar(heatmap: &IpgHeatmap, bar: Rectangle, range: (f64, f64, Option<f64>),
+                frame: &mut Frame, text_color: Color) {
+    let (min, max, center) = range;
+    // the colorbar shows the same range the cells are mapped over
+    let (low, high) = match center {
+        Some(center) => {
+            let half = (max - center).abs().max((center - min).abs());
+            (center - half, center + half)
+        },
+        None => (min, max),
+    };
+
+    let steps = 64;
+    let step_height = bar.height / steps as f32;
+    for i in 0..steps {
+        // highest value at the top
+        let t = 1.0 - (i as f32 + 0.5) / steps as f32;
+        frame.fill_rectangle(
+            Point::new(bar.x, bar.y + i as f32 * step_height),
+            Size::new(bar.width, step_height + 0.5),
+            get_colormap_color(heatmap.colormap, t));
+    }
+    frame.stroke(
+        &Path::rectangle(bar.position(), bar.size()),
+        Stroke::default().with_width(1.0).with_color(Color { a: 0.5, ..text_color }));
+
+    if high <= low {
+        return
+    }
+    let tick_size = heatmap.text_size * 0.85;
+    let max_ticks = (bar.height / (tick_size * 2.5)).max(2.0) as usize;
+    let (ticks, step) = get_nice_ticks(low, high, max_ticks);
+    for tick in ticks.iter() {
+        let y = bar.y + bar.height - ((tick - low) / (high - low)) as f32 * bar.height;
+        frame.stroke(
+            &Path::line(Point::new(bar.x + bar.width, y), Point::new(bar.x + bar.width + 4.0, y)),
+            Stroke::default().with_width(1.0).with_color(text_color));
+        fill_label(frame,
+            format_tick(*tick, step),
+            Point::new(bar.x + bar.width + 6.0, y),
+            tick_size,
+            text_color,
+            alignment::Horizontal::Left);
+    }
+}
+
+pub fn heatmap_callback(id: usize, message: HeatmapMessage) {
+    match message {
+        HeatmapMessage::OnHover(row, column, value) => {
+            process_callback(id, "on_hover".to_string(), (row, column, value));
+        },
+    }
+}
+
+fn process_callback(
+        id: usize,
+        event_name: String,
+        value: (usize, usize, f64))
+{
+    dispatch_callback("Heatmap", id, &event_name, (id, value));
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass(eq, eq_int)]
+pub enum IpgHeatmapParam {
+    Center,
+    ColorMap,
+    ColumnLabels,
+    Height,
+    HeightFill,
+    PolarsDf,
+    Precision,
+    RowLabels,
+    Show,
+    ShowColorbar,
+    ShowValues,
+    Values,
+    VMax,
+    VMin,
+    Width,
+    WidthFill,
+}
+
+pub fn heatmap_item_update(heatmap: &mut IpgHeatmap,
+                            item: &PyObject,
+                            value: &PyObject,
+                            ) -> PyResult<()>
+{
+    let update = try_extract_heatmap_update(item)?;
+    let name = "Heatmap".to_string();
+    match update {
+        IpgHeatmapParam::Center => {
+            heatmap.center = try_extract_f64_option(value);
+        },
+        IpgHeatmapParam::ColorMap => {
+            heatmap.colormap = Python::with_gil(|py| {
+                match value.extract::<IpgColorMap>(py) {
+                    Ok(colormap) => Ok(colormap),
+                    Err(_) => Err(IpgParamError::new_err("Heatmap: colormap extraction failed")),
+                }
+            })?;
+        },
+        IpgHeatmapParam::ColumnLabels => {
+            heatmap.column_labels = Some(try_extract_vec_str(value, name)?);
+        },
+        IpgHeatmapParam::Height => {
+            let val = try_extract_f64(value, name)?;
+            heatmap.height = get_height(Some(val as f32), false);
+        },
+        IpgHeatmapParam::HeightFill => {
+            let val = try_extract_boolean(value, name)?;
+            heatmap.height = get_height(None, val);
+        },
+        IpgHeatmapParam::PolarsDf => {
+            return Err(IpgParamError::new_err("Heatmap: use update_dataframe to change the dataframe"))
+        },
+        IpgHeatmapParam::Precision => {
+            heatmap.precision = try_extract_usize(value, name)?;
+        },
+        IpgHeatmapParam::RowLabels => {
+            heatmap.row_labels = Some(try_extract_vec_str(value, name)?);
+        },
+        IpgHeatmapParam::Show => {
+            heatmap.show = try_extract_boolean(value, name)?;
+        },
+        IpgHeatmapParam::ShowColorbar => {
+            heatmap.show_colorbar = try_extract_boolean(value, name)?;
+        },
+        IpgHeatmapParam::ShowValues => {
+            heatmap.show_values = try_extract_boolean(value, name)?;
+        },
+        IpgHeatmapParam::Values => {
+            heatmap.values = try_extract_heatmap_values(value)?;
+        },
+        IpgHeatmapParam::VMax => {
+            heatmap.vmax = try_extract_f64_option(value);
+        },
+        IpgHeatmapParam::VMin => {
+            heatmap.vmin = try_extract_f64_option(value);
+        },
+        IpgHeatmapParam::Width => {
+            let val = try_extract_f64(value, name)?;
+            heatmap.width = get_width(Some(val as f32), false);
+        },
+        IpgHeatmapParam::WidthFill => {
+            let val = try_extract_boolean(value, name)?;
+            heatmap.width = get_width(None, val);
+        },
+    }
+    heatmap.version += 1;
+    Ok(())
+}
+
+pub fn heatmap_get_items(heatmap: &IpgHeatmap) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Center", heatmap.center.map(|center| (center as f32).into())),
+        ("ColorMap", item_object(heatmap.colormap)?),
+        ("ColumnLabels", heatmap.column_labels.clone().map(Into::into)),
+        ("Height", item_length(heatmap.height)),
+        ("HeightFill", item_fill(heatmap.height)),
+        ("Precision", Some(heatmap.precision.into())),
+        ("RowLabels", heatmap.row_labels.clone().map(Into::into)),
+        ("Show", Some(heatmap.show.into())),
+        ("ShowColorbar", Some(heatmap.show_colorbar.into())),
+        ("ShowValues", Some(heatmap.show_values.into())),
+        ("Values", item_value(heatmap.values.clone())?),
+        ("VMax", heatmap.vmax.map(|vmax| (vmax as f32).into())),
+        ("VMin", heatmap.vmin.map(|vmin| (vmin as f32).into())),
+        ("Width", item_length(heatmap.width)),
+        ("WidthFill", item_fill(heatmap.width)),
+    ])
+}
+
+pub fn heatmap_dataframe_update(heatmap: &mut IpgHeatmap,
+                                item: &PyObject,
+                                value: &PyDataFrame,
+                                ) -> PyResult<()>
+{
+    let update = try_extract_heatmap_update(item)?;
+    if update == IpgHeatmapParam::PolarsDf {
+        let df: DataFrame = value.clone().into();
+        heatmap.set_dataframe(&df).map_err(IpgParamError::new_err)?;
+    }
+    Ok(())
+}
+
+pub fn try_extract_heatmap_update(update_obj: &PyObject) -> PyResult<IpgHeatmapParam> {
+
+    Python::with_gil(|py| {
+        let res = update_obj.extract::<IpgHeatmapParam>(py);
+        match res {
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Heatmap update extraction failed")),
+        }
+    })
+}
+
+// None in python becomes NaN and is left blank
+pub fn try_extract_heatmap_values(value: &PyObject) -> PyResult<Vec<Vec<f64>>> {
+
+    Python::with_gil(|py| {
+        let res = value.extract::<Vec<Vec<Option<f64>>>>(py);
+        match res {
+            Ok(rows) => Ok(rows.into_iter()
+                .map(|row| row.into_iter().map(|v| v.unwrap_or(f64::NAN)).collect())
+                .collect()),
+            Err(_) => Err(IpgParamError::new_err("Heatmap values extraction failed, expected a list of lists of floats")),
+        }
+    })
+}
@@ -2,15 +2,15 @@
 #![allow(clippy::enum_variant_names)]
 use std::collections::HashMap;
 
-use crate::access_user_data1;
-use crate::access_user_data2;
+use super::callbacks::{dispatch_callback, dispatch_callback_at};
 use crate::app;
-use crate::access_callbacks;
 use super::helpers::{get_height, get_padding_f64, get_width, 
     try_extract_boolean, try_extract_f64, try_extract_string, 
-    try_extract_vec_f64};
+    try_extract_vec_f64, 
+    item_fill, item_length, item_padding, IpgItems};
 use super::ipg_mousearea::get_interaction;
 use super::ipg_mousearea::IpgMousePointer;
+use super::ipg_errors::IpgParamError;
     
 use iced::widget::image::FilterMethod;
 use iced::{Length, Element, Padding, Point, Radians, Rotation};
@@ -19,7 +19,7 @@ use iced::mouse::Interaction;
 use iced::advanced::image;
 
 use pyo3::pyclass;
-use pyo3::{PyObject, Python};
+use pyo3::{PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -218,69 +218,13 @@ fn process_callback(
     event_name: String,
     points_opt: Option<HashMap<String, f32>>,
 ) {
-    let ud1 = access_user_data1();
-    let ud_opt = ud1.user_data.get(&id);
-
-    let app_cbs = access_callbacks();
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => cb,
-        None => return,
-    };
-
-    let cb = Python::with_gil(|py| callback.clone_ref(py));
-    drop(app_cbs);
-
-    // Execute the callback with user data from ud1
-    if let Some(user_data) = ud_opt {
-        Python::with_gil(|py| {
-            let res = match points_opt {
-                Some(ref points) => cb.call1(py, (id, points.clone(), user_data)),
-                None => cb.call1(py, (id, user_data)),
-            };
-
-            match res {
-                Ok(_) => (),
-                Err(err) => panic!("Image callback error with user_data from ud1: {err}")
-            }
-        });
-        drop(ud1); // Drop ud1 after processing
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Execute the callback with user data from ud2
-    let ud2 = access_user_data2();
-    
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            let res = match points_opt {
-                Some(ref points) => cb.call1(py, (id, points.clone(), user_data)),
-                None => cb.call1(py, (id, user_data)),
-            };
-
-            match res {
-                Ok(_) => (),
-                Err(err) => panic!("Image callback error with user_data from ud2: {err}")
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
+    match points_opt {
+        Some(points) => {
+            let position = Some(Point::new(points["x"], points["y"]));
+            dispatch_callback_at("Image", id, &event_name, position, (id, points))
+        },
+        None => dispatch_callback("Image", id, &event_name, (id,)),
     }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // Execute the callback without user data
-    Python::with_gil(|py| {
-        let res = match points_opt {
-                Some(ref points) => cb.call1(py, (id, points.clone())),
-                None => cb.call1(py, (id,)),
-            };
-
-            match res {
-                Ok(_) => (),
-                Err(err) => panic!("Image callback error without user_data: {err}")
-            }
-    });
-
 }
 
 
@@ -302,55 +246,70 @@ pub enum IpgImageParam {
 pub fn image_item_update(img: &mut IpgImage,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_button_update(item);
+    let update = try_extract_button_update(item)?;
     let name = "Image".to_string();
     match update {
         IpgImageParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             img.height = get_height(Some(val as f32), false);
         },
         IpgImageParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             img.height = get_height(None, val);
         },
         IpgImageParam::ImagePath => {
-            img.image_path = try_extract_string(value, name);
+            img.image_path = try_extract_string(value, name)?;
         },
         IpgImageParam::Padding => {
-            let val = try_extract_vec_f64(value, name);
-            img.padding =  get_padding_f64(val);
+            let val = try_extract_vec_f64(value, name)?;
+            img.padding =  get_padding_f64(val)?;
         },
         IpgImageParam::Show => {
-            img.show = try_extract_boolean(value, name);
+            img.show = try_extract_boolean(value, name)?;
         },
         IpgImageParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             img.width = get_width(Some(val as f32), false);
         },
         IpgImageParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             img.width = get_width(None, val);
         },
         IpgImageParam::RotationRadians => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             img.rotation_radians = val as f32;
         },
         IpgImageParam::Opacity => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             img.opacity = val as f32;
         },
     }
+    Ok(())
+}
+
+pub fn image_get_items(img: &IpgImage) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Height", item_length(img.height)),
+        ("HeightFill", item_fill(img.height)),
+        ("ImagePath", Some(img.image_path.clone().into())),
+        ("Padding", item_padding(img.padding)),
+        ("Show", Some(img.show.into())),
+        ("Width", item_length(img.width)),
+        ("WidthFill", item_fill(img.width)),
+        ("RotationRadians", Some(img.rotation_radians.into())),
+        ("Opacity", Some(img.opacity.into())),
+    ])
 }
 
-pub fn try_extract_button_update(update_obj: &PyObject) -> IpgImageParam {
+pub fn try_extract_button_update(update_obj: &PyObject) -> PyResult<IpgImageParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgImageParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Image update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Image update extraction failed")),
         }
     })
 }
\ No newline at end of file
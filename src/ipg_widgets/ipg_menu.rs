@@ -13,11 +13,13 @@ use crate::iced_aw_widgets::menu::menu_bar_style::{primary, Style};
 
 
 use crate::app;
-use super::helpers::{get_height, get_padding_f64, get_radius, get_width, try_extract_array_2, try_extract_boolean, try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, try_extract_vec_f32, try_extract_vec_f64};
+use super::helpers::{get_height, get_padding_f64, get_radius, get_width, try_extract_array_2, try_extract_boolean, try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, try_extract_vec_f32, try_extract_vec_f64, 
+    item_fill, item_length, item_padding, IpgItems};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 #[derive(Debug, Clone)]
 pub struct IpgMenu {
@@ -391,13 +393,13 @@ pub enum IpgMenuParam {
 pub fn menu_item_update(mn: &mut IpgMenu,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_menu_update(item);
+    let update = try_extract_menu_update(item)?;
     let name = "Menu".to_string();
     match update {
         IpgMenuParam::BarHeight => {
-            let val = try_extract_f64(value, name) as f32;
+            let val = try_extract_f64(value, name)? as f32;
             if val < 32.0 {
                 mn.bar_height = get_height(None, false);
             } else {
@@ -405,37 +407,49 @@ pub fn menu_item_update(mn: &mut IpgMenu,
             }
         },
         IpgMenuParam::BarHeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             mn.bar_height = get_height(None, val);
         },
         IpgMenuParam::BarPadding => {
-            let val = try_extract_vec_f64(value, name);
-            mn.bar_padding = get_padding_f64(val);
+            let val = try_extract_vec_f64(value, name)?;
+            mn.bar_padding = get_padding_f64(val)?;
         },
         IpgMenuParam::BarSpacing => {
-            mn.bar_spacing = try_extract_f64(value, name) as f32;
+            mn.bar_spacing = try_extract_f64(value, name)? as f32;
         },
         IpgMenuParam::BarWidth => {
-            let width = get_width(Some(try_extract_f64(value, name) as f32), false);
+            let width = get_width(Some(try_extract_f64(value, name)? as f32), false);
             mn.bar_width = width;
         },
         IpgMenuParam::CheckBoundsWidth => {
-            mn.check_bounds_width = try_extract_f64(value, name) as f32;
+            mn.check_bounds_width = try_extract_f64(value, name)? as f32;
         },
         IpgMenuParam::Show => {
-            mn.show = try_extract_boolean(value, name);
+            mn.show = try_extract_boolean(value, name)?;
         },
     }
+    Ok(())
+}
 
+pub fn menu_get_items(mn: &IpgMenu) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("BarHeight", item_length(mn.bar_height)),
+        ("BarHeightFill", item_fill(mn.bar_height)),
+        ("BarPadding", item_padding(mn.bar_padding)),
+        ("BarSpacing", Some(mn.bar_spacing.into())),
+        ("BarWidth", item_length(mn.bar_width)),
+        ("CheckBoundsWidth", Some(mn.check_bounds_width.into())),
+        ("Show", Some(mn.show.into())),
+    ])
 }
 
-pub fn try_extract_menu_update(update_obj: &PyObject) -> IpgMenuParam {
+pub fn try_extract_menu_update(update_obj: &PyObject) -> PyResult<IpgMenuParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgMenuParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Menu update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Menu update extraction failed")),
         }
     })
 }
@@ -457,45 +471,46 @@ pub enum IpgMenuBarStyleParam {
 
 pub fn menu_bar_style_update_item(style: &mut IpgMenuBarStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_menu_bar_style_update(item);
+    let update = try_extract_menu_bar_style_update(item)?;
     let name = "BarStyle".to_string();
     match update {
         IpgMenuBarStyleParam::BaseIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.base_color = get_color(None, Some(color), 1.0, false);
         },
         IpgMenuBarStyleParam::BaseRgbaColor => {
-            style.base_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.base_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgMenuBarStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgMenuBarStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgMenuBarStyleParam::BorderRadius => {
-            style.border_radius = Some(try_extract_vec_f32(value, name));
+            style.border_radius = Some(try_extract_vec_f32(value, name)?);
         },
         IpgMenuBarStyleParam::BorderWidth => {
-            style.border_width = Some(try_extract_f64(value, name) as f32);
+            style.border_width = Some(try_extract_f64(value, name)? as f32);
         },
         IpgMenuBarStyleParam::ShadowIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.shadow_color = get_color(None, Some(color), 1.0, false);
         },
         IpgMenuBarStyleParam::ShadowRgbaColor => {
-            style.shadow_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.shadow_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgMenuBarStyleParam::ShadowOffsetXY => {
-            style.shadow_offset_xy = Some(try_extract_array_2(value, name));
+            style.shadow_offset_xy = Some(try_extract_array_2(value, name)?);
         },
         IpgMenuBarStyleParam::ShadowBlurRadius => {
-            style.shadow_blur_radius = Some(try_extract_f64(value, name) as f32);
+            style.shadow_blur_radius = Some(try_extract_f64(value, name)? as f32);
         },
     }
+    Ok(())
 }
 
 fn get_menu_bar_style(style: Option<&IpgWidgets>) -> Option<IpgMenuBarStyle>{
@@ -507,13 +522,13 @@ fn get_menu_bar_style(style: Option<&IpgWidgets>) -> Option<IpgMenuBarStyle>{
     }
 }
 
-pub fn try_extract_menu_bar_style_update(update_obj: &PyObject) -> IpgMenuBarStyleParam {
+pub fn try_extract_menu_bar_style_update(update_obj: &PyObject) -> PyResult<IpgMenuBarStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgMenuBarStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Menu Bar style parameter update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Menu Bar style parameter update extraction failed")),
         }
     })
 }
@@ -541,65 +556,66 @@ pub enum IpgMenuStyleParam {
 
 pub fn menu_style_update_item(style: &mut IpgMenuStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_menu_style_update(item);
+    let update = try_extract_menu_style_update(item)?;
     let name = "MenuStyle".to_string();
     match update {
         IpgMenuStyleParam::BaseIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.base_color = get_color(None, Some(color), 1.0, false);
         },
         IpgMenuStyleParam::BaseRgbaColor => {
-            style.base_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.base_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgMenuStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgMenuStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgMenuStyleParam::BorderRadius => {
-            style.border_radius = Some(try_extract_vec_f32(value, name));
+            style.border_radius = Some(try_extract_vec_f32(value, name)?);
         },
         IpgMenuStyleParam::BorderWidth => {
-            style.border_width = Some(try_extract_f64(value, name) as f32);
+            style.border_width = Some(try_extract_f64(value, name)? as f32);
         },
         IpgMenuStyleParam::ShadowIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.shadow_color = get_color(None, Some(color), 1.0, false);
         },
         IpgMenuStyleParam::ShadowRgbaColor => {
-            style.shadow_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.shadow_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgMenuStyleParam::ShadowOffsetXY => {
-            style.shadow_offset_xy = Some(try_extract_array_2(value, name));
+            style.shadow_offset_xy = Some(try_extract_array_2(value, name)?);
         },
         IpgMenuStyleParam::ShadowBlurRadius => {
-            style.shadow_blur_radius = Some(try_extract_f64(value, name) as f32);
+            style.shadow_blur_radius = Some(try_extract_f64(value, name)? as f32);
         },
         IpgMenuStyleParam::PathBaseIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.path_base_color = get_color(None, Some(color), 1.0, false);
         },
         IpgMenuStyleParam::PathBaseRgbaColor => {
-            style.path_base_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.path_base_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgMenuStyleParam::PathBorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.path_border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgMenuStyleParam::PathBorderRgbaColor => {
-            style.path_border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.path_border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgMenuStyleParam::PathBorderRadius => {
-            style.path_border_radius = Some(try_extract_vec_f32(value, name));
+            style.path_border_radius = Some(try_extract_vec_f32(value, name)?);
         },
         IpgMenuStyleParam::PathBorderWidth => {
-            style.path_border_width = Some(try_extract_f64(value, name) as f32);
+            style.path_border_width = Some(try_extract_f64(value, name)? as f32);
         },
     }
+    Ok(())
 }
 
 fn get_menu_style(style: Option<&IpgWidgets>) -> Option<IpgMenuStyle>{
@@ -611,13 +627,13 @@ fn get_menu_style(style: Option<&IpgWidgets>) -> Option<IpgMenuStyle>{
     }
 }
 
-pub fn try_extract_menu_style_update(update_obj: &PyObject) -> IpgMenuStyleParam {
+pub fn try_extract_menu_style_update(update_obj: &PyObject) -> PyResult<IpgMenuStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgMenuStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Menu style parameter update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Menu style parameter update extraction failed")),
         }
     })
 }
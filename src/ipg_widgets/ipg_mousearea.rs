@@ -1,7 +1,9 @@
 //! ipg_mousearea
-use crate::{access_callbacks, access_user_data1, access_user_data2, IpgState};
+use crate::IpgState;
+use super::callbacks::{dispatch_callback, dispatch_callback_at};
 use crate::app::Message;
-use super::helpers::try_extract_boolean;
+use super::helpers::{try_extract_boolean, IpgItems};
+use super::ipg_errors::IpgParamError;
 
 use iced::widget::MouseArea;
 use iced::{Element, Point};
@@ -9,7 +11,7 @@ use iced::widget::Column;
 use iced::mouse::Interaction;
 
 use pyo3::pyclass;
-use pyo3::{PyObject, Python};
+use pyo3::{PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -122,69 +124,13 @@ fn process_callback(
     event_name: String, 
     points_opt: Option<(String, f32, String, f32)>) 
 {
-    let ud1 = access_user_data1();
-    let ud_opt = ud1.user_data.get(&id);
-
-    let app_cbs = access_callbacks();
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => cb,
-        None => return,
-    };
-
-    let cb = Python::with_gil(|py| callback.clone_ref(py));
-    drop(app_cbs);
-
-    // Execute the callback with user data from ud1
-    if let Some(user_data) = ud_opt {
-        Python::with_gil(|py| {
-            let res = match points_opt {
-                Some(ref points) => cb.call1(py, (id, points.clone(), user_data)),
-                None => cb.call1(py, (id, user_data)),
-            };
-
-            match res {
-                Ok(_) => (),
-                Err(err) => panic!("MouseArea callback error with user_data from ud1: {err}")
-            }
-        });
-        drop(ud1); // Drop ud1 after processing
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Execute the callback with user data from ud2
-    let ud2 = access_user_data2();
-    
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            let res = match points_opt {
-                Some(ref points) => cb.call1(py, (id, points.clone(), user_data)),
-                None => cb.call1(py, (id, user_data)),
-            };
-
-            match res {
-                Ok(_) => (),
-                Err(err) => panic!("MouseArea callback error with user_data from ud2: {err}")
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
+    match points_opt {
+        Some(points) => {
+            let position = Some(Point::new(points.1, points.3));
+            dispatch_callback_at("MouseArea", id, &event_name, position, (id, points))
+        },
+        None => dispatch_callback("MouseArea", id, &event_name, (id,)),
     }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // Execute the callback without user data
-    Python::with_gil(|py| {
-        let res = match points_opt {
-                Some(ref points) => cb.call1(py, (id, points.clone())),
-                None => cb.call1(py, (id,)),
-            };
-
-            match res {
-                Ok(_) => (),
-                Err(err) => panic!("MouseArea callback error without user_data: {err}")
-            }
-    });
-
 }
 
 
@@ -198,25 +144,32 @@ pub enum IpgMouseAreaParam {
 pub fn mousearea_item_update(img: &mut IpgMouseArea,
                                 item: &PyObject,
                                 value: &PyObject,
-                                )
+                                ) -> PyResult<()>
 {
 
-    let update = try_extract_mousearea_update(item);
+    let update = try_extract_mousearea_update(item)?;
 
     match update {
         IpgMouseAreaParam::Show => {
-            img.show = try_extract_boolean(value, "MouseArea".to_string());
+            img.show = try_extract_boolean(value, "MouseArea".to_string())?;
         },
     }
+    Ok(())
+}
+
+pub fn mousearea_get_items(ma: &IpgMouseArea) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Show", Some(ma.show.into())),
+    ])
 }
 
-pub fn try_extract_mousearea_update(update_obj: &PyObject) -> IpgMouseAreaParam {
+pub fn try_extract_mousearea_update(update_obj: &PyObject) -> PyResult<IpgMouseAreaParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgMouseAreaParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("MouseArea update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("MouseArea update extraction failed")),
         }
     })
 }
\ No newline at end of file
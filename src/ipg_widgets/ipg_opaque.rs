@@ -2,15 +2,18 @@
 use iced::mouse::Interaction;
 use iced::{Color, Element, Length};
 use iced::widget::{horizontal_space, mouse_area, opaque, Container};
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use crate::graphics::colors::get_color;
-use crate::{access_callbacks, access_user_data1, IpgState};
+use crate::IpgState;
+use super::callbacks::dispatch_callback;
 use crate::app::Message;
 
-use super::helpers::{get_horizontal_alignment, get_vertical_alignment, try_extract_boolean, try_extract_ipg_color, try_extract_rgba_color};
+use super::helpers::{get_horizontal_alignment, get_vertical_alignment, try_extract_boolean, try_extract_ipg_color, try_extract_rgba_color, 
+    IpgItems};
 use super::ipg_container::{self, get_cont_style};
 use super::ipg_enums::{IpgHorizontalAlignment, IpgVerticalAlignment, IpgWidgets};
+use super::ipg_errors::IpgParamError;
 
 
 #[derive(Debug, Clone)]
@@ -111,24 +114,31 @@ pub enum IpgOpaqueParam {
 
 pub fn opaque_item_update(op: &mut IpgOpaque,
                             item: &PyObject,
-                            value: &PyObject,) {
+                            value: &PyObject,) -> PyResult<()> {
 
-    let update = try_extract_stack_update(item);
+    let update = try_extract_stack_update(item)?;
     let name = "OpaqueContainer".to_string();
     match update {
         IpgOpaqueParam::Show => {
-            op.show = try_extract_boolean(value, name);
+            op.show = try_extract_boolean(value, name)?;
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_stack_update(update_obj: &PyObject) -> IpgOpaqueParam {
+pub fn opaque_get_items(op: &IpgOpaque) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Show", Some(op.show.into())),
+    ])
+}
+
+pub fn try_extract_stack_update(update_obj: &PyObject) -> PyResult<IpgOpaqueParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgOpaqueParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Opaque update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Opaque update extraction failed")),
         }
     })
 }
@@ -141,53 +151,7 @@ pub fn opaque_callback(_state: &mut IpgState, id: usize, event_name: String) {
 
 fn process_callback(id: usize, event_name: String) 
 {
-    let ud = access_user_data1();
-    let user_data_opt = ud.user_data.get(&id);
-
-    let app_cbs = access_callbacks();
-
-    let callback_present = 
-        app_cbs.callbacks.get(&(id, event_name));
-    
-    let callback = match callback_present {
-        Some(cb) => cb,
-        None => return,
-    };
-
-    let cb = 
-        Python::with_gil(|py| {
-            callback.clone_ref(py)
-        });
-
-    drop(app_cbs);
-              
-    Python::with_gil(|py| {
-        if user_data_opt.is_some() {
-            let res = cb.call1(py, (
-                                                        id,
-                                                        user_data_opt.unwrap()  
-                                                        ));
-            match res {
-                Ok(_) => (),
-                Err(er) => panic!("Opaque: Only 2 parameter (id, user_data) 
-                                    is required or a python error in this function. {er}"),
-            }
-        } else {
-            let res = cb.call1(py, (
-                                                        id,  
-                                                        ));
-            match res {
-                Ok(_) => (),
-                Err(er) => panic!("Opaque: Only 1 parameter (id) 
-                                    is required or a python error in this function. {er}"),
-            }
-        }
-        
-        
-    });
-    
-    drop(ud);   
-
+    dispatch_callback("Opaque", id, &event_name, (id,));
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -199,28 +163,29 @@ pub enum IpgOpaqueStyleParam {
 
 pub fn opaque_style_update_item(style: &mut IpgOpaqueStyle, 
                                 item: &PyObject, 
-                                value: &PyObject) 
+                                value: &PyObject) -> PyResult<()> 
 {
-    let update = try_extract_opaque_style_update(item);
+    let update = try_extract_opaque_style_update(item)?;
     let name = "ContainerStyle".to_string();
     match update {
         IpgOpaqueStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgOpaqueStyleParam::BackgroundRgbaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_opaque_style_update(update_obj: &PyObject) -> IpgOpaqueStyleParam {
+pub fn try_extract_opaque_style_update(update_obj: &PyObject) -> PyResult<IpgOpaqueStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgOpaqueStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Opaque style parameter update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Opaque style parameter update extraction failed")),
         }
     })
 }
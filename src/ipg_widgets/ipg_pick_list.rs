@@ -1,7 +1,5 @@
 //! ipg_pick_list
-use crate::access_callbacks;
-use crate::access_user_data1;
-use crate::access_user_data2;
+use super::callbacks::dispatch_callback;
 use crate::app;
 use crate::graphics::colors::get_color;
 use crate::IpgState;
@@ -11,11 +9,13 @@ use super::helpers::try_extract_ipg_color;
 use super::helpers::try_extract_rgba_color;
 use super::helpers::try_extract_vec_f32;
 use super::helpers::try_extract_vec_str;
-use super::helpers::{get_padding_f64, get_radius, get_width};
+use super::helpers::{block_input, get_padding_f64, get_radius, get_width, 
+    item_length, item_line_height, item_padding, IpgItems};
 use super::helpers::{try_extract_boolean, try_extract_f64,
     try_extract_string, try_extract_vec_f64};
 use super::ipg_button::{IpgButtonArrow, get_bootstrap_arrow_char};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use iced::widget::pick_list::{self, Status};
 use iced::{Color, Font, Pixels, Theme};
@@ -25,7 +25,7 @@ use iced::widget::pick_list::{Handle, Icon};
 use iced::widget::text::{LineHeight, Shaping};
 
 use pyo3::pyclass;
-use pyo3::{PyObject, Python};
+use pyo3::{PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -33,6 +33,7 @@ pub struct IpgPickList {
     pub id: usize,
     pub parent_id: String,
     pub show: bool,
+    pub enabled: bool,
     pub options: Vec<String>,
     pub placeholder: Option<String>,
     pub selected: Option<String>,
@@ -54,6 +55,7 @@ impl IpgPickList {
         id: usize,
         parent_id: String,
         show: bool,
+        enabled: bool,
         options: Vec<String>,
         placeholder: Option<String>,
         selected: Option<String>,
@@ -73,6 +75,7 @@ impl IpgPickList {
             id,
             parent_id,
             show,
+            enabled,
             options,
             placeholder,
             selected,
@@ -102,6 +105,8 @@ pub struct IpgPickListStyle {
     pub border_color_hovered: Option<Color>,
     pub border_radius: Vec<f32>,
     pub border_width: f32,
+    pub background_color_disabled: Option<Color>,
+    pub text_color_disabled: Option<Color>,
 }
 
 impl IpgPickListStyle {
@@ -115,6 +120,8 @@ impl IpgPickListStyle {
         border_color_hovered: Option<Color>,
         border_radius: Vec<f32>,
         border_width: f32,
+        background_color_disabled: Option<Color>,
+        text_color_disabled: Option<Color>,
     ) -> Self {
         Self {
             id,
@@ -126,6 +133,8 @@ impl IpgPickListStyle {
             border_color_hovered,
             border_radius,
             border_width,
+            background_color_disabled,
+            text_color_disabled,
         }
     }
 }
@@ -156,6 +165,8 @@ pub fn construct_picklist<'a>(pick: &'a IpgPickList,
 
    
 
+    let enabled = pick.enabled;
+
     let pl: Element<'_, PLMessage> = 
         PickList::new(pick.options.clone(), 
             pick.selected.clone(), 
@@ -169,12 +180,16 @@ pub fn construct_picklist<'a>(pick: &'a IpgPickList,
         .text_shaping(pick.text_shaping)
         .handle(handle)
         .style(move|theme: &Theme, status| {   
-            get_styling(theme, status, 
-                style.clone(),
-            )  
+            if enabled {
+                get_styling(theme, status, style.clone())
+            } else {
+                disabled(get_styling(theme, Status::Active, style.clone()), style.as_ref())
+            }
             })
         .into();
 
+    let pl = if pick.enabled { pl } else { block_input(pl) };
+
     Some(pl.map(move |message| app::Message::PickList(pick.id, message)))
 
 }
@@ -200,49 +215,7 @@ pub fn construct_picklist<'a>(pick: &'a IpgPickList,
         event_name: String, 
         selected: String) 
  {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, selected, user_data)) {
-                panic!("PickList callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, selected, user_data)) {
-                panic!("PickList callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with only the id and selected
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id, selected)) {
-            panic!("PickList callback error: {err}");
-        }
-    });
-
+    dispatch_callback("PickList", id, &event_name, (id, selected));
  }
 
 
@@ -305,6 +278,7 @@ pub enum IpgPickListParam {
     Placeholder,
     Padding,
     Selected,
+    Enabled,
     Show,
     Style,
     TextSize,
@@ -317,39 +291,42 @@ pub enum IpgPickListParam {
 pub fn pick_list_item_update(pl: &mut IpgPickList,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_pick_list_update(item);
+    let update = try_extract_pick_list_update(item)?;
     let name = "PickList".to_string();
     match update {
         IpgPickListParam::Options => {
-            pl.options = try_extract_vec_str(value, name);
+            pl.options = try_extract_vec_str(value, name)?;
             pl.selected = None;
         },
         IpgPickListParam::Placeholder => {
-            pl.placeholder = Some(try_extract_string(value, name));
+            pl.placeholder = Some(try_extract_string(value, name)?);
             pl.selected = None;
         },
         IpgPickListParam::Padding => {
-            pl.padding =  get_padding_f64(try_extract_vec_f64(value, name));
+            pl.padding =  get_padding_f64(try_extract_vec_f64(value, name)?)?;
         },
         IpgPickListParam::Selected => {
-            pl.selected = Some(try_extract_string(value, name));
+            pl.selected = Some(try_extract_string(value, name)?);
         }
+        IpgPickListParam::Enabled => {
+            pl.enabled = try_extract_boolean(value, name)?;
+        },
         IpgPickListParam::Show => {
-            pl.show = try_extract_boolean(value, name);
+            pl.show = try_extract_boolean(value, name)?;
         },
         IpgPickListParam::Style => {
-            pl.style_id = Some(try_extract_f64(value, name) as usize);
+            pl.style_id = Some(try_extract_f64(value, name)? as usize);
         },
         IpgPickListParam::TextSize => {
-            pl.text_size = Some(try_extract_f64(value, name) as f32);
+            pl.text_size = Some(try_extract_f64(value, name)? as f32);
         },
         IpgPickListParam::TextLineHeight => {
-            pl.text_line_height = LineHeight::Relative(try_extract_f64(value, name) as f32);
+            pl.text_line_height = LineHeight::Relative(try_extract_f64(value, name)? as f32);
         },
         IpgPickListParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             pl.width = get_width(Some(val as f32), false);
         },
          // TODO: Doesn't work, shrink the box but still displaces the text.
@@ -358,16 +335,31 @@ pub fn pick_list_item_update(pl: &mut IpgPickList,
         //     pl.width = get_width(None, val);
         // },
     }
+    Ok(())
+}
 
+pub fn pick_list_get_items(pl: &IpgPickList) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Options", Some(pl.options.clone().into())),
+        ("Placeholder", pl.placeholder.clone().map(Into::into)),
+        ("Padding", item_padding(pl.padding)),
+        ("Selected", pl.selected.clone().map(Into::into)),
+        ("Enabled", Some(pl.enabled.into())),
+        ("Show", Some(pl.show.into())),
+        ("Style", pl.style_id.map(Into::into)),
+        ("TextSize", pl.text_size.map(Into::into)),
+        ("TextLineHeight", item_line_height(pl.text_line_height)),
+        ("Width", item_length(pl.width)),
+    ])
 }
 
-pub fn try_extract_pick_list_update(update_obj: &PyObject) -> IpgPickListParam {
+pub fn try_extract_pick_list_update(update_obj: &PyObject) -> PyResult<IpgPickListParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgPickListParam>(py);
         match res {
-            Ok(update) => update,
-            Err(error) => panic!("PickList update extraction failed {}", error),
+            Ok(update) => Ok(update),
+            Err(error) => Err(IpgParamError::new_err(format!("PickList update extraction failed {}", error))),
         }
     })
 }
@@ -457,58 +449,76 @@ pub enum IpgPickListStyleParam {
     PlaceholderRgbaColor,
     TextIpgColor,
     TextRgbaColor,
+    BackgroundIpgColorDisabled,
+    BackgroundRgbaColorDisabled,
+    TextIpgColorDisabled,
+    TextRgbaColorDisabled,
 }
 
 pub fn pick_list_style_update_item(style: &mut IpgPickListStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_pick_list_style_update(item);
+    let update = try_extract_pick_list_style_update(item)?;
     let name = "PickListStyle".to_string();
     match update {
         IpgPickListStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgPickListStyleParam::BackgroundRbgaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgPickListStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgPickListStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgPickListStyleParam::BorderRadius => {
-            style.border_radius = try_extract_vec_f32(value, name);
+            style.border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgPickListStyleParam::BorderWidth => {
-            style.border_width = try_extract_f64(value, name) as f32;
+            style.border_width = try_extract_f64(value, name)? as f32;
         },
         IpgPickListStyleParam::HandleIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.handle_color = get_color(None, Some(color), 1.0, false);
         },
         IpgPickListStyleParam::HandleRgbaColor => {
-            style.handle_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.handle_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgPickListStyleParam::PlaceholderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.placeholder_color = get_color(None, Some(color), 1.0, false);
         },
         IpgPickListStyleParam::PlaceholderRgbaColor => {
-            style.placeholder_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.placeholder_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgPickListStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgPickListStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
+        },
+        IpgPickListStyleParam::BackgroundIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.background_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgPickListStyleParam::BackgroundRgbaColorDisabled => {
+            style.background_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
+        },
+        IpgPickListStyleParam::TextIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.text_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgPickListStyleParam::TextRgbaColorDisabled => {
+            style.text_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
-        
     }
+    Ok(())
 }
 
 pub fn get_styling(theme: &Theme, status: Status, 
@@ -563,13 +573,29 @@ pub fn get_styling(theme: &Theme, status: Status,
 
 }
 
-pub fn try_extract_pick_list_style_update(update_obj: &PyObject) -> IpgPickListStyleParam {
+// A pick list has no disabled status, the disabled colors of the
+// style are used and the active style is faded where they are not set.
+fn disabled(style: pick_list::Style, ipg_style: Option<&IpgPickListStyle>) -> pick_list::Style {
+    let mut style = style;
+    style.background = match ipg_style.and_then(|ipg_style| ipg_style.background_color_disabled) {
+        Some(color) => iced::Background::Color(color),
+        None => style.background.scale_alpha(0.5),
+    };
+    style.text_color = ipg_style.and_then(|ipg_style| ipg_style.text_color_disabled)
+                            .unwrap_or_else(|| style.text_color.scale_alpha(0.5));
+    style.placeholder_color = style.placeholder_color.scale_alpha(0.5);
+    style.handle_color = style.handle_color.scale_alpha(0.5);
+    style.border.color = style.border.color.scale_alpha(0.5);
+    style
+}
+
+pub fn try_extract_pick_list_style_update(update_obj: &PyObject) -> PyResult<IpgPickListStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgPickListStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Pick List style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Pick List style update extraction failed")),
         }
     })
 }
@@ -1,15 +1,17 @@
 //! ipg_progress_bar
 use iced::{Color, Element, Length, Theme};
 use iced::widget::{progress_bar, ProgressBar};
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 use crate::graphics::colors::get_color;
 use crate::style::styling::IpgStyleStandard;
 use crate::app;
 
 use super::helpers::{get_height, get_radius, get_width, try_extract_boolean,
     try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, 
-    try_extract_style_standard, try_extract_vec_f32};
+    try_extract_style_standard, try_extract_vec_f32, 
+    item_fill, item_length, item_object_opt, IpgItems};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 
 #[derive(Debug, Clone)]
@@ -127,52 +129,67 @@ pub enum IpgProgressBarParam {
 pub fn progress_bar_item_update(pb: &mut IpgProgressBar,
                                 item: &PyObject,
                                 value: &PyObject,
-                                )
+                                ) -> PyResult<()>
 {
-    let update = try_extract_progress_bar_update(item);
+    let update = try_extract_progress_bar_update(item)?;
     let name = "ProgressBar".to_string();
     match update {
         IpgProgressBarParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             pb.height = get_height(Some(val as f32), false);
         },
         IpgProgressBarParam::Min => {
-            pb.min = try_extract_f64(value, name) as f32;
+            pb.min = try_extract_f64(value, name)? as f32;
         },
         IpgProgressBarParam::Max => {
-            pb.max = try_extract_f64(value, name) as f32;
+            pb.max = try_extract_f64(value, name)? as f32;
         },
         IpgProgressBarParam::Show => {
-            pb.show = try_extract_boolean(value, name);
+            pb.show = try_extract_boolean(value, name)?;
         },
         IpgProgressBarParam::StyleStandard => {
-            pb.style_standard = Some(try_extract_style_standard(value, name))
+            pb.style_standard = Some(try_extract_style_standard(value, name)?)
         },
         IpgProgressBarParam::Style => {
-            pb.style_id = Some(try_extract_f64(value, name) as usize)
+            pb.style_id = Some(try_extract_f64(value, name)? as usize)
         },
         IpgProgressBarParam::Value => {
-            pb.value = try_extract_f64(value, name) as f32;
+            pb.value = try_extract_f64(value, name)? as f32;
         },
         IpgProgressBarParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             pb.width = get_width(Some(val as f32), false);
         },
         IpgProgressBarParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             pb.width = get_width(None, val);
         },
     }
+    Ok(())
+}
+
+pub fn progress_bar_get_items(pb: &IpgProgressBar) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Height", item_length(pb.height)),
+        ("Min", Some(pb.min.into())),
+        ("Max", Some(pb.max.into())),
+        ("Show", Some(pb.show.into())),
+        ("StyleStandard", item_object_opt(pb.style_standard.clone())?),
+        ("Style", pb.style_id.map(Into::into)),
+        ("Value", Some(pb.value.into())),
+        ("Width", item_length(pb.width)),
+        ("WidthFill", item_fill(pb.width)),
+    ])
 }
 
 
-pub fn try_extract_progress_bar_update(update_obj: &PyObject) -> IpgProgressBarParam {
+pub fn try_extract_progress_bar_update(update_obj: &PyObject) -> PyResult<IpgProgressBarParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgProgressBarParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("ProgressBar update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("ProgressBar update extraction failed")),
         }
     })
 }
@@ -262,39 +279,40 @@ pub enum IpgProgressBarStyleParam {
 
 pub fn progress_bar_style_update_item(style: &mut IpgProgressBarStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_progress_bar_style_update(item);
+    let update = try_extract_progress_bar_style_update(item)?;
     let name = "ProgressBarStyle".to_string();
     match update {
         IpgProgressBarStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgProgressBarStyleParam::BackgroundRgbaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgProgressBarStyleParam::BarIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.bar_color = get_color(None, Some(color), 1.0, false);
         },
         IpgProgressBarStyleParam::BarRgbaColor => {
-            style.bar_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.bar_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgProgressBarStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgProgressBarStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgProgressBarStyleParam::BorderRadius => {
-            style.border_radius = Some(try_extract_vec_f32(value, name));
+            style.border_radius = Some(try_extract_vec_f32(value, name)?);
         },
         IpgProgressBarStyleParam::BorderWidth => {
-            style.border_width = Some(try_extract_f64(value, name) as f32);
+            style.border_width = Some(try_extract_f64(value, name)? as f32);
         },
     }
+    Ok(())
 }
 
 fn get_progress_bar_style(style: Option<&IpgWidgets>) -> Option<IpgProgressBarStyle>{
@@ -306,13 +324,13 @@ fn get_progress_bar_style(style: Option<&IpgWidgets>) -> Option<IpgProgressBarSt
         }
 }
 
-pub fn try_extract_progress_bar_style_update(update_obj: &PyObject) -> IpgProgressBarStyleParam {
+pub fn try_extract_progress_bar_style_update(update_obj: &PyObject) -> PyResult<IpgProgressBarStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgProgressBarStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Button style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Button style update extraction failed")),
         }
     })
 }
\ No newline at end of file
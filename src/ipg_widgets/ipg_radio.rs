@@ -1,20 +1,23 @@
 //!ipg_radio
 use crate::graphics::colors::get_color;
 use crate::ipg_widgets::helpers::try_extract_boolean;
-use crate::{access_callbacks, access_user_data1, access_user_data2, IpgState};
+use crate::{mirror_item, IpgState};
+use super::callbacks::dispatch_callback;
 use crate::app;
-use super::helpers::{get_height, get_padding_f64, get_width, 
+use super::helpers::{block_input, get_height, get_padding_f64, get_width, 
     try_extract_f64, try_extract_f64_option, try_extract_i64_option, 
     try_extract_ipg_color, try_extract_rgba_color, try_extract_u16, 
-    try_extract_vec_f64, try_extract_vec_str};
+    try_extract_vec_f64, try_extract_vec_str, 
+    item_fill, item_length, item_object, item_padding, IpgItems};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use iced::widget::radio::{self, Status};
 use iced::{Color, Element, Length, Padding, Pixels, Theme};
 use iced::widget::text::{LineHeight, Shaping};
 use iced::widget::{Column, Radio, Row};
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -26,6 +29,7 @@ pub struct IpgRadio {
     pub spacing: f32,
     pub padding: Padding,
     pub show: bool,
+    pub enabled: bool,
     pub is_selected: Option<usize>,
     pub width: Length,
     pub height: Length,
@@ -48,6 +52,7 @@ impl IpgRadio {
         spacing: f32,
         padding: Padding,
         show: bool,
+        enabled: bool,
         is_selected: Option<usize>,
         width: Length,
         height: Length,
@@ -68,6 +73,7 @@ impl IpgRadio {
             spacing,
             padding,
             show,
+            enabled,
             is_selected,
             width,
             height,
@@ -93,6 +99,9 @@ pub struct IpgRadioStyle {
     pub border_color: Option<Color>,
     pub border_width: Option<f32>,
     pub text_color: Option<Color>,
+    pub background_color_disabled: Option<Color>,
+    pub dot_color_disabled: Option<Color>,
+    pub text_color_disabled: Option<Color>,
 }
 
 impl IpgRadioStyle {
@@ -105,6 +114,9 @@ impl IpgRadioStyle {
         border_color: Option<Color>,
         border_width: Option<f32>,
         text_color: Option<Color>,
+        background_color_disabled: Option<Color>,
+        dot_color_disabled: Option<Color>,
+        text_color_disabled: Option<Color>,
     ) -> Self {
         Self {
             id,
@@ -115,6 +127,9 @@ impl IpgRadioStyle {
             border_color,
             border_width,
             text_color,
+            background_color_disabled,
+            dot_color_disabled,
+            text_color_disabled,
         }
     }
 }
@@ -160,6 +175,7 @@ pub fn construct_radio<'a>(radio: &'a IpgRadio,
                 border_width: st.border_width, 
                 text_color: st.text_color });
 
+        let enabled = radio.enabled;
         radio_elements.push(Radio::new(label.clone(), 
                                         CHOICES[radio.group_index][i],
                                         selected,
@@ -171,9 +187,11 @@ pub fn construct_radio<'a>(radio: &'a IpgRadio,
                                     .text_line_height(radio.text_line_height)
                                     .text_shaping(radio.text_shaping)
                                     .style(move|theme: &Theme, status| {
-                                        get_styling(theme, status, 
-                                        style,
-                                        )})
+                                        if enabled {
+                                            get_styling(theme, status, style)
+                                        } else {
+                                            disabled(theme, status, style)
+                                        }})
                                     .into());
     }
 
@@ -192,6 +210,8 @@ pub fn construct_radio<'a>(radio: &'a IpgRadio,
                                                     .into(),
     };
 
+    let rd = if radio.enabled { rd } else { block_input(rd) };
+
     Some(rd.map(move |message| app::Message::Radio(radio.id, message)))
 
 }
@@ -295,8 +315,11 @@ pub fn radio_callback(state: &mut IpgState, id: usize, message: RDMessage) {
     };
 
     radio.is_selected = Some(ch_usize);
+    let label = radio.labels[ch_usize].clone();
+    // the python callback may read the radio back with get_item
+    mirror_item(state, id);
 
-    process_callback(id, "on_select".to_string(), ch_usize, radio.labels[ch_usize].clone());
+    process_callback(id, "on_select".to_string(), ch_usize, label);
     
 }
 
@@ -307,48 +330,7 @@ fn process_callback(
     index: usize, 
     label: String) 
 {
-let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, (index, label), user_data)) {
-                panic!("Radio callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, (index, label), user_data)) {
-                panic!("Radio callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with the id, index, and label
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id, (index, label))) {
-            panic!("Radio callback error: {err}");
-        }
-    });
+    dispatch_callback("Radio", id, &event_name, (id, (index, label)));
 }
 
 
@@ -367,6 +349,7 @@ pub enum IpgRadioParam {
     Labels,
     Padding,
     SelectedIndex,
+    Enabled,
     Show,
     Size,
     Spacing,
@@ -384,20 +367,20 @@ pub enum IpgRadioParam {
 pub fn radio_item_update(rd: &mut IpgRadio,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_radio_update(item);
+    let update = try_extract_radio_update(item)?;
     let name = "Radio".to_string();
     match update {
         IpgRadioParam::Direction => {
-            rd.direction = try_extract_radio_direction(value);
+            rd.direction = try_extract_radio_direction(value)?;
         },
         IpgRadioParam::Labels => {
-            rd.labels = try_extract_vec_str(value, name);
+            rd.labels = try_extract_vec_str(value, name)?;
         },
         IpgRadioParam::Padding => {
-            let val = try_extract_vec_f64(value, name);
-            rd.padding =  get_padding_f64(val);
+            let val = try_extract_vec_f64(value, name)?;
+            rd.padding =  get_padding_f64(val)?;
         },
         IpgRadioParam::SelectedIndex => {
             let index_opt = try_extract_i64_option(value);
@@ -406,40 +389,43 @@ pub fn radio_item_update(rd: &mut IpgRadio,
                 Some(index)  => index as usize,
                 None => {
                     rd.is_selected = None;
-                    return
+                    return Ok(())
                 }
             };
             
             if selected_index > rd.labels.len()-1 {
-                panic!("Radio selected_index is greater than the size of the labels")
+                return Err(IpgParamError::new_err("Radio selected_index is greater than the size of the labels"))
             } else {
                 rd.is_selected = Some(selected_index);
             }
         },
+        IpgRadioParam::Enabled => {
+            rd.enabled = try_extract_boolean(value, name)?;
+        },
         IpgRadioParam::Show => {
-            rd.show = try_extract_boolean(value, name);
+            rd.show = try_extract_boolean(value, name)?;
         },
         IpgRadioParam::Size => {
-            rd.size = try_extract_f64(value, name) as f32;
+            rd.size = try_extract_f64(value, name)? as f32;
         },
         IpgRadioParam::Spacing => {
-            rd.spacing = try_extract_f64(value, name) as f32;
+            rd.spacing = try_extract_f64(value, name)? as f32;
         },
         IpgRadioParam::StyleId => {
-            rd.style_id = Some(try_extract_f64(value, name) as usize);
+            rd.style_id = Some(try_extract_f64(value, name)? as usize);
         },
         IpgRadioParam::TextSpacing => {
-            rd.text_spacing = try_extract_f64(value, name) as f32;
+            rd.text_spacing = try_extract_f64(value, name)? as f32;
         },
         IpgRadioParam::TextSize => {
-            rd.text_size = try_extract_f64(value, name) as f32;
+            rd.text_size = try_extract_f64(value, name)? as f32;
         },
         IpgRadioParam::LineHeightPixels => {
-            let val = try_extract_u16(value, name);
+            let val = try_extract_u16(value, name)?;
             rd.text_line_height = LineHeight::Absolute(Pixels(val.into()));
         },
         IpgRadioParam::LineHeightRelative => {
-            let val = try_extract_f64(value, name) as f32;
+            let val = try_extract_f64(value, name)? as f32;
             rd.text_line_height = LineHeight::Relative(val);
         },
         IpgRadioParam::Width => {
@@ -449,7 +435,7 @@ pub fn radio_item_update(rd: &mut IpgRadio,
             }
         },
         IpgRadioParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             if val {
                 rd.width = get_width(None, val);
             } else {
@@ -463,7 +449,7 @@ pub fn radio_item_update(rd: &mut IpgRadio,
             }
         },
         IpgRadioParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             if val {
                 rd.height = get_height(None, val);
             } else {
@@ -471,29 +457,55 @@ pub fn radio_item_update(rd: &mut IpgRadio,
             } 
         },
     }
+    Ok(())
+}
 
+pub fn radio_get_items(rd: &IpgRadio) -> PyResult<IpgItems> {
+    let (line_height_pixels, line_height_relative) = match rd.text_line_height {
+        LineHeight::Absolute(pixels) => (Some(pixels.0.into()), None),
+        LineHeight::Relative(value) => (None, Some(value.into())),
+    };
+    Ok(vec![
+        ("Direction", item_object(rd.direction.clone())?),
+        ("Labels", Some(rd.labels.clone().into())),
+        ("Padding", item_padding(rd.padding)),
+        ("SelectedIndex", rd.is_selected.map(Into::into)),
+        ("Enabled", Some(rd.enabled.into())),
+        ("Show", Some(rd.show.into())),
+        ("Size", Some(rd.size.into())),
+        ("Spacing", Some(rd.spacing.into())),
+        ("StyleId", rd.style_id.map(Into::into)),
+        ("TextSpacing", Some(rd.text_spacing.into())),
+        ("TextSize", Some(rd.text_size.into())),
+        ("LineHeightPixels", line_height_pixels),
+        ("LineHeightRelative", line_height_relative),
+        ("Width", item_length(rd.width)),
+        ("WidthFill", item_fill(rd.width)),
+        ("Height", item_length(rd.height)),
+        ("HeightFill", item_fill(rd.height)),
+    ])
 }
 
 
-pub fn try_extract_radio_update(update_obj: &PyObject) -> IpgRadioParam {
+pub fn try_extract_radio_update(update_obj: &PyObject) -> PyResult<IpgRadioParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgRadioParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Radio update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Radio update extraction failed")),
         }
     })
 }
 
 
-pub fn try_extract_radio_direction(direct_obj: &PyObject) -> IpgRadioDirection {
+pub fn try_extract_radio_direction(direct_obj: &PyObject) -> PyResult<IpgRadioDirection> {
     Python::with_gil(|py| {
         let res = direct_obj.extract::<IpgRadioDirection>(py);
             
         match res {
-            Ok(direction) => direction,
-            Err(_) => panic!("RadioDirection failed to extract."),
+            Ok(direction) => Ok(direction),
+            Err(_) => Err(IpgParamError::new_err("RadioDirection failed to extract.")),
         }
     })  
 }
@@ -545,6 +557,31 @@ pub fn get_styling(theme: &Theme, status: Status,
 
 }
 
+// A radio has no disabled status, the hover is ignored and the disabled
+// colors of the style are used, the active style is faded where they are not set.
+fn disabled(theme: &Theme, status: Status, 
+            style_opt: Option<IpgRadioStyle>,
+            ) -> radio::Style {
+    let is_selected = match status {
+        Status::Active { is_selected } => is_selected,
+        Status::Hovered { is_selected } => is_selected,
+    };
+
+    let mut style = get_styling(theme, Status::Active { is_selected }, style_opt);
+    style.background = match style_opt.and_then(|ipg_style| ipg_style.background_color_disabled) {
+        Some(color) => iced::Background::Color(color),
+        None => style.background.scale_alpha(0.5),
+    };
+    style.dot_color = style_opt.and_then(|ipg_style| ipg_style.dot_color_disabled)
+                        .unwrap_or_else(|| style.dot_color.scale_alpha(0.5));
+    style.border_color = style.border_color.scale_alpha(0.5);
+    style.text_color = Some(style_opt.and_then(|ipg_style| ipg_style.text_color_disabled)
+                                .unwrap_or_else(|| style.text_color
+                                    .unwrap_or(theme.palette().text)
+                                    .scale_alpha(0.5)));
+    style
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[pyclass(eq, eq_int)]
 pub enum IpgRadioStyleParam {
@@ -559,54 +596,82 @@ pub enum IpgRadioStyleParam {
     DotRgbaColorHovered,
     TextIpgColor,
     TextRgbaColor,
+    BackgroundIpgColorDisabled,
+    BackgroundRgbaColorDisabled,
+    DotIpgColorDisabled,
+    DotRgbaColorDisabled,
+    TextIpgColorDisabled,
+    TextRgbaColorDisabled,
 }
 
 pub fn radio_style_update_item(style: &mut IpgRadioStyle,
                                 item: &PyObject,
-                                value: &PyObject,) 
+                                value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_radio_style_update(item);
+    let update = try_extract_radio_style_update(item)?;
     let name = "RadioStyle".to_string();
     match update {
         IpgRadioStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgRadioStyleParam::BackgroundRbgaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgRadioStyleParam::DotIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.dot_color = get_color(None, Some(color), 1.0, false);
         },
         IpgRadioStyleParam::DotRgbaColor => {
-            style.dot_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.dot_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgRadioStyleParam::DotIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.dot_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgRadioStyleParam::DotRgbaColorHovered => {
-            style.dot_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.dot_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgRadioStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgRadioStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgRadioStyleParam::BorderWidth => {
-            style.border_width = Some(try_extract_f64(value, name) as f32);
+            style.border_width = Some(try_extract_f64(value, name)? as f32);
         },
         IpgRadioStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgRadioStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
+        },
+        IpgRadioStyleParam::BackgroundIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.background_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgRadioStyleParam::BackgroundRgbaColorDisabled => {
+            style.background_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
+        },
+        IpgRadioStyleParam::DotIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.dot_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgRadioStyleParam::DotRgbaColorDisabled => {
+            style.dot_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
+        },
+        IpgRadioStyleParam::TextIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.text_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgRadioStyleParam::TextRgbaColorDisabled => {
+            style.text_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
 fn get_radio_style(style: Option<&IpgWidgets>) -> Option<IpgRadioStyle>{
@@ -618,13 +683,13 @@ fn get_radio_style(style: Option<&IpgWidgets>) -> Option<IpgRadioStyle>{
     }
 }
 
-pub fn try_extract_radio_style_update(update_obj: &PyObject) -> IpgRadioStyleParam {
+pub fn try_extract_radio_style_update(update_obj: &PyObject) -> PyResult<IpgRadioStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgRadioStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Radio style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Radio style update extraction failed")),
         }
     })
 }
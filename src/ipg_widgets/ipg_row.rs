@@ -1,14 +1,16 @@
 //! ipg_row
 use iced::{Alignment, Padding, Length, Element};
 use iced::widget::Row;
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use crate::app::Message;
 
 use super::helpers::{get_height, get_padding_f64, get_width, 
     try_extract_boolean, try_extract_f64, try_extract_ipg_alignment, 
-    try_extract_vec_f64};
+    try_extract_vec_f64, 
+    item_fill, item_length, item_object, item_padding, IpgItems};
 use super::ipg_enums::IpgAlignment;
+use super::ipg_errors::IpgParamError;
 
 
 #[derive(Debug, Clone)]
@@ -88,49 +90,64 @@ pub enum IpgRowParam {
 pub fn row_item_update(col: &mut IpgRow,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_row_update(item);
+    let update = try_extract_row_update(item)?;
     let name = "Row".to_string();
     match update {
         IpgRowParam::Align => {
-            col.align = try_extract_ipg_alignment(value).unwrap();
+            col.align = try_extract_ipg_alignment(value)
+                .ok_or_else(|| IpgParamError::new_err(format!("{}-Unable to extract python object for IpgAlignment", name)))?;
         },
         IpgRowParam::Clip => {
-            col.clip = try_extract_boolean(value, name);
+            col.clip = try_extract_boolean(value, name)?;
         },
         IpgRowParam::Padding => {
-            col.padding =  get_padding_f64(try_extract_vec_f64(value, name));
+            col.padding =  get_padding_f64(try_extract_vec_f64(value, name)?)?;
         },
         IpgRowParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             col.width = get_width(Some(val as f32), false);
         },
         IpgRowParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             col.width = get_width(None, val);
         },
         IpgRowParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             col.height = get_height(Some(val as f32), false);
         },
         IpgRowParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             col.height = get_height(None, val);
         },
         IpgRowParam::Spacing => {
-            col.spacing = try_extract_f64(value, name) as f32;
+            col.spacing = try_extract_f64(value, name)? as f32;
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_row_update(update_obj: &PyObject) -> IpgRowParam {
+pub fn row_get_items(row: &IpgRow) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Align", item_object(row.align.clone())?),
+        ("Clip", Some(row.clip.into())),
+        ("Padding", item_padding(row.padding)),
+        ("Width", item_length(row.width)),
+        ("WidthFill", item_fill(row.width)),
+        ("Height", item_length(row.height)),
+        ("HeightFill", item_fill(row.height)),
+        ("Spacing", Some(row.spacing.into())),
+    ])
+}
+
+pub fn try_extract_row_update(update_obj: &PyObject) -> PyResult<IpgRowParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgRowParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Row update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Row update extraction failed")),
         }
     })
 }
@@ -2,7 +2,7 @@
 use iced::widget::rule::{self, FillMode, Style};
 use iced::{Color, Element, Length, Theme};
 use iced::widget::{Container, Rule};
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 use crate::app;
 use crate::graphics::colors::get_color;
 
@@ -10,6 +10,7 @@ use super::helpers::{get_radius, try_extract_f64, try_extract_ipg_color,
     try_extract_rgba_color, try_extract_u16, try_extract_vec_f32, 
     try_extract_vec_u16};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 #[derive(Debug, Clone)]
 pub struct IpgRule {
@@ -200,40 +201,41 @@ pub enum IpgRuleStyleParam {
 
 pub fn rule_style_update_item(style: &mut IpgRuleStyle,
                             item: &PyObject,
-                            value: &PyObject,) {
+                            value: &PyObject,) -> PyResult<()> {
 
-    let update = try_extract_rule_style_update(item);
+    let update = try_extract_rule_style_update(item)?;
     let name = "RulerStyle".to_string();
     match update {
         IpgRuleStyleParam::IpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.color = get_color(None, Some(color), 1.0, false);
         },
         IpgRuleStyleParam::RbgaColor => {
-            style.color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgRuleStyleParam::BorderRadius => {
-            style.border_radius = Some(try_extract_vec_f32(value, name));
+            style.border_radius = Some(try_extract_vec_f32(value, name)?);
         },
         IpgRuleStyleParam::FillModePercent => {
-            style.fillmode_percent = Some(try_extract_f64(value, name) as f32);
+            style.fillmode_percent = Some(try_extract_f64(value, name)? as f32);
         },
         IpgRuleStyleParam::FillModePadded => {
-            style.fillmode_padded = Some(try_extract_u16(value, name))
+            style.fillmode_padded = Some(try_extract_u16(value, name)?)
         },
         IpgRuleStyleParam::FillModeAsymmetricPadding => {
-            style.fillmode_asymmetric_padding = Some(try_extract_vec_u16(value, name))
+            style.fillmode_asymmetric_padding = Some(try_extract_vec_u16(value, name)?)
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_rule_style_update(update_obj: &PyObject) -> IpgRuleStyleParam {
+pub fn try_extract_rule_style_update(update_obj: &PyObject) -> PyResult<IpgRuleStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgRuleStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Rule style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Rule style update extraction failed")),
         }
     })
 }
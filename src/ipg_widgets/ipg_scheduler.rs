@@ -0,0 +1,77 @@
+//! ipg_scheduler
+//! The call_later and call_every callbacks.  Each call has its own
+//! time::every subscription, keyed by its handle, so any number of
+//! them run together without a widget.
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use once_cell::sync::Lazy;
+use pyo3::prelude::*;
+
+use super::callbacks::{call_callback, IpgEventSource};
+
+
+#[derive(Debug)]
+pub struct IpgScheduledCall {
+    pub interval_ms: u64,
+    pub repeat: bool,
+    pub callback: PyObject,
+    pub user_data: Option<PyObject>,
+}
+
+#[derive(Debug)]
+pub struct Scheduler {
+    pub calls: Lazy<HashMap<usize, IpgScheduledCall>>,
+}
+
+pub static SCHEDULER: Mutex<Scheduler> = Mutex::new(Scheduler {
+    calls: Lazy::new(||HashMap::new()),
+});
+
+pub fn access_scheduler() -> MutexGuard<'static, Scheduler> {
+    SCHEDULER.lock().unwrap()
+}
+
+// the handles are not widget ids, they only need to be unique
+static NEXT_HANDLE: AtomicUsize = AtomicUsize::new(1);
+
+/// Adds the call and returns its handle.
+pub fn schedule_call(call: IpgScheduledCall) -> usize {
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    access_scheduler().calls.insert(handle, call);
+    handle
+}
+
+/// Removes the call, returns false when it already ran or was cancelled.
+pub fn cancel_call(handle: usize) -> bool {
+    access_scheduler().calls.remove(&handle).is_some()
+}
+
+/// The handles and intervals of the calls, for the subscriptions.
+pub fn scheduled_calls() -> Vec<(usize, u64)> {
+    access_scheduler().calls.iter()
+        .map(|(handle, call)| (*handle, call.interval_ms))
+        .collect()
+}
+
+/// Calls the callback when its interval is up, a call_later
+/// is removed before it is called.
+pub fn process_scheduled_call(handle: usize) {
+    let call = Python::with_gil(|py| {
+        let mut scheduler = access_scheduler();
+        match scheduler.calls.get(&handle) {
+            Some(call) if call.repeat => Some((call.callback.clone_ref(py),
+                                              call.user_data.as_ref().map(|ud| ud.clone_ref(py)))),
+            Some(_) => scheduler.calls.remove(&handle)
+                            .map(|call| (call.callback, call.user_data)),
+            // cancelled while the tick was on its way
+            None => None,
+        }
+    });
+
+    if let Some((callback, user_data)) = call {
+        let source = IpgEventSource::new("Scheduled call", handle, "on_call");
+        call_callback(source, callback, user_data, (handle,));
+    }
+}
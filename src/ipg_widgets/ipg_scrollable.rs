@@ -2,10 +2,13 @@
 use std::collections::HashMap;
 
 use crate::graphics::colors::get_color;
-use crate::{access_callbacks, access_user_data1, access_user_data2, app, IpgState};
-use super::helpers::{get_height, get_radius, get_width, try_extract_f32, 
-    try_extract_ipg_color, try_extract_rgba_color, try_extract_vec_f32};
+use crate::{app, IpgState};
+use super::callbacks::dispatch_callback;
+use super::helpers::{get_height, get_radius, get_width, try_extract_boolean, try_extract_f32, 
+    try_extract_ipg_color, try_extract_rgba_color, try_extract_vec_f32, 
+    item_length, item_object, IpgItems};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use iced::widget::container;
 use iced::widget::scrollable;
@@ -19,7 +22,7 @@ use iced::{Border, Color, Element, Length, Shadow, Vector, Theme};
 use iced::widget::Column;
 
 use pyo3::pyclass;
-use pyo3::{Python, PyObject};
+use pyo3::{Python, PyObject, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -39,6 +42,7 @@ pub struct IpgScrollable {
     pub v_spacing: f32,
     pub v_bar_alignment: IpgScrollableAlignment,
     pub style_id: Option<usize>,
+    pub show: bool,
     pub scroll_y_pos: f32,
     pub scroll_x_pos: f32,
     pub bounds: Rectangle,
@@ -62,6 +66,7 @@ impl IpgScrollable {
         v_spacing: f32,
         v_bar_alignment: IpgScrollableAlignment,
         style_id: Option<usize>,
+        show: bool,
     ) -> Self {
         Self {
             id,
@@ -79,6 +84,7 @@ impl IpgScrollable {
             v_spacing,
             v_bar_alignment,
             style_id,
+            show,
             scroll_y_pos: 0.0,
             scroll_x_pos: 0.0,
             bounds: Rectangle { x: 0.0, y: 0.0, width: 0.0, height: 0.0 },
@@ -272,49 +278,7 @@ pub fn process_callback(id: usize,
                         event_name: String, 
                         hmap: HashMap<String, f32>) 
 {
-let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, hmap, user_data)) {
-                panic!("Scollable callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, hmap, user_data)) {
-                panic!("Scrollable callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with the id and hmap
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id, hmap)) {
-            panic!("Scollable callback error: {err}");
-        }
-    });
-
+    dispatch_callback("Scrollable", id, &event_name, (id, hmap));
 }
 
 
@@ -335,80 +299,105 @@ pub enum IpgScrollableParam {
     VBarAlignment,
     ScrollXTo,
     ScrollYTo,
+    Show,
 }
 
 
 pub fn scrollable_item_update(scroll: &mut IpgScrollable,
                                 item: &PyObject,
                                 value: &PyObject,
-                            ) 
+                            ) -> PyResult<()> 
 {
-    let update = try_extract_scrollable_update(item);
+    let update = try_extract_scrollable_update(item)?;
     let name = "Scrollable".to_string();
     match update {
         IpgScrollableParam::Width => {
-            let val = try_extract_f32(value, name);
+            let val = try_extract_f32(value, name)?;
             scroll.width = get_width(Some(val), false);
         },
         IpgScrollableParam::Height => {
-            let val = try_extract_f32(value, name);
+            let val = try_extract_f32(value, name)?;
             scroll.height = get_height(Some(val), false);
         },
         IpgScrollableParam::HBarWidth => {
-            scroll.h_bar_width = try_extract_f32(value, name);
+            scroll.h_bar_width = try_extract_f32(value, name)?;
         },
         IpgScrollableParam::HBarMargin => {
-            scroll.h_bar_margin = try_extract_f32(value, name);
+            scroll.h_bar_margin = try_extract_f32(value, name)?;
         },
         IpgScrollableParam::HScrollerWidth => {
-            scroll.h_scroller_width = try_extract_f32(value, name);
+            scroll.h_scroller_width = try_extract_f32(value, name)?;
         },
         IpgScrollableParam::HSpacing => {
-            scroll.h_spacing = try_extract_f32(value, name);
+            scroll.h_spacing = try_extract_f32(value, name)?;
         },
         IpgScrollableParam::HBarAlignment => {
-            scroll.h_bar_alignment = try_extract_alignment(value);
+            scroll.h_bar_alignment = try_extract_alignment(value)?;
         },
         IpgScrollableParam::VBarWidth => {
-            scroll.v_bar_width = try_extract_f32(value, name);
+            scroll.v_bar_width = try_extract_f32(value, name)?;
         },
         IpgScrollableParam::VBarMargin => {
-            scroll.v_bar_margin = try_extract_f32(value, name);
+            scroll.v_bar_margin = try_extract_f32(value, name)?;
         },
         IpgScrollableParam::VScrollerWidth => {
-            scroll.v_scroller_width = try_extract_f32(value, name);
+            scroll.v_scroller_width = try_extract_f32(value, name)?;
         },
         IpgScrollableParam::VSpacing => {
-            scroll.v_spacing = try_extract_f32(value, name);
+            scroll.v_spacing = try_extract_f32(value, name)?;
         },
         IpgScrollableParam::VBarAlignment => {
-            scroll.v_bar_alignment = try_extract_alignment(value);
+            scroll.v_bar_alignment = try_extract_alignment(value)?;
         },
         IpgScrollableParam::ScrollXTo => todo!(),
         IpgScrollableParam::ScrollYTo => todo!(),
+        IpgScrollableParam::Show => {
+            scroll.show = try_extract_boolean(value, name)?;
+        },
     }
+    Ok(())
+}
+
+pub fn scrollable_get_items(scroll: &IpgScrollable) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Width", item_length(scroll.width)),
+        ("Height", item_length(scroll.height)),
+        ("HBarWidth", Some(scroll.h_bar_width.into())),
+        ("HBarMargin", Some(scroll.h_bar_margin.into())),
+        ("HScrollerWidth", Some(scroll.h_scroller_width.into())),
+        ("HSpacing", Some(scroll.h_spacing.into())),
+        ("HBarAlignment", item_object(scroll.h_bar_alignment.clone())?),
+        ("VBarWidth", Some(scroll.v_bar_width.into())),
+        ("VBarMargin", Some(scroll.v_bar_margin.into())),
+        ("VScrollerWidth", Some(scroll.v_scroller_width.into())),
+        ("VSpacing", Some(scroll.v_spacing.into())),
+        ("VBarAlignment", item_object(scroll.v_bar_alignment.clone())?),
+        ("ScrollXTo", Some(scroll.scroll_x_pos.into())),
+        ("ScrollYTo", Some(scroll.scroll_y_pos.into())),
+        ("Show", Some(scroll.show.into())),
+    ])
 }
 
 
-pub fn try_extract_scrollable_update(update_obj: &PyObject) -> IpgScrollableParam {
+pub fn try_extract_scrollable_update(update_obj: &PyObject) -> PyResult<IpgScrollableParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgScrollableParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Scrollable update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Scrollable update extraction failed")),
         }
     })
 }
 
 
-pub fn try_extract_alignment(direct_obj: &PyObject) -> IpgScrollableAlignment {
+pub fn try_extract_alignment(direct_obj: &PyObject) -> PyResult<IpgScrollableAlignment> {
     Python::with_gil(|py| {
         let res = direct_obj.extract::<IpgScrollableAlignment>(py);
             
         match res {
-            Ok(align) => align,
-            Err(_) => panic!("ScrollableAlignment failed to extract."),
+            Ok(align) => Ok(align),
+            Err(_) => Err(IpgParamError::new_err("ScrollableAlignment failed to extract.")),
         }
     })  
 }
@@ -595,96 +584,97 @@ pub enum IpgScrollableStyleParam {
 
 pub fn scroll_style_update_item(style: &mut IpgScrollableStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_scroll_style_update(item);
+    let update = try_extract_scroll_style_update(item)?;
     let name = "ScrollableStyle".to_string();
     match update {
         IpgScrollableStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgScrollableStyleParam::BackgroundRbgaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgScrollableStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgScrollableStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgScrollableStyleParam::BorderRadius => {
-            style.border_radius = try_extract_vec_f32(value, name);
+            style.border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgScrollableStyleParam::BorderWidth => {
-            style.border_width = try_extract_f32(value, name);
+            style.border_width = try_extract_f32(value, name)?;
         },
         IpgScrollableStyleParam::ShadowIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.shadow_color = get_color(None, Some(color), 1.0, false);
         },
         IpgScrollableStyleParam::ShadowRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgScrollableStyleParam::ShadowOffsetX => {
-            style.shadow_offset_x = try_extract_f32(value, name);
+            style.shadow_offset_x = try_extract_f32(value, name)?;
         },
         IpgScrollableStyleParam::ShadowOffsetY => {
-            style.shadow_offset_y = try_extract_f32(value, name);
+            style.shadow_offset_y = try_extract_f32(value, name)?;
         },
         IpgScrollableStyleParam::ShadowBlurRadius => {
-            style.shadow_blur_radius = try_extract_f32(value, name);
+            style.shadow_blur_radius = try_extract_f32(value, name)?;
         },
         IpgScrollableStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgScrollableStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgScrollableStyleParam::ScrollbarIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.scrollbar_color = get_color(None, Some(color), 1.0, false);
         },
         IpgScrollableStyleParam::ScrollbarRgbaColor => {
-            style.scrollbar_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.scrollbar_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgScrollableStyleParam::ScrollbarBorderRadius => {
-            style.scrollbar_border_radius = try_extract_vec_f32(value, name);
+            style.scrollbar_border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgScrollableStyleParam::ScrollbarBorderWidth => {
-            style.scrollbar_border_width = try_extract_f32(value, name);
+            style.scrollbar_border_width = try_extract_f32(value, name)?;
         },
         IpgScrollableStyleParam::ScrollbarBorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.scrollbar_border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgScrollableStyleParam::ScrollbarBorderRgbaColor => {
-            style.scrollbar_border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.scrollbar_border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgScrollableStyleParam::ScrollerIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.scroller_color = get_color(None, Some(color), 1.0, false);
         },
         IpgScrollableStyleParam::ScrollerRgbaColor => {
-            style.scroller_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.scroller_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgScrollableStyleParam::ScrollerIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.scroller_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgScrollableStyleParam::ScrollerRgbaColorHovered => {
-            style.scroller_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.scroller_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgScrollableStyleParam::ScrollerIpgColorDragged => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.scroller_color_dragged = get_color(None, Some(color), 1.0, false);
         },
         IpgScrollableStyleParam::ScrollerRgbaColorDragged => {
-            style.scroller_color_dragged = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.scroller_color_dragged = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
 fn get_scroll_style(style: Option<&IpgWidgets>) -> Option<IpgScrollableStyle>{
@@ -696,13 +686,13 @@ fn get_scroll_style(style: Option<&IpgWidgets>) -> Option<IpgScrollableStyle>{
     }
 }
 
-pub fn try_extract_scroll_style_update(update_obj: &PyObject) -> IpgScrollableStyleParam {
+pub fn try_extract_scroll_style_update(update_obj: &PyObject) -> PyResult<IpgScrollableStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgScrollableStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Button style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Button style update extraction failed")),
         }
     })
 }
@@ -1,16 +1,17 @@
 //!ipg_selectable_text
 #![allow(clippy::enum_variant_names)]
-use crate::access_user_data1;
-use crate::access_user_data2;
+use super::callbacks::{dispatch_callback, dispatch_callback_at};
 use crate::app;
-use crate::access_callbacks;
 use crate::graphics::colors::get_color;
 use super::helpers::try_extract_ipg_color;
 use super::helpers::try_extract_vec_f32;
 use super::helpers::{get_height, get_width, try_extract_boolean,
-                    try_extract_f64, try_extract_string};
+                    try_extract_f64, try_extract_string, 
+                    item_color, item_fill, item_length, item_line_height, 
+                    item_object, IpgItems};
 use super::ipg_enums::IpgHorizontalAlignment;
 use super::ipg_enums::IpgVerticalAlignment;
+use super::ipg_errors::IpgParamError;
 
 use iced::Color;
 use iced::{Length, Element, Point};
@@ -20,7 +21,7 @@ use iced::widget::{MouseArea, Text};
 use iced::mouse::Interaction;
 
 use pyo3::pyclass;
-use pyo3::{PyObject, Python};
+use pyo3::{PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -174,69 +175,13 @@ fn process_callback(
     event_name: String, 
     points_opt: Option<(String, f32, String, f32)>) 
 {
-    let ud1 = access_user_data1();
-    let ud_opt = ud1.user_data.get(&id);
-
-    let app_cbs = access_callbacks();
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => cb,
-        None => return,
-    };
-
-    let cb = Python::with_gil(|py| callback.clone_ref(py));
-    drop(app_cbs);
-
-    // Execute the callback with user data from ud1
-    if let Some(user_data) = ud_opt {
-        Python::with_gil(|py| {
-            let res = match points_opt {
-                Some(ref points) => cb.call1(py, (id, points.clone(), user_data)),
-                None => cb.call1(py, (id, user_data)),
-            };
-
-            match res {
-                Ok(_) => (),
-                Err(err) => panic!("SelectableText callback error with user_data from ud1: {err}")
-            }
-        });
-        drop(ud1); // Drop ud1 after processing
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Execute the callback with user data from ud2
-    let ud2 = access_user_data2();
-    
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            let res = match points_opt {
-                Some(ref points) => cb.call1(py, (id, points.clone(), user_data)),
-                None => cb.call1(py, (id, user_data)),
-            };
-
-            match res {
-                Ok(_) => (),
-                Err(err) => panic!("SelectableText callback error with user_data from ud2: {err}")
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
+    match points_opt {
+        Some(points) => {
+            let position = Some(Point::new(points.1, points.3));
+            dispatch_callback_at("SelectableText", id, &event_name, position, (id, points))
+        },
+        None => dispatch_callback("SelectableText", id, &event_name, (id,)),
     }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // Execute the callback without user data
-    Python::with_gil(|py| {
-        let res = match points_opt {
-                Some(ref points) => cb.call1(py, (id, points.clone())),
-                None => cb.call1(py, (id,)),
-            };
-
-            match res {
-                Ok(_) => (),
-                Err(err) => panic!("SelectableText callback error without user_data: {err}")
-            }
-    });
-
 }
 
 
@@ -260,87 +205,106 @@ pub enum IpgSelectableTextParam {
 pub fn selectable_text_item_update(st: &mut IpgSelectableText,
                                         item: &PyObject,
                                         value: &PyObject
-                                    )
+                                    ) -> PyResult<()>
 {
-    let update = try_extract_selectable_update(item);
+    let update = try_extract_selectable_update(item)?;
     let name = "SelectableText".to_string();
     match update {
         IpgSelectableTextParam::Text => {
-            st.content = try_extract_string(value, name);
+            st.content = try_extract_string(value, name)?;
         },
         IpgSelectableTextParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             st.width = get_width(Some(val as f32), false);
         },
         IpgSelectableTextParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             st.width = get_width(None, val);
         },
         IpgSelectableTextParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             st.height = get_height(Some(val as f32), false);
         },
         IpgSelectableTextParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             st.height = get_height(None, val);
         }
         IpgSelectableTextParam::HorizontalAlign => {
-            st.horizontal_alignment = try_extract_hor_align(value);
+            st.horizontal_alignment = try_extract_hor_align(value)?;
         },
         IpgSelectableTextParam::VerticalAlign => {
-            st.vertical_alignment = try_extract_vert_align(value);
+            st.vertical_alignment = try_extract_vert_align(value)?;
         },
         IpgSelectableTextParam::LineHeight => {
-            let val = try_extract_f64(value, name) as f32;
+            let val = try_extract_f64(value, name)? as f32;
             st.line_height = LineHeight::Relative(val);
         },
         IpgSelectableTextParam::Size => {
-            st.size = try_extract_f64(value, name) as f32;
+            st.size = try_extract_f64(value, name)? as f32;
         },
         IpgSelectableTextParam::TextColor => {
-            let ipg_color = Some(try_extract_ipg_color(value, name));
+            let ipg_color = Some(try_extract_ipg_color(value, name)?);
             st.text_color = get_color(None, ipg_color, 1.0, false);
         },
         IpgSelectableTextParam::TextRgba => {
-            let v = try_extract_vec_f32(value, name);
+            let v = try_extract_vec_f32(value, name)?;
             let color_rgba = Some([v[0], v[1], v[2], v[3]]);
             st.text_color = get_color(color_rgba, None, 1.0, false);
         },
         IpgSelectableTextParam::Show => {
-            st.show = try_extract_boolean(value, name);
+            st.show = try_extract_boolean(value, name)?;
         },
     }
+    Ok(())
+}
+
+pub fn selectable_text_get_items(st: &IpgSelectableText) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Text", Some(st.content.clone().into())),
+        ("Width", item_length(st.width)),
+        ("WidthFill", item_fill(st.width)),
+        ("Height", item_length(st.height)),
+        ("HeightFill", item_fill(st.height)),
+        ("HorizontalAlign", item_object(st.horizontal_alignment.clone())?),
+        ("VerticalAlign", item_object(st.vertical_alignment.clone())?),
+        ("LineHeight", item_line_height(st.line_height)),
+        // the color is returned as rgba
+        ("TextColor", item_color(st.text_color)),
+        ("TextRgba", item_color(st.text_color)),
+        ("Size", Some(st.size.into())),
+        ("Show", Some(st.show.into())),
+    ])
 }
 
-fn try_extract_selectable_update(update_obj: &PyObject) -> IpgSelectableTextParam {
+fn try_extract_selectable_update(update_obj: &PyObject) -> PyResult<IpgSelectableTextParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgSelectableTextParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Button update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Button update extraction failed")),
         }
     })
 }
 
-fn try_extract_hor_align(value: &PyObject) -> IpgHorizontalAlignment {
+fn try_extract_hor_align(value: &PyObject) -> PyResult<IpgHorizontalAlignment> {
     
     Python::with_gil(|py| {
         let res = value.extract::<IpgHorizontalAlignment>(py);
         match res {
-            Ok(h_align) => h_align,
-            Err(_) => panic!("IpgSectableText: unable to extract IpgSelectableTextHorAlign"),
+            Ok(h_align) => Ok(h_align),
+            Err(_) => Err(IpgParamError::new_err("IpgSectableText: unable to extract IpgSelectableTextHorAlign")),
         }
     })
 }
 
-fn try_extract_vert_align(value: &PyObject) -> IpgVerticalAlignment {
+fn try_extract_vert_align(value: &PyObject) -> PyResult<IpgVerticalAlignment> {
     
     Python::with_gil(|py| {
         let res = value.extract::<IpgVerticalAlignment>(py);
         match res {
-            Ok(v_align) => v_align,
-            Err(_) => panic!("IpgSelectableText: unable to extract IpgSelectableTextHorAlign"),
+            Ok(v_align) => Ok(v_align),
+            Err(_) => Err(IpgParamError::new_err("IpgSelectableText: unable to extract IpgSelectableTextHorAlign")),
         }
     })
 }
@@ -8,13 +8,15 @@ use crate::app;
 use super::helpers::{get_height, get_width, 
     try_extract_boolean, try_extract_f64, try_extract_i64, 
     try_extract_ipg_color, try_extract_rgba_color, 
-    try_extract_string};
+    try_extract_string, 
+    item_fill, item_length, IpgItems};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use iced::border::Radius;
 
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use iced::widget::{row, Row, Text};
 use iced::{Background, Border, Color, Element, 
@@ -272,62 +274,79 @@ pub enum IpgSeparatorParam {
 pub fn separator_item_update(sep: &mut IpgSeparator,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_separator_update(item);
+    let update = try_extract_separator_update(item)?;
     let name = "Separator".to_string();
     match update {
         IpgSeparatorParam::DotBorderWidth => {
-            sep.dot_border_width = try_extract_f64(value, name) as f32;
+            sep.dot_border_width = try_extract_f64(value, name)? as f32;
         },
         IpgSeparatorParam::DotCount => {
-            sep.dot_count = try_extract_i64(value, name) as usize;
+            sep.dot_count = try_extract_i64(value, name)? as usize;
         },
         IpgSeparatorParam::DotFill => {
-            sep.dot_fill = try_extract_boolean(value, name);
+            sep.dot_fill = try_extract_boolean(value, name)?;
         },
         IpgSeparatorParam::DotRadius => {
-            sep.dot_radius = try_extract_f64(value, name) as f32;
+            sep.dot_radius = try_extract_f64(value, name)? as f32;
         },
         IpgSeparatorParam::Label => {
-            sep.label = Some(try_extract_string(value, name));
+            sep.label = Some(try_extract_string(value, name)?);
         },
         IpgSeparatorParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             sep.height = get_height(Some(val as f32), false);
         },
         IpgSeparatorParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             sep.height = get_height(None, val);
         },
         IpgSeparatorParam::Spacing => {
-            sep.spacing = try_extract_f64(value, name) as f32;
+            sep.spacing = try_extract_f64(value, name)? as f32;
         },
         IpgSeparatorParam::Show => {
-            sep.show = try_extract_boolean(value, name);
+            sep.show = try_extract_boolean(value, name)?;
         },
         IpgSeparatorParam::StyleId => {
-            sep.style_id = Some(try_extract_f64(value, name) as usize);
+            sep.style_id = Some(try_extract_f64(value, name)? as usize);
         },
         IpgSeparatorParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             sep.width = get_width(Some(val as f32), false);
         },
         IpgSeparatorParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             sep.width = get_width(None, val);
         },
     }
+    Ok(())
+}
 
+pub fn separator_get_items(sep: &IpgSeparator) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("DotCount", Some(sep.dot_count.into())),
+        ("DotFill", Some(sep.dot_fill.into())),
+        ("DotBorderWidth", Some(sep.dot_border_width.into())),
+        ("DotRadius", Some(sep.dot_radius.into())),
+        ("Height", item_length(sep.height)),
+        ("HeightFill", item_fill(sep.height)),
+        ("Label", sep.label.clone().map(Into::into)),
+        ("Spacing", Some(sep.spacing.into())),
+        ("Show", Some(sep.show.into())),
+        ("StyleId", sep.style_id.map(Into::into)),
+        ("Width", item_length(sep.width)),
+        ("WidthFill", item_fill(sep.width)),
+    ])
 }
 
-fn try_extract_separator_update(update_obj: &PyObject) -> IpgSeparatorParam {
+fn try_extract_separator_update(update_obj: &PyObject) -> PyResult<IpgSeparatorParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgSeparatorParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Separator update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Separator update extraction failed")),
         }
     })
 }
@@ -343,35 +362,36 @@ pub enum IpgSeparatorStyleParam {
 
 pub fn separator_style_update_item(style: &mut IpgSeparatorStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_separator_style_update(item);
+    let update = try_extract_separator_style_update(item)?;
     let name = "SeparatorStyle".to_string();
     match update {
         IpgSeparatorStyleParam::IpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.color = get_color(None, Some(color), 1.0, false);
         },
         IpgSeparatorStyleParam::RbgaColor => {
-            style.color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgSeparatorStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgSeparatorStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
-fn try_extract_separator_style_update(update_obj: &PyObject) -> IpgSeparatorStyleParam {
+fn try_extract_separator_style_update(update_obj: &PyObject) -> PyResult<IpgSeparatorStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgSeparatorStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Separator type update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Separator type update extraction failed")),
         }
     })
 }
@@ -0,0 +1,297 @@
+//! ipg_shortcuts
+//! Keyboard shortcuts given as accelerator strings, "Ctrl+Shift+S",
+//! "Mod+O" or the chord "Ctrl+K Ctrl+C".  The key presses are matched
+//! here, so python is only called when a shortcut is used.
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use iced::keyboard::{Key, Modifiers};
+use once_cell::sync::Lazy;
+use pyo3::prelude::*;
+
+use super::callbacks::{call_callback, IpgEventSource};
+
+
+// The named keys that can be used, the modifier keys are left out
+// since they are part of the key stroke.
+const NAMED_KEYS: [&str; 39] = [
+    "Tab", "Enter", "Escape", "Backspace", "Delete", "Insert", "Home", "End",
+    "PageUp", "PageDown", "ArrowUp", "ArrowDown", "ArrowLeft", "ArrowRight", "Space",
+    "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12",
+    "F13", "F14", "F15", "F16", "F17", "F18", "F19", "F20", "F21", "F22", "F23", "F24",
+];
+
+/// A key with the modifiers held, the key is lower case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpgKeyStroke {
+    pub modifiers: Modifiers,
+    pub key: String,
+}
+
+impl IpgKeyStroke {
+    /// The key stroke of a key press, None for a modifier key
+    /// so that a chord isn't broken by pressing Ctrl again.
+    pub fn from_key(key: Key<&str>, modifiers: Modifiers) -> Option<Self> {
+        let key = match key {
+            Key::Named(named) => {
+                let name = format!("{:?}", named);
+                NAMED_KEYS.contains(&name.as_str()).then(|| name.to_lowercase())?
+            },
+            Key::Character(chr) => chr.to_lowercase(),
+            Key::Unidentified => return None,
+        };
+        Some(Self { modifiers, key })
+    }
+
+    fn text(&self) -> String {
+        let mut parts = vec![];
+        if self.modifiers.control() {
+            parts.push("Ctrl");
+        }
+        if self.modifiers.alt() {
+            parts.push(if cfg!(target_os = "macos") { "Option" } else { "Alt" });
+        }
+        if self.modifiers.shift() {
+            parts.push("Shift");
+        }
+        if self.modifiers.logo() {
+            parts.push(if cfg!(target_os = "macos") { "Cmd" } else { "Super" });
+        }
+        let key = match NAMED_KEYS.iter().find(|name| name.to_lowercase() == self.key) {
+            Some(name) => name.to_string(),
+            None => self.key.to_uppercase(),
+        };
+        parts.push(&key);
+        parts.join("+")
+    }
+}
+
+/// Parses an accelerator, the strokes of a chord are separated by spaces.
+pub fn parse_accelerator(accelerator: &str) -> Result<Vec<IpgKeyStroke>, String> {
+    let strokes = accelerator.split_whitespace()
+        .map(parse_key_stroke)
+        .collect::<Result<Vec<IpgKeyStroke>, String>>()?;
+
+    if strokes.is_empty() {
+        return Err("The accelerator is empty".to_string())
+    }
+    Ok(strokes)
+}
+
+fn parse_key_stroke(stroke: &str) -> Result<IpgKeyStroke, String> {
+    // the last part is the key, "Ctrl++" is Ctrl and the plus key
+    let (mods, key) = match stroke.strip_suffix("++") {
+        Some(mods) => (mods, "+"),
+        None => stroke.rsplit_once('+').unwrap_or(("", stroke)),
+    };
+
+    let mut modifiers = Modifiers::empty();
+    for part in mods.split('+').filter(|part| !part.is_empty()) {
+        modifiers |= match part.to_lowercase().as_str() {
+            "ctrl" | "control" => Modifiers::CTRL,
+            "shift" => Modifiers::SHIFT,
+            "alt" | "option" => Modifiers::ALT,
+            "cmd" | "command" | "super" | "logo" | "win" | "meta" => Modifiers::LOGO,
+            // Cmd on macOS, Ctrl everywhere else
+            "mod" => Modifiers::COMMAND,
+            _ => return Err(format!("Unknown modifier {part} in {stroke}")),
+        };
+    }
+
+    let key = match key.to_lowercase().as_str() {
+        "" => return Err(format!("No key in {stroke}")),
+        "esc" => "escape".to_string(),
+        "del" => "delete".to_string(),
+        "ins" => "insert".to_string(),
+        "return" => "enter".to_string(),
+        "up" | "down" | "left" | "right" => format!("arrow{}", key.to_lowercase()),
+        "pgup" => "pageup".to_string(),
+        "pgdn" => "pagedown".to_string(),
+        "plus" => "+".to_string(),
+        "minus" => "-".to_string(),
+        name if name.chars().count() == 1 => name.to_string(),
+        name if NAMED_KEYS.iter().any(|named| named.to_lowercase() == name) => name.to_string(),
+        _ => return Err(format!("Unknown key {key} in {stroke}")),
+    };
+
+    Ok(IpgKeyStroke { modifiers, key })
+}
+
+/// The text shown for an accelerator, for example in a menu item.
+pub fn accelerator_text(strokes: &[IpgKeyStroke]) -> String {
+    strokes.iter()
+        .map(IpgKeyStroke::text)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+
+#[derive(Debug)]
+pub struct IpgShortcut {
+    pub strokes: Vec<IpgKeyStroke>,
+    pub window_id: Option<String>,
+    pub when_focused: Option<usize>,
+    pub callback: PyObject,
+    pub user_data: Option<PyObject>,
+}
+
+#[derive(Debug)]
+pub struct Shortcuts {
+    pub shortcuts: Lazy<HashMap<usize, IpgShortcut>>,
+    // the strokes of a chord typed so far
+    pub pending: Vec<IpgKeyStroke>,
+}
+
+pub static SHORTCUTS: Mutex<Shortcuts> = Mutex::new(Shortcuts {
+    shortcuts: Lazy::new(||HashMap::new()),
+    pending: vec![],
+});
+
+pub fn access_shortcuts() -> MutexGuard<'static, Shortcuts> {
+    SHORTCUTS.lock().unwrap()
+}
+
+static NEXT_SHORTCUT_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Adds the shortcut, unless it has the same window and focus as
+/// another one and one of them would hide the other.
+pub fn add_shortcut(shortcut: IpgShortcut) -> Result<usize, String> {
+    let mut shortcuts = access_shortcuts();
+
+    let conflict = shortcuts.shortcuts.values().find(|other| {
+        other.window_id == shortcut.window_id &&
+        other.when_focused == shortcut.when_focused &&
+        (other.strokes.starts_with(&shortcut.strokes) || shortcut.strokes.starts_with(&other.strokes))
+    });
+    if let Some(other) = conflict {
+        return Err(format!("The shortcut {} conflicts with the shortcut {}",
+                            accelerator_text(&shortcut.strokes), accelerator_text(&other.strokes)))
+    }
+
+    let id = NEXT_SHORTCUT_ID.fetch_add(1, Ordering::Relaxed);
+    shortcuts.shortcuts.insert(id, shortcut);
+    Ok(id)
+}
+
+pub fn remove_shortcut(id: usize) -> bool {
+    let mut shortcuts = access_shortcuts();
+    shortcuts.pending.clear();
+    shortcuts.shortcuts.remove(&id).is_some()
+}
+
+pub fn has_shortcuts() -> bool {
+    !access_shortcuts().shortcuts.is_empty()
+}
+
+/// Matches the key stroke, with any strokes of a chord typed before it.
+/// The shortcuts matched are returned with their when_focused id, the
+/// most specific first, so that the one to call can be picked once
+/// the focused widget is known.  Nothing is returned while a chord
+/// is being typed.
+pub fn match_key_stroke(window_id: Option<&str>, stroke: IpgKeyStroke) -> Vec<(usize, Option<usize>)> {
+    let mut shortcuts = access_shortcuts();
+    let Shortcuts { shortcuts, pending } = &mut *shortcuts;
+
+    let in_window: Vec<(&usize, &IpgShortcut)> = shortcuts.iter()
+        .filter(|(_, shortcut)| shortcut.window_id.is_none() || shortcut.window_id.as_deref() == window_id)
+        .collect();
+    let continues = |pending: &[IpgKeyStroke]| in_window.iter()
+        .any(|(_, shortcut)| shortcut.strokes.starts_with(pending));
+
+    pending.push(stroke.clone());
+    if pending.len() > 1 && !continues(pending.as_slice()) {
+        // a broken chord, the stroke may be a shortcut or start a new chord
+        *pending = vec![stroke];
+    }
+
+    let mut matched: Vec<(&usize, &IpgShortcut)> = in_window.iter()
+        .filter(|(_, shortcut)| shortcut.strokes == *pending)
+        .copied()
+        .collect();
+
+    if matched.is_empty() {
+        if !continues(pending.as_slice()) {
+            pending.clear();
+        }
+        return vec![]
+    }
+    pending.clear();
+
+    matched.sort_by_key(|(id, shortcut)| (shortcut.when_focused.is_none(), shortcut.window_id.is_none(), **id));
+    matched.iter()
+        .map(|(id, shortcut)| (**id, shortcut.when_focused))
+        .collect()
+}
+
+/// Calls the shortcut's callback with the shortcut id and accelerator text.
+pub fn process_shortcut(id: usize) {
+    let shortcut = Python::with_gil(|py| {
+        access_shortcuts().shortcuts.get(&id)
+            .map(|shortcut| (shortcut.callback.clone_ref(py),
+                            shortcut.user_data.as_ref().map(|ud| ud.clone_ref(py)),
+                            accelerator_text(&shortcut.strokes)))
+    });
+
+    if let Some((callback, user_data, text)) = shortcut {
+        let source = IpgEventSource::new("Shortcut", id, "on_shortcut");
+        call_callback(source, callback, user_data, (id, text));
+    }
+}
+
+
+#[cfg(test)]
+fn test_shortcut(accelerator: &str, window_id: Option<&str>) -> Result<usize, String> {
+    pyo3::prepare_freethreaded_python();
+    let callback = Python::with_gil(|py| py.None());
+    add_shortcut(IpgShortcut {
+        strokes: parse_accelerator(accelerator)?,
+        window_id: window_id.map(str::to_string),
+        when_focused: None,
+        callback,
+        user_data: None,
+    })
+}
+
+#[test]
+fn test_parse_accelerator() {
+    let strokes = parse_accelerator("ctrl+shift+s").unwrap();
+    assert_eq!(Modifiers::CTRL | Modifiers::SHIFT, strokes[0].modifiers);
+    assert_eq!("s", strokes[0].key);
+    assert_eq!("Ctrl+Shift+S", accelerator_text(&strokes));
+
+    assert_eq!(Modifiers::COMMAND, parse_accelerator("Mod+O").unwrap()[0].modifiers);
+    assert_eq!("+", parse_accelerator("Ctrl++").unwrap()[0].key);
+    assert_eq!("Alt+PageUp Escape",
+        accelerator_text(&parse_accelerator("alt+pgup  esc").unwrap()).replace("Option", "Alt"));
+    assert!(parse_accelerator("Hyper+S").is_err());
+    assert!(parse_accelerator("Ctrl+Banana").is_err());
+    assert!(parse_accelerator(" ").is_err());
+}
+
+#[test]
+fn test_shortcut_chords_and_conflicts() {
+    let stroke = |accelerator| parse_accelerator(accelerator).unwrap().remove(0);
+
+    let save = test_shortcut("Ctrl+Alt+F7", Some("shortcut test")).unwrap();
+    let chord = test_shortcut("Ctrl+Alt+F8 Ctrl+Alt+F9", None).unwrap();
+    // the same strokes, or a prefix of a chord, in the same window
+    assert!(test_shortcut("Ctrl+Alt+F7", Some("shortcut test")).is_err());
+    assert!(test_shortcut("Ctrl+Alt+F8", None).is_err());
+    // a window shortcut is used before a shortcut in every window
+    let global = test_shortcut("Ctrl+Alt+F7", None).unwrap();
+
+    assert_eq!(vec![(save, None), (global, None)],
+                match_key_stroke(Some("shortcut test"), stroke("Ctrl+Alt+F7")));
+    assert_eq!(vec![(global, None)], match_key_stroke(Some("other"), stroke("Ctrl+Alt+F7")));
+
+    assert!(match_key_stroke(None, stroke("Ctrl+Alt+F8")).is_empty());
+    assert_eq!(vec![(chord, None)], match_key_stroke(None, stroke("Ctrl+Alt+F9")));
+    // a broken chord, the last stroke is matched on its own
+    assert!(match_key_stroke(None, stroke("Ctrl+Alt+F8")).is_empty());
+    assert_eq!(vec![(global, None)], match_key_stroke(None, stroke("Ctrl+Alt+F7")));
+
+    for id in [save, chord, global] {
+        assert!(remove_shortcut(id));
+    }
+}
@@ -1,20 +1,23 @@
 //! ipg_slider
 use crate::graphics::colors::get_color;
-use crate::{access_callbacks, access_user_data1, access_user_data2, app, IpgState};
+use crate::{app, IpgState};
+use super::callbacks::dispatch_callback;
 use super::callbacks::{set_or_get_widget_callback_data, 
     WidgetCallbackIn};
 
-use super::helpers::{get_radius, get_width, try_extract_ipg_color, 
+use super::helpers::{block_input, get_radius, get_width, try_extract_ipg_color, 
     try_extract_rgba_color, try_extract_u16, try_extract_vec_f32, 
-    try_extract_boolean, try_extract_f64};
+    try_extract_boolean, try_extract_f64, 
+    item_fill, item_length, IpgItems};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use iced::border::Radius;
 use iced::widget::slider::{self, HandleShape, Status, Style};
 use iced::{Background, Color, Element, Length, Theme};
 use iced::widget::Slider;
 
-use pyo3::{PyObject, pyclass, Python};
+use pyo3::{PyObject, pyclass, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -22,6 +25,7 @@ pub struct IpgSlider {
     pub id: usize,
     pub parent_id: String,
     pub show: bool,
+    pub enabled: bool,
     
     pub min: f32,
     pub max: f32,
@@ -37,6 +41,7 @@ impl IpgSlider {
         id: usize,
         parent_id: String,
         show: bool,
+        enabled: bool,
         min: f32,
         max: f32,
         step: f32,
@@ -49,6 +54,7 @@ impl IpgSlider {
             id,
             parent_id,
             show,
+            enabled,
             min,
             max,
             step,
@@ -73,6 +79,8 @@ pub struct IpgSliderStyle {
     pub handle_color: Option<Color>,
     pub handle_border_width: Option<f32>,
     pub handle_border_color: Option<Color>,
+    pub rail_color_disabled: Option<Color>,
+    pub handle_color_disabled: Option<Color>,
 }
 
 impl IpgSliderStyle {
@@ -88,6 +96,8 @@ impl IpgSliderStyle {
         handle_color: Option<Color>,
         handle_border_width: Option<f32>,
         handle_border_color: Option<Color>,
+        rail_color_disabled: Option<Color>,
+        handle_color_disabled: Option<Color>,
     ) -> Self {
         Self {
             id,
@@ -101,6 +111,8 @@ impl IpgSliderStyle {
             handle_color,
             handle_border_width,
             handle_border_color,
+            rail_color_disabled,
+            handle_color_disabled,
         }
     }
 }
@@ -133,10 +145,13 @@ pub fn construct_slider<'a>(slider: &'a IpgSlider,
                     .height(slider.height)
                     .style(move|theme, status|
                     get_styling(theme, status,
-                        style.clone()
+                        style.clone(),
+                        slider.enabled,
                     ))
                     .into();
 
+    let sld = if slider.enabled { sld } else { block_input(sld) };
+
     Some(sld.map(move |message| app::Message::Slider(slider.id, message)))
 }
 
@@ -163,49 +178,7 @@ pub fn process_callback(
         event_name: String, 
         value: f32) 
 {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, value, user_data)) {
-                panic!("Slider callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, value, user_data)) {
-                panic!("Slider callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with only the id and value
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id, value)) {
-            panic!("Slider callback error: {err}");
-        }
-    });
-
+    dispatch_callback("Slider", id, &event_name, (id, value));
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -219,65 +192,93 @@ pub enum IpgSliderParam {
     WidthFill,
     Height,
     Style,
+    Enabled,
     Show,
 }
 
 pub fn slider_item_update(sldr: &mut IpgSlider, 
                             item: &PyObject, 
-                            value: &PyObject) {
+                            value: &PyObject) -> PyResult<()> {
 
-    let update = try_extract_slider_update(item);
+    let update = try_extract_slider_update(item)?;
     let name = "Slider".to_string();
     match update {
         IpgSliderParam::Min => {
-            sldr.min = try_extract_f64(value, name) as f32;
+            sldr.min = try_extract_f64(value, name)? as f32;
         },
         IpgSliderParam::Max => {
-            sldr.max = try_extract_f64(value, name) as f32;
+            sldr.max = try_extract_f64(value, name)? as f32;
         },
         IpgSliderParam::Step => {
-            sldr.step = try_extract_f64(value, name) as f32;
+            sldr.step = try_extract_f64(value, name)? as f32;
         },
         IpgSliderParam::Value => {
-            sldr.value = try_extract_f64(value, name) as f32;
+            sldr.value = try_extract_f64(value, name)? as f32;
         },
         IpgSliderParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             sldr.width = get_width(Some(val as f32), false);
         },
         IpgSliderParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             sldr.width = get_width(None, val);
         },
         IpgSliderParam::Height => {
-            sldr.height = try_extract_f64(value, name) as f32;
+            sldr.height = try_extract_f64(value, name)? as f32;
         },
         IpgSliderParam::Style => {
-            sldr.style_id = Some(try_extract_f64(value, name) as usize);
+            sldr.style_id = Some(try_extract_f64(value, name)? as usize);
         }
+        IpgSliderParam::Enabled => {
+            sldr.enabled = try_extract_boolean(value, name)?;
+        },
         IpgSliderParam::Show => {
-            sldr.show = try_extract_boolean(value, name);
+            sldr.show = try_extract_boolean(value, name)?;
         },
     }
+    Ok(())
+}
+
+pub fn slider_get_items(sldr: &IpgSlider) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Min", Some(sldr.min.into())),
+        ("Max", Some(sldr.max.into())),
+        ("Step", Some(sldr.step.into())),
+        ("Value", Some(sldr.value.into())),
+        ("Width", item_length(sldr.width)),
+        ("WidthFill", item_fill(sldr.width)),
+        ("Height", Some(sldr.height.into())),
+        ("Style", sldr.style_id.map(Into::into)),
+        ("Enabled", Some(sldr.enabled.into())),
+        ("Show", Some(sldr.show.into())),
+    ])
 }
 
 
-fn try_extract_slider_update(update_obj: &PyObject) -> IpgSliderParam {
+fn try_extract_slider_update(update_obj: &PyObject) -> PyResult<IpgSliderParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgSliderParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Slider update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Slider update extraction failed")),
         }
     })
 }
 
 fn get_styling(theme: &Theme, 
                 status: Status,
-                style_opt: Option<IpgSliderStyle>) 
+                style_opt: Option<IpgSliderStyle>,
+                enabled: bool) 
                 -> Style {
 
+    // a slider has no disabled status, it is drawn without the hover
+    // in the disabled colors or faded
+    if !enabled {
+        let active_style = get_styling(theme, Status::Active, style_opt.clone(), true);
+        return disabled(active_style, style_opt.as_ref())
+    }
+
     if style_opt.is_none() {
         return slider::default(theme, status)
     }     
@@ -349,6 +350,23 @@ fn get_styling(theme: &Theme,
 
 }
 
+// The disabled colors of the style are used, the active style is faded
+// where they are not set.
+fn disabled(style: Style, ipg_style: Option<&IpgSliderStyle>) -> Style {
+    let mut style = style;
+    style.rail.backgrounds = match ipg_style.and_then(|ipg_style| ipg_style.rail_color_disabled) {
+        Some(color) => (Background::Color(color), Background::Color(color)),
+        None => (style.rail.backgrounds.0.scale_alpha(0.5), 
+                style.rail.backgrounds.1.scale_alpha(0.5)),
+    };
+    style.handle.background = match ipg_style.and_then(|ipg_style| ipg_style.handle_color_disabled) {
+        Some(color) => Background::Color(color),
+        None => style.handle.background.scale_alpha(0.5),
+    };
+    style.handle.border_color = style.handle.border_color.scale_alpha(0.5);
+    style
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[pyclass(eq, eq_int)]
 pub enum IpgSliderStyleParam {
@@ -367,71 +385,91 @@ pub enum IpgSliderStyleParam {
     HandleCircleRadius,
     HandleRectangleWidth,
     HandleRectangleBorderRadius,
+
+    RailIpgColorDisabled,
+    RailRgbaColorDisabled,
+    HandleIpgColorDisabled,
+    HandleRgbaColorDisabled,
 }
 
 pub fn slider_style_update_item(style: &mut IpgSliderStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_slider_style_update(item);
+    let update = try_extract_slider_style_update(item)?;
     let name = "SliderStyle".to_string();
     match update {
         IpgSliderStyleParam::RailIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.rail_color = get_color(None, Some(color), 1.0, false);
         },
         IpgSliderStyleParam::RailRbgaColor => {
-            style.rail_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.rail_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgSliderStyleParam::RailIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.rail_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgSliderStyleParam::RailIpgRgbaHovered => {
-            style.rail_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.rail_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgSliderStyleParam::RailBorderRadius => {
-            style.rail_border_radius = Some(try_extract_vec_f32(value, name));
+            style.rail_border_radius = Some(try_extract_vec_f32(value, name)?);
         },
         IpgSliderStyleParam::RailWidth => {
-            style.rail_width = Some(try_extract_f64(value, name) as f32);
+            style.rail_width = Some(try_extract_f64(value, name)? as f32);
         },
         IpgSliderStyleParam::HandleIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.handle_color = get_color(None, Some(color), 1.0, false);
         },
         IpgSliderStyleParam::HandleRgbaColor => {
-            style.handle_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.handle_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgSliderStyleParam::HandleBorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.handle_border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgSliderStyleParam::HandleBorderRgbaColor => {
-            style.handle_border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.handle_border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgSliderStyleParam::HandleBorderWidth => {
-            style.handle_border_width = Some(try_extract_f64(value, name) as f32);
+            style.handle_border_width = Some(try_extract_f64(value, name)? as f32);
         },
         IpgSliderStyleParam::HandleCircleRadius => {
-            style.handle_circle_radius = Some(try_extract_f64(value, name) as f32);
+            style.handle_circle_radius = Some(try_extract_f64(value, name)? as f32);
         },
         IpgSliderStyleParam::HandleRectangleWidth => {
-            style.handle_rectangle_width = Some(try_extract_u16(value, name));
+            style.handle_rectangle_width = Some(try_extract_u16(value, name)?);
         },
         IpgSliderStyleParam::HandleRectangleBorderRadius => {
-            style.handle_rectangle_border_radius = Some(try_extract_vec_f32(value, name));
+            style.handle_rectangle_border_radius = Some(try_extract_vec_f32(value, name)?);
+        },
+        IpgSliderStyleParam::RailIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.rail_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgSliderStyleParam::RailRgbaColorDisabled => {
+            style.rail_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
+        },
+        IpgSliderStyleParam::HandleIpgColorDisabled => {
+            let color = try_extract_ipg_color(value, name)?;
+            style.handle_color_disabled = get_color(None, Some(color), 1.0, false);
+        },
+        IpgSliderStyleParam::HandleRgbaColorDisabled => {
+            style.handle_color_disabled = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_slider_style_update(update_obj: &PyObject) -> IpgSliderStyleParam {
+pub fn try_extract_slider_style_update(update_obj: &PyObject) -> PyResult<IpgSliderStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgSliderStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Slider style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Slider style update extraction failed")),
         }
     })
 }
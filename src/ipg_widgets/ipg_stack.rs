@@ -1,11 +1,13 @@
 //! ipg_stack
 use iced::{Element, Length};
 use iced::widget::Stack;
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use crate::app::Message;
 
-use super::helpers::{get_height, get_width, try_extract_boolean, try_extract_f32};
+use super::helpers::{get_height, get_width, try_extract_boolean, try_extract_f32, 
+    item_fill, item_length, IpgItems};
+use super::ipg_errors::IpgParamError;
 
 #[derive(Debug, Clone)]
 pub struct IpgStack {
@@ -71,38 +73,49 @@ pub enum IpgStackParam {
 
 pub fn stack_item_update(stk: &mut IpgStack,
                             item: &PyObject,
-                            value: &PyObject,) {
+                            value: &PyObject,) -> PyResult<()> {
 
-    let update = try_extract_stack_update(item);
+    let update = try_extract_stack_update(item)?;
     let name = "Stack".to_string();
     match update {
         IpgStackParam::Width => {
-            let w = Some(try_extract_f32(value, name));
+            let w = Some(try_extract_f32(value, name)?);
             stk.width = get_width(w, false)
         },
         IpgStackParam::WidthFill => {
-            stk.width = get_width(None, try_extract_boolean(value, name));
+            stk.width = get_width(None, try_extract_boolean(value, name)?);
         },
         IpgStackParam::Height => {
-            let h = Some(try_extract_f32(value, name));
+            let h = Some(try_extract_f32(value, name)?);
             stk.height = get_height(h, false)
         },
         IpgStackParam::HeightFill => {
-            stk.height = get_height(None, try_extract_boolean(value, name));
+            stk.height = get_height(None, try_extract_boolean(value, name)?);
         },
         IpgStackParam::Show => {
-            stk.show = try_extract_boolean(value, name);
+            stk.show = try_extract_boolean(value, name)?;
         },
     }
+    Ok(())
 }
 
-pub fn try_extract_stack_update(update_obj: &PyObject) -> IpgStackParam {
+pub fn stack_get_items(stk: &IpgStack) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Width", item_length(stk.width)),
+        ("WidthFill", item_fill(stk.width)),
+        ("Height", item_length(stk.height)),
+        ("HeightFill", item_fill(stk.height)),
+        ("Show", Some(stk.show.into())),
+    ])
+}
+
+pub fn try_extract_stack_update(update_obj: &PyObject) -> PyResult<IpgStackParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgStackParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Stack update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Stack update extraction failed")),
         }
     })
 }
@@ -0,0 +1,635 @@
+//! ipg_stream_plot
+use std::cell::Cell;
+use std::collections::VecDeque;
+
+use iced::mouse;
+use iced::widget::canvas::{self, Canvas, Geometry, Path, Stroke};
+use iced::{alignment, Color, Element, Length, Point, Rectangle, Renderer, Theme};
+use pyo3::{pyclass, PyObject, Python, PyResult};
+
+use crate::{access_stream_samples, app, IpgState};
+
+use super::helpers::{get_height, get_width, try_extract_boolean, try_extract_f64,
+    try_extract_f64_option, try_extract_string, try_extract_usize, 
+    item_fill, item_length, IpgItems};
+use super::ipg_chart::{format_tick, get_nice_ticks};
+use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
+
+
+#[derive(Debug, Clone)]
+pub struct IpgStreamPlot {
+    pub id: usize,
+    pub parent_id: String,
+    pub show: bool,
+
+    pub series: Vec<IpgStreamSeries>,
+    pub capacity: usize,
+    pub x_window: Option<f64>,
+    pub y_min: Option<f64>,
+    pub y_max: Option<f64>,
+    pub title: Option<String>,
+    pub show_legend: bool,
+    pub text_size: f32,
+    pub width: Length,
+    pub height: Length,
+    // bumped when samples arrive so the drawing cache is refreshed
+    pub version: u64,
+}
+
+impl IpgStreamPlot {
+    pub fn new(
+        id: usize,
+        parent_id: String,
+        show: bool,
+        series_names: Vec<String>,
+        series_colors: Option<Vec<Color>>,
+        capacity: usize,
+        x_window: Option<f64>,
+        y_min: Option<f64>,
+        y_max: Option<f64>,
+        title: Option<String>,
+        show_legend: bool,
+        text_size: f32,
+        width: Length,
+        height: Length,
+    ) -> Self {
+        let series = series_names.into_iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let color = match &series_colors {
+                    Some(colors) if i < colors.len() => colors[i],
+                    _ => STREAM_PALETTE[i % STREAM_PALETTE.len()],
+                };
+                IpgStreamSeries { name, color, buffer: IpgRingBuffer::new(capacity) }
+            })
+            .collect();
+
+        Self {
+            id,
+            parent_id,
+            show,
+            series,
+            capacity,
+            x_window,
+            y_min,
+            y_max,
+            title,
+            show_legend,
+            text_size,
+            width,
+            height,
+            version: 0,
+        }
+    }
+
+    // A copy with empty buffers, for the items read by get_item.
+    pub fn without_samples(&self) -> Self {
+        let series = self.series.iter()
+            .map(|series| IpgStreamSeries {
+                name: series.name.clone(),
+                color: series.color,
+                buffer: IpgRingBuffer {
+                    capacity: series.buffer.capacity,
+                    xs: VecDeque::new(),
+                    ys: VecDeque::new(),
+                },
+            })
+            .collect();
+
+        Self {
+            id: self.id,
+            parent_id: self.parent_id.clone(),
+            show: self.show,
+            series,
+            capacity: self.capacity,
+            x_window: self.x_window,
+            y_min: self.y_min,
+            y_max: self.y_max,
+            title: self.title.clone(),
+            show_legend: self.show_legend,
+            text_size: self.text_size,
+            width: self.width,
+            height: self.height,
+            version: self.version,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IpgStreamSeries {
+    pub name: String,
+    pub color: Color,
+    pub buffer: IpgRingBuffer,
+}
+
+// A fixed capacity buffer, the oldest samples are dropped
+// once the capacity is reached.
+#[derive(Debug, Clone)]
+pub struct IpgRingBuffer {
+    pub capacity: usize,
+    pub xs: VecDeque<f64>,
+    pub ys: VecDeque<f64>,
+}
+
+impl IpgRingBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            xs: VecDeque::with_capacity(capacity),
+            ys: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn extend(&mut self, xs: &[f64], ys: &[f64]) {
+        // only the tail can survive when more samples than the capacity arrive
+        let count = xs.len().min(ys.len());
+        let start = count.saturating_sub(self.capacity);
+        for (x, y) in xs[start..count].iter().zip(ys[start..count].iter()) {
+            self.push(*x, *y);
+        }
+    }
+
+    pub fn append(&mut self, other: &IpgRingBuffer) {
+        let start = other.len().saturating_sub(self.capacity);
+        for (x, y) in other.iter().skip(start) {
+            self.push(x, y);
+        }
+    }
+
+    fn push(&mut self, x: f64, y: f64) {
+        if self.xs.len() == self.capacity {
+            self.xs.pop_front();
+            self.ys.pop_front();
+        }
+        self.xs.push_back(x);
+        self.ys.push_back(y);
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) {
+        while self.xs.len() > capacity {
+            self.xs.pop_front();
+            self.ys.pop_front();
+        }
+        self.capacity = capacity;
+    }
+
+    pub fn clear(&mut self) {
+        self.xs.clear();
+        self.ys.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.xs.iter().copied().zip(self.ys.iter().copied())
+    }
+}
+
+// The buffers are allocated up front, a million samples is about 16MB a series.
+pub const MAX_STREAM_CAPACITY: usize = 1_000_000;
+
+pub fn check_capacity(capacity: usize) -> PyResult<usize> {
+    if capacity == 0 || capacity > MAX_STREAM_CAPACITY {
+        return Err(IpgParamError::new_err(format!(
+            "StreamPlot: capacity {capacity} must be from 1 to {MAX_STREAM_CAPACITY}")))
+    }
+    Ok(capacity)
+}
+
+const STREAM_PALETTE: [Color; 6] = [
+    Color::from_rgb(0.122, 0.467, 0.706),
+    Color::from_rgb(1.0, 0.498, 0.055),
+    Color::from_rgb(0.173, 0.627, 0.173),
+    Color::from_rgb(0.839, 0.153, 0.157),
+    Color::from_rgb(0.580, 0.404, 0.741),
+    Color::from_rgb(0.549, 0.337, 0.294),
+];
+
+// Moves the samples appended from python into the plots.
+// Called on the wake up and the canvas timer tick, the samples
+// appended in between are drawn together.  The plots aren't
+// mirrored, the mirror has no samples.
+pub fn process_stream_samples(state: &mut IpgState) {
+    let mut pending = access_stream_samples();
+    if pending.samples.is_empty() {
+        return
+    }
+    let samples = std::mem::take(&mut *pending.samples);
+    drop(pending);
+
+    // checked by append_samples, the plot may have been deleted since
+    for ((wid, series_index), samples) in samples.iter() {
+        let plot = match state.widgets.get_mut(wid) {
+            Some(IpgWidgets::IpgStreamPlot(plot)) => plot,
+            _ => continue,
+        };
+        let series = match plot.series.get_mut(*series_index) {
+            Some(series) => series,
+            None => continue,
+        };
+        series.buffer.append(samples);
+        plot.version += 1;
+    }
+}
+
+pub fn construct_stream_plot(plot: &IpgStreamPlot) -> Option<Element<app::Message>> {
+
+    if !plot.show {
+        return None
+    }
+
+    Some(Canvas::new(plot)
+            .width(plot.width)
+            .height(plot.height)
+            .into())
+}
+
+#[derive(Default)]
+pub struct StreamPlotState {
+    cache: canvas::Cache,
+    version: Cell<u64>,
+}
+
+impl canvas::Program<app::Message> for IpgStreamPlot {
+    type State = StreamPlotState;
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+
+        if state.version.get() != self.version {
+            state.version.set(self.version);
+            state.cache.clear();
+        }
+
+        let geometry = state.cache.draw(renderer, bounds.size(), |frame| {
+            draw_stream_plot(self, frame, theme.palette().text);
+        });
+
+        vec![geometry]
+    }
+}
+
+// The x window follows the newest sample of all the series.
+pub fn get_stream_x_range(plot: &IpgStreamPlot) -> Option<(f64, f64)> {
+    let mut x0 = f64::INFINITY;
+    let mut x1 = f64::NEG_INFINITY;
+    for series in plot.series.iter() {
+        if let (Some(first), Some(last)) = (series.buffer.xs.front(), series.buffer.xs.back()) {
+            x0 = x0.min(*first);
+            x1 = x1.max(*last);
+        }
+    }
+    if !x1.is_finite() {
+        return None
+    }
+    if let Some(window) = plot.x_window {
+        x0 = x1 - window;
+    }
+    if x1 <= x0 {
+        x0 = x1 - 1.0;
+    }
+    Some((x0, x1))
+}
+
+// Reduces the points to the min and max of each pixel column,
+// keeping the order they occurred in so the line stays continuous.
+pub fn decimate_min_max(
+    points: impl Iterator<Item = (f64, f64)>,
+    x_range: (f64, f64),
+    columns: usize,
+) -> Vec<(f64, f64)> {
+    let columns = columns.max(1);
+    let span = x_range.1 - x_range.0;
+    let mut output = vec![];
+    // column, the min and the max points
+    let mut current: Option<(usize, (f64, f64), (f64, f64))> = None;
+
+    for (x, y) in points {
+        if x < x_range.0 || x > x_range.1 {
+            continue;
+        }
+        let column = (((x - x_range.0) / span * columns as f64) as usize).min(columns - 1);
+        match current.as_mut() {
+            Some((col, min, max)) if *col == column => {
+                if y < min.1 {
+                    *min = (x, y);
+                }
+                if y > max.1 {
+                    *max = (x, y);
+                }
+            },
+            _ => {
+                if let Some(bucket) = current.take() {
+                    push_bucket(bucket, &mut output);
+                }
+                current = Some((column, (x, y), (x, y)));
+            },
+        }
+    }
+    if let Some(bucket) = current {
+        push_bucket(bucket, &mut output);
+    }
+
+    output
+}
+
+fn push_bucket(bucket: (usize, (f64, f64), (f64, f64)), output: &mut Vec<(f64, f64)>) {
+    let (_, min, max) = bucket;
+    if min == max {
+        output.push(min);
+    } else if min.0 <= max.0 {
+        output.push(min);
+        output.push(max);
+    } else {
+        output.push(max);
+        output.push(min);
+    }
+}
+
+fn draw_stream_plot(plot: &IpgStreamPlot, frame: &mut canvas::Frame, text_color: Color) {
+    let size = frame.size();
+    let top = if plot.title.is_some() { plot.text_size * 2.0 } else { plot.text_size };
+    let area = Rectangle {
+        x: plot.text_size * 3.8,
+        y: top,
+        width: (size.width - plot.text_size * 4.8).max(1.0),
+        height: (size.height - top - plot.text_size * 2.0).max(1.0),
+    };
+    let grid_color = Color { a: 0.15, ..text_color };
+    let axis_color = Color { a: 0.6, ..text_color };
+    let tick_size = plot.text_size * 0.85;
+
+    if let Some(title) = &plot.title {
+        frame.fill_text(canvas::Text {
+            content: title.clone(),
+            position: Point::new(area.x + area.width / 2.0, top / 2.0),
+            color: text_color,
+            size: (plot.text_size * 1.2).into(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            ..canvas::Text::default()
+        });
+    }
+
+    frame.stroke(
+        &Path::new(|p| {
+            p.move_to(Point::new(area.x, area.y));
+            p.line_to(Point::new(area.x, area.y + area.height));
+            p.line_to(Point::new(area.x + area.width, area.y + area.height));
+        }),
+        Stroke::default().with_width(1.0).with_color(axis_color),
+    );
+
+    let Some(x_range) = get_stream_x_range(plot) else {
+        return
+    };
+
+    let columns = area.width as usize;
+    let decimated: Vec<Vec<(f64, f64)>> = plot.series.iter()
+        .map(|s| decimate_min_max(s.buffer.iter(), x_range, columns))
+        .collect();
+
+    let (mut y0, mut y1) = (f64::INFINITY, f64::NEG_INFINITY);
+    for (_, y) in decimated.iter().flatten() {
+        y0 = y0.min(*y);
+        y1 = y1.max(*y);
+    }
+    if !y0.is_finite() {
+        y0 = 0.0;
+        y1 = 1.0;
+    }
+    let pad = if y1 > y0 { (y1 - y0) * 0.05 } else { 0.5 };
+    let y_range = (plot.y_min.unwrap_or(y0 - pad), plot.y_max.unwrap_or(y1 + pad));
+
+    let to_pixel = |x: f64, y: f64| Point::new(
+        area.x + ((x - x_range.0) / (x_range.1 - x_range.0)) as f32 * area.width,
+        area.y + area.height - ((y - y_range.0) / (y_range.1 - y_range.0)) as f32 * area.height,
+    );
+
+    let max_y_ticks = (area.height / (tick_size * 3.0)).max(2.0) as usize;
+    let (y_ticks, y_step) = get_nice_ticks(y_range.0, y_range.1, max_y_ticks);
+    for tick in y_ticks.iter() {
+        let y = to_pixel(x_range.0, *tick).y;
+        frame.stroke(
+            &Path::line(Point::new(area.x, y), Point::new(area.x + area.width, y)),
+            Stroke::default().with_width(1.0).with_color(grid_color),
+        );
+        frame.fill_text(canvas::Text {
+            content: format_tick(*tick, y_step),
+            position: Point::new(area.x - 6.0, y),
+            color: text_color,
+            size: tick_size.into(),
+            horizontal_alignment: alignment::Horizontal::Right,
+            vertical_alignment: alignment::Vertical::Center,
+            ..canvas::Text::default()
+        });
+    }
+
+    let max_x_ticks = (area.width / (tick_size * 6.0)).max(2.0) as usize;
+    let (x_ticks, x_step) = get_nice_ticks(x_range.0, x_range.1, max_x_ticks);
+    for tick in x_ticks.iter() {
+        let x = to_pixel(*tick, y_range.0).x;
+        frame.fill_text(canvas::Text {
+            content: format_tick(*tick, x_step),
+            position: Point::new(x, area.y + area.height + 6.0),
+            color: text_color,
+            size: tick_size.into(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Top,
+            ..canvas::Text::default()
+        });
+    }
+
+    frame.with_clip(area, |frame| {
+        // the clipped frame starts at the plot origin
+        frame.translate(iced::Vector::new(-area.x, -area.y));
+        for (series, points) in plot.series.iter().zip(decimated.iter()) {
+            if points.len() < 2 {
+                continue;
+            }
+            let path = Path::new(|p| {
+                p.move_to(to_pixel(points[0].0, points[0].1));
+                for (x, y) in points.iter().skip(1) {
+                    p.line_to(to_pixel(*x, *y));
+                }
+            });
+            frame.stroke(&path, Stroke::default().with_width(1.5).with_color(series.color));
+        }
+    });
+
+    if plot.show_legend && plot.series.len() > 1 {
+        let mut x = area.x + 8.0;
+        for series in plot.series.iter() {
+            frame.fill(
+                &Path::rectangle(Point::new(x, area.y + 4.0),
+                    iced::Size::new(plot.text_size * 0.7, plot.text_size * 0.7)),
+                series.color);
+            frame.fill_text(canvas::Text {
+                content: series.name.clone(),
+                position: Point::new(x + plot.text_size, area.y + 4.0 + plot.text_size * 0.35),
+                color: text_color,
+                size: plot.text_size.into(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+                ..canvas::Text::default()
+            });
+            x += plot.text_size * (series.name.chars().count() as f32 * 0.6 + 2.5);
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass(eq, eq_int)]
+pub enum IpgStreamPlotParam {
+    Capacity,
+    Clear,
+    Height,
+    HeightFill,
+    Show,
+    ShowLegend,
+    Title,
+    Width,
+    WidthFill,
+    XWindow,
+    YMax,
+    YMin,
+}
+
+pub fn stream_plot_item_update(plot: &mut IpgStreamPlot,
+                                item: &PyObject,
+                                value: &PyObject,
+                                ) -> PyResult<()>
+{
+    let update = try_extract_stream_plot_update(item)?;
+    let name = "StreamPlot".to_string();
+    match update {
+        IpgStreamPlotParam::Capacity => {
+            plot.capacity = check_capacity(try_extract_usize(value, name)?)?;
+            for series in plot.series.iter_mut() {
+                series.buffer.set_capacity(plot.capacity);
+            }
+        },
+        IpgStreamPlotParam::Clear => {
+            if try_extract_boolean(value, name)? {
+                for series in plot.series.iter_mut() {
+                    series.buffer.clear();
+                }
+            }
+        },
+        IpgStreamPlotParam::Height => {
+            let val = try_extract_f64(value, name)?;
+            plot.height = get_height(Some(val as f32), false);
+        },
+        IpgStreamPlotParam::HeightFill => {
+            let val = try_extract_boolean(value, name)?;
+            plot.height = get_height(None, val);
+        },
+        IpgStreamPlotParam::Show => {
+            plot.show = try_extract_boolean(value, name)?;
+        },
+        IpgStreamPlotParam::ShowLegend => {
+            plot.show_legend = try_extract_boolean(value, name)?;
+        },
+        IpgStreamPlotParam::Title => {
+            plot.title = Some(try_extract_string(value, name)?);
+        },
+        IpgStreamPlotParam::Width => {
+            let val = try_extract_f64(value, name)?;
+            plot.width = get_width(Some(val as f32), false);
+        },
+        IpgStreamPlotParam::WidthFill => {
+            let val = try_extract_boolean(value, name)?;
+            plot.width = get_width(None, val);
+        },
+        IpgStreamPlotParam::XWindow => {
+            plot.x_window = try_extract_f64_option(value);
+        },
+        IpgStreamPlotParam::YMax => {
+            plot.y_max = try_extract_f64_option(value);
+        },
+        IpgStreamPlotParam::YMin => {
+            plot.y_min = try_extract_f64_option(value);
+        },
+    }
+    plot.version += 1;
+    Ok(())
+}
+
+pub fn stream_plot_get_items(plot: &IpgStreamPlot) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Capacity", Some(plot.capacity.into())),
+        ("Height", item_length(plot.height)),
+        ("HeightFill", item_fill(plot.height)),
+        ("Show", Some(plot.show.into())),
+        ("ShowLegend", Some(plot.show_legend.into())),
+        ("Title", plot.title.clone().map(Into::into)),
+        ("Width", item_length(plot.width)),
+        ("WidthFill", item_fill(plot.width)),
+        ("XWindow", plot.x_window.map(|x| (x as f32).into())),
+        ("YMax", plot.y_max.map(|y| (y as f32).into())),
+        ("YMin", plot.y_min.map(|y| (y as f32).into())),
+    ])
+}
+
+pub fn try_extract_stream_plot_update(update_obj: &PyObject) -> PyResult<IpgStreamPlotParam> {
+
+    Python::with_gil(|py| {
+        let res = update_obj.extract::<IpgStreamPlotParam>(py);
+        match res {
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("StreamPlot update extraction failed")),
+        }
+    })
+}
+
+
+#[test]
+fn test_ring_buffer_keeps_newest() {
+    let mut buffer = IpgRingBuffer::new(3);
+    buffer.extend(&[1.0, 2.0], &[10.0, 20.0]);
+    buffer.extend(&[3.0, 4.0], &[30.0, 40.0]);
+    assert_eq!(vec![(2.0, 20.0), (3.0, 30.0), (4.0, 40.0)], buffer.iter().collect::<Vec<_>>());
+
+    buffer.extend(&[5.0, 6.0, 7.0, 8.0], &[50.0, 60.0, 70.0, 80.0]);
+    assert_eq!(vec![6.0, 7.0, 8.0], buffer.xs.iter().copied().collect::<Vec<_>>());
+
+    buffer.set_capacity(2);
+    assert_eq!(vec![7.0, 8.0], buffer.xs.iter().copied().collect::<Vec<_>>());
+
+    // the pending samples are appended the same way
+    let mut pending = IpgRingBuffer::new(3);
+    pending.extend(&[9.0, 10.0, 11.0], &[90.0, 100.0, 110.0]);
+    buffer.append(&pending);
+    assert_eq!(vec![(10.0, 100.0), (11.0, 110.0)], buffer.iter().collect::<Vec<_>>());
+
+    assert!(check_capacity(0).is_err());
+    assert!(check_capacity(MAX_STREAM_CAPACITY + 1).is_err());
+    assert_eq!(1000, check_capacity(1000).unwrap());
+}
+
+#[test]
+fn test_decimate_min_max() {
+    let points: Vec<(f64, f64)> = (0..100).map(|i| (i as f64, if i % 2 == 0 { 1.0 } else { -1.0 })).collect();
+    let decimated = decimate_min_max(points.iter().copied(), (0.0, 100.0), 10);
+    // one min and one max per column
+    assert_eq!(20, decimated.len());
+    assert!(decimated.iter().all(|(_, y)| *y == 1.0 || *y == -1.0));
+
+    // points outside of the window are dropped
+    let decimated = decimate_min_max(points.iter().copied(), (90.0, 100.0), 100);
+    assert_eq!(10, decimated.len());
+}
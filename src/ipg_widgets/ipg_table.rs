@@ -3,7 +3,8 @@
 
 use crate::app::Message;
 use crate::graphics::colors::get_color;
-use crate::{access_callbacks, access_user_data1, access_user_data2, IpgState};
+use crate::IpgState;
+use super::callbacks::dispatch_callback;
 
 use iced::border::Radius;
 use iced::widget::scrollable::Scrollbar;
@@ -13,13 +14,15 @@ use iced::{Element, Renderer, Theme};
 use iced::widget::{column, container, Space, row, scrollable, stack, text};
 
 use polars::frame::DataFrame;
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 use pyo3_polars::PyDataFrame;
 
 use super::callbacks::{set_or_get_widget_callback_data, WidgetCallbackIn};
 use super::divider::{self, divider_horizontal};
-use super::helpers::{try_extract_boolean, try_extract_f32, try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, try_extract_usize, try_extract_vec_f32, try_extract_vec_usize};
+use super::helpers::{try_extract_boolean, try_extract_f32, try_extract_f64, try_extract_ipg_color, try_extract_rgba_color, try_extract_usize, try_extract_vec_f32, try_extract_vec_usize, 
+    item_dataframe, item_value, IpgItems};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 
 #[derive(Debug, Clone)]
@@ -555,49 +558,7 @@ pub fn process_callback1(
         index: usize, 
         value: Vec<f32>) 
 {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, index, value, user_data)) {
-                panic!("Table callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, index, value, user_data)) {
-                panic!("Table callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with only the id, index, and value
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id, index, value)) {
-            panic!("Table callback error: {err}");
-        }
-    });
-
+    dispatch_callback("Table", id, &event_name, (id, index, value));
 }
 
 // Table Divider released
@@ -605,49 +566,7 @@ pub fn process_callback2(
         id: usize, 
         event_name: String) 
 {
-     let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, user_data)) {
-                panic!("Table Divider release callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, user_data)) {
-                panic!("Table Divider release callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with only the id
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id,)) {
-            panic!("Table Divider release callback error: {err}");
-        }
-    });
-
+    dispatch_callback("Table Divider release", id, &event_name, (id,));
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -695,139 +614,182 @@ pub fn table_item_update(
                     table: &mut IpgTable,
                     item: &PyObject,
                     value: &PyObject,
-                    ) 
+                    ) -> PyResult<()> 
 {
-    let update = try_extract_table_update(item);
+    let update = try_extract_table_update(item)?;
     let name = "Table".to_string();
     match update {
         IpgTableParam::ColumnWidths => {
-            table.column_widths = try_extract_vec_f32(value, name);
+            table.column_widths = try_extract_vec_f32(value, name)?;
         },
         IpgTableParam::Width => {
-            table.width = Some(try_extract_f32(value, name));
+            table.width = Some(try_extract_f32(value, name)?);
         },
         IpgTableParam::Height => {
-            table.height = try_extract_f32(value, name);
+            table.height = try_extract_f32(value, name)?;
         },
         IpgTableParam::HeaderEnabled => {
-            table.header_enabled = try_extract_boolean(value, name);
+            table.header_enabled = try_extract_boolean(value, name)?;
         },
         IpgTableParam::HeaderHeight => {
-            table.header_row_height = try_extract_f32(value, name);
+            table.header_row_height = try_extract_f32(value, name)?;
         },
         IpgTableParam::HeaderScrollbarSpacing => {
-            table.header_scrollbar_spacing = try_extract_f32(value, name);
+            table.header_scrollbar_spacing = try_extract_f32(value, name)?;
         },
         IpgTableParam::HeaderRowSpacing => {
-            table.header_row_spacing = try_extract_f32(value, name);
+            table.header_row_spacing = try_extract_f32(value, name)?;
         },
         IpgTableParam::FooterHeight => {
-            table.footer_height = try_extract_f32(value, name);
+            table.footer_height = try_extract_f32(value, name)?;
         },
         IpgTableParam::FooterScrollbarSpacing => {
-            table.footer_scrollbar_spacing = try_extract_f32(value, name);
+            table.footer_scrollbar_spacing = try_extract_f32(value, name)?;
         },
         IpgTableParam::FooterSpacing => {
-            table.footer_spacing = try_extract_f32(value, name);
+            table.footer_spacing = try_extract_f32(value, name)?;
         },
         IpgTableParam::BodyScrollbarSpacing => {
-            table.body_scrollbar_spacing = try_extract_f32(value, name);
+            table.body_scrollbar_spacing = try_extract_f32(value, name)?;
         },
         IpgTableParam::CustomHeaderRows => {
-            table.custom_header_rows = try_extract_usize(value, name);
+            table.custom_header_rows = try_extract_usize(value, name)?;
         },
         IpgTableParam::CustomFooterRows => {
-            table.custom_footer_rows = try_extract_usize(value, name);
+            table.custom_footer_rows = try_extract_usize(value, name)?;
         },
         IpgTableParam::ControlColumns => {
-            table.control_columns = try_extract_vec_usize(value, name);
+            table.control_columns = try_extract_vec_usize(value, name)?;
         },
         IpgTableParam::RowHeight => {
-            table.row_height = try_extract_f32(value, name);
+            table.row_height = try_extract_f32(value, name)?;
         },
         IpgTableParam::HeaderBodySpacing => {
-            table.header_body_spacing = try_extract_f32(value, name);
+            table.header_body_spacing = try_extract_f32(value, name)?;
         },
         IpgTableParam::BodyFooterSpacing => {
-            table.body_footer_spacing = try_extract_f32(value, name);
+            table.body_footer_spacing = try_extract_f32(value, name)?;
         },
         IpgTableParam::TextSize => {
-            table.text_size = try_extract_f32(value, name);
+            table.text_size = try_extract_f32(value, name)?;
         },
         IpgTableParam::StyleId => {
-            table.style_id = Some(try_extract_usize(value, name));
+            table.style_id = Some(try_extract_usize(value, name)?);
         },
         IpgTableParam::ColumnProportionalResize => {
-            table.column_proportional_resize = try_extract_boolean(value, name);
+            table.column_proportional_resize = try_extract_boolean(value, name)?;
         },
         IpgTableParam::RowSpacing => {
-            table.row_spacing = try_extract_f64(value, name) as f32;
+            table.row_spacing = try_extract_f64(value, name)? as f32;
         },
         IpgTableParam::ResizerWidth => {
-            table.resizer_width = try_extract_f64(value, name) as f32;
+            table.resizer_width = try_extract_f64(value, name)? as f32;
         },
         IpgTableParam::ResizeColumnsEnabled => {
-            table.resize_columns_enabled = try_extract_boolean(value, name);
+            table.resize_columns_enabled = try_extract_boolean(value, name)?;
         },
         IpgTableParam::MinColumnWidth => {
-            table.min_column_width = Some(try_extract_f64(value, name) as f32);
+            table.min_column_width = Some(try_extract_f64(value, name)? as f32);
         },
         IpgTableParam::Show => {
-            table.show = try_extract_boolean(value, name);
+            table.show = try_extract_boolean(value, name)?;
         },
         IpgTableParam::TableWidthFixed => {
-            table.table_width_fixed = try_extract_boolean(value, name);
+            table.table_width_fixed = try_extract_boolean(value, name)?;
         },
         IpgTableParam::HeaderScrollbarHeight => {
-            table.header_scrollbar_height = try_extract_f32(value, name);
+            table.header_scrollbar_height = try_extract_f32(value, name)?;
         },
         IpgTableParam::HeaderScrollbarMargin => {
-            table.header_scrollbar_margin = try_extract_f32(value, name);
+            table.header_scrollbar_margin = try_extract_f32(value, name)?;
         },
         IpgTableParam::HeaderScrollerHeight => {
-            table.header_scroller_height = try_extract_f32(value, name);
+            table.header_scroller_height = try_extract_f32(value, name)?;
         },
         IpgTableParam::FooterScrollbarHeight => {
-            table.footer_scrollbar_height = try_extract_f32(value, name);
+            table.footer_scrollbar_height = try_extract_f32(value, name)?;
         },
         IpgTableParam::FooterScrollbarMargin => {
-            table.footer_scrollbar_margin = try_extract_f32(value, name);
+            table.footer_scrollbar_margin = try_extract_f32(value, name)?;
         },
         IpgTableParam::FooterScrollerHeight => {
-            table.footer_scroller_height = try_extract_f32(value, name);
+            table.footer_scroller_height = try_extract_f32(value, name)?;
         },
         IpgTableParam::BodyScrollbarWidth => {
-            table.body_scrollbar_width = try_extract_f32(value, name);
+            table.body_scrollbar_width = try_extract_f32(value, name)?;
         },
         IpgTableParam::BodyScrollbarMargin => {
-            table.body_scrollbar_margin = try_extract_f32(value, name);
+            table.body_scrollbar_margin = try_extract_f32(value, name)?;
         },
         IpgTableParam::BodyScrollerWidth => {
-            table.body_scroller_width = try_extract_f32(value, name);
+            table.body_scroller_width = try_extract_f32(value, name)?;
         },
         _ => ()
     }
+    Ok(())
 }
 
 pub fn table_dataframe_update( 
                     table: &mut IpgTable,
                     item: &PyObject,
                     value: &PyDataFrame,
-                    ) 
+                    ) -> PyResult<()> 
 {
-    let update = try_extract_table_update(item);
+    let update = try_extract_table_update(item)?;
     if update == IpgTableParam::PolarsDf {
          let df = Into::<DataFrame>::into(value.clone());
          table.df= df;
      }
+    Ok(())
 }
-pub fn try_extract_table_update(update_obj: &PyObject) -> IpgTableParam {
+
+pub fn table_get_items(table: &IpgTable) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("PolarsDf", item_dataframe(&table.df)?),
+        ("ColumnWidths", Some(table.column_widths.clone().into())),
+        ("Height", Some(table.height.into())),
+        ("Width", table.width.map(Into::into)),
+        ("ResizerWidth", Some(table.resizer_width.into())),
+        ("HeaderEnabled", Some(table.header_enabled.into())),
+        ("HeaderHeight", Some(table.header_row_height.into())),
+        ("HeaderScrollbarHeight", Some(table.header_scrollbar_height.into())),
+        ("HeaderScrollbarMargin", Some(table.header_scrollbar_margin.into())),
+        ("HeaderScrollerHeight", Some(table.header_scroller_height.into())),
+        ("HeaderScrollbarSpacing", Some(table.header_scrollbar_spacing.into())),
+        ("HeaderRowSpacing", Some(table.header_row_spacing.into())),
+        ("FooterHeight", Some(table.footer_height.into())),
+        ("FooterScrollbarHeight", Some(table.footer_scrollbar_height.into())),
+        ("FooterScrollbarMargin", Some(table.footer_scrollbar_margin.into())),
+        ("FooterScrollerHeight", Some(table.footer_scroller_height.into())),
+        ("FooterScrollbarSpacing", Some(table.footer_scrollbar_spacing.into())),
+        ("FooterSpacing", Some(table.footer_spacing.into())),
+        ("BodyScrollbarWidth", Some(table.body_scrollbar_width.into())),
+        ("BodyScrollbarMargin", Some(table.body_scrollbar_margin.into())),
+        ("BodyScrollerWidth", Some(table.body_scroller_width.into())),
+        ("BodyScrollbarSpacing", Some(table.body_scrollbar_spacing.into())),
+        ("CustomHeaderRows", Some(table.custom_header_rows.into())),
+        ("CustomFooterRows", Some(table.custom_footer_rows.into())),
+        ("ControlColumns", item_value(table.control_columns.clone())?),
+        ("ColumnProportionalResize", Some(table.column_proportional_resize.into())),
+        ("RowSpacing", Some(table.row_spacing.into())),
+        ("RowHeight", Some(table.row_height.into())),
+        ("HeaderBodySpacing", Some(table.header_body_spacing.into())),
+        ("BodyFooterSpacing", Some(table.body_footer_spacing.into())),
+        ("ResizeColumnsEnabled", Some(table.resize_columns_enabled.into())),
+        ("MinColumnWidth", table.min_column_width.map(Into::into)),
+        ("TextSize", Some(table.text_size.into())),
+        ("Show", Some(table.show.into())),
+        ("TableWidthFixed", Some(table.table_width_fixed.into())),
+        ("StyleId", table.style_id.map(Into::into)),
+    ])
+}
+pub fn try_extract_table_update(update_obj: &PyObject) -> PyResult<IpgTableParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTableParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Table update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Table update extraction failed")),
         }
     })
 }
@@ -1326,145 +1288,145 @@ pub fn table_style_update_item(
         style: &mut IpgTableStyle,
         item: &PyObject,
         value: &PyObject,
-    ) 
+    ) -> PyResult<()> 
 {
-    let update = try_extract_table_style_update(item);
+    let update = try_extract_table_style_update(item)?;
     let name = "TableStyle".to_string();
     match update {
         IpgTableStyleParam::HeaderBackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.header_background = Some(get_color(None, Some(color), 1.0, false).unwrap().into());
         },
         IpgTableStyleParam::BodyBackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.body_background = Some(get_color(None, Some(color), 1.0, false).unwrap().into());
         },
         IpgTableStyleParam::FooterBackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.footer_background = Some(get_color(None, Some(color), 1.0, false).unwrap().into());
         },
         IpgTableStyleParam::DividerBackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.divider_background = Some(get_color(None, Some(color), 1.0, false).unwrap().into());
         },
         IpgTableStyleParam::DividerHoverIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.divider_hover_color = Some(get_color(None, Some(color), 1.0, false).unwrap().into());
         },
         IpgTableStyleParam::ScrollerBackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.scroller = Some(get_color(None, Some(color), 1.0, false).unwrap().into());
         },
         IpgTableStyleParam::ScrollerHoverIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.scroller_hover = Some(get_color(None, Some(color), 1.0, false).unwrap().into());
         },
         IpgTableStyleParam::ScrollerRailIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.rail = Some(get_color(None, Some(color), 1.0, false).unwrap().into());
         },
         IpgTableStyleParam::HeaderBackgroundRgbaColor => {
-            style.header_background = Some(Color::from(try_extract_rgba_color(value, name)).into());
+            style.header_background = Some(Color::from(try_extract_rgba_color(value, name)?).into());
         },
         IpgTableStyleParam::BodyBackgroundRgbaColor => {
-            style.body_background = Some(Color::from(try_extract_rgba_color(value, name)).into());
+            style.body_background = Some(Color::from(try_extract_rgba_color(value, name)?).into());
         },
         IpgTableStyleParam::FooterBackgroundRgbaColor => {
-            style.footer_background = Some(Color::from(try_extract_rgba_color(value, name)).into());
+            style.footer_background = Some(Color::from(try_extract_rgba_color(value, name)?).into());
         },
         IpgTableStyleParam::DividerBackgroundRgbaColor => {
-            style.divider_background = Some(Color::from(try_extract_rgba_color(value, name)).into());
+            style.divider_background = Some(Color::from(try_extract_rgba_color(value, name)?).into());
         },
         IpgTableStyleParam::DividerHoverRgbaColor => {
-            style.divider_hover_color = Some(Color::from(try_extract_rgba_color(value, name)).into());
+            style.divider_hover_color = Some(Color::from(try_extract_rgba_color(value, name)?).into());
         },
         IpgTableStyleParam::ScrollerBackgroundRgbaColor => {
-            style.scroller = Some(Color::from(try_extract_rgba_color(value, name)).into());
+            style.scroller = Some(Color::from(try_extract_rgba_color(value, name)?).into());
         },
         IpgTableStyleParam::ScrollerHoverRgbaColor => {
-            style.scroller_hover = Some(Color::from(try_extract_rgba_color(value, name)).into());
+            style.scroller_hover = Some(Color::from(try_extract_rgba_color(value, name)?).into());
         },
         IpgTableStyleParam::ScrollerRailRgbaColor => {
-            style.rail = Some(Color::from(try_extract_rgba_color(value, name)).into());
+            style.rail = Some(Color::from(try_extract_rgba_color(value, name)?).into());
         },
         IpgTableStyleParam::HeaderBorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.header_border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTableStyleParam::BodyBorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.body_border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTableStyleParam::FooterBorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.footer_border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTableStyleParam::HeaderBorderRgbaColor => {
-            style.header_border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.header_border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTableStyleParam::BodyBorderRgbaColor => {
-            style.body_border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.body_border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTableStyleParam::FooterBorderRgbaColor => {
-            style.footer_border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.footer_border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTableStyleParam::HeaderBorderRadius => {
-            style.header_border_radius = try_extract_f32(value, name).into();
+            style.header_border_radius = try_extract_f32(value, name)?.into();
         },
         IpgTableStyleParam::BodyBorderRadius => {
-            style.body_border_radius = try_extract_f32(value, name).into();
+            style.body_border_radius = try_extract_f32(value, name)?.into();
         },
         IpgTableStyleParam::FooterBorderRadius => {
-            style.footer_border_radius = try_extract_f32(value, name).into();
+            style.footer_border_radius = try_extract_f32(value, name)?.into();
         },
         IpgTableStyleParam::HeaderBorderWidth => {
-            style.header_border_width = try_extract_f32(value, name);
+            style.header_border_width = try_extract_f32(value, name)?;
         },
         IpgTableStyleParam::BodyBorderWidth => {
-            style.body_border_width = try_extract_f32(value, name);
+            style.body_border_width = try_extract_f32(value, name)?;
         },
         IpgTableStyleParam::FooterBorderWidth => {
-            style.footer_border_width = try_extract_f32(value, name);
+            style.footer_border_width = try_extract_f32(value, name)?;
         },
         IpgTableStyleParam::HeaderTextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.header_text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTableStyleParam::BodyTextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.body_text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTableStyleParam::FooterTextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.footer_text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTableStyleParam::HeaderTextRgbaColor => {
-            style.header_text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.header_text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTableStyleParam::BodyTextRgbaColor => {
-            style.body_text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.body_text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTableStyleParam::FooterTextRgbaColor => {
-            style.footer_text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.footer_text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTableStyleParam::BodyRowHighlightColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.body_row_highlight = get_color(None, Some(color), 1.0, false);
         },
         IpgTableStyleParam::BodyRowHighlightRgba => {
-            style.body_row_highlight = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.body_row_highlight = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
-
+    Ok(())
 }
 
-pub fn try_extract_table_style_update(update_obj: &PyObject) -> IpgTableStyleParam {
+pub fn try_extract_table_style_update(update_obj: &PyObject) -> PyResult<IpgTableStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTableStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Table style parameter update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Table style parameter update extraction failed")),
         }
     })
 }
@@ -5,13 +5,15 @@ use iced::widget::Text;
 use crate::app::Message;
 use crate::graphics::colors::get_color;
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use super::helpers::{get_height, get_horizontal_alignment, 
     get_vertical_alignment, get_width, try_extract_boolean, 
     try_extract_f64, try_extract_ipg_color, try_extract_string, 
-    try_extract_vec_f32};
+    try_extract_vec_f32, 
+    item_color, item_fill, item_length, item_line_height, item_object, IpgItems};
 use super::ipg_enums::{IpgHorizontalAlignment, IpgVerticalAlignment};
+use super::ipg_errors::IpgParamError;
 
 #[derive(Debug, Clone)]
 pub struct IpgText {
@@ -115,88 +117,107 @@ pub enum IpgTextParam {
 
 pub fn text_item_update(txt: &mut IpgText, 
                         item: &PyObject, 
-                        value: &PyObject) {
+                        value: &PyObject) -> PyResult<()> {
 
-    let update = try_extract_text_update(item);
+    let update = try_extract_text_update(item)?;
     let name = "Text".to_string();
     match update {
         IpgTextParam::Content => {
-            txt.content = try_extract_string(value, name);
+            txt.content = try_extract_string(value, name)?;
         },
         IpgTextParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             txt.height = get_height(Some(val as f32), false); 
         },
         IpgTextParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             txt.height = get_height(None, val);
         },
         IpgTextParam::AlignX => {
-            txt.align_x = try_extract_hor_alignment(value);
+            txt.align_x = try_extract_hor_alignment(value)?;
         },
         IpgTextParam::AlignY => {
-            txt.align_y = try_extract_vert_alignment(value);
+            txt.align_y = try_extract_vert_alignment(value)?;
         },
         IpgTextParam::LineHeight => {
-            let val = try_extract_f64(value, name) as f32;
+            let val = try_extract_f64(value, name)? as f32;
             txt.line_height = LineHeight::Relative(val);
         },
         IpgTextParam::Show => {
-            txt.show = try_extract_boolean(value, name);
+            txt.show = try_extract_boolean(value, name)?;
         },
         IpgTextParam::Size => {
-            txt.size = try_extract_f64(value, name) as f32;
+            txt.size = try_extract_f64(value, name)? as f32;
         },
         IpgTextParam::TextColor => {
-            let ipg_color = Some(try_extract_ipg_color(value, name));
+            let ipg_color = Some(try_extract_ipg_color(value, name)?);
             txt.style = get_color(None, ipg_color, 1.0, false);
         },
         IpgTextParam::TextRgba => {
-            let v = try_extract_vec_f32(value, name);
+            let v = try_extract_vec_f32(value, name)?;
             let color_rgba = Some([v[0], v[1], v[2], v[3]]);
             txt.style = get_color(color_rgba, None, 1.0, false);
         },
         IpgTextParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             txt.width = get_width(Some(val as f32), false);
         },
         IpgTextParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             txt.width = get_width(None, val);
         },
     }
+    Ok(())
+}
+
+pub fn text_get_items(txt: &IpgText) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Content", Some(txt.content.clone().into())),
+        ("Height", item_length(txt.height)),
+        ("HeightFill", item_fill(txt.height)),
+        ("AlignX", item_object(txt.align_x.clone())?),
+        ("AlignY", item_object(txt.align_y.clone())?),
+        ("LineHeight", item_line_height(txt.line_height)),
+        ("Show", Some(txt.show.into())),
+        ("Size", Some(txt.size.into())),
+        // the color is returned as rgba
+        ("TextColor", item_color(txt.style)),
+        ("TextRgba", item_color(txt.style)),
+        ("Width", item_length(txt.width)),
+        ("WidthFill", item_fill(txt.width)),
+    ])
 }
 
 
-fn try_extract_text_update(update_obj: &PyObject) -> IpgTextParam {
+fn try_extract_text_update(update_obj: &PyObject) -> PyResult<IpgTextParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTextParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Text update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Text update extraction failed")),
         }
     })
 }
 
-fn try_extract_hor_alignment(update_obj: &PyObject) -> IpgHorizontalAlignment {
+fn try_extract_hor_alignment(update_obj: &PyObject) -> PyResult<IpgHorizontalAlignment> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgHorizontalAlignment>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Text HorizontalAlignment extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Text HorizontalAlignment extraction failed")),
         }
     })
 }
 
-fn try_extract_vert_alignment(update_obj: &PyObject) -> IpgVerticalAlignment {
+fn try_extract_vert_alignment(update_obj: &PyObject) -> PyResult<IpgVerticalAlignment> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgVerticalAlignment>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Text VerticalAlignment extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Text VerticalAlignment extraction failed")),
         }
     })
 }
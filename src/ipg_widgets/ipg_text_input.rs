@@ -1,12 +1,15 @@
 //! ipg_text_input
 #![allow(clippy::enum_variant_names)]
 use crate::graphics::colors::get_color;
-use crate::{access_callbacks, access_user_data1, access_user_data2, app, IpgState};
+use crate::{app, IpgState};
+use super::callbacks::dispatch_callback;
 use super::callbacks::{set_or_get_widget_callback_data, WidgetCallbackIn};
-use super::helpers::{get_padding_f64, get_radius, get_width, try_extract_ipg_color, try_extract_rgba_color, try_extract_vec_f32};
+use super::helpers::{get_padding_f64, get_radius, get_width, try_extract_ipg_color, try_extract_rgba_color, try_extract_vec_f32, 
+    item_length, item_padding, IpgItems};
 use super::helpers::{try_extract_boolean, try_extract_f64, 
     try_extract_string, try_extract_u16, try_extract_vec_f64};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use iced::widget::text::LineHeight;
 use iced::widget::text_input;
@@ -15,7 +18,7 @@ use iced::{Color, Element, Length, Padding, Pixels, Theme};
 use iced::widget::TextInput;
 
 use pyo3::pyclass;
-use pyo3::{PyObject, Python};
+use pyo3::{PyObject, Python, PyResult};
 
 
 #[derive(Debug, Clone)]
@@ -33,6 +36,7 @@ pub struct IpgTextInput {
     // icon: Option<Message>,
     pub style_id: Option<usize>,
     pub show: bool,
+    pub enabled: bool,
 }
 
 impl IpgTextInput {
@@ -49,6 +53,7 @@ impl IpgTextInput {
         // icon: Option<Message>,
         style_id: Option<usize>,
         show: bool,
+        enabled: bool,
         ) -> Self {
         Self {
             id,
@@ -64,6 +69,7 @@ impl IpgTextInput {
             // icon,
             style_id,
             show,
+            enabled,
         }
     }
 }
@@ -134,7 +140,9 @@ pub fn construct_text_input<'a>(input: &'a IpgTextInput,
     let txt: Element<TIMessage> =  TextInput::new(input.placeholder.as_str(), 
                                                 input.value.as_str()
                                             )
-                                            .on_input(TIMessage::OnInput)
+                                            // the same id as focus_id
+                                            .id(text_input::Id::new(input.id.to_string()))
+                                            .on_input_maybe(input.enabled.then_some(TIMessage::OnInput))
                                             .on_submit(TIMessage::OnSubmit(input.value.clone()))
                                             .on_paste(TIMessage::OnPaste)
                                             .secure(input.is_secure)
@@ -186,54 +194,22 @@ pub fn text_input_callback(state: &mut IpgState, id: usize, message: TIMessage)
     }
 }
 
+/// The on_focus or on_blur callback with the value of the text input.
+pub fn text_input_focus_callback(state: &IpgState, id: usize, focused: bool) {
+    let value = match state.widgets.get(&id) {
+        Some(IpgWidgets::IpgTextInput(input)) => input.value.clone(),
+        _ => return,
+    };
+    let event_name = if focused { "on_focus" } else { "on_blur" };
+    process_callback(id, event_name.to_string(), value);
+}
+
 pub fn process_callback(
         id: usize, 
         event_name: String, 
         value: String) 
 {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, value, user_data)) {
-                panic!("TextInput callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, value, user_data)) {
-                panic!("TextInput callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with the id and value
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id, value)) {
-            panic!("TextInput callback error: {err}");
-        }
-    });
-
+    dispatch_callback("TextInput", id, &event_name, (id, value));
 }
 
 
@@ -248,59 +224,83 @@ pub enum IpgTextInputParam {
     Size,
     LineHeightPixels,
     LineHeightRelative,
+    Enabled,
     StyleId,
 }
 
 pub fn text_input_item_update(ti: &mut IpgTextInput,
                                 item: &PyObject,
                                 value: &PyObject,
-                                )
+                                ) -> PyResult<()>
 {
-    let update = try_extract_text_input_update(item);
+    let update = try_extract_text_input_update(item)?;
     let name = "TextInput".to_string();
     match update {
         IpgTextInputParam::Placeholder => {
-            ti.placeholder = try_extract_string(value, name);
+            ti.placeholder = try_extract_string(value, name)?;
         },
         IpgTextInputParam::Value => {
-            ti.value = try_extract_string(value, name);
+            ti.value = try_extract_string(value, name)?;
         },
         IpgTextInputParam::IsSecure => {
-            ti.is_secure = try_extract_boolean(value, name);
+            ti.is_secure = try_extract_boolean(value, name)?;
         },
         IpgTextInputParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             ti.width = get_width(Some(val as f32), false);
         },
         IpgTextInputParam::Padding => {
-            let val = try_extract_vec_f64(value, name);
-            ti.padding =  get_padding_f64(val);
+            let val = try_extract_vec_f64(value, name)?;
+            ti.padding =  get_padding_f64(val)?;
         },
         IpgTextInputParam::Size => {
-            ti.size = try_extract_f64(value, name) as f32;
+            ti.size = try_extract_f64(value, name)? as f32;
         },
         IpgTextInputParam::LineHeightPixels => {
-            let val = try_extract_u16(value, name);
+            let val = try_extract_u16(value, name)?;
             ti.line_height = LineHeight::Absolute(Pixels(val.into()));
         },
         IpgTextInputParam::LineHeightRelative => {
-            let val = try_extract_f64(value, name) as f32;
+            let val = try_extract_f64(value, name)? as f32;
             ti.line_height = LineHeight::Relative(val);
         },
+        IpgTextInputParam::Enabled => {
+            ti.enabled = try_extract_boolean(value, name)?;
+        },
         IpgTextInputParam::StyleId => {
-            ti.style_id = Some(try_extract_f64(value, name) as usize);
+            ti.style_id = Some(try_extract_f64(value, name)? as usize);
         },
     }
+    Ok(())
 }
 
+pub fn text_input_get_items(ti: &IpgTextInput) -> PyResult<IpgItems> {
+    let (line_height_pixels, line_height_relative) = match ti.line_height {
+        LineHeight::Absolute(pixels) => (Some(pixels.0.into()), None),
+        LineHeight::Relative(value) => (None, Some(value.into())),
+    };
+    Ok(vec![
+        ("Placeholder", Some(ti.placeholder.clone().into())),
+        ("Value", Some(ti.value.clone().into())),
+        ("IsSecure", Some(ti.is_secure.into())),
+        ("Width", item_length(ti.width)),
+        ("Padding", item_padding(ti.padding)),
+        ("Size", Some(ti.size.into())),
+        ("LineHeightPixels", line_height_pixels),
+        ("LineHeightRelative", line_height_relative),
+        ("Enabled", Some(ti.enabled.into())),
+        ("StyleId", ti.style_id.map(Into::into)),
+    ])
+}
 
-fn try_extract_text_input_update(update_obj: &PyObject) -> IpgTextInputParam {
+
+fn try_extract_text_input_update(update_obj: &PyObject) -> PyResult<IpgTextInputParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTextInputParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("TextInput update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("TextInput update extraction failed")),
         }
     })
 }
@@ -373,7 +373,10 @@ fn get_styling(theme: &Theme,
         },
         Status::Disabled => {
             style_base.value = style_base.placeholder;
-            style_base.background = palette.background.weak.color.into();
+            style_base.background = match style.background_color {
+                Some(color) => color.scale_alpha(0.5).into(),
+                None => palette.background.weak.color.into(),
+            };
             
             style_base
         }
@@ -413,77 +416,77 @@ pub enum IpgTextInputStyleParam {
 
 pub fn text_input_style_update_item(style: &mut IpgTextInputStyle,
                                     item: &PyObject,
-                                    value: &PyObject,) 
+                                    value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_text_input_style_update(item);
+    let update = try_extract_text_input_style_update(item)?;
     let name = "TextInputStyle".to_string();
     match update {
         IpgTextInputStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTextInputStyleParam::BackgroundRgbaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTextInputStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTextInputStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTextInputStyleParam::BorderIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgTextInputStyleParam::BorderRgbaColorHovered => {
-            style.border_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color_hovered = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTextInputStyleParam::BorderIpgColorFocused => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color_focused = get_color(None, Some(color), 1.0, false);
         },
         IpgTextInputStyleParam::BorderRgbaColorFocused => {
-            style.border_color_focused = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color_focused = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTextInputStyleParam::BorderWidth => {
-            style.border_width = Some(try_extract_f64(value, name) as f32);
+            style.border_width = Some(try_extract_f64(value, name)? as f32);
         },
         IpgTextInputStyleParam::BorderRadius => {
-            style.border_radius = Some(try_extract_vec_f32(value, name))
+            style.border_radius = Some(try_extract_vec_f32(value, name)?)
         },
         IpgTextInputStyleParam::PlaceholderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.placeholder_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTextInputStyleParam::PlaceholderRgbaColor => {
-            style.placeholder_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.placeholder_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTextInputStyleParam::ValueIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.value_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTextInputStyleParam::ValueRgbaColor => {
-            style.value_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.value_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTextInputStyleParam::SelectionIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.selection_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTextInputStyleParam::SelectionRgbaColor => {
-            style.selection_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.selection_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
-
+    Ok(())
 }
 
-fn try_extract_text_input_style_update(update_obj: &PyObject) -> IpgTextInputStyleParam {
+fn try_extract_text_input_style_update(update_obj: &PyObject) -> PyResult<IpgTextInputStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTextInputStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Text Input style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Text Input style update extraction failed")),
         }
     })
 }
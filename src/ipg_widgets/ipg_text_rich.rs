@@ -5,13 +5,14 @@ use iced::widget::text::{LineHeight, Rich, Span};
 use iced::widget::rich_text;
 use crate::app::Message;
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use super::helpers::{get_height, 
     get_width, try_extract_boolean, 
     try_extract_f64, try_extract_ipg_color, try_extract_string, 
     try_extract_vec_f32};
 use super::ipg_enums::{IpgHorizontalAlignment, IpgVerticalAlignment};
+use super::ipg_errors::IpgParamError;
 
 #[derive(Debug, Clone)]
 pub struct IpgRichText {
@@ -179,9 +180,9 @@ pub enum IpgTextParam {
 
 pub fn text_item_update(txt: &mut IpgRichText, 
                         item: &PyObject, 
-                        value: &PyObject) {
+                        value: &PyObject) -> PyResult<()> {
 
-    let update = try_extract_text_update(item);
+    let update = try_extract_text_update(item)?;
     let name = "Text".to_string();
     match update {
         IpgTextParam::Content => todo!(),
@@ -197,38 +198,39 @@ pub fn text_item_update(txt: &mut IpgRichText,
         IpgTextParam::Width => todo!(),
         IpgTextParam::WidthFill => todo!(),
             }
+    Ok(())
 }
 
 
-fn try_extract_text_update(update_obj: &PyObject) -> IpgTextParam {
+fn try_extract_text_update(update_obj: &PyObject) -> PyResult<IpgTextParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTextParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Text update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Text update extraction failed")),
         }
     })
 }
 
-fn try_extract_hor_alignment(update_obj: &PyObject) -> IpgHorizontalAlignment {
+fn try_extract_hor_alignment(update_obj: &PyObject) -> PyResult<IpgHorizontalAlignment> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgHorizontalAlignment>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Text HorizontalAlignment extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Text HorizontalAlignment extraction failed")),
         }
     })
 }
 
-fn try_extract_vert_alignment(update_obj: &PyObject) -> IpgVerticalAlignment {
+fn try_extract_vert_alignment(update_obj: &PyObject) -> PyResult<IpgVerticalAlignment> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgVerticalAlignment>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Text VerticalAlignment extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Text VerticalAlignment extraction failed")),
         }
     })
 }
@@ -1,17 +1,22 @@
 //! ipg_timer
 use crate::graphics::colors::get_color;
 use crate::style::styling::IpgStyleStandard;
-use crate::{access_callbacks, access_user_data1, access_user_data2, app, IpgState};
+use crate::{app, mirror_item, IpgState};
+use super::callbacks::dispatch_callback;
 use super::callbacks::{set_or_get_widget_callback_data, WidgetCallbackIn, WidgetCallbackOut};
-use super::helpers::{get_height, get_padding_f64, get_radius, get_width, try_extract_boolean, try_extract_f64, try_extract_i64, try_extract_ipg_color, try_extract_rgba_color, try_extract_string, try_extract_style_standard, try_extract_u64, try_extract_vec_f32, try_extract_vec_f64};
+use super::helpers::{get_height, get_padding_f64, get_radius, get_width, try_extract_boolean, try_extract_f64, try_extract_i64, try_extract_ipg_color, try_extract_rgba_color, try_extract_string, try_extract_style_standard, try_extract_u64, try_extract_vec_f32, try_extract_vec_f64, 
+    item_fill, item_length, item_object_opt, item_padding, IpgItemValue, IpgItems};
 use super::ipg_button::{get_bootstrap_arrow, get_standard_style, try_extract_button_arrow, IpgButtonArrow};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::{report_error, IpgIdError, IpgParamError};
 
 use iced::widget::button::{self, Status};
 use iced::widget::{Button, Text};
 use iced::{Border, Color, Element, Length, Padding, Shadow, Theme, Vector};
 
-use pyo3::{pyclass, PyObject, Python};
+use std::time::{Duration, Instant};
+
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 #[derive(Debug, Clone)]
 pub struct IpgTimer {
@@ -30,6 +35,14 @@ pub struct IpgTimer {
     pub started: bool,
     pub ticking: bool,
     pub show: bool,
+    pub countdown_ms: Option<u64>,
+    pub paused: bool,
+    // the time run before the last start or resume
+    pub elapsed: Duration,
+    pub started_at: Option<Instant>,
+    // the countdown time left when it was last started, resumed or changed,
+    // the end has its own subscription so that the tick interval stays fixed
+    pub finish_in_ms: Option<u64>,
 }
 
 impl IpgTimer {
@@ -46,6 +59,7 @@ impl IpgTimer {
         style_standard: Option<IpgStyleStandard>,
         style_arrow: Option<IpgButtonArrow>,
         show: bool,
+        countdown_ms: Option<u64>,
         ) -> Self {
         Self {
             id,
@@ -63,7 +77,76 @@ impl IpgTimer {
             started: false,
             ticking: false,
             show,
+            countdown_ms,
+            paused: false,
+            elapsed: Duration::ZERO,
+            started_at: None,
+            finish_in_ms: None,
+        }
+    }
+
+    /// The time the timer has run, less the pauses.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed + self.started_at.map(|at| at.elapsed()).unwrap_or_default()
+    }
+
+    /// The time left of a countdown.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.countdown_ms.map(|ms| Duration::from_millis(ms).saturating_sub(self.elapsed()))
+    }
+
+    // The ticks come from the elapsed time, a late tick
+    // doesn't make the count fall behind.
+    pub fn ticks(&self) -> u64 {
+        (self.elapsed().as_millis() / self.duration_ms.max(1) as u128) as u64
+    }
+
+    pub fn start(&mut self) {
+        // a finished countdown starts over
+        if self.remaining().is_some_and(|remaining| remaining.is_zero()) {
+            self.elapsed = Duration::ZERO;
+        }
+        self.started = true;
+        self.paused = false;
+        self.started_at = Some(Instant::now());
+        self.schedule_finish();
+    }
+
+    pub fn stop(&mut self) {
+        self.elapsed = self.elapsed();
+        self.started = false;
+        self.paused = false;
+        self.started_at = None;
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        if !self.started || paused == self.paused {
+            return
+        }
+        if paused {
+            self.elapsed = self.elapsed();
+            self.started_at = None;
+        } else {
+            self.started_at = Some(Instant::now());
+        }
+        self.paused = paused;
+        self.schedule_finish();
+    }
+
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+        if self.started_at.is_some() {
+            self.started_at = Some(Instant::now());
         }
+        self.schedule_finish();
+    }
+
+    /// The end of a countdown comes from the elapsed time, it's only
+    /// worked out again after a start, pause or change, not on a tick.
+    pub fn schedule_finish(&mut self) {
+        // rounded up so that it isn't sent just before the end
+        self.finish_in_ms = self.remaining()
+            .map(|remaining| (remaining.as_nanos().div_ceil(1_000_000) as u64).max(1));
     }
 }
 
@@ -123,12 +206,16 @@ pub enum TIMMessage {
 pub enum IpgTimerParam {
     DurationMs,
     ArrowStyle,
+    CountdownMs,
     Counter,
+    ElapsedMs,
     Height,
     HeightFill,
     Label,
     Padding,
+    Paused,
     Clip,
+    RemainingMs,
     Show,
     StyleId,
     StyleStandard,
@@ -170,13 +257,11 @@ pub fn construct_timer<'a>(tim: &'a IpgTimer,
     
 }
 
-pub fn timer_callback(state: &mut IpgState, id: usize, started: bool) -> u64 {
+pub fn timer_callback(state: &mut IpgState, id: usize, started: bool) {
     let mut wci = WidgetCallbackIn{id, ..Default::default()};
     wci.value_bool = Some(started);
     let mut wco: WidgetCallbackOut = set_or_get_widget_callback_data(state, wci);
     wco.id = id;
-    // duration is the event time not total time
-    let duration = wco.duration.unwrap_or(1);
     let (event_name, counter) = if started {
         ("on_start".to_string(), None)
     } else {
@@ -184,18 +269,36 @@ pub fn timer_callback(state: &mut IpgState, id: usize, started: bool) -> u64 {
     };
     
     process_callback(id, event_name, counter);
-    // After the start, the duratiob is sent back 
-    // to set the timer event duration
-    duration
 }
 
-pub fn tick_callback(state: &mut IpgState)
+/// Returns true when a countdown has finished and the timer stopped,
+/// or the timer is no longer there.
+pub fn tick_callback(state: &mut IpgState, id: usize) -> bool
 {
-    let id= state.timer_event_id_enabled.0;
-    let mut wci = WidgetCallbackIn{id, ..Default::default()};
-    wci.value_str = Some("on_tick".to_string());
-    let wco: WidgetCallbackOut = set_or_get_widget_callback_data(state, wci);
-    process_callback(id, "on_tick".to_string(), wco.counter);
+    let tim = match state.widgets.get_mut(&id) {
+        Some(IpgWidgets::IpgTimer(tim)) => tim,
+        _ => {
+            report_error(IpgIdError::new_err(format!("Timer: tick, timer with id {id} not found")));
+            return true
+        },
+    };
+    // a tick already sent when the timer was stopped or paused
+    if !tim.started || tim.paused {
+        return false
+    }
+    tim.counter = tim.ticks();
+    let counter = tim.counter;
+    let finished = tim.remaining().is_some_and(|remaining| remaining.is_zero());
+    if finished {
+        tim.stop();
+    }
+    mirror_item(state, id);
+
+    process_callback(id, "on_tick".to_string(), Some(counter));
+    if finished {
+        process_callback(id, "on_finished".to_string(), None);
+    }
+    finished
 }
 
 fn process_callback(
@@ -203,129 +306,125 @@ fn process_callback(
         event_name: String, 
         counter: Option<u64>)
 {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name.clone())) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    Python::with_gil(|py| {
-        
-        let name_bool = if event_name == "on_start".to_string() {
-            true
-        } else {
-            false
-        };
-
-        if let Some(user_data) = ud1.user_data.get(&id) {
-            let res = match name_bool {
-                    true => callback.call1(py, (id, user_data)),
-                    false => callback.call1(py, (id, counter, user_data)),
-            };
-            if let Err(err) = res {
-                panic!("CanvasTimer callback error: {err}");
-            } else {
-                drop(ud1);
-                return
-            }
-        }
-        drop(ud1); // Drop ud1 if no user data is found
-
-        // Check user data from ud2
-        let ud2 = access_user_data2();
-
-        if let Some(user_data) = ud2.user_data.get(&id) {
-            let res = match name_bool {
-                    true => callback.call1(py, (id, user_data)),
-                    false => callback.call1(py, (id, counter, user_data)),
-            };
-            if let Err(err) = res {
-                panic!("CanvasTimer callback error: {err}");
-            } else {
-                drop(ud2);
-                return
-            }
-        }
-        drop(ud2); // Drop ud1 if no user data is found
-
-        let res = match name_bool {
-            true => callback.call1(py, (id, )),
-            false => callback.call1(py, (id, counter)),
-        };
-        if let Err(err) = res {
-            panic!("CanvasTimer callback error: {err}");
-        }
-    });
+    if event_name == "on_start" || event_name == "on_finished" {
+        dispatch_callback("Timer", id, &event_name, (id,));
+    } else {
+        dispatch_callback("Timer", id, &event_name, (id, counter));
+    }
 }
 
 
 pub fn timer_item_update(tim: &mut IpgTimer,
                         item: &PyObject,
                         value: &PyObject,
-                        )
+                        ) -> PyResult<()>
 {
-    let update = try_extract_timer_update(item);
+    let update = try_extract_timer_update(item)?;
     let name = "Timer".to_string();
     match update {
         IpgTimerParam::DurationMs => {
-            tim.duration_ms = try_extract_i64(value, name) as u64;
+            tim.duration_ms = try_extract_i64(value, name)? as u64;
         },
        IpgTimerParam::ArrowStyle => {
-            tim.style_arrow = Some(try_extract_button_arrow(value));
+            tim.style_arrow = Some(try_extract_button_arrow(value)?);
+        },
+        IpgTimerParam::CountdownMs => {
+            tim.countdown_ms = if Python::with_gil(|py| value.is_none(py)) {
+                None
+            } else {
+                Some(try_extract_u64(value, name)?)
+            };
+            tim.schedule_finish();
         },
         IpgTimerParam::Counter => {
-            tim.counter = try_extract_u64(value, name);
-        }
+            tim.counter = try_extract_u64(value, name)?;
+            tim.set_elapsed(Duration::from_millis(tim.counter * tim.duration_ms));
+        },
+        IpgTimerParam::ElapsedMs => {
+            tim.set_elapsed(Duration::from_millis(try_extract_u64(value, name)?));
+            tim.counter = tim.ticks();
+        },
+        IpgTimerParam::Paused => {
+            tim.set_paused(try_extract_boolean(value, name)?);
+        },
+        IpgTimerParam::RemainingMs => {
+            let countdown_ms = match tim.countdown_ms {
+                Some(ms) => ms,
+                None => return Err(IpgParamError::new_err("Timer: RemainingMs needs a CountdownMs")),
+            };
+            let remaining_ms = try_extract_u64(value, name)?;
+            tim.set_elapsed(Duration::from_millis(countdown_ms.saturating_sub(remaining_ms)));
+            tim.counter = tim.ticks();
+        },
         IpgTimerParam::Label => {
-            tim.label = try_extract_string(value, name);
+            tim.label = try_extract_string(value, name)?;
         },
         IpgTimerParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             tim.height = get_height(Some(val as f32), false);
         },
         IpgTimerParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             tim.height = get_height(None, val);
         },
         IpgTimerParam::Padding => {
-            tim.padding =  get_padding_f64(try_extract_vec_f64(value, name));
+            tim.padding =  get_padding_f64(try_extract_vec_f64(value, name)?)?;
         },
         IpgTimerParam::Clip => {
-            tim.clip = try_extract_boolean(value, name);
+            tim.clip = try_extract_boolean(value, name)?;
         }
         IpgTimerParam::Show => {
-            tim.show = try_extract_boolean(value, name);
+            tim.show = try_extract_boolean(value, name)?;
         },
         IpgTimerParam::StyleId => {
-            tim.style_id = Some(try_extract_f64(value, name) as usize);
+            tim.style_id = Some(try_extract_f64(value, name)? as usize);
         },
         IpgTimerParam::StyleStandard => {
-            tim.style_standard = Some(try_extract_style_standard(value, name));
+            tim.style_standard = Some(try_extract_style_standard(value, name)?);
         },
         IpgTimerParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             tim.width = get_width(Some(val as f32), false);
         },
         IpgTimerParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             tim.width = get_width(None, val);
         }, 
     }
+    Ok(())
+}
 
+// The elapsed and remaining times come from the start time,
+// they are current between the ticks.
+pub fn timer_get_items(tim: &IpgTimer) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("DurationMs", Some((tim.duration_ms as usize).into())),
+        ("ArrowStyle", item_object_opt(tim.style_arrow.clone())?),
+        ("CountdownMs", tim.countdown_ms.map(|ms| (ms as usize).into())),
+        ("Counter", Some((tim.counter as usize).into())),
+        ("ElapsedMs", Some((tim.elapsed().as_millis() as usize).into())),
+        ("Height", item_length(tim.height)),
+        ("HeightFill", item_fill(tim.height)),
+        ("Label", Some(tim.label.clone().into())),
+        ("Padding", item_padding(tim.padding)),
+        ("Paused", Some(tim.paused.into())),
+        ("Clip", Some(tim.clip.into())),
+        ("RemainingMs", tim.remaining().map(|remaining| (remaining.as_millis() as usize).into())),
+        ("Show", Some(tim.show.into())),
+        ("StyleId", tim.style_id.map(Into::into)),
+        ("StyleStandard", item_object_opt(tim.style_standard.clone())?),
+        ("Width", item_length(tim.width)),
+        ("WidthFill", item_fill(tim.width)),
+    ])
 }
 
-pub fn try_extract_timer_update(update_obj: &PyObject) -> IpgTimerParam {
+pub fn try_extract_timer_update(update_obj: &PyObject) -> PyResult<IpgTimerParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTimerParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Timer update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Timer update extraction failed")),
         }
     })
 }
@@ -428,62 +527,63 @@ pub enum IpgTimerStyleParam {
 
 pub fn timer_style_update_item(style: &mut IpgTimerStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_timer_style_update(item);
+    let update = try_extract_timer_style_update(item)?;
     let name = "TimerStyle".to_string();
     match update {
         IpgTimerStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTimerStyleParam::BackgroundRbgaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTimerStyleParam::BackgroundIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgTimerStyleParam::BackgroundIpgRgbaHovered => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTimerStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTimerStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTimerStyleParam::BorderRadius => {
-            style.border_radius = try_extract_vec_f32(value, name);
+            style.border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgTimerStyleParam::BorderWidth => {
-            style.border_width = try_extract_f64(value, name) as f32;
+            style.border_width = try_extract_f64(value, name)? as f32;
         },
         IpgTimerStyleParam::ShadowIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.shadow_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTimerStyleParam::ShadowRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTimerStyleParam::ShadowOffsetX => {
-            style.shadow_offset_x = try_extract_f64(value, name) as f32;
+            style.shadow_offset_x = try_extract_f64(value, name)? as f32;
         },
         IpgTimerStyleParam::ShadowOffsetY => {
-            style.shadow_offset_y = try_extract_f64(value, name) as f32;
+            style.shadow_offset_y = try_extract_f64(value, name)? as f32;
         },
         IpgTimerStyleParam::ShadowBlurRadius => {
-            style.shadow_blur_radius = try_extract_f64(value, name) as f32;
+            style.shadow_blur_radius = try_extract_f64(value, name)? as f32;
         },
         IpgTimerStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTimerStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
 fn get_timer_style(style: Option<&IpgWidgets>) -> Option<IpgTimerStyle>{
@@ -495,13 +595,55 @@ fn get_timer_style(style: Option<&IpgWidgets>) -> Option<IpgTimerStyle>{
     }
 }
 
-fn try_extract_timer_style_update(update_obj: &PyObject) -> IpgTimerStyleParam {
+fn try_extract_timer_style_update(update_obj: &PyObject) -> PyResult<IpgTimerStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTimerStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Timer style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Timer style update extraction failed")),
         }
     })
 }
+
+
+#[test]
+fn test_timer_ticks_from_elapsed_time() {
+    let mut tim = IpgTimer::new(1, "main".to_string(), 100, "Start".to_string(),
+                                Length::Shrink, Length::Shrink, Padding::ZERO, false,
+                                None, None, None, true, Some(1000));
+    tim.start();
+    // as if the timer had run 350ms with some of the ticks late
+    tim.started_at = Instant::now().checked_sub(Duration::from_millis(350));
+    assert_eq!(3, tim.ticks());
+
+    tim.set_paused(true);
+    let elapsed = tim.elapsed();
+    assert!(elapsed >= Duration::from_millis(350) && elapsed < Duration::from_millis(400));
+    // the end of the countdown is from the time run, not the start
+    assert!(tim.finish_in_ms.is_some_and(|ms| ms > 600 && ms <= 651));
+    assert_eq!(elapsed, tim.elapsed());
+
+    tim.set_elapsed(Duration::from_millis(1000));
+    assert_eq!(Some(Duration::ZERO), tim.remaining());
+    // a finished countdown starts over
+    tim.stop();
+    tim.start();
+    assert!(tim.elapsed() < Duration::from_millis(100));
+}
+
+#[test]
+fn test_timer_items_between_ticks() {
+    let mut tim = IpgTimer::new(1, "main".to_string(), 100, "Start".to_string(),
+                                Length::Shrink, Length::Shrink, Padding::ZERO, false,
+                                None, None, None, true, Some(1000));
+    tim.start();
+    tim.started_at = Instant::now().checked_sub(Duration::from_millis(250));
+    let items = timer_get_items(&tim).unwrap();
+    let get_ms = |name: &str| match items.iter().find(|(item, _)| *item == name) {
+        Some((_, Some(IpgItemValue::Int(ms)))) => Some(*ms),
+        _ => None,
+    };
+    assert!(get_ms("ElapsedMs").is_some_and(|ms| (250..300).contains(&ms)));
+    assert!(get_ms("RemainingMs").is_some_and(|ms| ms > 700 && ms <= 750));
+}
@@ -1,22 +1,25 @@
 //! ipg_timer
 use crate::graphics::colors::get_color;
 use crate::style::styling::IpgStyleStandard;
-use crate::{access_callbacks, access_user_data1, access_user_data2, app, IpgState};
+use crate::{app, IpgState};
+use super::callbacks::dispatch_callback;
 use super::callbacks::{set_or_get_widget_callback_data, 
     WidgetCallbackIn, WidgetCallbackOut};
 use super::helpers::{get_height, get_padding_f64, get_radius, 
     get_width, try_extract_boolean, try_extract_f64, try_extract_i64, 
     try_extract_ipg_color, try_extract_rgba_color, try_extract_string, 
     try_extract_style_standard, try_extract_u64, try_extract_vec_f32, 
-    try_extract_vec_f64};
+    try_extract_vec_f64, 
+    item_fill, item_length, item_object_opt, item_padding, IpgItems};
 use super::ipg_button::{get_bootstrap_arrow, get_standard_style, 
     try_extract_button_arrow, IpgButtonArrow};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 use iced::widget::{button, Button, Text};
 use iced::{Border, Color, Element, Length, Padding, Shadow, Theme, Vector};
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 #[derive(Debug, Clone)]
 pub struct IpgCanvasTimer {
@@ -190,9 +193,8 @@ pub fn canvas_timer_callback(state: &mut IpgState, id: usize, started: bool) ->
     duration 
 }
 
-pub fn canvas_tick_callback(state: &mut IpgState) 
+pub fn canvas_tick_callback(state: &mut IpgState, id: usize)
 {
-    let id= state.canvas_timer_event_id_enabled.0;
     let mut wci = WidgetCallbackIn{id, ..Default::default()};
     wci.value_str = Some("on_tick".to_string());
     let wco: WidgetCallbackOut = set_or_get_widget_callback_data(state, wci);
@@ -204,129 +206,94 @@ fn process_callback(
         event_name: String, 
         counter: Option<u64>)
 {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name.clone())) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    Python::with_gil(|py| {
-        
-        let name_bool = if event_name == "on_start".to_string() {
-            true
-        } else {
-            false
-        };
-
-        if let Some(user_data) = ud1.user_data.get(&id) {
-            let res = match name_bool {
-                    true => callback.call1(py, (id, user_data)),
-                    false => callback.call1(py, (id, counter, user_data)),
-            };
-            if let Err(err) = res {
-                panic!("CanvasTimer callback error: {err}");
-            } else {
-                drop(ud1);
-                return
-            }
-        }
-        drop(ud1); // Drop ud1 if no user data is found
-
-        // Check user data from ud2
-        let ud2 = access_user_data2();
-
-        if let Some(user_data) = ud2.user_data.get(&id) {
-            let res = match name_bool {
-                    true => callback.call1(py, (id, user_data)),
-                    false => callback.call1(py, (id, counter, user_data)),
-            };
-            if let Err(err) = res {
-                panic!("CanvasTimer callback error: {err}");
-            } else {
-                drop(ud2);
-                return
-            }
-        }
-        drop(ud2); // Drop ud1 if no user data is found
-
-        let res = match name_bool {
-            true => callback.call1(py, (id, )),
-            false => callback.call1(py, (id, counter)),
-        };
-        if let Err(err) = res {
-            panic!("CanvasTimer callback error: {err}");
-        }
-    });
+    if event_name == "on_start" {
+        dispatch_callback("CanvasTimer", id, &event_name, (id,));
+    } else {
+        dispatch_callback("CanvasTimer", id, &event_name, (id, counter));
+    }
 }
 
 
 pub fn canvas_timer_item_update(ctim: &mut IpgCanvasTimer,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_timer_update(item);
+    let update = try_extract_timer_update(item)?;
     let name = "CanvasTimer".to_string();
     match update {
         IpgCanvasTimerParam::DurationMs => {
-            ctim.duration_ms = try_extract_i64(value, name) as u64;
+            ctim.duration_ms = try_extract_i64(value, name)? as u64;
         },
        IpgCanvasTimerParam::ArrowStyle => {
-            ctim.style_arrow = Some(try_extract_button_arrow(value));
+            ctim.style_arrow = Some(try_extract_button_arrow(value)?);
         },
         IpgCanvasTimerParam::Counter => {
-            ctim.counter = try_extract_u64(value, name);
+            ctim.counter = try_extract_u64(value, name)?;
         }
         IpgCanvasTimerParam::Label => {
-            ctim.label = try_extract_string(value, name);
+            ctim.label = try_extract_string(value, name)?;
         },
         IpgCanvasTimerParam::Height => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             ctim.height = get_height(Some(val as f32), false);
         },
         IpgCanvasTimerParam::HeightFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             ctim.height = get_height(None, val);
         },
         IpgCanvasTimerParam::Padding => {
-            ctim.padding =  get_padding_f64(try_extract_vec_f64(value, name));
+            ctim.padding =  get_padding_f64(try_extract_vec_f64(value, name)?)?;
         },
         IpgCanvasTimerParam::Clip => {
-            ctim.clip = try_extract_boolean(value, name);
+            ctim.clip = try_extract_boolean(value, name)?;
         }
         IpgCanvasTimerParam::Show => {
-            ctim.show = try_extract_boolean(value, name);
+            ctim.show = try_extract_boolean(value, name)?;
         },
         IpgCanvasTimerParam::StyleId => {
-            ctim.style_id = Some(try_extract_f64(value, name) as usize);
+            ctim.style_id = Some(try_extract_f64(value, name)? as usize);
         },
         IpgCanvasTimerParam::StyleStandard => {
-            ctim.style_standard = Some(try_extract_style_standard(value, name));
+            ctim.style_standard = Some(try_extract_style_standard(value, name)?);
         },
         IpgCanvasTimerParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             ctim.width = get_width(Some(val as f32), false);
         },
         IpgCanvasTimerParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             ctim.width = get_width(None, val);
         }, 
     }
+    Ok(())
+}
 
+pub fn canvas_timer_get_items(ctim: &IpgCanvasTimer) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("DurationMs", Some((ctim.duration_ms as usize).into())),
+        ("ArrowStyle", item_object_opt(ctim.style_arrow.clone())?),
+        ("Counter", Some((ctim.counter as usize).into())),
+        ("Height", item_length(ctim.height)),
+        ("HeightFill", item_fill(ctim.height)),
+        ("Label", Some(ctim.label.clone().into())),
+        ("Padding", item_padding(ctim.padding)),
+        ("Clip", Some(ctim.clip.into())),
+        ("Show", Some(ctim.show.into())),
+        ("StyleId", ctim.style_id.map(Into::into)),
+        ("StyleStandard", item_object_opt(ctim.style_standard.clone())?),
+        ("Width", item_length(ctim.width)),
+        ("WidthFill", item_fill(ctim.width)),
+    ])
 }
 
-pub fn try_extract_timer_update(update_obj: &PyObject) -> IpgCanvasTimerParam {
+pub fn try_extract_timer_update(update_obj: &PyObject) -> PyResult<IpgCanvasTimerParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgCanvasTimerParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("CanvasTimer update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("CanvasTimer update extraction failed")),
         }
     })
 }
@@ -429,62 +396,63 @@ pub enum IpgCanvasTimerStyleParam {
 
 pub fn canvas_timer_style_update_item(style: &mut IpgCanvasTimerStyle,
                                     item: &PyObject,
-                                    value: &PyObject,) 
+                                    value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_canvas_timer_style_update(item);
+    let update = try_extract_canvas_timer_style_update(item)?;
     let name = "CanvasTimerStyle".to_string();
     match update {
         IpgCanvasTimerStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCanvasTimerStyleParam::BackgroundRbgaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCanvasTimerStyleParam::BackgroundIpgColorHovered => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color_hovered = get_color(None, Some(color), 1.0, false);
         },
         IpgCanvasTimerStyleParam::BackgroundIpgRgbaHovered => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCanvasTimerStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCanvasTimerStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCanvasTimerStyleParam::BorderRadius => {
-            style.border_radius = try_extract_vec_f32(value, name);
+            style.border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgCanvasTimerStyleParam::BorderWidth => {
-            style.border_width = try_extract_f64(value, name) as f32;
+            style.border_width = try_extract_f64(value, name)? as f32;
         },
         IpgCanvasTimerStyleParam::ShadowIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.shadow_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCanvasTimerStyleParam::ShadowRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgCanvasTimerStyleParam::ShadowOffsetX => {
-            style.shadow_offset_x = try_extract_f64(value, name) as f32;
+            style.shadow_offset_x = try_extract_f64(value, name)? as f32;
         },
         IpgCanvasTimerStyleParam::ShadowOffsetY => {
-            style.shadow_offset_y = try_extract_f64(value, name) as f32;
+            style.shadow_offset_y = try_extract_f64(value, name)? as f32;
         },
         IpgCanvasTimerStyleParam::ShadowBlurRadius => {
-            style.shadow_blur_radius = try_extract_f64(value, name) as f32;
+            style.shadow_blur_radius = try_extract_f64(value, name)? as f32;
         },
         IpgCanvasTimerStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgCanvasTimerStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
+    Ok(())
 }
 
 fn get_canvas_timer_style(style: Option<&IpgWidgets>) -> Option<IpgCanvasTimerStyle>{
@@ -496,13 +464,13 @@ fn get_canvas_timer_style(style: Option<&IpgWidgets>) -> Option<IpgCanvasTimerSt
     }
 }
 
-fn try_extract_canvas_timer_style_update(update_obj: &PyObject) -> IpgCanvasTimerStyleParam {
+fn try_extract_canvas_timer_style_update(update_obj: &PyObject) -> PyResult<IpgCanvasTimerStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgCanvasTimerStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Canvas Timer style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Canvas Timer style update extraction failed")),
         }
     })
 }
\ No newline at end of file
@@ -1,15 +1,18 @@
 //! ipg_toggler
 use crate::graphics::colors::get_color;
-use crate::{access_callbacks, access_user_data1, access_user_data2, app, IpgState};
+use crate::{app, IpgState};
+use super::callbacks::dispatch_callback;
 use super::helpers::{get_width, try_extract_boolean, 
     try_extract_f64, try_extract_ipg_color, 
     try_extract_ipg_horizontal_alignment, 
-    try_extract_rgba_color, try_extract_string};
+    try_extract_rgba_color, try_extract_string, 
+    item_fill, item_length, item_line_height, item_object, IpgItems};
 use super::callbacks::{set_or_get_widget_callback_data, WidgetCallbackIn};
 use super::ipg_enums::{IpgHorizontalAlignment, IpgWidgets};
+use super::ipg_errors::IpgParamError;
 use iced::widget::text::LineHeight;
 use iced::widget::toggler::{self, Status};
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
 use iced::widget::Toggler;
 use iced::{alignment, Color, Element, Length, Theme};
@@ -20,6 +23,7 @@ pub struct IpgToggler {
     pub id: usize,
     pub parent_id: String,
     pub show: bool,
+    pub enabled: bool,
     pub is_toggled: bool,
     pub label: Option<String>,
     pub width: Length,
@@ -36,6 +40,7 @@ impl IpgToggler {
         id: usize,
         parent_id: String,
         show: bool,
+        enabled: bool,
         label: Option<String>,
         width: Length,
         size: f32,
@@ -49,6 +54,7 @@ impl IpgToggler {
             id,
             parent_id,
             show,
+            enabled,
             is_toggled: false,
             label,
             width,
@@ -133,7 +139,7 @@ pub fn construct_toggler<'a>(tog: &'a IpgToggler,
 
     let ipg_tog: Element<TOGMessage> = Toggler::new(tog.is_toggled)
                                                     .label(label.clone())
-                                                    .on_toggle(TOGMessage::Toggled)
+                                                    .on_toggle_maybe(tog.enabled.then_some(TOGMessage::Toggled))
                                                     .size(tog.size)
                                                     .width(tog.width)
                                                     .text_size(tog.text_size)
@@ -168,49 +174,7 @@ pub fn process_callback(
     event_name: String, 
     toggled: bool) 
 {
-    let ud1 = access_user_data1();
-    let app_cbs = access_callbacks();
-
-    // Retrieve the callback
-    let callback = match app_cbs.callbacks.get(&(id, event_name)) {
-        Some(cb) => Python::with_gil(|py| cb.clone_ref(py)),
-        None => return,
-    };
-
-    drop(app_cbs);
-
-    // Check user data from ud1
-    if let Some(user_data) = ud1.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, toggled, user_data)) {
-                panic!("Toggler callback error: {err}");
-            }
-        });
-        drop(ud1); // Drop ud1 before processing ud2
-        return;
-    }
-    drop(ud1); // Drop ud1 if no user data is found
-
-    // Check user data from ud2
-    let ud2 = access_user_data2();
-    if let Some(user_data) = ud2.user_data.get(&id) {
-        Python::with_gil(|py| {
-            if let Err(err) = callback.call1(py, (id, toggled, user_data)) {
-                panic!("Toggler callback error: {err}");
-            }
-        });
-        drop(ud2); // Drop ud2 after processing
-        return;
-    }
-    drop(ud2); // Drop ud2 if no user data is found
-
-    // If no user data is found in both ud1 and ud2, call the callback with the id and toggled
-    Python::with_gil(|py| {
-        if let Err(err) = callback.call1(py, (id, toggled)) {
-            panic!("Toggler callback error: {err}");
-        }
-    });
-         
+    dispatch_callback("Toggler", id, &event_name, (id, toggled));
 }
 
 
@@ -220,6 +184,7 @@ pub enum IpgTogglerParam {
     HorizontalAlignment,
     Label,
     LineHeight,
+    Enabled,
     Show,
     Size,
     TextSize,
@@ -231,56 +196,70 @@ pub enum IpgTogglerParam {
 pub fn toggler_item_update(tog: &mut IpgToggler,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_toggler_update(item);
+    let update = try_extract_toggler_update(item)?;
     let name = "Toggler".to_string();
     match update {
         IpgTogglerParam::Label => {
-            tog.label = Some(try_extract_string(value, name));
+            tog.label = Some(try_extract_string(value, name)?);
+        },
+        IpgTogglerParam::Enabled => {
+            tog.enabled = try_extract_boolean(value, name)?;
         },
         IpgTogglerParam::Show => {
-            tog.show = try_extract_boolean(value, name);
+            tog.show = try_extract_boolean(value, name)?;
         },
         IpgTogglerParam::Width => {
-            let val = try_extract_f64(value, name);
+            let val = try_extract_f64(value, name)?;
             tog.width = get_width(Some(val as f32), false);
         },
         IpgTogglerParam::WidthFill => {
-            let val = try_extract_boolean(value, name);
+            let val = try_extract_boolean(value, name)?;
             tog.width = get_width(None, val);
         },
         IpgTogglerParam::HorizontalAlignment => {
-            let val = try_extract_ipg_horizontal_alignment(value);
-            if val.is_none() {
-                panic!("Unable to extract the toggler alignment")
-            }
-            tog.text_alignment = val.unwrap();
+            tog.text_alignment = try_extract_ipg_horizontal_alignment(value)
+                .ok_or_else(|| IpgParamError::new_err("Unable to extract the toggler alignment"))?;
         },
         IpgTogglerParam::LineHeight => {
-            let val = try_extract_f64(value, name) as f32; 
+            let val = try_extract_f64(value, name)? as f32; 
             tog.text_line_height = LineHeight::Relative(val);
         },
         IpgTogglerParam::Size => {
-            let val = try_extract_f64(value, name) as f32;
+            let val = try_extract_f64(value, name)? as f32;
             tog.size = val;
         },
         IpgTogglerParam::TextSize => {
-            let val = try_extract_f64(value, name) as f32;
+            let val = try_extract_f64(value, name)? as f32;
             tog.text_size = val;
         },
     }
+    Ok(())
+}
 
+pub fn toggler_get_items(tog: &IpgToggler) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("HorizontalAlignment", item_object(tog.text_alignment.clone())?),
+        ("Label", tog.label.clone().map(Into::into)),
+        ("LineHeight", item_line_height(tog.text_line_height)),
+        ("Enabled", Some(tog.enabled.into())),
+        ("Show", Some(tog.show.into())),
+        ("Size", Some(tog.size.into())),
+        ("TextSize", Some(tog.text_size.into())),
+        ("Width", item_length(tog.width)),
+        ("WidthFill", item_fill(tog.width)),
+    ])
 }
 
 
-pub fn try_extract_toggler_update(update_obj: &PyObject) -> IpgTogglerParam {
+pub fn try_extract_toggler_update(update_obj: &PyObject) -> PyResult<IpgTogglerParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTogglerParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Toggler update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Toggler update extraction failed")),
         }
     })
 }
@@ -342,7 +321,12 @@ pub fn get_styling(theme: &Theme, status: Status,
                 tog_style.background = style.background_color_toggled.unwrap();
             }
         }
-        Status::Disabled => (),
+        Status::Disabled => {
+            tog_style.background = match style.background_color_disabled {
+                Some(color) => color,
+                None => tog_style.background.scale_alpha(0.5),
+            };
+        },
     }
 
     match status {
@@ -360,7 +344,12 @@ pub fn get_styling(theme: &Theme, status: Status,
                     };
                 } 
         }
-        Status::Disabled => (),
+        Status::Disabled => {
+            tog_style.foreground = match style.foreground_color_disabled {
+                Some(color) => color,
+                None => tog_style.foreground.scale_alpha(0.5),
+            };
+        },
     }
 
     tog_style
@@ -393,74 +382,75 @@ pub enum IpgTogglerStyleParam {
 
 pub fn toggler_style_update_item(style: &mut IpgTogglerStyle,
                             item: &PyObject,
-                            value: &PyObject,) 
+                            value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_toggler_style_update(item);
+    let update = try_extract_toggler_style_update(item)?;
     let name = "ogglerStyle".to_string();
     match update {
         IpgTogglerStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTogglerStyleParam::BackgroundRbgaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTogglerStyleParam::BackgroundIpgColorToggled => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color_toggled = get_color(None, Some(color), 1.0, false);
         },
         IpgTogglerStyleParam::BackgroundRgbaToggled => {
-            style.background_color_toggled = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color_toggled = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTogglerStyleParam::BackgroundIpgColorDisabled => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color_toggled = get_color(None, Some(color), 1.0, false);
         },
         IpgTogglerStyleParam::BackgroundRbgaColorDisabled => {
-            style.background_color_toggled = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color_toggled = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTogglerStyleParam::BackgroundBorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTogglerStyleParam::BackgroundBorderRgbaColor => {
-            style.background_border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTogglerStyleParam::BackgroundBorderWidth => {
-            style.background_border_width = Some(try_extract_f64(value, name) as f32);
+            style.background_border_width = Some(try_extract_f64(value, name)? as f32);
         },
         IpgTogglerStyleParam::ForegroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.foreground_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTogglerStyleParam::ForegroundRbgaColor => {
-            style.foreground_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.foreground_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTogglerStyleParam::ForegroundIpgColorToggled => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.foreground_color_toggled = get_color(None, Some(color), 1.0, false);
         },
         IpgTogglerStyleParam::ForegroundRgbaToggled => {
-            style.foreground_color_toggled = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.foreground_color_toggled = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTogglerStyleParam::ForegroundIpgColorDisabled => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.foreground_color_toggled = get_color(None, Some(color), 1.0, false);
         },
         IpgTogglerStyleParam::ForegroundRbgaColorDisabled => {
-            style.foreground_color_toggled = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.foreground_color_toggled = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTogglerStyleParam::ForegroundBorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.foreground_border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgTogglerStyleParam::ForegroundBorderRgbaColor => {
-            style.foreground_border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.foreground_border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgTogglerStyleParam::ForegroundBorderWidth => {
-            style.foreground_border_width = Some(try_extract_f64(value, name) as f32);
+            style.foreground_border_width = Some(try_extract_f64(value, name)? as f32);
         },
     }
+    Ok(())
 }
 
 pub fn get_toggler_style(style: Option<&IpgWidgets>) -> Option<IpgTogglerStyle>{
@@ -472,13 +462,13 @@ pub fn get_toggler_style(style: Option<&IpgWidgets>) -> Option<IpgTogglerStyle>{
         }
 }
 
-pub fn try_extract_toggler_style_update(update_obj: &PyObject) -> IpgTogglerStyleParam {
+pub fn try_extract_toggler_style_update(update_obj: &PyObject) -> PyResult<IpgTogglerStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgTogglerStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Toggler style update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Toggler style update extraction failed")),
         }
     })
 }
@@ -2,15 +2,17 @@
 use iced::{Border, Color, Element, Shadow, Theme, Vector};
 use iced::widget::{container, text, Column, Tooltip};
 use iced::widget::tooltip::Position;
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 use crate::app::Message;
 use crate::graphics::colors::get_color;
 
 use super::helpers::{get_radius, try_extract_array_2, 
     try_extract_boolean, try_extract_f32, try_extract_ipg_color, 
     try_extract_rgba_color, try_extract_string, try_extract_u16, 
-    try_extract_usize, try_extract_vec_f32};
+    try_extract_usize, try_extract_vec_f32, 
+    item_object, IpgItems};
 use super::ipg_enums::IpgWidgets;
+use super::ipg_errors::IpgParamError;
 
 
 #[derive(Debug, Clone)]
@@ -22,6 +24,7 @@ pub struct IpgToolTip {
     pub padding: f32,
     pub snap_within_viewport: bool,
     pub style_id: Option<usize>,
+    pub show: bool,
 }
 
 impl IpgToolTip {
@@ -33,6 +36,7 @@ impl IpgToolTip {
             padding: f32,
             snap_within_viewport: bool,
             style_id: Option<usize>,
+            show: bool,
         ) -> Self {
         Self {
             id,
@@ -42,6 +46,7 @@ impl IpgToolTip {
             padding,
             snap_within_viewport,
             style_id,
+            show,
         }
     }
 }
@@ -139,55 +144,72 @@ pub enum IpgToolTipParam {
     Padding,
     SnapWithinViewport,
     StyleId,
+    Show,
 }
 
 pub fn tooltip_item_update(tt: &mut IpgToolTip,
                             item: &PyObject,
                             value: &PyObject,
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_tooltip_update(item);
+    let update = try_extract_tooltip_update(item)?;
     let name = "ToolTip".to_string();
     match update {
         IpgToolTipParam::Position => {
-            tt.position = try_extract_position(value, name);
+            tt.position = try_extract_position(value, name)?;
         },
         IpgToolTipParam::TextToDisplay => {
-            tt.text_to_display = try_extract_string(value, name);
+            tt.text_to_display = try_extract_string(value, name)?;
         },
         IpgToolTipParam::Gap => {
-            tt.gap = try_extract_u16(value, name);
+            tt.gap = try_extract_u16(value, name)?;
         },
         IpgToolTipParam::Padding => {
-            tt.padding = try_extract_f32(value, name);
+            tt.padding = try_extract_f32(value, name)?;
         },
         IpgToolTipParam::SnapWithinViewport => {
-            tt.snap_within_viewport = try_extract_boolean(value, name);
+            tt.snap_within_viewport = try_extract_boolean(value, name)?;
         },
         IpgToolTipParam::StyleId => {
-            tt.style_id = Some(try_extract_usize(value, name));
+            tt.style_id = Some(try_extract_usize(value, name)?);
+        },
+        IpgToolTipParam::Show => {
+            tt.show = try_extract_boolean(value, name)?;
         },
     }
+    Ok(())
+}
+
+pub fn tooltip_get_items(tt: &IpgToolTip) -> PyResult<IpgItems> {
+    Ok(vec![
+        ("Position", item_object(tt.position.clone())?),
+        ("TextToDisplay", Some(tt.text_to_display.clone().into())),
+        ("Gap", Some((tt.gap as usize).into())),
+        ("Padding", Some(tt.padding.into())),
+        ("SnapWithinViewport", Some(tt.snap_within_viewport.into())),
+        ("StyleId", tt.style_id.map(Into::into)),
+        ("Show", Some(tt.show.into())),
+    ])
 }
 
-pub fn try_extract_tooltip_update(update_obj: &PyObject) -> IpgToolTipParam {
+pub fn try_extract_tooltip_update(update_obj: &PyObject) -> PyResult<IpgToolTipParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgToolTipParam>(py);
 
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("ToolTip update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("ToolTip update extraction failed")),
         }
     })
 }
 
-pub fn try_extract_position(value: &PyObject, name: String) -> IpgToolTipPosition {
+pub fn try_extract_position(value: &PyObject, name: String) -> PyResult<IpgToolTipPosition> {
     Python::with_gil(|py| {
         let res = value.extract::<IpgToolTipPosition>(py);
         match res {
-            Ok(val) => val,
-            Err(_) => panic!("{}-Unable to extract tooltip position", name),
+            Ok(val) => Ok(val),
+            Err(_) => Err(IpgParamError::new_err(format!("{}-Unable to extract tooltip position", name))),
         }
     })  
 }
@@ -263,62 +285,62 @@ pub enum IpgToolTipStyleParam {
 pub fn tool_tip_style_update_item(
     style: &mut IpgToolTipStyle,
     item: &PyObject,
-    value: &PyObject,) 
+    value: &PyObject,) -> PyResult<()> 
 {
-    let update = try_extract_container_style_update(item);
+    let update = try_extract_container_style_update(item)?;
     let name = "ToolTipStyle".to_string();
     match update {
         IpgToolTipStyleParam::BackgroundIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.background_color = get_color(None, Some(color), 1.0, false);
         },
         IpgToolTipStyleParam::BackgroundRgbaColor => {
-            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.background_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgToolTipStyleParam::BorderIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.border_color = get_color(None, Some(color), 1.0, false);
         },
         IpgToolTipStyleParam::BorderRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgToolTipStyleParam::BorderRadius => {
-            style.border_radius = try_extract_vec_f32(value, name);
+            style.border_radius = try_extract_vec_f32(value, name)?;
         },
         IpgToolTipStyleParam::BorderWidth => {
-            style.border_width = try_extract_f32(value, name);
+            style.border_width = try_extract_f32(value, name)?;
         },
         IpgToolTipStyleParam::ShadowIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.shadow_color = get_color(None, Some(color), 1.0, false);
         },
         IpgToolTipStyleParam::ShadowRgbaColor => {
-            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.border_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
         IpgToolTipStyleParam::ShadowOffsetXY => {
-            style.shadow_offset_xy = try_extract_array_2(value, name);
+            style.shadow_offset_xy = try_extract_array_2(value, name)?;
         },
         IpgToolTipStyleParam::ShadowBlurRadius => {
-            style.shadow_blur_radius = try_extract_f32(value, name);
+            style.shadow_blur_radius = try_extract_f32(value, name)?;
         },
         IpgToolTipStyleParam::TextIpgColor => {
-            let color = try_extract_ipg_color(value, name);
+            let color = try_extract_ipg_color(value, name)?;
             style.text_color = get_color(None, Some(color), 1.0, false);
         },
         IpgToolTipStyleParam::TextRgbaColor => {
-            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)));
+            style.text_color = Some(Color::from(try_extract_rgba_color(value, name)?));
         },
     }
-
+    Ok(())
 }
 
-pub fn try_extract_container_style_update(update_obj: &PyObject) -> IpgToolTipStyleParam {
+pub fn try_extract_container_style_update(update_obj: &PyObject) -> PyResult<IpgToolTipStyleParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgToolTipStyleParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("ToolTip style parameter update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("ToolTip style parameter update extraction failed")),
         }
     })
 }
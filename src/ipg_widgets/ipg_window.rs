@@ -1,15 +1,19 @@
 #![allow(dead_code)]
 
 use crate::app::{self, Message};
-use crate::{access_window_actions, IpgState};
+use crate::{access_window_actions, mirror_item, IpgState, WindowActions};
 
 use iced::window;
-use iced::{Element, Task, Theme, Size};
+use iced::{Element, Point, Task, Theme, Size};
 use iced::widget::Column;
 
-use pyo3::{pyclass, PyObject, Python};
+use pyo3::{pyclass, PyObject, Python, PyResult};
 
-use super::helpers::{try_extract_boolean, try_extract_f64, try_extract_u64, try_extract_vec_f32};
+use super::ipg_enums::IpgContainers;
+use super::ipg_errors::{report_error, IpgError, IpgParamError};
+use super::ipg_window_state::save_window;
+use super::helpers::{try_extract_boolean, try_extract_f64, try_extract_u64, try_extract_vec_f32, 
+    item_object, item_object_opt, IpgItems};
 
 
 #[derive(Debug, Clone)]
@@ -29,6 +33,7 @@ pub struct IpgWindow {
     pub level: IpgWindowLevel,
     pub scale_factor: f64,
     pub debug: bool,
+    pub icon: Option<window::Icon>,
 }
 
 impl IpgWindow {
@@ -48,6 +53,7 @@ impl IpgWindow {
         level: IpgWindowLevel,
         scale_factor: f64,
         debug: bool,
+        icon: Option<window::Icon>,
         ) -> Self {
         Self {
             id,
@@ -65,6 +71,7 @@ impl IpgWindow {
             level,
             scale_factor,
             debug,
+            icon,
         }
     }
 }
@@ -80,60 +87,137 @@ pub enum WndMessage {
 
 pub fn add_windows(state: &mut IpgState) -> Vec<Task<app::Message>> {
 
-    let mut modes: Vec<(usize, window::Mode)> = vec![];
-
-    let mut spawn_window: Vec<Task<app::Message>> = vec![];
-
-    for i in 0..state.windows.len() {
-        let visible = match state.windows[i].mode {
-            IpgWindowMode::Windowed => {
-                modes.push((state.windows[i].id, window::Mode::Windowed));
-                true
-            },
-            IpgWindowMode::FullScreen => {
-                modes.push((state.windows[i].id, window::Mode::Fullscreen));
-                true
-            },
-            IpgWindowMode::Closed => {
-                modes.push((state.windows[i].id, window::Mode::Hidden));
-                false
-            },
-        };
-        let (iced_id, open) = window::open(window::Settings {
-            size: state.windows[i].size,
-            min_size: state.windows[i].min_size,
-            max_size: state.windows[i].max_size,
-            position: state.windows[i].position,
-            visible,
-            resizable: state.windows[i].resizable,
-            decorations: state.windows[i].decorations,
-            transparent: state.windows[i].transparent,
-            level: get_level(&state.windows[i].level),
-            exit_on_close_request: state.windows[i].exit_on_close_request,
-            ..Default::default()
-        });
-        let id = state.windows[i].id;
-        let debug = state.windows[i].debug;
-        let theme = state.windows[i].theme.clone();
-        let mode = state.windows[i].mode.clone();
-
-        state.window_debug.insert(iced_id, (id, debug));
-        state.window_theme.insert(iced_id, (id, theme));
-        state.window_mode.insert(iced_id, (id, get_iced_mode(&mode)));
-        state.windows_opened.push(iced_id);
-        if !visible {
-            state.windows_hidden.push(iced_id);
+    let windows = state.windows.clone();
+
+    windows.iter()
+        .map(|wnd| {
+            let visible = wnd.mode != IpgWindowMode::Closed;
+            open_window(state, wnd, visible)
+        })
+        .collect()
+
+}
+
+// Opens the window with its settings, when reopened after being 
+// closed the window gets a new iced id.
+pub fn open_window(state: &mut IpgState, wnd: &IpgWindow, visible: bool) -> Task<app::Message> {
+    let (iced_id, open) = window::open(window::Settings {
+        size: wnd.size,
+        min_size: wnd.min_size,
+        max_size: wnd.max_size,
+        position: wnd.position,
+        visible,
+        resizable: wnd.resizable,
+        decorations: wnd.decorations,
+        transparent: wnd.transparent,
+        level: get_level(&wnd.level),
+        exit_on_close_request: wnd.exit_on_close_request,
+        icon: wnd.icon.clone(),
+        ..Default::default()
+    });
+
+    state.window_debug.insert(iced_id, (wnd.id, wnd.debug));
+    state.window_theme.insert(iced_id, (wnd.id, wnd.theme.clone()));
+    state.window_mode.insert(iced_id, (wnd.id, get_iced_mode(&wnd.mode)));
+    state.windows_opened.push(iced_id);
+    if !visible {
+        state.windows_hidden.push(iced_id);
+    }
+    state.windows_iced_ipg_ids.insert(iced_id, wnd.id);
+
+    let size = wnd.size;
+    open.map(move|_|Message::WindowOpened(iced_id, None, size))
+}
+
+// A closed window is only hidden so that it can be shown again.
+pub fn hide_window(state: &mut IpgState, 
+                    actions: &mut WindowActions, 
+                    ipg_id: usize, 
+                    iced_id: window::Id) 
+{
+    actions.mode.push((ipg_id, window::Mode::Hidden));
+    if !state.windows_hidden.contains(&iced_id){
+        state.windows_hidden.push(iced_id);
+    }
+    save_window_state(state, ipg_id);
+    set_window_mode(state, ipg_id, IpgWindowMode::Closed);
+}
+
+// The window is gone, its iced id is not used again.
+pub fn forget_window(state: &mut IpgState, ipg_id: usize, iced_id: window::Id) {
+    state.windows_opened.retain(|id| *id != iced_id);
+    state.windows_hidden.retain(|id| *id != iced_id);
+    state.windows_iced_ipg_ids.remove(&iced_id);
+    state.window_debug.remove(&iced_id);
+    state.window_theme.remove(&iced_id);
+    state.window_mode.remove(&iced_id);
+    save_window_state(state, ipg_id);
+    set_window_mode(state, ipg_id, IpgWindowMode::Closed);
+}
+
+pub fn set_window_mode(state: &mut IpgState, ipg_id: usize, mode: IpgWindowMode) {
+    if let Some(IpgContainers::IpgWindow(wnd)) = state.containers.get_mut(&ipg_id) {
+        wnd.mode = mode;
+        mirror_item(state, ipg_id);
+    }
+}
+
+// Saved when the window is closed, if the app has a window state file.
+fn save_window_state(state: &IpgState, ipg_id: usize) {
+    let wnd = match state.containers.get(&ipg_id) {
+        Some(IpgContainers::IpgWindow(wnd)) if wnd.mode != IpgWindowMode::Closed => wnd,
+        _ => return,
+    };
+    let window_id = state.windows_str_ids.iter()
+                        .find(|(_, id)| **id == ipg_id)
+                        .map(|(window_id, _)| window_id);
+    if let Some(window_id) = window_id {
+        if let Err(msg) = save_window(window_id, wnd) {
+            report_error(IpgError::new_err(msg));
         }
+    }
+}
 
-        let ipg_id = state.windows[i].id;
-        state.windows_iced_ipg_ids.insert(iced_id, ipg_id);
-        let size = state.windows[i].size;
-        spawn_window.push(open.map(move|_|Message::WindowOpened(iced_id, None, size)));
-        
+/// The position and size are followed from the window events, a
+/// window resized outside of its min or max size is sized back.
+pub fn set_window_geometry(state: &mut IpgState, 
+                            ipg_id: usize, 
+                            position: Option<Point>, 
+                            size: Option<Size>) 
+{
+    let wnd = match state.containers.get_mut(&ipg_id) {
+        Some(IpgContainers::IpgWindow(wnd)) => wnd,
+        _ => return,
+    };
+    if let Some(point) = position {
+        wnd.position = window::Position::Specific(point);
+    }
+    if let Some(size) = size {
+        wnd.size = size;
+        resize_within_bounds(wnd);
+    }
+    mirror_item(state, ipg_id);
+}
+
+// The min and max sizes can't be changed after the window
+// is opened, so the window is resized instead.
+fn resize_within_bounds(wnd: &mut IpgWindow) {
+    let size = clamp_size(wnd.size, wnd.min_size, wnd.max_size);
+    if size != wnd.size {
+        wnd.size = size;
+        access_window_actions().resize.push((wnd.id, size.width, size.height));
     }
+}
 
-    spawn_window
+pub fn clamp_size(size: Size, min_size: Option<Size>, max_size: Option<Size>) -> Size {
+    let min_size = min_size.unwrap_or(Size::ZERO);
+    let max_size = max_size.unwrap_or(Size::INFINITY);
+    Size::new(size.width.min(max_size.width).max(min_size.width), 
+                size.height.min(max_size.height).max(min_size.height))
+}
 
+pub fn all_windows_hidden(state: &IpgState) -> bool {
+    state.windows_opened.len() == state.windows_hidden.len()
 }
 
 pub fn construct_window(content: Vec<Element<app::Message>>) -> Element<app::Message> {
@@ -214,6 +298,36 @@ pub fn get_iced_window_theme(theme: IpgWindowTheme) -> Theme {
     }
 }
 
+pub fn get_ipg_window_theme(theme: &Theme) -> Option<IpgWindowTheme> {
+
+    match theme {
+        Theme::Dark => Some(IpgWindowTheme::Dark),
+        Theme::Light => Some(IpgWindowTheme::Light),
+        Theme::CatppuccinLatte => Some(IpgWindowTheme::CatppuccinLatte),
+        Theme::CatppuccinFrappe => Some(IpgWindowTheme::CatppuccinFrappe),
+        Theme::CatppuccinMacchiato => Some(IpgWindowTheme::CatppuccinMacchiato),
+        Theme::CatppuccinMocha => Some(IpgWindowTheme::CatppuccinMocha),
+        Theme::Dracula => Some(IpgWindowTheme::Dracula),
+        Theme::Ferra => Some(IpgWindowTheme::Ferra),
+        Theme::GruvboxLight => Some(IpgWindowTheme::GruvboxLight),
+        Theme::GruvboxDark => Some(IpgWindowTheme::GruvboxDark),
+        Theme::KanagawaWave => Some(IpgWindowTheme::KanagawaWave),
+        Theme::KanagawaDragon => Some(IpgWindowTheme::KanagawaDragon),
+        Theme::KanagawaLotus => Some(IpgWindowTheme::KanagawaLotus),
+        Theme::Moonfly => Some(IpgWindowTheme::Moonfly),
+        Theme::Nightfly => Some(IpgWindowTheme::Nightfly),
+        Theme::Nord => Some(IpgWindowTheme::Nord),
+        Theme::Oxocarbon => Some(IpgWindowTheme::Oxocarbon),
+        Theme::SolarizedDark => Some(IpgWindowTheme::SolarizedDark),
+        Theme::SolarizedLight => Some(IpgWindowTheme::SolarizedLight),
+        Theme::TokyoNight => Some(IpgWindowTheme::TokyoNight),
+        Theme::TokyoNightLight => Some(IpgWindowTheme::TokyoNightLight),
+        Theme::TokyoNightStorm => Some(IpgWindowTheme::TokyoNightStorm),
+        // a custom theme
+        _ => None,
+    }
+}
+
 
 #[derive(Debug, Clone, PartialEq)]
 #[pyclass(eq, eq_int)]
@@ -226,28 +340,31 @@ pub enum IpgWindowParam {
     Size,
     Theme,
     ScaleFactor,
+    Icon,
+    MinSize,
+    MaxSize,
 }
 
 pub fn window_item_update(wnd: &mut IpgWindow,
                             item: &PyObject,
                             value: &PyObject
-                            )
+                            ) -> PyResult<()>
 {
-    let update = try_extract_window_update(item);
+    let update = try_extract_window_update(item)?;
     let name = "Window".to_string();
     match update {
         IpgWindowParam::Debug => {
-            wnd.debug = try_extract_boolean(value, name);
+            wnd.debug = try_extract_boolean(value, name)?;
         },
         IpgWindowParam::Theme => {
-            let val = try_extract_ipg_theme(value);
+            let val = try_extract_ipg_theme(value)?;
             wnd.theme = get_iced_window_theme(val);
         },
         IpgWindowParam::ScaleFactor => {
-            wnd.scale_factor = try_extract_f64(value, name);
+            wnd.scale_factor = try_extract_f64(value, name)?;
         },
         IpgWindowParam::Mode => {
-            let ipg_mode = try_extract_mode(value);
+            let ipg_mode = try_extract_mode(value)?;
             let mode = get_iced_mode(&ipg_mode);
             wnd.mode = ipg_mode;
             let mut state = access_window_actions();
@@ -255,13 +372,13 @@ pub fn window_item_update(wnd: &mut IpgWindow,
             drop(state)
         },
         IpgWindowParam::Decorations => {
-            let val = try_extract_u64(value, name) as usize;
+            let val = try_extract_u64(value, name)? as usize;
             let mut state = access_window_actions();
             state.decorations.push(val);
             drop(state)
         },
         IpgWindowParam::Level => {
-            let ipg_level = try_extract_level(value);
+            let ipg_level = try_extract_level(value)?;
             let level = get_level(&ipg_level);
             wnd.level = ipg_level;
             let mut state = access_window_actions();
@@ -269,60 +386,126 @@ pub fn window_item_update(wnd: &mut IpgWindow,
             drop(state)
         },
         IpgWindowParam::Position => {
-            let val = try_extract_vec_f32(value, name);
+            let val = try_extract_vec_f32(value, name)?;
             let mut state = access_window_actions();
             state.position.push((wnd.id, val[0], val[1]));
             drop(state)
         },
         IpgWindowParam::Size => {
-            let val = try_extract_vec_f32(value, name);
+            let val = try_extract_vec_f32(value, name)?;
+            wnd.size = clamp_size(Size::new(val[0], val[1]), wnd.min_size, wnd.max_size);
+            let mut state = access_window_actions();
+            state.resize.push((wnd.id, wnd.size.width, wnd.size.height));
+            drop(state)
+        },
+        IpgWindowParam::Icon => {
+            let icon = match try_extract_icon(value) {
+                Ok(icon) => icon,
+                Err(msg) => return Err(IpgParamError::new_err(format!("Window icon: {msg}"))),
+            };
+            wnd.icon = Some(icon.clone());
             let mut state = access_window_actions();
-            state.resize.push((wnd.id, val[0], val[1]));
+            state.icon.push((wnd.id, icon));
             drop(state)
         },
+        IpgWindowParam::MinSize => {
+            wnd.min_size = try_extract_size_opt(value, name)?;
+            resize_within_bounds(wnd);
+        },
+        IpgWindowParam::MaxSize => {
+            wnd.max_size = try_extract_size_opt(value, name)?;
+            resize_within_bounds(wnd);
+        },
     }
+    Ok(())
+}
 
+pub fn window_get_items(wnd: &IpgWindow) -> PyResult<IpgItems> {
+    let position = match wnd.position {
+        window::Position::Specific(point) => Some(vec![point.x, point.y].into()),
+        _ => None,
+    };
+    Ok(vec![
+        ("Decorations", Some(wnd.decorations.into())),
+        ("Debug", Some(wnd.debug.into())),
+        ("Level", item_object(wnd.level.clone())?),
+        ("Mode", item_object(wnd.mode.clone())?),
+        ("Position", position),
+        ("Size", Some(vec![wnd.size.width, wnd.size.height].into())),
+        ("Theme", item_object_opt(get_ipg_window_theme(&wnd.theme))?),
+        ("ScaleFactor", Some((wnd.scale_factor as f32).into())),
+        ("MinSize", wnd.min_size.map(|size| vec![size.width, size.height].into())),
+        ("MaxSize", wnd.max_size.map(|size| vec![size.width, size.height].into())),
+    ])
 }
 
 
-fn try_extract_window_update(update_obj: &PyObject) -> IpgWindowParam {
+fn try_extract_window_update(update_obj: &PyObject) -> PyResult<IpgWindowParam> {
 
     Python::with_gil(|py| {
         let res = update_obj.extract::<IpgWindowParam>(py);
         match res {
-            Ok(update) => update,
-            Err(_) => panic!("Window update extraction failed"),
+            Ok(update) => Ok(update),
+            Err(_) => Err(IpgParamError::new_err("Window update extraction failed")),
         }
     })
 }
 
-fn try_extract_ipg_theme(theme: &PyObject) -> IpgWindowTheme {
+/// The icon is the bytes of an image, like a png, or the path of one.
+pub fn try_extract_icon(icon: &PyObject) -> Result<window::Icon, String> {
+    Python::with_gil(|py| {
+        if let Ok(path) = icon.extract::<String>(py) {
+            return window::icon::from_file(&path)
+                        .map_err(|e| format!("Unable to load the icon {path}: {e}"))
+        }
+        match icon.extract::<Vec<u8>>(py) {
+            Ok(bytes) => window::icon::from_file_data(&bytes, None)
+                            .map_err(|e| format!("Unable to load the icon: {e}")),
+            Err(_) => Err("The icon must be the bytes of an image or its path".to_string()),
+        }
+    })
+}
+
+// None removes the min or max size.
+fn try_extract_size_opt(value: &PyObject, name: String) -> PyResult<Option<Size>> {
+    let is_none = Python::with_gil(|py| value.is_none(py));
+    if is_none {
+        return Ok(None)
+    }
+    let val = try_extract_vec_f32(value, name)?;
+    if val.len() != 2 {
+        return Err(IpgParamError::new_err("Window size must be a list of [width, height]"))
+    }
+    Ok(Some(Size::new(val[0], val[1])))
+}
+
+fn try_extract_ipg_theme(theme: &PyObject) -> PyResult<IpgWindowTheme> {
 
     Python::with_gil(|py| {
         let res = theme.extract::<IpgWindowTheme>(py);
         match res {
-            Ok(theme) => theme,
-            Err(_) => panic!("Window theme extraction failed"),
+            Ok(theme) => Ok(theme),
+            Err(_) => Err(IpgParamError::new_err("Window theme extraction failed")),
         }
     })
 }
 
-fn try_extract_mode(mode: &PyObject) -> IpgWindowMode {
+fn try_extract_mode(mode: &PyObject) -> PyResult<IpgWindowMode> {
     Python::with_gil(|py| {
         let res = mode.extract::<IpgWindowMode>(py);
         match res {
-            Ok(mode) => mode,
-            Err(e) => panic!("Window mode extraction failed with error {}", e),
+            Ok(mode) => Ok(mode),
+            Err(e) => Err(IpgParamError::new_err(format!("Window mode extraction failed with error {}", e))),
         }
     })
 }
 
-fn try_extract_level(level: &PyObject) -> IpgWindowLevel {
+fn try_extract_level(level: &PyObject) -> PyResult<IpgWindowLevel> {
     Python::with_gil(|py| {
         let res = level.extract::<IpgWindowLevel>(py);
         match res {
-            Ok(level) => level,
-            Err(e) => panic!("Window level extraction failed with error {}", e),
+            Ok(level) => Ok(level),
+            Err(e) => Err(IpgParamError::new_err(format!("Window level extraction failed with error {}", e))),
         }
     })
 }
@@ -366,3 +549,13 @@ pub fn get_ipg_mode(mode: window::Mode) -> IpgWindowMode {
         window::Mode::Hidden => IpgWindowMode::Closed,
     }
 }
+
+
+#[test]
+fn test_clamp_size() {
+    let min_size = Some(Size::new(100.0, 50.0));
+    let max_size = Some(Size::new(400.0, 300.0));
+    assert_eq!(Size::new(100.0, 300.0), clamp_size(Size::new(20.0, 500.0), min_size, max_size));
+    assert_eq!(Size::new(200.0, 200.0), clamp_size(Size::new(200.0, 200.0), min_size, max_size));
+    assert_eq!(Size::new(900.0, 10.0), clamp_size(Size::new(900.0, 10.0), None, None));
+}
@@ -0,0 +1,155 @@
+//! ipg_window_state
+//! With IPG(window_state_file=...), the position, size and mode of each
+//! window are saved to a json file, keyed by the window_id, when the window
+//! is closed.  The next time the window is added they replace the ones given.
+//! The saved position isn't checked against the monitors, iced has no list
+//! of them before the window opens.  A window saved on a monitor that's no
+//! longer connected may open off screen, removing its entry resets it.
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use iced::{window, Point, Size};
+use serde::{Deserialize, Serialize};
+
+use super::ipg_window::{IpgWindow, IpgWindowMode};
+
+
+static WINDOW_STATE_FILE: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+fn access_window_state_file() -> MutexGuard<'static, Option<PathBuf>> {
+    WINDOW_STATE_FILE.lock().unwrap()
+}
+
+pub fn set_window_state_file(path: Option<String>) {
+    *access_window_state_file() = path.map(PathBuf::from);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SavedWindowMode {
+    Windowed,
+    FullScreen,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedWindow {
+    // none when the platform doesn't give the position
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+    pub width: f32,
+    pub height: f32,
+    pub mode: SavedWindowMode,
+}
+
+impl SavedWindow {
+    pub fn new(wnd: &IpgWindow) -> Self {
+        let (x, y) = match wnd.position {
+            window::Position::Specific(point) => (Some(point.x), Some(point.y)),
+            _ => (None, None),
+        };
+        let mode = match wnd.mode {
+            IpgWindowMode::FullScreen => SavedWindowMode::FullScreen,
+            _ => SavedWindowMode::Windowed,
+        };
+        Self {
+            x,
+            y,
+            width: wnd.size.width,
+            height: wnd.size.height,
+            mode,
+        }
+    }
+
+    // A window added closed stays closed, it's opened by the app.
+    pub fn restore(&self,
+                    size: &mut Size,
+                    position: &mut window::Position,
+                    mode: &mut IpgWindowMode)
+    {
+        *size = Size::new(self.width, self.height);
+        if let (Some(x), Some(y)) = (self.x, self.y) {
+            *position = window::Position::Specific(Point::new(x, y));
+        }
+        if *mode != IpgWindowMode::Closed {
+            *mode = match self.mode {
+                SavedWindowMode::Windowed => IpgWindowMode::Windowed,
+                SavedWindowMode::FullScreen => IpgWindowMode::FullScreen,
+            };
+        }
+    }
+}
+
+// A missing file has no windows yet.
+fn read_saved_windows(path: &Path) -> Result<HashMap<String, SavedWindow>, String> {
+    if !path.exists() {
+        return Ok(HashMap::new())
+    }
+    let data = fs::read_to_string(path)
+        .map_err(|e| format!("Unable to read the window state file {}: {e}", path.display()))?;
+    serde_json::from_str(&data)
+        .map_err(|e| format!("Unable to parse the window state file {}: {e}", path.display()))
+}
+
+fn write_saved_windows(path: &Path, windows: &HashMap<String, SavedWindow>) -> Result<(), String> {
+    let data = serde_json::to_string_pretty(windows)
+        .map_err(|e| format!("Unable to format the window state: {e}"))?;
+    fs::write(path, data + "\n")
+        .map_err(|e| format!("Unable to write the window state file {}: {e}", path.display()))
+}
+
+/// Replaces the size, position and mode with the saved ones, if any.
+pub fn restore_window(window_id: &str,
+                        size: &mut Size,
+                        position: &mut window::Position,
+                        mode: &mut IpgWindowMode)
+                        -> Result<(), String>
+{
+    let path = match access_window_state_file().clone() {
+        Some(path) => path,
+        None => return Ok(()),
+    };
+    if let Some(saved) = read_saved_windows(&path)?.get(window_id) {
+        saved.restore(size, position, mode);
+    }
+    Ok(())
+}
+
+/// Saves the window with the others in the file, an unreadable
+/// file is replaced.
+pub fn save_window(window_id: &str, wnd: &IpgWindow) -> Result<(), String> {
+    let path = match access_window_state_file().clone() {
+        Some(path) => path,
+        None => return Ok(()),
+    };
+    let mut windows = read_saved_windows(&path).unwrap_or_default();
+    windows.insert(window_id.to_string(), SavedWindow::new(wnd));
+    write_saved_windows(&path, &windows)
+}
+
+
+#[test]
+fn test_saved_windows() {
+    let path = std::env::temp_dir().join(format!("ipg_window_state_{}.json", std::process::id()));
+    let saved = SavedWindow{x: Some(10.0), y: Some(20.0), width: 300.0, height: 200.0,
+                            mode: SavedWindowMode::FullScreen};
+    let windows = HashMap::from([("main".to_string(), saved.clone())]);
+    write_saved_windows(&path, &windows).unwrap();
+    let read = read_saved_windows(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(Some(&saved), read.get("main"));
+    assert!(read_saved_windows(&path).unwrap().is_empty());
+
+    let mut size = Size::new(100.0, 100.0);
+    let mut position = window::Position::Centered;
+    let mut mode = IpgWindowMode::Windowed;
+    saved.restore(&mut size, &mut position, &mut mode);
+    assert_eq!(Size::new(300.0, 200.0), size);
+    assert_eq!(window::Position::Specific(Point::new(10.0, 20.0)), position);
+    assert_eq!(IpgWindowMode::FullScreen, mode);
+
+    // a window added closed isn't opened
+    let mut mode = IpgWindowMode::Closed;
+    saved.restore(&mut size, &mut position, &mut mode);
+    assert_eq!(IpgWindowMode::Closed, mode);
+}
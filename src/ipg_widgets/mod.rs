@@ -4,9 +4,13 @@ pub mod callbacks;
 pub mod divider;
 pub mod helpers;
 // pub mod table;
+pub mod ipg_async;
+pub mod ipg_batch;
+pub mod ipg_bindings;
 pub mod ipg_button;
 pub mod ipg_canvas;
 pub mod ipg_card;
+pub mod ipg_chart;
 pub mod ipg_checkbox;
 pub mod ipg_color_picker;
 pub mod ipg_column;
@@ -14,7 +18,12 @@ pub mod ipg_container;
 pub mod ipg_date_picker;
 pub mod ipg_divider;
 pub mod ipg_enums;
+pub mod ipg_errors;
 pub mod ipg_events;
+pub mod ipg_focus;
+pub mod ipg_gauge;
+pub mod ipg_gestures;
+pub mod ipg_heatmap;
 pub mod ipg_image;
 pub mod ipg_menu;
 // pub mod ipg_modal;
@@ -25,12 +34,15 @@ pub mod ipg_progress_bar;
 pub mod ipg_radio;
 pub mod ipg_row;
 pub mod ipg_rule;
+pub mod ipg_scheduler;
 pub mod ipg_scrollable;
 pub mod ipg_selectable_text;
 pub mod ipg_separator;
+pub mod ipg_shortcuts;
 pub mod ipg_slider;
 pub mod ipg_space;
 pub mod ipg_stack;
+pub mod ipg_stream_plot;
 pub mod ipg_svg;
 pub mod ipg_table;
 pub mod ipg_text;
@@ -40,3 +52,4 @@ pub mod ipg_timer_canvas;
 pub mod ipg_toggle;
 pub mod ipg_tool_tip;
 pub mod ipg_window;
+pub mod ipg_window_state;
@@ -1,22 +1,23 @@
 //!lib for all of the python callable functions using pyo3
 #![allow(clippy::too_many_arguments, clippy::redundant_closure)]
 #![allow(clippy::type_complexity)]
-use canvas::canvas_helpers::{build_polygon, get_mid_point, to_radians};
+use canvas::canvas_helpers::{build_polygon, get_horizontal_angle_of_vector, get_mid_point, to_radians};
 use canvas::draw_canvas::{IpgCanvasState, IpgDrawMode, IpgDrawStatus, IpgWidget};
-use canvas::geometries::{IpgArc, IpgBezier, IpgCanvasImage, IpgCanvasWidget, 
-    IpgCircle, IpgEllipse, IpgLine, IpgPolyLine, IpgPolygon, IpgRectangle};
+use canvas::geometries::{IpgAngleMeasure, IpgArc, IpgBezier, IpgCallout, IpgCanvasImage, 
+    IpgCanvasWidget, IpgCircle, IpgDimension, IpgEllipse, IpgLine, IpgPolyLine, 
+    IpgPolygon, IpgRectangle};
 
 use iced::widget::image;
 use iced_aw::iced_fonts;
 
-use ipg_widgets::ipg_color_picker::{color_picker_style_update_item, color_picker_update, 
+use ipg_widgets::ipg_color_picker::{color_picker_get_items, color_picker_style_update_item, color_picker_update, 
     IpgColorPicker, IpgColorPickerParam, IpgColorPickerStyle, IpgColorPickerStyleParam};
-use ipg_widgets::ipg_divider::{divider_horizontal_item_update, divider_style_update_item, 
+use ipg_widgets::ipg_divider::{divider_horizontal_get_items, divider_horizontal_item_update, divider_style_update_item, divider_vertical_get_items, 
     divider_vertical_item_update, IpgDividerHorizontal, IpgDividerParam, IpgDividerStyle, 
     IpgDividerStyleParam, IpgDividerVertical};
-use ipg_widgets::ipg_separator::{separator_item_update, separator_style_update_item, 
+use ipg_widgets::ipg_separator::{separator_get_items, separator_item_update, separator_style_update_item, 
     IpgSeparator, IpgSeparatorParam, IpgSeparatorStyle, IpgSeparatorStyleParam, IpgSeparatorType};
-use ipg_widgets::ipg_timer_canvas::{canvas_timer_item_update, canvas_timer_style_update_item, 
+use ipg_widgets::ipg_timer_canvas::{canvas_timer_get_items, canvas_timer_item_update, canvas_timer_style_update_item, 
     IpgCanvasTimer, IpgCanvasTimerParam, IpgCanvasTimerStyle, IpgCanvasTimerStyleParam};
 
 use polars::frame::DataFrame;
@@ -30,7 +31,7 @@ use iced::{Color, Font, Length, Point, Radians, Rectangle, Size, Theme, Vector};
 use iced::widget::text::{self, LineHeight};
 
 use core::panic;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 mod app;
 use app::App;
@@ -42,76 +43,100 @@ mod graphics;
 mod style;
 mod canvas;
 
-use ipg_widgets::ipg_button::{button_item_update, button_style_update_item, 
+use ipg_widgets::ipg_async::{run_coroutine, stop_session_loop, wrap_async_callback};
+use ipg_widgets::ipg_batch::{add_to_batch, in_batch, IpgBatch};
+use ipg_widgets::ipg_scheduler::{cancel_call, schedule_call, IpgScheduledCall};
+use ipg_widgets::ipg_shortcuts::{accelerator_text, add_shortcut, parse_accelerator, remove_shortcut, IpgShortcut};
+use ipg_widgets::callbacks::{set_event_objects, IpgEvent};
+use ipg_widgets::ipg_bindings::{access_bindings, try_extract_bind_value, 
+    IpgBindValue, IpgBinding, IpgConverter};
+use ipg_widgets::ipg_errors::{set_error_handler, IpgCallbackError, IpgError, 
+    IpgIdError, IpgParamError};
+use ipg_widgets::ipg_button::{button_get_items, button_item_update, button_style_update_item, 
     IpgButton, IpgButtonArrow, IpgButtonParam, IpgButtonStyle, IpgButtonStyleParam};
 use ipg_widgets::ipg_canvas::{canvas_item_update, IpgCanvas, 
     IpgCanvasGeometryParam, IpgCanvasParam};
-use ipg_widgets::ipg_card::{card_item_update, card_style_update, IpgCard, 
+use ipg_widgets::ipg_card::{card_get_items, card_item_update, card_style_update, IpgCard, 
     IpgCardParam, IpgCardStyle, IpgCardStyleParam};
-use ipg_widgets::ipg_checkbox::{checkbox_item_update, checkbox_style_update_item, 
+use ipg_widgets::ipg_chart::{chart_dataframe_update, chart_get_items, chart_item_update, 
+    IpgChart, IpgChartKind, IpgChartParam};
+use ipg_widgets::ipg_checkbox::{checkbox_get_items, checkbox_item_update, checkbox_style_update_item, 
     IpgCheckBox, IpgCheckboxParam, IpgCheckboxStyle, IpgCheckboxStyleParam};
-use ipg_widgets::ipg_column::{column_item_update, IpgColumn, IpgColumnParam};
-use ipg_widgets::ipg_container::{container_item_update, container_style_update_item, 
+use ipg_widgets::ipg_column::{column_get_items, column_item_update, IpgColumn, IpgColumnParam};
+use ipg_widgets::ipg_container::{container_get_items, container_item_update, container_style_update_item, 
     IpgContainer, IpgContainerParam, IpgContainerStyle, IpgContainerStyleParam};
-use ipg_widgets::ipg_date_picker::{date_picker_item_update, 
+use ipg_widgets::ipg_date_picker::{date_picker_get_items, date_picker_item_update, 
         IpgDatePicker, IpgDatePickerParam};
-use ipg_widgets::ipg_events::IpgEvents;
-use ipg_widgets::ipg_image::{image_item_update, IpgImage, 
+use ipg_widgets::ipg_events::{IpgEvents, IpgMouseInput};
+use ipg_widgets::ipg_gestures::IpgGestures;
+use ipg_widgets::ipg_focus::{set_tab_order, watch_focus};
+use ipg_widgets::ipg_gauge::{gauge_get_items, gauge_item_update, get_gauge_bands, knob_get_items, knob_item_update, meter_get_items, 
+    meter_item_update, IpgGauge, IpgGaugeParam, IpgKnob, IpgMeter};
+use ipg_widgets::ipg_heatmap::{heatmap_dataframe_update, heatmap_get_items, heatmap_item_update, 
+    IpgHeatmap, IpgHeatmapParam};
+use ipg_widgets::ipg_image::{image_get_items, image_item_update, IpgImage, 
         IpgImageContentFit, IpgImageFilterMethod, 
         IpgImageParam, IpgImageRotation};
-use ipg_widgets::ipg_menu::{menu_bar_style_update_item, menu_item_update, 
+use ipg_widgets::ipg_menu::{menu_bar_style_update_item, menu_get_items, menu_item_update, 
     menu_style_update_item, IpgMenu, IpgMenuBarStyle, IpgMenuBarStyleParam, 
     IpgMenuParam, IpgMenuStyle, IpgMenuStyleParam};
-use ipg_widgets::ipg_mousearea::{mousearea_item_update, IpgMouseArea, 
+use ipg_widgets::ipg_mousearea::{mousearea_get_items, mousearea_item_update, IpgMouseArea, 
         IpgMouseAreaParam, IpgMousePointer};
-use ipg_widgets::ipg_opaque::{opaque_item_update, opaque_style_update_item, 
+use ipg_widgets::ipg_opaque::{opaque_get_items, opaque_item_update, opaque_style_update_item, 
         IpgOpaque, IpgOpaqueParam, IpgOpaqueStyle};
-use ipg_widgets::ipg_pick_list::{convert_pyobject_vec_string, pick_list_item_update, 
+use ipg_widgets::ipg_pick_list::{convert_pyobject_vec_string, pick_list_get_items, pick_list_item_update, 
     pick_list_style_update_item, IpgPickList, IpgPickListHandle, IpgPickListParam, 
     IpgPickListStyle, IpgPickListStyleParam};
-use ipg_widgets::ipg_progress_bar::{progress_bar_item_update, progress_bar_style_update_item, 
+use ipg_widgets::ipg_progress_bar::{progress_bar_get_items, progress_bar_item_update, progress_bar_style_update_item, 
     IpgProgressBar, IpgProgressBarParam, IpgProgressBarStyle, IpgProgressBarStyleParam};
-use ipg_widgets::ipg_radio::{radio_item_update, radio_style_update_item, IpgRadio, 
+use ipg_widgets::ipg_radio::{radio_get_items, radio_item_update, radio_style_update_item, IpgRadio, 
     IpgRadioDirection, IpgRadioParam, IpgRadioStyle, IpgRadioStyleParam};
-use ipg_widgets::ipg_row::{row_item_update, IpgRow, IpgRowParam};
+use ipg_widgets::ipg_row::{row_get_items, row_item_update, IpgRow, IpgRowParam};
 use ipg_widgets::ipg_rule::{rule_style_update_item, IpgRule, IpgRuleStyle, IpgRuleStyleParam};
-use ipg_widgets::ipg_scrollable::{scroll_style_update_item, scrollable_item_update, 
+use ipg_widgets::ipg_scrollable::{scroll_style_update_item, scrollable_get_items, scrollable_item_update, 
     IpgScrollable, IpgScrollableAlignment, IpgScrollableDirection, IpgScrollableParam, 
     IpgScrollableStyle, IpgScrollableStyleParam};
-use ipg_widgets::ipg_selectable_text::{selectable_text_item_update, 
+use ipg_widgets::ipg_selectable_text::{selectable_text_get_items, selectable_text_item_update, 
         IpgSelectableText, IpgSelectableTextParam};
-use ipg_widgets::ipg_slider::{slider_item_update, slider_style_update_item, IpgSlider, 
+use ipg_widgets::ipg_slider::{slider_get_items, slider_item_update, slider_style_update_item, IpgSlider, 
     IpgSliderParam, IpgSliderStyle, IpgSliderStyleParam};
 use ipg_widgets::ipg_space::IpgSpace;
-use ipg_widgets::ipg_stack::{stack_item_update, IpgStack, IpgStackParam};
-use ipg_widgets::ipg_svg::{svg_item_update, IpgSvg, IpgSvgContentFit, 
+use ipg_widgets::ipg_stack::{stack_get_items, stack_item_update, IpgStack, IpgStackParam};
+use ipg_widgets::ipg_stream_plot::{check_capacity, stream_plot_get_items, stream_plot_item_update, IpgRingBuffer, 
+    IpgStreamPlot, IpgStreamPlotParam};
+use ipg_widgets::ipg_svg::{svg_get_items, svg_item_update, IpgSvg, IpgSvgContentFit, 
         IpgSvgParam, IpgSvgRotation};
-use ipg_widgets::ipg_table::{table_dataframe_update, table_item_update, 
+use ipg_widgets::ipg_table::{table_dataframe_update, table_get_items, table_item_update, 
     table_style_update_item, IpgTable, IpgTableParam, IpgTableStyle, IpgTableStyleParam};
-use ipg_widgets::ipg_text::{text_item_update, IpgText, IpgTextParam};
-use ipg_widgets::ipg_text_input::{text_input_item_update, text_input_style_update_item, 
+use ipg_widgets::ipg_text::{text_get_items, text_item_update, IpgText, IpgTextParam};
+use ipg_widgets::ipg_text_input::{text_input_get_items, text_input_item_update, text_input_style_update_item, 
     IpgTextInput, IpgTextInputParam, IpgTextInputStyle, IpgTextInputStyleParam};
-use ipg_widgets::ipg_timer::{timer_item_update, timer_style_update_item, IpgTimer, 
+use ipg_widgets::ipg_timer::{timer_get_items, timer_item_update, timer_style_update_item, IpgTimer, 
     IpgTimerParam, IpgTimerStyle, IpgTimerStyleParam};
-use ipg_widgets::ipg_toggle::{toggler_item_update, toggler_style_update_item, 
+use ipg_widgets::ipg_toggle::{toggler_get_items, toggler_item_update, toggler_style_update_item, 
     IpgToggler, IpgTogglerParam, IpgTogglerStyle, IpgTogglerStyleParam};
-use ipg_widgets::ipg_tool_tip::{tool_tip_style_update_item, tooltip_item_update, IpgToolTip, IpgToolTipParam, IpgToolTipPosition, IpgToolTipStyle, IpgToolTipStyleParam};
-use ipg_widgets::ipg_window::{get_iced_window_theme, 
+use ipg_widgets::ipg_tool_tip::{tool_tip_style_update_item, tooltip_get_items, tooltip_item_update, IpgToolTip, IpgToolTipParam, IpgToolTipPosition, IpgToolTipStyle, IpgToolTipStyleParam};
+use ipg_widgets::ipg_window::{clamp_size, get_iced_window_theme, try_extract_icon, window_get_items, 
         window_item_update, IpgWindow, IpgWindowLevel, IpgWindowMode, 
         IpgWindowParam, IpgWindowTheme};
+use ipg_widgets::ipg_window_state::{restore_window, set_window_state_file};
 use ipg_widgets::ipg_enums::{IpgAlignment, IpgContainers, IpgHorizontalAlignment, 
     IpgVerticalAlignment, IpgWidgets};
 
 use ipg_widgets::helpers::{check_for_dup_container_ids, get_height, 
-    get_horizontal_alignment, get_line_height, get_padding_f32, 
-    get_padding_f64, get_shaping, get_vertical_alignment, get_width};
+    get_horizontal_alignment, get_item_value, get_line_height, get_padding_f32, 
+    get_padding_f64, get_param_names, get_shaping, get_vertical_alignment, get_width, 
+    IpgItemValue, IpgItems};
 
+use graphics::colormaps::IpgColorMap;
 use graphics::colors::{get_color, IpgColor};
 use style::styling::{readable, IpgStyleStandard};
 
 const ICON_FONT_BOOT: Font = Font::with_name("bootstrap-icons");
 
-use std::sync::{Mutex, MutexGuard};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::sync::atomic::{AtomicBool, Ordering};
+use iced::futures::channel::mpsc::UnboundedSender;
 use once_cell::sync::Lazy;
 
 #[derive(Debug)]
@@ -168,14 +193,18 @@ pub fn access_user_data2() -> MutexGuard<'static, UserData2> {
 
 #[derive(Debug)]
 pub struct UpdateItems {
-    // wid, (item, value)
-    pub updates: Vec<(usize, PyObject, PyObject)>, 
+    // wid, (item, value), each batch is applied as a whole
+    pub updates: Vec<Vec<(usize, PyObject, PyObject)>>, 
     // window_id_widget_id, (window_id, wid, target_container_str_id, move_after(wid), move_before(wid))
     pub moves: Vec<(String, usize, String, Option<usize>, Option<usize>)>,
     // window_id, wid
     pub deletes: Vec<(String, usize)>,
+    // window_id, container id
+    pub clears: Vec<(String, usize)>,
     pub shows: Vec<(String, Vec<(usize, bool)>)>,
     pub dataframes: Vec<(usize, PyObject, PyDataFrame)>,
+    // the names of the bound values that changed
+    pub bound_values: Vec<String>,
     pub new_widgets: Lazy<HashMap<usize, IpgWidgets>>,
 }
 
@@ -183,8 +212,10 @@ pub static UPDATE_ITEMS: Mutex<UpdateItems> = Mutex::new(UpdateItems {
     updates: vec![],
     moves: vec![],
     deletes: vec![],
+    clears: vec![],
     shows: vec![],
     dataframes: vec![],
+    bound_values: vec![],
     new_widgets: Lazy::new(||HashMap::new()),
 });
 
@@ -192,6 +223,21 @@ pub fn access_update_items() -> MutexGuard<'static, UpdateItems> {
     UPDATE_ITEMS.lock().unwrap()
 }
 
+#[derive(Debug)]
+pub struct StreamSamples {
+    // (wid, series index), the samples not yet taken by the gui,
+    // kept to the capacity of the plot
+    pub samples: Lazy<HashMap<(usize, usize), IpgRingBuffer>>,
+}
+
+pub static STREAM_SAMPLES: Mutex<StreamSamples> = Mutex::new(StreamSamples {
+    samples: Lazy::new(||HashMap::new()),
+});
+
+pub fn access_stream_samples() -> MutexGuard<'static, StreamSamples> {
+    STREAM_SAMPLES.lock().unwrap()
+}
+
 #[derive(Debug)]
 pub struct UpdateCanvasItems {
     // wid, (item, value)
@@ -212,6 +258,22 @@ pub fn access_canvas_update_items() -> MutexGuard<'static, UpdateCanvasItems> {
     UPDATE_CANVAS_ITEMS.lock().unwrap()
 }
 
+#[derive(Debug)]
+pub struct TaskResults {
+    pub last_id: usize,
+    // task id, on_done, user_data, result of the coroutine
+    pub results: Vec<(usize, Option<PyObject>, Option<PyObject>, PyResult<PyObject>)>,
+}
+
+pub static TASK_RESULTS: Mutex<TaskResults> = Mutex::new(TaskResults {
+    last_id: 0,
+    results: vec![],
+});
+
+pub fn access_task_results() -> MutexGuard<'static, TaskResults> {
+    TASK_RESULTS.lock().unwrap()
+}
+
 #[derive(Debug)]
 pub struct WindowActions {
     pub mode: Vec<(usize, window::Mode)>,
@@ -219,6 +281,11 @@ pub struct WindowActions {
     pub resize: Vec<(usize, f32, f32)>,
     pub position: Vec<(usize, f32, f32)>,
     pub level: Vec<(usize, window::Level)>,
+    // the text input to focus
+    pub focus: Option<usize>,
+    pub open: Vec<usize>,
+    pub close: Vec<usize>,
+    pub icon: Vec<(usize, window::Icon)>,
 }
 
 pub static WINDOW_ACTIONS: Mutex<WindowActions> = Mutex::new(WindowActions {
@@ -227,12 +294,59 @@ pub static WINDOW_ACTIONS: Mutex<WindowActions> = Mutex::new(WindowActions {
     resize: vec![],
     position: vec![],
     level: vec![],
+    focus: None,
+    open: vec![],
+    close: vec![],
+    icon: vec![],
 });
 
+impl WindowActions {
+    /// The number of each kind of action queued so far.
+    pub fn queued(&self) -> [usize; 8] {
+        [self.mode.len(), self.decorations.len(), self.resize.len(), self.position.len(),
+        self.level.len(), self.open.len(), self.close.len(), self.icon.len()]
+    }
+
+    /// Drops the actions queued after the count was taken.
+    pub fn drop_queued_after(&mut self, queued: [usize; 8]) {
+        self.mode.truncate(queued[0]);
+        self.decorations.truncate(queued[1]);
+        self.resize.truncate(queued[2]);
+        self.position.truncate(queued[3]);
+        self.level.truncate(queued[4]);
+        self.open.truncate(queued[5]);
+        self.close.truncate(queued[6]);
+        self.icon.truncate(queued[7]);
+    }
+}
+
 pub fn access_window_actions() -> MutexGuard<'static, WindowActions> {
     WINDOW_ACTIONS.lock().unwrap()
 }
 
+// The sender is set by the wake up subscription once the runtime starts.
+// The pending flag keeps a burst of updates down to a single wake up message.
+pub static WAKE_UP: Mutex<Option<UnboundedSender<()>>> = Mutex::new(None);
+pub static WAKE_UP_PENDING: AtomicBool = AtomicBool::new(false);
+
+/// Wakes the iced runtime so that the queued updates are processed
+/// even when no other event is pending.  Safe to call from any thread.
+pub fn wake_up() {
+    if WAKE_UP_PENDING.swap(true, Ordering::AcqRel) {
+        return
+    }
+    let sender = WAKE_UP.lock().unwrap();
+    match sender.as_ref() {
+        Some(sender) => {
+            if sender.unbounded_send(()).is_err() {
+                WAKE_UP_PENDING.store(false, Ordering::Release);
+            }
+        },
+        // not running yet, the updates are picked up on the first message
+        None => WAKE_UP_PENDING.store(false, Ordering::Release),
+    }
+}
+
 #[derive(Debug)]
 pub struct State {
     pub ids_ipd_ids: Lazy<HashMap<usize, Vec<IpgIds>>>,  // <window_id=usize, Vec<IpgIds=structure>>
@@ -258,12 +372,10 @@ pub struct State {
     pub events: Vec<IpgEvents>,
     pub keyboard_event_id_enabled: (usize, bool),
     pub mouse_event_id_enabled: (usize, bool),
-    pub timer_event_id_enabled: (usize, bool),
-    pub canvas_timer_event_id_enabled: (usize, bool),
     pub window_event_id_enabled: (usize, bool),
     pub touch_event_id_enabled: (usize, bool),
-    pub timer_duration: u64,
-    pub canvas_timer_duration: u64,
+    pub mouse_click_interval_ms: u64,
+    pub touch_long_press_ms: u64,
 
 }
 
@@ -292,12 +404,10 @@ pub static STATE: Mutex<State> = Mutex::new(
         events: vec![],
         keyboard_event_id_enabled: (0, false),
         mouse_event_id_enabled: (0, false), 
-        timer_event_id_enabled: (0, false),
-        canvas_timer_event_id_enabled: (0, false),
         window_event_id_enabled: (0, false),
         touch_event_id_enabled: (0, false),
-        timer_duration: 0,
-        canvas_timer_duration: 0,
+        mouse_click_interval_ms: 500,
+        touch_long_press_ms: 500,
 
     }
 );
@@ -309,6 +419,8 @@ pub fn access_state() -> MutexGuard<'static, State> {
 #[derive(Debug)]
 pub struct CanvasState {
     pub canvas_ids_str: Lazy<HashMap<String, usize>>,
+    pub canvas_id: usize,
+    pub long_press_ms: u64,
     pub curves: Lazy<HashMap<usize, IpgWidget>>,
     pub text_curves: Lazy<HashMap<usize, IpgWidget>>,
     pub image_curves: Lazy<HashMap<usize, IpgWidget>>,
@@ -322,6 +434,8 @@ pub struct CanvasState {
 pub static CANVAS_STATE: Mutex<CanvasState> = Mutex::new(
     CanvasState {
         canvas_ids_str: Lazy::new(||HashMap::new()),
+        canvas_id: 0,
+        long_press_ms: 500,
         curves: Lazy::new(||HashMap::new()),
         text_curves: Lazy::new(||HashMap::new()),
         image_curves: Lazy::new(||HashMap::new()),
@@ -337,6 +451,58 @@ pub fn access_canvas_state() -> MutexGuard<'static, CanvasState> {
     CANVAS_STATE.lock().unwrap()
 }
 
+// A copy of the widgets and containers of the running gui.  The running
+// state is owned by the app, so get_item reads this copy, which is
+// refreshed each time an item is changed.
+#[derive(Debug)]
+pub struct RunningItems {
+    pub widgets: Lazy<HashMap<usize, IpgWidgets>>,
+    pub containers: Lazy<HashMap<usize, IpgContainers>>,
+}
+
+pub static RUNNING_ITEMS: Mutex<RunningItems> = Mutex::new(
+    RunningItems {
+        widgets: Lazy::new(||HashMap::new()),
+        containers: Lazy::new(||HashMap::new()),
+    }
+);
+
+pub fn access_running_items() -> MutexGuard<'static, RunningItems> {
+    RUNNING_ITEMS.lock().unwrap()
+}
+
+pub fn mirror_state(state: &IpgState) {
+    let mut items = access_running_items();
+    *items.widgets = state.widgets.iter()
+        .map(|(id, widget)| (*id, mirror_widget(widget)))
+        .collect();
+    *items.containers = state.containers.clone();
+}
+
+pub fn mirror_item(state: &IpgState, id: usize) {
+    let mut items = access_running_items();
+    if let Some(widget) = state.widgets.get(&id) {
+        items.widgets.insert(id, mirror_widget(widget));
+    } else if let Some(container) = state.containers.get(&id) {
+        items.containers.insert(id, container.clone());
+    }
+}
+
+// The samples of a stream plot aren't read back by get_item,
+// only its settings are copied.
+fn mirror_widget(widget: &IpgWidgets) -> IpgWidgets {
+    match widget {
+        IpgWidgets::IpgStreamPlot(plot) => IpgWidgets::IpgStreamPlot(plot.without_samples()),
+        _ => widget.clone(),
+    }
+}
+
+pub fn remove_mirror_item(id: usize) {
+    let mut items = access_running_items();
+    items.widgets.remove(&id);
+    items.containers.remove(&id);
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct IpgState {
     pub ids: HashMap<usize, Vec<IpgIds>>,  // <window_id=usize, Vec<IpgIds=structure>>
@@ -379,12 +545,18 @@ pub struct IpgState {
 
     pub keyboard_event_id_enabled: (usize, bool),
     pub mouse_event_id_enabled: (usize, bool),
-    pub timer_event_id_enabled: (usize, bool),
-    pub canvas_timer_event_id_enabled: (usize, bool),
     pub window_event_id_enabled: (usize, bool),
     pub touch_event_id_enabled: (usize, bool),
-    pub timer_duration: u64,
-    pub canvas_timer_duration: u64,
+    pub mouse_input: IpgMouseInput,
+    pub touch_gestures: IpgGestures,
+    pub touch_window_str_id: String,
+    // the text input with the focus, for on_focus and on_blur
+    pub focused: Option<usize>,
+    // a focus asked for before start_session, given when its window opens
+    pub focus_on_open: Option<usize>,
+    // the started timers, and the canvas timers with their duration, each ticks on its own
+    pub running_timers: HashSet<usize>,
+    pub running_canvas_timers: HashMap<usize, u64>,
 
     pub mode: Vec<(usize, window::Mode)>,
     pub decorations: Vec<usize>,
@@ -436,12 +608,15 @@ impl IpgState {
 
             keyboard_event_id_enabled: (0, false),
             mouse_event_id_enabled: (0, false), 
-            timer_event_id_enabled: (0, false),
-            canvas_timer_event_id_enabled: (0, false),
             window_event_id_enabled: (0, false),
             touch_event_id_enabled: (0, false),
-            timer_duration: 0,
-            canvas_timer_duration: 0,
+            mouse_input: IpgMouseInput::new(500),
+            touch_gestures: IpgGestures::new(500),
+            touch_window_str_id: String::new(),
+            focused: None,
+            focus_on_open: None,
+            running_timers: HashSet::new(),
+            running_canvas_timers: HashMap::new(),
 
             mode: vec![],
             decorations: vec![],
@@ -479,23 +654,32 @@ pub struct IPG {
 #[pymethods]
 impl IPG {
     #[new]
-    fn new() -> IPG {
+    #[pyo3(signature = (event_objects=false, window_state_file=None))]
+    fn new(event_objects: bool, window_state_file: Option<String>) -> IPG {
+        set_event_objects(event_objects);
+        set_window_state_file(window_state_file);
         IPG {
             group_index: 0,
             theme: Theme::Dark,
         }
     }
 
-    #[pyo3(signature = ())]
-    fn start_session(&self) {
-
-        let _ = iced::daemon(App::title, App::update, App::view)
-                    .subscription(App::subscription)
-                    .theme(App::theme)
-                    .font(iced_fonts::REQUIRED_FONT_BYTES)
-                    .scale_factor(App::scale_factor)
-                    .antialiasing(true)
-                    .run_with(||App::new());
+    #[pyo3(signature = (on_error=None))]
+    fn start_session(&self, py: Python, on_error: Option<PyObject>) {
+        set_error_handler(on_error);
+
+        // The gil is released while the runtime is running so that
+        // other python threads can queue updates.
+        py.allow_threads(|| {
+            let _ = iced::daemon(App::title, App::update, App::view)
+                        .subscription(App::subscription)
+                        .theme(App::theme)
+                        .font(iced_fonts::REQUIRED_FONT_BYTES)
+                        .scale_factor(App::scale_factor)
+                        .antialiasing(true)
+                        .run_with(||App::new());
+        });
+        stop_session_loop(py);
     }
 
     #[pyo3(signature = ())]
@@ -531,6 +715,7 @@ impl IPG {
         on_resize=None, 
         mode=IpgWindowMode::Windowed, 
         debug=false, 
+        icon=None,
         user_data=None,
         gen_id=None
         ))]
@@ -555,17 +740,18 @@ impl IPG {
         theme: IpgWindowTheme,
         exit_on_close: bool,
         on_resize: Option<PyObject>,
-        mode: IpgWindowMode,
+        mut mode: IpgWindowMode,
         debug: bool,
+        icon: Option<PyObject>,
         user_data: Option<PyObject>,
         gen_id: Option<usize>,
     ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let mut window_position = Position::Default;
 
-        let size = Size::new(width, height);
+        let mut size = Size::new(width, height);
 
         let mut max_size = Size::INFINITY;
 
@@ -596,17 +782,27 @@ impl IPG {
             window_position = Position::Centered;
         }
 
+        restore_window(&window_id, &mut size, &mut window_position, &mut mode)
+            .map_err(IpgParamError::new_err)?;
+        // the saved size may be outside the min and max sizes given now
+        let size = clamp_size(size, Some(min_size), Some(max_size));
+
+        let icon = match icon {
+            Some(icon) => Some(try_extract_icon(&icon).map_err(IpgParamError::new_err)?),
+            None => None,
+        };
+
         self.theme = get_iced_window_theme(theme.clone());
         let iced_theme = get_iced_window_theme(theme);
 
         let mut state = access_state();
 
         if state.windows_str_ids.get(&window_id).is_some() {
-            panic!("Window id {} is not unique", window_id)
+            return Err(IpgIdError::new_err(format!("Window id {} is not unique", window_id)))
         };
 
         if let Some(py) = on_resize {
-            add_callback_to_mutex(id, "on_resize".to_string(), py);
+            add_callback_to_mutex(id, "on_resize".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -639,6 +835,7 @@ impl IPG {
                 level.clone(),
                 scale_factor,
                 debug,
+                icon.clone(),
                 )));
         
         state.windows.push(
@@ -658,9 +855,12 @@ impl IPG {
                 level,
                 scale_factor,
                 debug,
+                icon,
                 ));
  
         drop(state);
+        // opened when added during the session
+        wake_up();
         Ok(id)
 
     }
@@ -678,6 +878,14 @@ impl IPG {
         background_ipg_color=None,
         background_rgba_color=None,
         parent_id=None,
+        on_pinch=None,
+        on_rotate=None,
+        on_pan=None,
+        on_swipe=None,
+        on_long_press=None,
+        long_press_ms=500,
+        user_data=None,
+        show=true,
         gen_id=None,
         ))]
     fn add_canvas(
@@ -694,10 +902,38 @@ impl IPG {
         background_ipg_color: Option<IpgColor>,
         background_rgba_color: Option<[f32; 4]>,
         parent_id: Option<String>,
+        on_pinch: Option<PyObject>,
+        on_rotate: Option<PyObject>,
+        on_pan: Option<PyObject>,
+        on_swipe: Option<PyObject>,
+        on_long_press: Option<PyObject>,
+        long_press_ms: u64,
+        user_data: Option<PyObject>,
+        show: bool,
         gen_id: Option<usize>,
         )  -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
+
+        // the touch gestures made on the canvas
+        if let Some(py) = on_pinch {
+            add_callback_to_mutex(id, "on_pinch".to_string(), py)?;
+        }
+        if let Some(py) = on_rotate {
+            add_callback_to_mutex(id, "on_rotate".to_string(), py)?;
+        }
+        if let Some(py) = on_pan {
+            add_callback_to_mutex(id, "on_pan".to_string(), py)?;
+        }
+        if let Some(py) = on_swipe {
+            add_callback_to_mutex(id, "on_swipe".to_string(), py)?;
+        }
+        if let Some(py) = on_long_press {
+            add_callback_to_mutex(id, "on_long_press".to_string(), py)?;
+        }
+        if let Some(py) = user_data {
+            add_user_data_to_mutex(id, py);
+        }
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
@@ -710,14 +946,15 @@ impl IPG {
             None => window_id.clone(),
         };
 
-        set_state_of_container(id, window_id.clone(), Some(canvas_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(canvas_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, canvas_id.clone(), id, "add_canvas".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, canvas_id.clone(), id, "add_canvas".to_string())?;
 
         state.containers.insert(id, IpgContainers::IpgCanvas(IpgCanvas::new(
                                                 id,
+                                                show,
                                             )));
  
         drop(state);
@@ -725,12 +962,15 @@ impl IPG {
         // set up the CanvasState
         let mut canvas_state = access_canvas_state();
         canvas_state.canvas_ids_str.insert(canvas_id, id);
+        canvas_state.canvas_id = id;
+        canvas_state.long_press_ms = long_press_ms;
         canvas_state.width = width;
         canvas_state.height = height;
         canvas_state.background = background;
         canvas_state.border_width = border_width;
         canvas_state.border_color = border_color;
         drop(canvas_state);
+        wake_up();
 
         Ok(id)
     }
@@ -774,11 +1014,11 @@ impl IPG {
         style_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(None);
+        let id = self.get_id(None)?;
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
         
         let prt_id = match parent_id {
             Some(id) => id,
@@ -790,11 +1030,11 @@ impl IPG {
             align_y = IpgVerticalAlignment::Center;
         };
 
-        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_container".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_container".to_string())?;
 
         state.containers.insert(id, IpgContainers::IpgContainer(
             IpgContainer::new(
@@ -812,6 +1052,7 @@ impl IPG {
             )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -848,7 +1089,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -910,23 +1151,23 @@ impl IPG {
         show: bool,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(None);
+        let id = self.get_id(None)?;
         
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
         let prt_id = match parent_id {
             Some(id) => id,
             None => window_id.clone(),
         };
 
-        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_column".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_column".to_string())?;
 
         state.containers
             .insert(id, IpgContainers::IpgColumn(
@@ -943,6 +1184,7 @@ impl IPG {
                 )));
 
     drop(state);
+    wake_up();
     Ok(id)
 
     }
@@ -991,10 +1233,10 @@ impl IPG {
         gen_id: Option<usize>,
     ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_select {
-            add_callback_to_mutex(id, "on_select".to_string(), py);
+            add_callback_to_mutex(id, "on_select".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -1003,7 +1245,7 @@ impl IPG {
 
         let spacing = bar_spacing.unwrap_or(0.0);
 
-        let padding = get_padding_f32(bar_padding);
+        let padding = get_padding_f32(bar_padding)?;
         
         let height = get_height(bar_height, false);
 
@@ -1026,11 +1268,11 @@ impl IPG {
             None => window_id.clone(),
         };
         
-        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_menu".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_menu".to_string())?;
 
         state.containers.insert(id, IpgContainers::IpgMenu(
             IpgMenu::new(
@@ -1052,6 +1294,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
     }
 
@@ -1082,7 +1325,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let base: Option<Color> = 
             get_color(base_rgba, base_color, 1.0, false);
@@ -1148,7 +1391,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
         
         let base_color: Option<Color> = 
             get_color(base_rgba, base_color, 1.0, false);
@@ -1222,7 +1465,7 @@ impl IPG {
         user_data: Option<PyObject>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let prt_id = match parent_id {
             Some(id) => id,
@@ -1230,50 +1473,50 @@ impl IPG {
         };
 
         if let Some(py) = on_press {
-        add_callback_to_mutex(id, "on_press".to_string(), py);
+        add_callback_to_mutex(id, "on_press".to_string(), py)?;
         }
         
         if let Some(py) = on_release {
-            add_callback_to_mutex(id, "event_name".to_string(), py);
+            add_callback_to_mutex(id, "event_name".to_string(), py)?;
         }
         
         if let Some(py) = on_right_press {
-            add_callback_to_mutex(id, "on_right_press".to_string(), py);
+            add_callback_to_mutex(id, "on_right_press".to_string(), py)?;
         }
         
         if let Some(py) = on_right_release {
-            add_callback_to_mutex(id, "on_right_release".to_string(), py);
+            add_callback_to_mutex(id, "on_right_release".to_string(), py)?;
         }
         
         if let Some(py) = on_middle_press {
-            add_callback_to_mutex(id, "on_middle_press".to_string(), py);
+            add_callback_to_mutex(id, "on_middle_press".to_string(), py)?;
         }
         
         if let Some(py) = on_middle_release {
-            add_callback_to_mutex(id, "on_middle_release".to_string(), py);
+            add_callback_to_mutex(id, "on_middle_release".to_string(), py)?;
         }
         
         if let Some(py) = on_enter {
-            add_callback_to_mutex(id, "on_enter".to_string(), py);
+            add_callback_to_mutex(id, "on_enter".to_string(), py)?;
         }
         
         if let Some(py) = on_move {
-            add_callback_to_mutex(id, "on_move".to_string(), py);
+            add_callback_to_mutex(id, "on_move".to_string(), py)?;
         }
         
         if let Some(py) = on_exit {
-            add_callback_to_mutex(id, "on_exit".to_string(), py);
+            add_callback_to_mutex(id, "on_exit".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
             add_user_data_to_mutex(id, py);
         }
 
-        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_mousearea".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_mousearea".to_string())?;
 
         state.containers.insert(id, IpgContainers::IpgMouseArea(
             IpgMouseArea::new(
@@ -1283,6 +1526,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -1324,7 +1568,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
@@ -1335,7 +1579,7 @@ impl IPG {
         };
 
         let include_mouse_area = if let Some(py) = mouse_on_press {
-            add_callback_to_mutex(id, "on_press".to_string(), py);
+            add_callback_to_mutex(id, "on_press".to_string(), py)?;
             true
         } else {
             false
@@ -1350,11 +1594,11 @@ impl IPG {
             None => window_id.clone(),
         };
 
-        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_opaque".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_opaque".to_string())?;
 
         state.containers.insert(id, IpgContainers::IpgOpaque(
             IpgOpaque::new(
@@ -1369,6 +1613,7 @@ impl IPG {
                 )));
 
         drop(state);         
+        wake_up();
         Ok(id)
     }
 
@@ -1384,7 +1629,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let mut state = access_state();
 
@@ -1431,23 +1676,23 @@ impl IPG {
         show: bool,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(None);
+        let id = self.get_id(None)?;
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
         let prt_id = match parent_id {
             Some(id) => id,
             None => window_id.clone(),
         };
 
-        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_row".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_row".to_string())?;
 
         state.containers.
             insert(id, IpgContainers::IpgRow(IpgRow::new(
@@ -1462,6 +1707,7 @@ impl IPG {
             )));
 
         drop(state);         
+        wake_up();
         Ok(id)
 
     }
@@ -1491,7 +1737,7 @@ impl IPG {
         show: bool,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(None);
+        let id = self.get_id(None)?;
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
@@ -1501,11 +1747,11 @@ impl IPG {
             None => window_id.clone(),
         };
 
-        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_stack".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_stack".to_string())?;
 
         state.containers.insert(id, IpgContainers::IpgStack(
             IpgStack::new(
@@ -1517,6 +1763,7 @@ impl IPG {
                 )));
 
         drop(state);         
+        wake_up();
         Ok(id)
 
     }
@@ -1543,6 +1790,7 @@ impl IPG {
         on_scroll=None, 
         user_data=None,
         style_id=None,
+        show=true,
         ))]
     fn add_scrollable(
         &self,
@@ -1568,12 +1816,13 @@ impl IPG {
         on_scroll: Option<PyObject>,
         user_data: Option<PyObject>,
         style_id: Option<usize>,
+        show: bool,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(None);
+        let id = self.get_id(None)?;
 
         if let Some(py) = on_scroll {
-            add_callback_to_mutex(id, "on_scroll".to_string(), py);
+            add_callback_to_mutex(id, "on_scroll".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -1592,11 +1841,11 @@ impl IPG {
             None => window_id.clone(),
         };
 
-        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_scrollable".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_scrollable".to_string())?;
       
         state.containers.insert(id, IpgContainers::IpgScrollable(
             IpgScrollable::new( 
@@ -1615,9 +1864,11 @@ impl IPG {
                 v_spacing,
                 v_bar_alignment,
                 style_id,
+                show,
                 )));
  
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -1674,7 +1925,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -1820,7 +2071,7 @@ impl IPG {
     ) -> PyResult<usize> 
     {
 
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let prt_id = match parent_id {
             Some(id) => id,
@@ -1842,21 +2093,21 @@ impl IPG {
         }
 
         if let Some(py) = on_column_resize {
-            add_callback_to_mutex(id, "dragging".to_string(), py);
+            add_callback_to_mutex(id, "dragging".to_string(), py)?;
         }
 
         let released = if let Some(py) = on_column_resize_release {
-            add_callback_to_mutex(id, "released".to_string(), py);
+            add_callback_to_mutex(id, "released".to_string(), py)?;
             true
         } else {
             false
         };
 
-        set_state_of_container(id, window_id.clone(), Some(table_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(table_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, table_id, id, "add_table".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, table_id, id, "add_table".to_string())?;
 
         state.containers.insert(id, IpgContainers::IpgTable(
             IpgTable::new( 
@@ -1902,6 +2153,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -1996,7 +2248,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let header_background =  
             get_color(header_background_rgba, header_background_color, 1.0, false);
@@ -2076,6 +2328,7 @@ impl IPG {
         padding=0.0, 
         snap_within_viewport=true, 
         style_id=None,
+        show=true,
         gen_id=None,
         ))]
     fn add_tool_tip(
@@ -2089,22 +2342,23 @@ impl IPG {
         padding: f32,
         snap_within_viewport: bool,
         style_id: Option<usize>,
+        show: bool,
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
 
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let prt_id = match parent_id {
             Some(id) => id,
             None => window_id.clone(),
         };
 
-        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id);
+        set_state_of_container(id, window_id.clone(), Some(container_id.clone()), prt_id)?;
 
         let mut state = access_state();
 
-        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_tool_tip".to_string());
+        set_state_cont_wnd_ids(&mut state, &window_id, container_id, id, "add_tool_tip".to_string())?;
 
         state.containers.insert(id, IpgContainers::IpgToolTip(
             IpgToolTip::new( 
@@ -2115,8 +2369,10 @@ impl IPG {
                 padding,
                 snap_within_viewport,
                 style_id,
+                show,
                 )));
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -2153,7 +2409,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -2200,8 +2456,10 @@ impl IPG {
         style_id=None, 
         style_standard=None, 
         style_arrow=None, 
+        accelerator=None,
         user_data=None, 
         show=true, 
+        enabled=true, 
         ))]
     fn add_button(
         &self,
@@ -2222,24 +2480,33 @@ impl IPG {
         style_id: Option<usize>,
         style_standard: Option<IpgStyleStandard>,
         style_arrow: Option<IpgButtonArrow>,
+        accelerator: Option<String>,
         user_data: Option<PyObject>,
         show: bool,
+        enabled: bool,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
         let align_x = get_horizontal_alignment(&text_align_x);
         let align_y = get_vertical_alignment(&text_align_y);
 
-        set_state_of_widget(id, parent_id.clone());
+        // only the text is kept, the shortcut itself is added with add_shortcut
+        let accelerator = accelerator
+            .map(|accelerator| parse_accelerator(&accelerator)
+                .map(|strokes| accelerator_text(&strokes)))
+            .transpose()
+            .map_err(IpgParamError::new_err)?;
+
+        set_state_of_widget(id, parent_id.clone())?;
 
         if let Some(py) = on_press {
-            add_callback_to_mutex(id, "on_press".to_string(), py);
+            add_callback_to_mutex(id, "on_press".to_string(), py)?;
         }
 
         if let Some(py) = user_data{
@@ -2253,6 +2520,7 @@ impl IPG {
                 id,
                 parent_id,
                 show,
+                enabled,
                 label,
                 width,
                 height,
@@ -2263,10 +2531,12 @@ impl IPG {
                 clip,
                 style_id,
                 style_standard,
-                style_arrow,                              
+                style_arrow,
+                accelerator,
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
     
     }
@@ -2309,7 +2579,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -2392,10 +2662,10 @@ impl IPG {
         user_data: Option<PyObject>, 
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_close {
-            add_callback_to_mutex(id, "on_close".to_string(), py);
+            add_callback_to_mutex(id, "on_close".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -2405,11 +2675,11 @@ impl IPG {
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        let padding_head = get_padding_f64(padding_head);
-        let padding_body = get_padding_f64(padding_body);
-        let padding_foot = get_padding_f64(padding_foot);
+        let padding_head = get_padding_f64(padding_head)?;
+        let padding_body = get_padding_f64(padding_body)?;
+        let padding_foot = get_padding_f64(padding_foot)?;
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -2435,6 +2705,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -2487,7 +2758,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -2531,6 +2802,92 @@ impl IPG {
         Ok(id)
     }
 
+    #[pyo3(signature = (
+        parent_id,
+        polars_df,
+        x_column,
+        y_columns,
+        kind=IpgChartKind::Line,
+        series_kinds=None,
+        series_rgba_colors=None,
+        title=None,
+        x_label=None,
+        y_label=None,
+        show_legend=true,
+        text_size=12.0,
+        width=None,
+        width_fill=true,
+        height=Some(300.0),
+        height_fill=false,
+        gen_id=None,
+        show=true,
+        ))]
+    fn add_chart(
+        &self,
+        parent_id: String,
+        polars_df: PyDataFrame,
+        x_column: String,
+        y_columns: Vec<String>,
+        // ** above required
+        kind: IpgChartKind,
+        series_kinds: Option<Vec<IpgChartKind>>,
+        series_rgba_colors: Option<Vec<[f32; 4]>>,
+        title: Option<String>,
+        x_label: Option<String>,
+        y_label: Option<String>,
+        show_legend: bool,
+        text_size: f32,
+        width: Option<f32>,
+        width_fill: bool,
+        height: Option<f32>,
+        height_fill: bool,
+        gen_id: Option<usize>,
+        show: bool,
+        ) -> PyResult<usize>
+    {
+        let id = self.get_id(gen_id)?;
+
+        let df: DataFrame = polars_df.into();
+
+        let series_colors: Option<Vec<Color>> = series_rgba_colors.map(|colors| 
+            colors.iter()
+                .map(|rgba| get_color(Some(*rgba), None, 1.0, false).unwrap())
+                .collect());
+
+        let width = get_width(width, width_fill);
+        let height = get_height(height, height_fill);
+
+        // the columns are checked before the chart is added
+        let chart = IpgChart::new(
+                id,
+                parent_id.clone(),
+                show,
+                df,
+                x_column,
+                y_columns,
+                kind,
+                series_kinds,
+                series_colors,
+                title,
+                x_label,
+                y_label,
+                show_legend,
+                text_size,
+                width,
+                height,
+                ).map_err(IpgParamError::new_err)?;
+
+        set_state_of_widget(id, parent_id)?;
+
+        let mut state = access_state();
+
+        state.widgets.insert(id, IpgWidgets::IpgChart(chart));
+
+        drop(state);
+        wake_up();
+        Ok(id)
+    }
+
     #[pyo3(signature = (
         parent_id, 
         gen_id=None, 
@@ -2548,6 +2905,7 @@ impl IPG {
         icon_size=25.0, 
         user_data=None, 
         show=true, 
+        enabled=true, 
         style_id=None, 
         style_standard=None, 
         ))] 
@@ -2570,27 +2928,28 @@ impl IPG {
         icon_size: f32,
         user_data: Option<PyObject>,
         show: bool,
+        enabled: bool,
         style_id: Option<usize>,
         style_standard: Option<IpgStyleStandard>,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
         
         if let Some(py) = on_toggle {
-            add_callback_to_mutex(id, "on_toggle".to_string(), py);
+            add_callback_to_mutex(id, "on_toggle".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
             add_user_data_to_mutex(id, py);
         }
        
-        let text_shaping = get_shaping(text_shaping);
+        let text_shaping = get_shaping(text_shaping)?;
 
         let text_line_height = text::LineHeight::Relative(text_line_height);
 
         let width = get_width(width, width_fill);
         
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -2599,6 +2958,7 @@ impl IPG {
                 id,
                 parent_id,
                 show,
+                enabled,
                 is_checked,
                 label,
                 width,
@@ -2614,6 +2974,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -2635,6 +2996,12 @@ impl IPG {
         icon_rgba=None,
         text_color=None, 
         text_rgba=None,
+        background_color_disabled=None,
+        background_rgba_disabled=None,
+        icon_color_disabled=None,
+        icon_rgba_disabled=None,
+        text_color_disabled=None,
+        text_rgba_disabled=None,
         gen_id=None
         ))]
     fn add_checkbox_style(
@@ -2655,10 +3022,16 @@ impl IPG {
         icon_rgba: Option<[f32; 4]>,
         text_color: Option<IpgColor>,
         text_rgba: Option<[f32; 4]>,
+        background_color_disabled: Option<IpgColor>,
+        background_rgba_disabled: Option<[f32; 4]>,
+        icon_color_disabled: Option<IpgColor>,
+        icon_rgba_disabled: Option<[f32; 4]>,
+        text_color_disabled: Option<IpgColor>,
+        text_rgba_disabled: Option<[f32; 4]>,
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -2674,6 +3047,12 @@ impl IPG {
             get_color(icon_rgba, icon_color, 1.0, false);
         let text_color: Option<Color> = 
             get_color(text_rgba, text_color, 1.0, false);
+        let background_color_disabled: Option<Color> = 
+            get_color(background_rgba_disabled, background_color_disabled, 1.0, false);
+        let icon_color_disabled: Option<Color> = 
+            get_color(icon_rgba_disabled, icon_color_disabled, 1.0, false);
+        let text_color_disabled: Option<Color> = 
+            get_color(text_rgba_disabled, text_color_disabled, 1.0, false);
 
         let mut state = access_state();
 
@@ -2689,6 +3068,9 @@ impl IPG {
                 border_width,
                 icon_color,
                 text_color,
+                background_color_disabled,
+                icon_color_disabled,
+                text_color_disabled,
                 )));
 
         drop(state);
@@ -2714,6 +3096,7 @@ impl IPG {
         style_arrow=None,
         user_data=None,
         show=false, 
+        enabled=true, 
         ))]
     fn add_color_picker(
         &self,
@@ -2736,20 +3119,21 @@ impl IPG {
         style_arrow: Option<IpgButtonArrow>,
         user_data: Option<PyObject>,
         show: bool,
+        enabled: bool,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_press {
-            add_callback_to_mutex(id, "on_press".to_string(), py);
+            add_callback_to_mutex(id, "on_press".to_string(), py)?;
         }
 
         if let Some(py) = on_submit {
-            add_callback_to_mutex(id, "on_submit".to_string(), py);
+            add_callback_to_mutex(id, "on_submit".to_string(), py)?;
         }
 
         if let Some(py) = on_cancel {
-            add_callback_to_mutex(id, "on_cancel".to_string(), py);
+            add_callback_to_mutex(id, "on_cancel".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -2761,9 +3145,9 @@ impl IPG {
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -2772,6 +3156,7 @@ impl IPG {
                 id,
                 parent_id,
                 show,
+                enabled,
                 color,
                 // button related
                 label,
@@ -2785,6 +3170,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -2827,7 +3213,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -2871,6 +3257,7 @@ impl IPG {
         on_submit=None, 
         user_data=None,
         show=true,
+        enabled=true,
         show_calendar=false, 
         button_style_standard=None,
         button_style_id=None,
@@ -2886,28 +3273,29 @@ impl IPG {
         on_submit: Option<PyObject>,
         user_data: Option<PyObject>,
         show: bool,
+        enabled: bool,
         show_calendar: bool,
         button_style_standard: Option<IpgStyleStandard>,
         button_style_id: Option<usize>,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if size_factor < 1.0 {
-            panic!("Size factor for date picker must be > 1.0")
+            return Err(IpgParamError::new_err("Size factor for date picker must be > 1.0"))
         }
 
         if let Some(py) = on_submit {
-            add_callback_to_mutex(id, "on_submit".to_string(), py);
+            add_callback_to_mutex(id, "on_submit".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
             add_user_data_to_mutex(id, py);
         }
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -2919,12 +3307,14 @@ impl IPG {
                 size_factor,
                 padding,
                 show,
+                enabled,
                 show_calendar,
                 button_style_standard,
                 button_style_id,
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -2967,14 +3357,14 @@ impl IPG {
         show: bool,
     ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_change {
-            add_callback_to_mutex(id, "on_change".to_string(), py);
+            add_callback_to_mutex(id, "on_change".to_string(), py)?;
         }
 
         if let Some(py) = on_release {
-            add_callback_to_mutex(id, "on_release".to_string(), py);
+            add_callback_to_mutex(id, "on_release".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -2985,7 +3375,7 @@ impl IPG {
 
         let height = get_height(height, height_fill);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -3005,6 +3395,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
     }
 
@@ -3046,14 +3437,14 @@ impl IPG {
         show: bool,
     ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_change {
-            add_callback_to_mutex(id, "on_change".to_string(), py);
+            add_callback_to_mutex(id, "on_change".to_string(), py)?;
         }
 
         if let Some(py) = on_release {
-            add_callback_to_mutex(id, "on_release".to_string(), py);
+            add_callback_to_mutex(id, "on_release".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -3064,7 +3455,7 @@ impl IPG {
 
         let height = get_height(height, height_fill);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -3084,6 +3475,202 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
+        Ok(id)
+    }
+
+    #[pyo3(signature = (
+        parent_id,
+        min=0.0,
+        max=100.0,
+        value=0.0,
+        bands=None,
+        tick_count=11,
+        minor_ticks=4,
+        label=None,
+        units="".to_string(),
+        precision=0,
+        needle_rgba_color=None,
+        text_size=14.0,
+        size=200.0,
+        animation_ms=300,
+        gen_id=None,
+        show=true,
+        ))]
+    fn add_gauge(
+        &self,
+        parent_id: String,
+        // ** above required
+        min: f32,
+        max: f32,
+        value: f32,
+        bands: Option<Vec<(f32, f32, [f32; 4])>>,
+        tick_count: usize,
+        minor_ticks: usize,
+        label: Option<String>,
+        units: String,
+        precision: usize,
+        needle_rgba_color: Option<[f32; 4]>,
+        text_size: f32,
+        size: f32,
+        animation_ms: u64,
+        gen_id: Option<usize>,
+        show: bool,
+        ) -> PyResult<usize>
+    {
+        let id = self.get_id(gen_id)?;
+
+        let bands = get_gauge_bands(bands);
+        let needle_color = get_color(needle_rgba_color, None, 1.0, false);
+
+        set_state_of_widget(id, parent_id.clone())?;
+
+        let mut state = access_state();
+
+        state.widgets.insert(id, IpgWidgets::IpgGauge(
+            IpgGauge::new(
+                id,
+                parent_id,
+                show,
+                min,
+                max,
+                value,
+                bands,
+                tick_count,
+                minor_ticks,
+                label,
+                units,
+                precision,
+                needle_color,
+                text_size,
+                size,
+                animation_ms,
+                )));
+
+        drop(state);
+        wake_up();
+        Ok(id)
+    }
+
+    #[pyo3(signature = (
+        parent_id,
+        polars_df=None,
+        values=None,
+        row_labels=None,
+        column_labels=None,
+        row_label_column=None,
+        colormap=IpgColorMap::Viridis,
+        vmin=None,
+        vmax=None,
+        center=None,
+        show_values=false,
+        precision=2,
+        show_colorbar=true,
+        text_size=12.0,
+        on_hover=None,
+        user_data=None,
+        width=None,
+        width_fill=true,
+        height=Some(300.0),
+        height_fill=false,
+        gen_id=None,
+        show=true,
+        ))]
+    fn add_heatmap(
+        &self,
+        parent_id: String,
+        // ** above required
+        polars_df: Option<PyDataFrame>,
+        values: Option<Vec<Vec<Option<f64>>>>,
+        row_labels: Option<Vec<String>>,
+        column_labels: Option<Vec<String>>,
+        row_label_column: Option<String>,
+        colormap: IpgColorMap,
+        vmin: Option<f64>,
+        vmax: Option<f64>,
+        center: Option<f64>,
+        show_values: bool,
+        precision: usize,
+        show_colorbar: bool,
+        text_size: f32,
+        on_hover: Option<PyObject>,
+        user_data: Option<PyObject>,
+        width: Option<f32>,
+        width_fill: bool,
+        height: Option<f32>,
+        height_fill: bool,
+        gen_id: Option<usize>,
+        show: bool,
+        ) -> PyResult<usize>
+    {
+        let id = self.get_id(gen_id)?;
+
+        let values: Vec<Vec<f64>> = match values {
+            Some(rows) => rows.into_iter()
+                .map(|row| row.into_iter().map(|v| v.unwrap_or(f64::NAN)).collect())
+                .collect(),
+            None => vec![],
+        };
+
+        let width = get_width(width, width_fill);
+        let height = get_height(height, height_fill);
+
+        let mut heatmap = IpgHeatmap::new(
+                id,
+                parent_id.clone(),
+                show,
+                values,
+                row_labels.clone(),
+                column_labels.clone(),
+                row_label_column,
+                colormap,
+                vmin,
+                vmax,
+                center,
+                show_values,
+                precision,
+                show_colorbar,
+                text_size,
+                width,
+                height,
+                );
+
+        match polars_df {
+            Some(df) => {
+                let df: DataFrame = df.into();
+                heatmap.set_dataframe(&df).map_err(IpgParamError::new_err)?;
+                // labels given by the user take priority over the dataframe
+                if row_labels.is_some() {
+                    heatmap.row_labels = row_labels;
+                }
+                if column_labels.is_some() {
+                    heatmap.column_labels = column_labels;
+                }
+            },
+            None => {
+                if heatmap.values.is_empty() {
+                    return Err(IpgParamError::new_err("add_heatmap: either polars_df or values is required"))
+                }
+            },
+        }
+
+        // the callbacks are only kept once the heatmap is valid
+        if let Some(py) = on_hover {
+            add_callback_to_mutex(id, "on_hover".to_string(), py)?;
+        }
+
+        if let Some(py) = user_data {
+            add_user_data_to_mutex(id, py);
+        }
+
+        set_state_of_widget(id, parent_id)?;
+
+        let mut state = access_state();
+
+        state.widgets.insert(id, IpgWidgets::IpgHeatmap(heatmap));
+
+        drop(state);
+        wake_up();
         Ok(id)
     }
 
@@ -3144,42 +3731,42 @@ impl IPG {
         show: bool,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_press {
-            add_callback_to_mutex(id, "on_press".to_string(), py);
+            add_callback_to_mutex(id, "on_press".to_string(), py)?;
         }
         
         if let Some(py) = on_release {
-            add_callback_to_mutex(id, "event_name".to_string(), py);
+            add_callback_to_mutex(id, "event_name".to_string(), py)?;
         }
         
         if let Some(py) = on_right_press {
-            add_callback_to_mutex(id, "on_right_press".to_string(), py);
+            add_callback_to_mutex(id, "on_right_press".to_string(), py)?;
         }
         
         if let Some(py) = on_right_release {
-            add_callback_to_mutex(id, "on_right_release".to_string(), py);
+            add_callback_to_mutex(id, "on_right_release".to_string(), py)?;
         }
         
         if let Some(py) = on_middle_press {
-            add_callback_to_mutex(id, "on_middle_press".to_string(), py);
+            add_callback_to_mutex(id, "on_middle_press".to_string(), py)?;
         }
         
         if let Some(py) = on_middle_release {
-            add_callback_to_mutex(id, "on_middle_release".to_string(), py);
+            add_callback_to_mutex(id, "on_middle_release".to_string(), py)?;
         }
         
         if let Some(py) = on_enter {
-            add_callback_to_mutex(id, "on_enter".to_string(), py);
+            add_callback_to_mutex(id, "on_enter".to_string(), py)?;
         }
         
         if let Some(py) = on_move {
-            add_callback_to_mutex(id, "on_move".to_string(), py);
+            add_callback_to_mutex(id, "on_move".to_string(), py)?;
         }
         
         if let Some(py) = on_exit {
-            add_callback_to_mutex(id, "on_exit".to_string(), py);
+            add_callback_to_mutex(id, "on_exit".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -3189,9 +3776,9 @@ impl IPG {
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -3213,37 +3800,212 @@ impl IPG {
             )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
     
     #[pyo3(signature = (
-        background_color=None,
-        background_rgba=None,
-        background_color_hovered=None,
-        background_rgba_hovered=None,
-        background_transparent=false,
-        border_color=None,
-        border_rgba=None,
-        border_width=0.0,
-        border_radius=0.0,
-        gen_id=None
+        parent_id,
+        min=0.0,
+        max=100.0,
+        step=1.0,
+        value=0.0,
+        on_change=None,
+        on_release=None,
+        bands=None,
+        tick_count=11,
+        label=None,
+        units="".to_string(),
+        precision=0,
+        knob_rgba_color=None,
+        text_size=14.0,
+        size=100.0,
+        user_data=None,
+        gen_id=None,
+        show=true,
         ))]
-    fn add_divider_style(
+    fn add_knob(
         &self,
-        background_color: Option<IpgColor>,
-        background_rgba: Option<[f32; 4]>,
-        background_color_hovered: Option<IpgColor>,
-        background_rgba_hovered: Option<[f32; 4]>,
-        background_transparent: bool,
-        border_color: Option<IpgColor>,
-        border_rgba: Option<[f32; 4]>,
-        border_width: f32,
-        border_radius: f32,
-        gen_id: Option<usize>,
+        parent_id: String,
+        // ** above required
+        min: f32,
+        max: f32,
+        step: f32,
+        value: f32,
+        on_change: Option<PyObject>,
+        on_release: Option<PyObject>,
+        bands: Option<Vec<(f32, f32, [f32; 4])>>,
+        tick_count: usize,
+        label: Option<String>,
+        units: String,
+        precision: usize,
+        knob_rgba_color: Option<[f32; 4]>,
+        text_size: f32,
+        size: f32,
+        user_data: Option<PyObject>,
+        gen_id: Option<usize>,
+        show: bool,
+        ) -> PyResult<usize>
+    {
+        let id = self.get_id(gen_id)?;
+
+        if let Some(py) = on_change {
+            add_callback_to_mutex(id, "on_change".to_string(), py)?;
+        }
+
+        if let Some(py) = on_release {
+            add_callback_to_mutex(id, "on_release".to_string(), py)?;
+        }
+
+        if let Some(py) = user_data {
+            add_user_data_to_mutex(id, py);
+        }
+
+        let bands = get_gauge_bands(bands);
+        let knob_color = get_color(knob_rgba_color, None, 1.0, false);
+
+        set_state_of_widget(id, parent_id.clone())?;
+
+        let mut state = access_state();
+
+        state.widgets.insert(id, IpgWidgets::IpgKnob(
+            IpgKnob::new(
+                id,
+                parent_id,
+                show,
+                min,
+                max,
+                step,
+                value,
+                bands,
+                tick_count,
+                label,
+                units,
+                precision,
+                knob_color,
+                text_size,
+                size,
+                )));
+
+        drop(state);
+        wake_up();
+        Ok(id)
+    }
+
+    #[pyo3(signature = (
+        parent_id,
+        min=0.0,
+        max=100.0,
+        value=0.0,
+        bands=None,
+        tick_count=6,
+        minor_ticks=1,
+        label=None,
+        units="".to_string(),
+        precision=0,
+        bar_rgba_color=None,
+        text_size=14.0,
+        vertical=false,
+        width=None,
+        width_fill=true,
+        height=Some(60.0),
+        height_fill=false,
+        animation_ms=300,
+        gen_id=None,
+        show=true,
+        ))]
+    fn add_meter(
+        &self,
+        parent_id: String,
+        // ** above required
+        min: f32,
+        max: f32,
+        value: f32,
+        bands: Option<Vec<(f32, f32, [f32; 4])>>,
+        tick_count: usize,
+        minor_ticks: usize,
+        label: Option<String>,
+        units: String,
+        precision: usize,
+        bar_rgba_color: Option<[f32; 4]>,
+        text_size: f32,
+        vertical: bool,
+        width: Option<f32>,
+        width_fill: bool,
+        height: Option<f32>,
+        height_fill: bool,
+        animation_ms: u64,
+        gen_id: Option<usize>,
+        show: bool,
+        ) -> PyResult<usize>
+    {
+        let id = self.get_id(gen_id)?;
+
+        let bands = get_gauge_bands(bands);
+        let bar_color = get_color(bar_rgba_color, None, 1.0, false);
+
+        let width = get_width(width, width_fill);
+        let height = get_height(height, height_fill);
+
+        set_state_of_widget(id, parent_id.clone())?;
+
+        let mut state = access_state();
+
+        state.widgets.insert(id, IpgWidgets::IpgMeter(
+            IpgMeter::new(
+                id,
+                parent_id,
+                show,
+                min,
+                max,
+                value,
+                bands,
+                tick_count,
+                minor_ticks,
+                label,
+                units,
+                precision,
+                bar_color,
+                text_size,
+                vertical,
+                width,
+                height,
+                animation_ms,
+                )));
+
+        drop(state);
+        wake_up();
+        Ok(id)
+    }
+
+    #[pyo3(signature = (
+        background_color=None,
+        background_rgba=None,
+        background_color_hovered=None,
+        background_rgba_hovered=None,
+        background_transparent=false,
+        border_color=None,
+        border_rgba=None,
+        border_width=0.0,
+        border_radius=0.0,
+        gen_id=None
+        ))]
+    fn add_divider_style(
+        &self,
+        background_color: Option<IpgColor>,
+        background_rgba: Option<[f32; 4]>,
+        background_color_hovered: Option<IpgColor>,
+        background_rgba_hovered: Option<[f32; 4]>,
+        background_transparent: bool,
+        border_color: Option<IpgColor>,
+        border_rgba: Option<[f32; 4]>,
+        border_width: f32,
+        border_radius: f32,
+        gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
         
         let background: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -3291,6 +4053,7 @@ impl IPG {
         style_id=None, 
         user_data=None, 
         show=true,
+        enabled=true,
         ))]
     fn add_pick_list(
         &self,
@@ -3315,30 +4078,31 @@ impl IPG {
         style_id: Option<usize>,
         user_data: Option<PyObject>,
         show: bool,
+        enabled: bool,
     ) -> PyResult<usize>
     {
 
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_select {
-            add_callback_to_mutex(id, "on_select".to_string(), py);
+            add_callback_to_mutex(id, "on_select".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
             add_user_data_to_mutex(id, py);
         }
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
         let text_line_height = text::LineHeight::Relative(text_line_height);
         
-        let text_shaping = get_shaping(text_shaping);
+        let text_shaping = get_shaping(text_shaping)?;
 
         let width = get_width(width, width_fill);
 
          let options =  convert_pyobject_vec_string(options);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -3347,6 +4111,7 @@ impl IPG {
                 id,
                 parent_id,
                 show,
+                enabled,
                 options,
                 placeholder,
                 selected,
@@ -3365,6 +4130,7 @@ impl IPG {
 
  
         drop(state);
+        wake_up();
         Ok(id)
     }
 
@@ -3383,6 +4149,10 @@ impl IPG {
         border_rgba_hovered=None,
         border_radius=vec![2.0],
         border_width=1.0,
+        background_color_disabled=None,
+        background_rgba_disabled=None,
+        text_color_disabled=None,
+        text_rgba_disabled=None,
         gen_id=None
         ))]
     fn add_pick_list_style(
@@ -3401,10 +4171,14 @@ impl IPG {
         border_rgba_hovered: Option<[f32; 4]>,
         border_radius: Vec<f32>,
         border_width: f32,
+        background_color_disabled: Option<IpgColor>,
+        background_rgba_disabled: Option<[f32; 4]>,
+        text_color_disabled: Option<IpgColor>,
+        text_rgba_disabled: Option<[f32; 4]>,
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
         
         let background_color: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -3418,6 +4192,10 @@ impl IPG {
             get_color(placeholder_rgba, placeholder_color, 1.0, false);
         let text_color = 
             get_color(text_rgba, text_color, 1.0, false);
+        let background_color_disabled = 
+            get_color(background_rgba_disabled, background_color_disabled, 1.0, false);
+        let text_color_disabled = 
+            get_color(text_rgba_disabled, text_color_disabled, 1.0, false);
 
         let mut state = access_state();
 
@@ -3432,6 +4210,8 @@ impl IPG {
                 border_color_hovered,
                 border_radius,
                 border_width,
+                background_color_disabled,
+                text_color_disabled,
                 )));
 
         drop(state);
@@ -3470,12 +4250,12 @@ impl IPG {
         show: bool,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let width = get_width(width, width_fill);
         let height: Length = get_height(height, height_fill);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -3494,6 +4274,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -3522,7 +4303,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background: Option<Color> = get_color(background_rgba, background_color, 1.0, false);
         let bar_color: Option<Color> = get_color(bar_rgba, bar_color, 1.0, false);
@@ -3566,6 +4347,7 @@ impl IPG {
         text_shaping="basic".to_string(), 
         user_data=None, 
         show=true, 
+        enabled=true, 
         ))]
     fn add_radio(
         &mut self,
@@ -3591,15 +4373,16 @@ impl IPG {
         text_shaping: String,
         user_data: Option<PyObject>,
         show: bool,
+        enabled: bool,
         ) -> PyResult<usize>
     {
 
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let is_selected: Option<usize> = match selected_index {
             Some(index) => {
                 if index > labels.len()-1 {
-                    panic!("Radio selected_index is greater than the size of the labels")
+                    return Err(IpgParamError::new_err("Radio selected_index is greater than the size of the labels"))
                 } else {
                     Some(index)
                 }
@@ -3607,10 +4390,10 @@ impl IPG {
             None => None,
         };
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
         if let Some(py) = on_select {
-            add_callback_to_mutex(id, "on_select".to_string(), py);
+            add_callback_to_mutex(id, "on_select".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -3619,12 +4402,12 @@ impl IPG {
 
         let text_line_height = get_line_height(text_line_height_pixels, text_line_height_relative);
         
-        let text_shaping = get_shaping(text_shaping);
+        let text_shaping = get_shaping(text_shaping)?;
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -3637,6 +4420,7 @@ impl IPG {
                 spacing,
                 padding,
                 show,
+                enabled,
                 is_selected,
                 width,
                 height,
@@ -3652,6 +4436,7 @@ impl IPG {
         self.group_index += 1;
  
         drop(state);                                      
+        wake_up();
         Ok(id)
 
     }
@@ -3670,6 +4455,12 @@ impl IPG {
         dot_rgba_hovered=None,
         text_color=None, 
         text_rgba=None,
+        background_color_disabled=None,
+        background_rgba_disabled=None,
+        dot_color_disabled=None,
+        dot_rgba_disabled=None,
+        text_color_disabled=None,
+        text_rgba_disabled=None,
         gen_id=None
         ))]
     fn add_radio_style(
@@ -3687,10 +4478,16 @@ impl IPG {
         dot_rgba_hovered: Option<[f32; 4]>,
         text_color: Option<IpgColor>,
         text_rgba: Option<[f32; 4]>,
+        background_color_disabled: Option<IpgColor>,
+        background_rgba_disabled: Option<[f32; 4]>,
+        dot_color_disabled: Option<IpgColor>,
+        dot_rgba_disabled: Option<[f32; 4]>,
+        text_color_disabled: Option<IpgColor>,
+        text_rgba_disabled: Option<[f32; 4]>,
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -3704,6 +4501,12 @@ impl IPG {
             get_color(border_rgba, border_color, 1.0, false);
         let text_color: Option<Color> = 
             get_color(text_rgba, text_color, 1.0, false);
+        let background_color_disabled = 
+            get_color(background_rgba_disabled, background_color_disabled, 1.0, false);
+        let dot_color_disabled = 
+            get_color(dot_rgba_disabled, dot_color_disabled, 1.0, false);
+        let text_color_disabled = 
+            get_color(text_rgba_disabled, text_color_disabled, 1.0, false);
 
         let mut state = access_state();
 
@@ -3717,6 +4520,9 @@ impl IPG {
                 border_color,
                 border_width,
                 text_color,
+                background_color_disabled,
+                dot_color_disabled,
+                text_color_disabled,
                 )));
 
         drop(state);
@@ -3743,14 +4549,14 @@ impl IPG {
         show: bool
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let rule_type = "h".to_string();
 
         let width = get_width(width, width_fill);
         let height = get_height(None, true);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -3767,6 +4573,7 @@ impl IPG {
                 )));
  
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -3791,14 +4598,14 @@ impl IPG {
         show: bool, 
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let rule_type = "v".to_string();
 
         let width = get_width(None, true); // not used for vertical, just defaulted
         let height = get_height(height, height_fill);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -3815,6 +4622,7 @@ impl IPG {
                 )));
  
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -3839,7 +4647,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let color = get_color(color_rgba, color, 1.0, false);
         
@@ -3918,44 +4726,44 @@ impl IPG {
         ) -> PyResult<usize> 
     {
     
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let content = text.clone();
 
         if let Some(py) = on_press {
-        add_callback_to_mutex(id, "on_press".to_string(), py);
+        add_callback_to_mutex(id, "on_press".to_string(), py)?;
         }
         
         if let Some(py) = on_release {
-            add_callback_to_mutex(id, "event_name".to_string(), py);
+            add_callback_to_mutex(id, "event_name".to_string(), py)?;
         }
         
         if let Some(py) = on_right_press {
-            add_callback_to_mutex(id, "on_right_press".to_string(), py);
+            add_callback_to_mutex(id, "on_right_press".to_string(), py)?;
         }
         
         if let Some(py) = on_right_release {
-            add_callback_to_mutex(id, "on_right_release".to_string(), py);
+            add_callback_to_mutex(id, "on_right_release".to_string(), py)?;
         }
         
         if let Some(py) = on_middle_press {
-            add_callback_to_mutex(id, "on_middle_press".to_string(), py);
+            add_callback_to_mutex(id, "on_middle_press".to_string(), py)?;
         }
         
         if let Some(py) = on_middle_release {
-            add_callback_to_mutex(id, "on_middle_release".to_string(), py);
+            add_callback_to_mutex(id, "on_middle_release".to_string(), py)?;
         }
         
         if let Some(py) = on_enter {
-            add_callback_to_mutex(id, "on_enter".to_string(), py);
+            add_callback_to_mutex(id, "on_enter".to_string(), py)?;
         }
         
         if let Some(py) = on_move {
-            add_callback_to_mutex(id, "on_move".to_string(), py);
+            add_callback_to_mutex(id, "on_move".to_string(), py)?;
         }
         
         if let Some(py) = on_exit {
-            add_callback_to_mutex(id, "on_exit".to_string(), py);
+            add_callback_to_mutex(id, "on_exit".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -3967,11 +4775,11 @@ impl IPG {
 
         let line_height = LineHeight::Relative(line_height);
 
-        let shaping = get_shaping(shaping);
+        let shaping = get_shaping(shaping)?;
 
         let text_color = get_color(text_rgba, text_color, 1.0, false);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
         
@@ -3992,6 +4800,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -4029,12 +4838,12 @@ impl IPG {
         show: bool,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -4058,6 +4867,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -4078,7 +4888,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let color: Option<Color> = 
             get_color(rgba_color, ipg_color, 1.0, false);
@@ -4113,6 +4923,7 @@ impl IPG {
         style_id=None,
         user_data=None,
         show=true, 
+        enabled=true, 
         ))]
     fn add_slider(
         &self,
@@ -4130,16 +4941,17 @@ impl IPG {
         style_id: Option<usize>,
         user_data: Option<PyObject>,
         show: bool,
+        enabled: bool,
         ) -> PyResult<usize> 
         {
 
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_change {
-            add_callback_to_mutex(id, "on_change".to_string(), py);
+            add_callback_to_mutex(id, "on_change".to_string(), py)?;
         }
         if let Some(py) = on_release {
-            add_callback_to_mutex(id, "on_release".to_string(), py);
+            add_callback_to_mutex(id, "on_release".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -4149,7 +4961,7 @@ impl IPG {
         let width = get_width(width, width_fill);
         let height = height.unwrap_or(16.0);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -4158,6 +4970,7 @@ impl IPG {
                 id,
                 parent_id,
                 show,
+                enabled,
                 min,
                 max,
                 step,
@@ -4168,6 +4981,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -4187,6 +5001,10 @@ impl IPG {
         handle_border_width=None,
         handle_border_color=None,
         handle_border_rgba=None,
+        rail_color_disabled=None,
+        rail_rgba_disabled=None,
+        handle_color_disabled=None,
+        handle_rgba_disabled=None,
         gen_id=None,
         ))]
     fn add_slider_style(
@@ -4205,10 +5023,14 @@ impl IPG {
         handle_border_width: Option<f32>,
         handle_border_color: Option<IpgColor>,
         handle_border_rgba: Option<[f32; 4]>,
+        rail_color_disabled: Option<IpgColor>,
+        rail_rgba_disabled: Option<[f32; 4]>,
+        handle_color_disabled: Option<IpgColor>,
+        handle_rgba_disabled: Option<[f32; 4]>,
         gen_id: Option<usize>,
         )  -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let rail_color = 
             get_color(rail_rgba, rail_color, 1.0, false);
@@ -4218,6 +5040,10 @@ impl IPG {
             get_color(handle_rgba, handle_color, 1.0, false);
         let handle_border_color = 
             get_color(handle_border_rgba,handle_border_color,1.0, false);
+        let rail_color_disabled = 
+            get_color(rail_rgba_disabled, rail_color_disabled, 1.0, false);
+        let handle_color_disabled = 
+            get_color(handle_rgba_disabled, handle_color_disabled, 1.0, false);
 
         let mut state = access_state();
         
@@ -4234,6 +5060,8 @@ impl IPG {
                 handle_color,
                 handle_border_width,
                 handle_border_color,
+                rail_color_disabled,
+                handle_color_disabled,
                 )));
 
         drop(state);
@@ -4262,12 +5090,12 @@ impl IPG {
         ) -> PyResult<usize>
     {
 
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -4281,10 +5109,89 @@ impl IPG {
                 )));
  
         drop(state);
+        wake_up();
         Ok(id)
 
     }
 
+    #[pyo3(signature = (
+        parent_id,
+        series_names,
+        capacity=1000,
+        x_window=None,
+        y_min=None,
+        y_max=None,
+        series_rgba_colors=None,
+        title=None,
+        show_legend=true,
+        text_size=12.0,
+        width=None,
+        width_fill=true,
+        height=Some(200.0),
+        height_fill=false,
+        gen_id=None,
+        show=true,
+        ))]
+    fn add_stream_plot(
+        &self,
+        parent_id: String,
+        series_names: Vec<String>,
+        // ** above required
+        capacity: usize,
+        x_window: Option<f64>,
+        y_min: Option<f64>,
+        y_max: Option<f64>,
+        series_rgba_colors: Option<Vec<[f32; 4]>>,
+        title: Option<String>,
+        show_legend: bool,
+        text_size: f32,
+        width: Option<f32>,
+        width_fill: bool,
+        height: Option<f32>,
+        height_fill: bool,
+        gen_id: Option<usize>,
+        show: bool,
+        ) -> PyResult<usize>
+    {
+        let id = self.get_id(gen_id)?;
+
+        let capacity = check_capacity(capacity)?;
+
+        let series_colors: Option<Vec<Color>> = series_rgba_colors.map(|colors| 
+            colors.iter()
+                .map(|rgba| get_color(Some(*rgba), None, 1.0, false).unwrap())
+                .collect());
+
+        let width = get_width(width, width_fill);
+        let height = get_height(height, height_fill);
+
+        set_state_of_widget(id, parent_id.clone())?;
+
+        let mut state = access_state();
+
+        state.widgets.insert(id, IpgWidgets::IpgStreamPlot(
+            IpgStreamPlot::new(
+                id,
+                parent_id,
+                show,
+                series_names,
+                series_colors,
+                capacity,
+                x_window,
+                y_min,
+                y_max,
+                title,
+                show_legend,
+                text_size,
+                width,
+                height,
+                )));
+
+        drop(state);
+        wake_up();
+        Ok(id)
+    }
+
     #[pyo3(signature = (
         parent_id, 
         svg_path, 
@@ -4338,42 +5245,42 @@ impl IPG {
         user_data: Option<PyObject>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_press {
-            add_callback_to_mutex(id, "on_press".to_string(), py);
+            add_callback_to_mutex(id, "on_press".to_string(), py)?;
         }
         
         if let Some(py) = on_release {
-            add_callback_to_mutex(id, "event_name".to_string(), py);
+            add_callback_to_mutex(id, "event_name".to_string(), py)?;
         }
         
         if let Some(py) = on_right_press {
-            add_callback_to_mutex(id, "on_right_press".to_string(), py);
+            add_callback_to_mutex(id, "on_right_press".to_string(), py)?;
         }
         
         if let Some(py) = on_right_release {
-            add_callback_to_mutex(id, "on_right_release".to_string(), py);
+            add_callback_to_mutex(id, "on_right_release".to_string(), py)?;
         }
         
         if let Some(py) = on_middle_press {
-            add_callback_to_mutex(id, "on_middle_press".to_string(), py);
+            add_callback_to_mutex(id, "on_middle_press".to_string(), py)?;
         }
         
         if let Some(py) = on_middle_release {
-            add_callback_to_mutex(id, "on_middle_release".to_string(), py);
+            add_callback_to_mutex(id, "on_middle_release".to_string(), py)?;
         }
         
         if let Some(py) = on_enter {
-            add_callback_to_mutex(id, "on_enter".to_string(), py);
+            add_callback_to_mutex(id, "on_enter".to_string(), py)?;
         }
         
         if let Some(py) = on_move {
-            add_callback_to_mutex(id, "on_move".to_string(), py);
+            add_callback_to_mutex(id, "on_move".to_string(), py)?;
         }
         
         if let Some(py) = on_exit {
-            add_callback_to_mutex(id, "on_exit".to_string(), py);
+            add_callback_to_mutex(id, "on_exit".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -4383,7 +5290,7 @@ impl IPG {
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -4403,6 +5310,7 @@ impl IPG {
                 )));
  
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -4447,7 +5355,7 @@ impl IPG {
         ) -> PyResult<usize> 
     {
     
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
@@ -4459,11 +5367,11 @@ impl IPG {
 
         let line_height = LineHeight::Relative(line_height);
 
-        let shaping = get_shaping(shaping);
+        let shaping = get_shaping(shaping)?;
 
         let style = get_color(text_rgba, text_color, 1.0, false);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
         
@@ -4485,6 +5393,7 @@ impl IPG {
             )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -4592,6 +5501,8 @@ impl IPG {
         on_input=None, 
         on_submit=None, 
         on_paste=None, 
+        on_focus=None,
+        on_blur=None,
         width=None, 
         width_fill=false, 
         padding=vec![0.0], 
@@ -4601,6 +5512,7 @@ impl IPG {
         user_data=None,
         is_secure=false, 
         style_id=None, show=true,
+        enabled=true,
         ))]
     fn add_text_input(
         &self,
@@ -4611,6 +5523,8 @@ impl IPG {
         on_input: Option<PyObject>,
         on_submit: Option<PyObject>,
         on_paste: Option<PyObject>,
+        on_focus: Option<PyObject>,
+        on_blur: Option<PyObject>,
         width: Option<f32>,
         width_fill: bool,
         padding: Vec<f64>,
@@ -4621,33 +5535,44 @@ impl IPG {
         is_secure: bool,
         style_id: Option<usize>,
         show: bool,
+        enabled: bool,
         ) -> PyResult<usize> 
     {
 
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_input {
-            add_callback_to_mutex(id, "on_input".to_string(), py);
+            add_callback_to_mutex(id, "on_input".to_string(), py)?;
         }
         if let Some(py) = on_submit {
-            add_callback_to_mutex(id, "on_submit".to_string(), py);
+            add_callback_to_mutex(id, "on_submit".to_string(), py)?;
         }
 
         if let Some(py) = on_paste {
-            add_callback_to_mutex(id, "on_paste".to_string(), py);
+            add_callback_to_mutex(id, "on_paste".to_string(), py)?;
+        }
+
+        if on_focus.is_some() || on_blur.is_some() {
+            watch_focus(id);
+        }
+        if let Some(py) = on_focus {
+            add_callback_to_mutex(id, "on_focus".to_string(), py)?;
+        }
+        if let Some(py) = on_blur {
+            add_callback_to_mutex(id, "on_blur".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
             add_user_data_to_mutex(id, py);
         }
         
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
         let width = get_width(width, width_fill);
 
         let line_height = get_line_height(line_height_pixels, line_height_relative);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
         
@@ -4664,9 +5589,11 @@ impl IPG {
                 line_height,
                 style_id,
                 show,
+                enabled,
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -4714,7 +5641,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -4759,6 +5686,8 @@ impl IPG {
         on_start=None, 
         on_tick=None, 
         on_stop=None, 
+        on_finished=None,
+        countdown_ms=None,
         label="Start Timer".to_string(), 
         width=None, 
         height=None, 
@@ -4779,6 +5708,8 @@ impl IPG {
         on_start: Option<PyObject>,
         on_tick: Option<PyObject>,
         on_stop: Option<PyObject>,
+        on_finished: Option<PyObject>,
+        countdown_ms: Option<u64>,
         label: String,
         width: Option<f32>,
         height: Option<f32>,
@@ -4794,16 +5725,19 @@ impl IPG {
         show: bool,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_start {
-            add_callback_to_mutex(id, "on_start".to_string(), py);
+            add_callback_to_mutex(id, "on_start".to_string(), py)?;
         }
         if let Some(py) = on_tick {
-            add_callback_to_mutex(id, "on_tick".to_string(), py);
+            add_callback_to_mutex(id, "on_tick".to_string(), py)?;
         }
         if let Some(py) = on_stop {
-            add_callback_to_mutex(id, "on_stop".to_string(), py);
+            add_callback_to_mutex(id, "on_stop".to_string(), py)?;
+        }
+        if let Some(py) = on_finished {
+            add_callback_to_mutex(id, "on_finished".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -4813,9 +5747,9 @@ impl IPG {
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
         
         let mut state = access_state();
 
@@ -4833,9 +5767,11 @@ impl IPG {
                 style_standard,
                 style_arrow,
                 show,
+                countdown_ms,
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -4877,7 +5813,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -4954,16 +5890,16 @@ impl IPG {
         show: bool,
     ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = on_start {
-            add_callback_to_mutex(id, "on_start".to_string(), py);
+            add_callback_to_mutex(id, "on_start".to_string(), py)?;
         }
         if let Some(py) = on_tick {
-            add_callback_to_mutex(id, "on_tick".to_string(), py);
+            add_callback_to_mutex(id, "on_tick".to_string(), py)?;
         }
         if let Some(py) = on_stop {
-            add_callback_to_mutex(id, "on_stop".to_string(), py);
+            add_callback_to_mutex(id, "on_stop".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -4973,9 +5909,9 @@ impl IPG {
         let width = get_width(width, width_fill);
         let height = get_height(height, height_fill);
 
-        let padding = get_padding_f64(padding);
+        let padding = get_padding_f64(padding)?;
 
-        set_state_of_widget(id, parent_id);
+        set_state_of_widget(id, parent_id)?;
         
         let mut state = access_state();
 
@@ -4995,6 +5931,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
 
     }
@@ -5036,7 +5973,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color: Option<Color> = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -5085,6 +6022,7 @@ impl IPG {
         spacing=10.0, 
         user_data=None, 
         show=true, 
+        enabled=true, 
         style_id=None, 
         ))]
     fn add_toggler(
@@ -5103,13 +6041,14 @@ impl IPG {
         spacing: f32,
         user_data: Option<PyObject>,
         show: bool,
+        enabled: bool,
         style_id: Option<usize>,
         ) -> PyResult<usize> 
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         if let Some(py) = toggled {
-            add_callback_to_mutex(id, "toggled".to_string(), py);
+            add_callback_to_mutex(id, "toggled".to_string(), py)?;
         }
 
         if let Some(py) = user_data {
@@ -5120,7 +6059,7 @@ impl IPG {
 
         let width = get_width(width, width_fill);
 
-        set_state_of_widget(id, parent_id.clone());
+        set_state_of_widget(id, parent_id.clone())?;
 
         let mut state = access_state();
 
@@ -5129,6 +6068,7 @@ impl IPG {
                 id,
                 parent_id,
                 show,
+                enabled,
                 label,
                 width,
                 size,
@@ -5140,6 +6080,7 @@ impl IPG {
                 )));
 
         drop(state);
+        wake_up();
         Ok(id)
     
     }
@@ -5188,7 +6129,7 @@ impl IPG {
         gen_id: Option<usize>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let background_color = 
             get_color(background_rgba, background_color, 1.0, false);
@@ -5278,10 +6219,10 @@ impl IPG {
         let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
         
         if canvas_id_opt.is_none() {
-            panic!("Arc: You need to define a canvas before adding geometries or your canvas_id is incorrect.")
+            return Err(IpgIdError::new_err("Arc: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
         }
         
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let arc = 
             IpgArc { 
@@ -5354,10 +6295,10 @@ impl IPG {
         let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
         
         if canvas_id_opt.is_none() {
-            panic!("Bezier: You need to define a canvas before adding geometries or your canvas_id is incorrect.")
+            return Err(IpgIdError::new_err("Bezier: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
         }
         
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let bezier = 
             IpgBezier{ 
@@ -5427,10 +6368,10 @@ impl IPG {
         let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
         
         if canvas_id_opt.is_none() {
-            panic!("Circle: You need to define a canvas before adding geometries or your canvas_id is incorrect.")
+            return Err(IpgIdError::new_err("Circle: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
         }
         
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
         
         let circle = 
             IpgCircle{ 
@@ -5502,10 +6443,10 @@ impl IPG {
         let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
         
         if canvas_id_opt.is_none() {
-            panic!("Ellipse: You need to define a canvas before adding geometries or your canvas_id is incorrect.")
+            return Err(IpgIdError::new_err("Ellipse: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
         }
         
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let ellipse = 
             IpgEllipse{ 
@@ -5569,10 +6510,10 @@ impl IPG {
         let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
         
         if canvas_id_opt.is_none() {
-            panic!("Line: You need to define a canvas before adding geometries or your canvas_id is incorrect.")
+            return Err(IpgIdError::new_err("Line: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
         }
         
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
         
         let line = 
             IpgLine{ 
@@ -5596,75 +6537,69 @@ impl IPG {
 
     #[pyo3(signature = (
         canvas_id,
-        position_xy,
-        radius,
-        sides,
-        degrees=0.0,
+        start,
+        end,
+        unit_scale=1.0,
+        units="".to_string(),
+        precision=2,
+        text_size=14.0,
         stroke_width=2.0,
-        stroke_dash_offset=None,
-        stroke_dash_segments=None,
         stroke_ipg_color=IpgColor::WHITE,
         stroke_rgba_color=None,
-        fill_ipg_color=None,
-        fill_rgba_color=None,
         gen_id=None,
         ))]
-    fn add_polygon(
+    fn add_dimension(
         &self,
         canvas_id: String,
-        position_xy: (f32, f32),
-        radius: f32,
-        sides: usize,
-        degrees: f32,
+        start: (f32, f32),
+        end: (f32, f32),
+        unit_scale: f32,
+        units: String,
+        precision: usize,
+        text_size: f32,
         stroke_width: f32,
-        stroke_dash_offset: Option<usize>,
-        stroke_dash_segments: Option<Vec<f32>>,
         stroke_ipg_color: Option<IpgColor>,
         stroke_rgba_color: Option<[f32; 4]>,
-        fill_ipg_color: Option<IpgColor>,
-        fill_rgba_color: Option<[f32; 4]>,
         gen_id: Option<usize>,
-        ) -> PyResult<usize> 
+        )  -> PyResult<usize> 
     {
-        let center = Point::new(position_xy.0, position_xy.1);
+        let points = vec![Point::new(start.0, start.1), Point::new(end.0, end.1)];
 
-        let pg_point = Point::new(center.x+radius, center.y);
+        let mid_point = get_mid_point(points[0], points[1]);
+        let rotation = get_horizontal_angle_of_vector(points[0], points[1]);
 
         let color = if stroke_rgba_color.is_some() {
             get_color(stroke_rgba_color, None, 1.0, false).unwrap()
         } else {
             get_color(None, stroke_ipg_color, 1.0, false).unwrap()
         };
-         
-        let fill_color =  get_color(fill_rgba_color, fill_ipg_color, 1.0, false);
 
         let mut canvas_state = access_canvas_state();
         let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
         
         if canvas_id_opt.is_none() {
-            panic!("Polygon: You need to define a canvas before adding geometries or your canvas_id is incorrect.")
+            return Err(IpgIdError::new_err("Dimension: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
         }
         
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
         
-        let pg = 
-            IpgPolygon{ 
-                id,
-                points: build_polygon(center, pg_point, sides, degrees),
-                poly_points: sides-1,
-                mid_point: center,
-                pg_point,
-                color,
-                fill_color,
+        let dim = 
+            IpgDimension{ 
+                id, 
+                points, 
+                mid_point, 
+                color, 
                 width: stroke_width,
-                stroke_dash_offset,
-                stroke_dash_segments,
-                rotation: degrees,
+                unit_scale,
+                units,
+                precision,
+                text_size,
+                rotation, 
                 draw_mode: IpgDrawMode::Display, 
                 status: IpgDrawStatus::Completed,
                 };
 
-        canvas_state.curves.insert(id, IpgWidget::Polygon(pg));
+        canvas_state.curves.insert(id, IpgWidget::Dimension(dim));
         drop(canvas_state);
         Ok(id)
 
@@ -5672,31 +6607,35 @@ impl IPG {
 
     #[pyo3(signature = (
         canvas_id,
-        points,
-        stroke_width,
-        stroke_dash_offset=None,
-        stroke_dash_segments=None,
+        vertex,
+        start,
+        end,
+        precision=1,
+        text_size=14.0,
+        stroke_width=2.0,
         stroke_ipg_color=IpgColor::WHITE,
         stroke_rgba_color=None,
         gen_id=None,
         ))]
-    fn add_poly_line(
+    fn add_angle_measure(
         &self,
         canvas_id: String,
-        points: Vec<(f32, f32)>,
+        vertex: (f32, f32),
+        start: (f32, f32),
+        end: (f32, f32),
+        precision: usize,
+        text_size: f32,
         stroke_width: f32,
-        stroke_dash_offset: Option<usize>,
-        stroke_dash_segments: Option<Vec<f32>>,
         stroke_ipg_color: Option<IpgColor>,
         stroke_rgba_color: Option<[f32; 4]>,
         gen_id: Option<usize>,
-        ) -> PyResult<usize> 
+        )  -> PyResult<usize> 
     {
-        let mut p_points = vec![];
+        let points = vec![Point::new(vertex.0, vertex.1), 
+                                    Point::new(start.0, start.1), 
+                                    Point::new(end.0, end.1)];
 
-        for pt in points.iter() {
-            p_points.push(Point::new(pt.0, pt.1))
-        }
+        let rotation = get_horizontal_angle_of_vector(points[0], points[1]);
 
         let color = if stroke_rgba_color.is_some() {
             get_color(stroke_rgba_color, None, 1.0, false).unwrap()
@@ -5708,10 +6647,213 @@ impl IPG {
         let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
         
         if canvas_id_opt.is_none() {
-            panic!("PolyLine: You need to define a canvas before adding geometries or your canvas_id is incorrect.")
+            return Err(IpgIdError::new_err("AngleMeasure: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
         }
         
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
+        
+        let ang = 
+            IpgAngleMeasure{ 
+                id, 
+                mid_point: points[0], 
+                points, 
+                color, 
+                width: stroke_width,
+                precision,
+                text_size,
+                rotation, 
+                draw_mode: IpgDrawMode::Display, 
+                status: IpgDrawStatus::Completed,
+                };
+
+        canvas_state.curves.insert(id, IpgWidget::AngleMeasure(ang));
+        drop(canvas_state);
+        Ok(id)
+
+    }
+
+    #[pyo3(signature = (
+        canvas_id,
+        anchor_xy,
+        text_xy,
+        text,
+        text_size=16.0,
+        stroke_width=1.0,
+        stroke_ipg_color=IpgColor::WHITE,
+        stroke_rgba_color=None,
+        gen_id=None,
+        ))]
+    fn add_callout(
+        &self,
+        canvas_id: String,
+        anchor_xy: (f32, f32),
+        text_xy: (f32, f32),
+        text: String,
+        text_size: f32,
+        stroke_width: f32,
+        stroke_ipg_color: Option<IpgColor>,
+        stroke_rgba_color: Option<[f32; 4]>,
+        gen_id: Option<usize>,
+        )  -> PyResult<usize> 
+    {
+        let points = vec![Point::new(anchor_xy.0, anchor_xy.1), Point::new(text_xy.0, text_xy.1)];
+
+        let mid_point = get_mid_point(points[0], points[1]);
+        let rotation = get_horizontal_angle_of_vector(points[0], points[1]);
+
+        let color = if stroke_rgba_color.is_some() {
+            get_color(stroke_rgba_color, None, 1.0, false).unwrap()
+        } else {
+            get_color(None, stroke_ipg_color, 1.0, false).unwrap()
+        };
+
+        let mut canvas_state = access_canvas_state();
+        let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
+        
+        if canvas_id_opt.is_none() {
+            return Err(IpgIdError::new_err("Callout: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
+        }
+        
+        let id = self.get_id(gen_id)?;
+        
+        let co = 
+            IpgCallout{ 
+                id, 
+                points, 
+                mid_point, 
+                content: text,
+                color, 
+                width: stroke_width,
+                text_size,
+                rotation, 
+                draw_mode: IpgDrawMode::Display, 
+                status: IpgDrawStatus::Completed,
+                };
+
+        canvas_state.curves.insert(id, IpgWidget::Callout(co));
+        drop(canvas_state);
+        Ok(id)
+
+    }
+
+    #[pyo3(signature = (
+        canvas_id,
+        position_xy,
+        radius,
+        sides,
+        degrees=0.0,
+        stroke_width=2.0,
+        stroke_dash_offset=None,
+        stroke_dash_segments=None,
+        stroke_ipg_color=IpgColor::WHITE,
+        stroke_rgba_color=None,
+        fill_ipg_color=None,
+        fill_rgba_color=None,
+        gen_id=None,
+        ))]
+    fn add_polygon(
+        &self,
+        canvas_id: String,
+        position_xy: (f32, f32),
+        radius: f32,
+        sides: usize,
+        degrees: f32,
+        stroke_width: f32,
+        stroke_dash_offset: Option<usize>,
+        stroke_dash_segments: Option<Vec<f32>>,
+        stroke_ipg_color: Option<IpgColor>,
+        stroke_rgba_color: Option<[f32; 4]>,
+        fill_ipg_color: Option<IpgColor>,
+        fill_rgba_color: Option<[f32; 4]>,
+        gen_id: Option<usize>,
+        ) -> PyResult<usize> 
+    {
+        let center = Point::new(position_xy.0, position_xy.1);
+
+        let pg_point = Point::new(center.x+radius, center.y);
+
+        let color = if stroke_rgba_color.is_some() {
+            get_color(stroke_rgba_color, None, 1.0, false).unwrap()
+        } else {
+            get_color(None, stroke_ipg_color, 1.0, false).unwrap()
+        };
+         
+        let fill_color =  get_color(fill_rgba_color, fill_ipg_color, 1.0, false);
+
+        let mut canvas_state = access_canvas_state();
+        let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
+        
+        if canvas_id_opt.is_none() {
+            return Err(IpgIdError::new_err("Polygon: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
+        }
+        
+        let id = self.get_id(gen_id)?;
+        
+        let pg = 
+            IpgPolygon{ 
+                id,
+                points: build_polygon(center, pg_point, sides, degrees),
+                poly_points: sides-1,
+                mid_point: center,
+                pg_point,
+                color,
+                fill_color,
+                width: stroke_width,
+                stroke_dash_offset,
+                stroke_dash_segments,
+                rotation: degrees,
+                draw_mode: IpgDrawMode::Display, 
+                status: IpgDrawStatus::Completed,
+                };
+
+        canvas_state.curves.insert(id, IpgWidget::Polygon(pg));
+        drop(canvas_state);
+        Ok(id)
+
+    }
+
+    #[pyo3(signature = (
+        canvas_id,
+        points,
+        stroke_width,
+        stroke_dash_offset=None,
+        stroke_dash_segments=None,
+        stroke_ipg_color=IpgColor::WHITE,
+        stroke_rgba_color=None,
+        gen_id=None,
+        ))]
+    fn add_poly_line(
+        &self,
+        canvas_id: String,
+        points: Vec<(f32, f32)>,
+        stroke_width: f32,
+        stroke_dash_offset: Option<usize>,
+        stroke_dash_segments: Option<Vec<f32>>,
+        stroke_ipg_color: Option<IpgColor>,
+        stroke_rgba_color: Option<[f32; 4]>,
+        gen_id: Option<usize>,
+        ) -> PyResult<usize> 
+    {
+        let mut p_points = vec![];
+
+        for pt in points.iter() {
+            p_points.push(Point::new(pt.0, pt.1))
+        }
+
+        let color = if stroke_rgba_color.is_some() {
+            get_color(stroke_rgba_color, None, 1.0, false).unwrap()
+        } else {
+            get_color(None, stroke_ipg_color, 1.0, false).unwrap()
+        };
+
+        let mut canvas_state = access_canvas_state();
+        let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
+        
+        if canvas_id_opt.is_none() {
+            return Err(IpgIdError::new_err("PolyLine: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
+        }
+        
+        let id = self.get_id(gen_id)?;
 
         let poly_line = 
             IpgPolyLine{ 
@@ -5781,10 +6923,10 @@ impl IPG {
         let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
         
         if canvas_id_opt.is_none() {
-            panic!("Rectangle: You need to define a canvas before adding geometries or your canvas_id is incorrect.")
+            return Err(IpgIdError::new_err("Rectangle: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
         }
         
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let rectangle = 
             IpgRectangle{ 
@@ -5838,7 +6980,7 @@ impl IPG {
             let top_left = align_top_left_xy.unwrap();
             Rectangle::new(Point::new(top_left[0], top_left[1]), Size::new(width, height))
         } else {
-            panic!("add_canvas_image: align_center_xy or align_top_left_xy must be defined")
+            return Err(IpgParamError::new_err("add_canvas_image: align_center_xy or align_top_left_xy must be defined"))
         };
 
         let position = Point::new(position_xy[0], position_xy[1]);
@@ -5849,10 +6991,10 @@ impl IPG {
         let canvas_id_opt = canvas_state.canvas_ids_str.get(&canvas_id);
         
         if canvas_id_opt.is_none() {
-            panic!("Arc: You need to define a canvas before adding geometries or your canvas_id is incorrect.")
+            return Err(IpgIdError::new_err("CanvasImage: You need to define a canvas before adding geometries or your canvas_id is incorrect."))
         }
         
-        let id = self.get_id(gen_id);
+        let id = self.get_id(gen_id)?;
 
         let canvas_image = 
             IpgCanvasImage{ 
@@ -5877,24 +7019,29 @@ impl IPG {
         enabled=false, 
         on_key_press=None, 
         on_key_release=None,
+        on_text_input=None,
         user_data=None))]
     fn add_event_keyboard(
         &self, 
         enabled: bool,
         on_key_press: Option<PyObject>,
         on_key_release: Option<PyObject>,
+        on_text_input: Option<PyObject>,
         user_data: Option<PyObject>,
         )  -> PyResult<usize>
     {
-        let id = self.get_id(None);
+        let id = self.get_id(None)?;
 
         let mut events = access_events();
 
         if let Some(py) = on_key_press {
-            events.events.insert((id, "key pressed".to_string()), py);
+            events.events.insert((id, "key pressed".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_key_release {
-            events.events.insert((id, "key released".to_string()), py);
+            events.events.insert((id, "key released".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_text_input {
+            events.events.insert((id, "text input".to_string()), wrap_async_callback(py)?);
         }
 
         drop(events);
@@ -5927,6 +7074,9 @@ impl IPG {
         on_right_press=None, 
         on_right_release=None,
         on_middle_scroll_line=None,
+        on_double_click=None,
+        on_triple_click=None,
+        click_interval_ms=500,
         user_data=None))]
     fn add_event_mouse(
         &self, 
@@ -5941,42 +7091,51 @@ impl IPG {
         on_right_press: Option<PyObject>,
         on_right_release: Option<PyObject>,
         on_middle_scroll_line: Option<PyObject>,
+        on_double_click: Option<PyObject>,
+        on_triple_click: Option<PyObject>,
+        click_interval_ms: u64,
         user_data: Option<PyObject>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(None);
+        let id = self.get_id(None)?;
 
         let mut events = access_events();
 
         if let Some(py) = on_move {
-            events.events.insert((id, "move".to_string()), py);
+            events.events.insert((id, "move".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_enter_window {
-            events.events.insert((id, "enter window".to_string()), py);
+            events.events.insert((id, "enter window".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_exit_window {
-            events.events.insert((id, "exit window".to_string()), py);
+            events.events.insert((id, "exit window".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_left_press {
-            events.events.insert((id, "left press".to_string()), py);
+            events.events.insert((id, "left press".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_left_release {
-            events.events.insert((id, "left release".to_string()), py);
+            events.events.insert((id, "left release".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_middle_press {
-            events.events.insert((id, "middle press".to_string()), py);
+            events.events.insert((id, "middle press".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_middle_release {
-            events.events.insert((id, "middle release".to_string()), py);
+            events.events.insert((id, "middle release".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_right_press {
-            events.events.insert((id, "right press".to_string()), py);
+            events.events.insert((id, "right press".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_right_release {
-            events.events.insert((id, "right release".to_string()), py);
+            events.events.insert((id, "right release".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_middle_scroll_line {
-            events.events.insert((id, "middle scroll line".to_string()), py);
+            events.events.insert((id, "middle scroll line".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_double_click {
+            events.events.insert((id, "double click".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_triple_click {
+            events.events.insert((id, "triple click".to_string()), wrap_async_callback(py)?);
         }
 
         drop(events);
@@ -5992,6 +7151,87 @@ impl IPG {
         let mut state = access_state();
 
         state.mouse_event_id_enabled = (id, enabled);
+        state.mouse_click_interval_ms = click_interval_ms;
+
+        drop(state);
+        Ok(id)
+    }
+
+    #[pyo3(signature = (
+        enabled=false, 
+        on_finger_pressed=None, 
+        on_finger_moved=None, 
+        on_finger_lifted=None, 
+        on_finger_lost=None, 
+        on_pinch=None,
+        on_rotate=None, 
+        on_pan=None,
+        on_swipe=None, 
+        on_long_press=None,
+        long_press_ms=500,
+        user_data=None))]
+    fn add_event_touch(
+        &self, 
+        enabled: bool,
+        on_finger_pressed: Option<PyObject>,
+        on_finger_moved: Option<PyObject>,
+        on_finger_lifted: Option<PyObject>,
+        on_finger_lost: Option<PyObject>,
+        on_pinch: Option<PyObject>,
+        on_rotate: Option<PyObject>,
+        on_pan: Option<PyObject>,
+        on_swipe: Option<PyObject>,
+        on_long_press: Option<PyObject>,
+        long_press_ms: u64,
+        user_data: Option<PyObject>,
+        ) -> PyResult<usize>
+    {
+        let id = self.get_id(None)?;
+
+        let mut events = access_events();
+
+        if let Some(py) = on_finger_pressed {
+            events.events.insert((id, "finger pressed".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_finger_moved {
+            events.events.insert((id, "finger moved".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_finger_lifted {
+            events.events.insert((id, "finger lifted".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_finger_lost {
+            events.events.insert((id, "finger lost".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_pinch {
+            events.events.insert((id, "pinch".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_rotate {
+            events.events.insert((id, "rotate".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_pan {
+            events.events.insert((id, "pan".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_swipe {
+            events.events.insert((id, "swipe".to_string()), wrap_async_callback(py)?);
+        }
+        if let Some(py) = on_long_press {
+            events.events.insert((id, "long press".to_string()), wrap_async_callback(py)?);
+        }
+
+        drop(events);
+
+        let mut callback_user_data = access_user_data1();
+
+        if let Some(py) = user_data {
+            callback_user_data.user_data.insert(id, py);
+        }
+       
+        drop(callback_user_data);
+        
+        let mut state = access_state();
+
+        state.touch_event_id_enabled = (id, enabled);
+        state.touch_long_press_ms = long_press_ms;
 
         drop(state);
         Ok(id)
@@ -6026,40 +7266,40 @@ impl IPG {
         user_data: Option<PyObject>,
         ) -> PyResult<usize>
     {
-        let id = self.get_id(None);
+        let id = self.get_id(None)?;
 
         let mut events = access_events();
 
         if let Some(py) = on_closed {
-            events.events.insert((id, "closed".to_string()), py);
+            events.events.insert((id, "closed".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_moved {
-            events.events.insert((id, "moved".to_string()), py);
+            events.events.insert((id, "moved".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_resized {
-            events.events.insert((id, "resized".to_string()), py);
+            events.events.insert((id, "resized".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_redraw_requested {
-            events.events.insert((id, "redraw requested".to_string()), py);
+            events.events.insert((id, "redraw requested".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_close_requested {
-            events.events.insert((id, "close requested".to_string()), py);
+            events.events.insert((id, "close requested".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_focused {
-            events.events.insert((id, "focused".to_string()), py);
+            events.events.insert((id, "focused".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_unfocused {
-            events.events.insert((id, "unfocused".to_string()), py);
+            events.events.insert((id, "unfocused".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_file_hovered {
-            events.events.insert((id, "file hovered".to_string()), py);
+            events.events.insert((id, "file hovered".to_string()), wrap_async_callback(py)?);
         }
         if let Some(py) = on_file_dropped {
-            events.events.insert((id, "file dropped".to_string()), py);
+            events.events.insert((id, "file dropped".to_string()), wrap_async_callback(py)?);
         }
 
         if let Some(py) = on_files_hovered_left {
-            events.events.insert((id, "files hovered left".to_string()), py);
+            events.events.insert((id, "files hovered left".to_string()), wrap_async_callback(py)?);
         }
        
         drop(events);
@@ -6088,11 +7328,46 @@ impl IPG {
         window_id: String, 
         wid: usize) 
     {
+        // not yet moved over to the session, nothing left to delete there
+        if remove_state_items(&window_id, wid, true) {
+            return
+        }
+
         let mut all_updates = access_update_items();
 
         all_updates.deletes.push((window_id, wid));
 
         drop(all_updates);
+        wake_up();
+    }
+
+    #[pyo3(signature = (
+        container_id,))]
+    fn clear_container(
+        &self, 
+        container_id: String) 
+        -> PyResult<()>
+    {
+        let state = access_state();
+
+        let (window_id, id) = match (state.container_wnd_str_ids.get(&container_id), 
+                                        state.container_str_ids.get(&container_id)) {
+            (Some(window_id), Some(id)) => (window_id.clone(), *id),
+            _ => return Err(IpgIdError::new_err(format!("clear_container: container_id {} not found", container_id))),
+        };
+        drop(state);
+
+        if remove_state_items(&window_id, id, false) {
+            return Ok(())
+        }
+
+        let mut all_updates = access_update_items();
+
+        all_updates.clears.push((window_id, id));
+
+        drop(all_updates);
+        wake_up();
+        Ok(())
     }
 
     #[pyo3(signature = (
@@ -6108,6 +7383,191 @@ impl IPG {
         all_updates.shows.push((window_id, ids));
 
         drop(all_updates);
+        wake_up();
+    }
+
+    #[pyo3(signature = (
+        wid, 
+        xs, 
+        ys, 
+        series=0))]
+    fn append_samples(
+        &self, 
+        wid: usize, 
+        xs: Vec<f64>, 
+        ys: Vec<f64>, 
+        series: usize) 
+        -> PyResult<()>
+    {
+        if xs.len() != ys.len() {
+            return Err(IpgParamError::new_err("append_samples: xs and ys must be the same length"))
+        }
+        let capacity = match stream_plot_series(wid) {
+            Some((count, _)) if series >= count => return Err(IpgParamError::new_err(
+                format!("append_samples: stream plot {wid} has no series {series}"))),
+            Some((_, capacity)) => capacity,
+            None => return Err(IpgIdError::new_err(
+                format!("append_samples: stream plot with id {wid} not found"))),
+        };
+        let mut pending = access_stream_samples();
+
+        // the oldest samples are dropped when the gui hasn't taken them yet
+        let buffer = pending.samples.entry((wid, series))
+            .or_insert_with(|| IpgRingBuffer::new(capacity));
+        buffer.set_capacity(capacity);
+        buffer.extend(&xs, &ys);
+
+        drop(pending);
+        // woken once until the gui takes the samples, not for each append
+        wake_up();
+        Ok(())
+    }
+
+    #[pyo3(signature = (
+        coro, 
+        on_done=None, 
+        user_data=None))]
+    fn run_task(
+        &self, 
+        py: Python, 
+        coro: PyObject, 
+        on_done: Option<PyObject>, 
+        user_data: Option<PyObject>) 
+        -> PyResult<usize>
+    {
+        run_coroutine(py, coro, on_done, user_data)
+    }
+
+    #[pyo3(signature = (
+        delay_ms, 
+        callback, 
+        user_data=None))]
+    fn call_later(
+        &self, 
+        py: Python, 
+        delay_ms: u64, 
+        callback: PyObject, 
+        user_data: Option<PyObject>) 
+        -> PyResult<usize>
+    {
+        add_scheduled_call(py, delay_ms, false, callback, user_data)
+    }
+
+    #[pyo3(signature = (
+        interval_ms, 
+        callback, 
+        user_data=None))]
+    fn call_every(
+        &self, 
+        py: Python, 
+        interval_ms: u64, 
+        callback: PyObject, 
+        user_data: Option<PyObject>) 
+        -> PyResult<usize>
+    {
+        add_scheduled_call(py, interval_ms, true, callback, user_data)
+    }
+
+    #[pyo3(signature = (handle))]
+    fn cancel_call(&self, handle: usize) -> bool 
+    {
+        let cancelled = cancel_call(handle);
+        wake_up();
+        cancelled
+    }
+
+    #[pyo3(signature = (
+        accelerator, 
+        callback, 
+        window_id=None, 
+        when_focused=None, 
+        user_data=None))]
+    fn add_shortcut(
+        &self, 
+        py: Python, 
+        accelerator: String, 
+        callback: PyObject, 
+        window_id: Option<String>, 
+        when_focused: Option<usize>, 
+        user_data: Option<PyObject>) 
+        -> PyResult<usize>
+    {
+        let strokes = parse_accelerator(&accelerator)
+            .map_err(IpgParamError::new_err)?;
+        if !callback.bind(py).is_callable() {
+            return Err(IpgParamError::new_err("The callback must be callable"))
+        }
+
+        let state = access_state();
+        if let Some(window_id) = &window_id {
+            if !state.windows_str_ids.contains_key(window_id) {
+                return Err(IpgIdError::new_err(format!("add_shortcut: window_id {window_id} not found")))
+            }
+        }
+        drop(state);
+        // only the text inputs can have the focus
+        if let Some(wid) = when_focused {
+            if !is_text_input(wid) {
+                return Err(IpgParamError::new_err(format!("add_shortcut: when_focused {wid} is not a text input")))
+            }
+        }
+
+        let id = add_shortcut(IpgShortcut { strokes, window_id, when_focused, callback, user_data })
+            .map_err(IpgParamError::new_err)?;
+        // the key subscription is made when the first shortcut is added
+        wake_up();
+        Ok(id)
+    }
+
+    #[pyo3(signature = (shortcut_id))]
+    fn remove_shortcut(&self, shortcut_id: usize) -> bool 
+    {
+        let removed = remove_shortcut(shortcut_id);
+        wake_up();
+        removed
+    }
+
+    #[pyo3(signature = (wid))]
+    fn focus(&self, wid: usize) -> PyResult<()> 
+    {
+        if !is_text_input(wid) {
+            return Err(IpgIdError::new_err(format!("focus: wid {wid} is not a text input")))
+        }
+        // focused when the window opens if the session isn't started
+        access_window_actions().focus = Some(wid);
+        wake_up();
+        Ok(())
+    }
+
+    #[pyo3(signature = (window_id))]
+    fn open_window(&self, window_id: String) -> PyResult<()> 
+    {
+        let ipg_id = window_ipg_id("open_window", &window_id)?;
+        access_window_actions().open.push(ipg_id);
+        wake_up();
+        Ok(())
+    }
+
+    #[pyo3(signature = (window_id))]
+    fn close_window(&self, window_id: String) -> PyResult<()> 
+    {
+        let ipg_id = window_ipg_id("close_window", &window_id)?;
+        access_window_actions().close.push(ipg_id);
+        wake_up();
+        Ok(())
+    }
+
+    #[pyo3(signature = (window_id, wids))]
+    fn set_tab_order(&self, window_id: String, wids: Vec<usize>) -> PyResult<()> 
+    {
+        if !access_state().windows_str_ids.contains_key(&window_id) {
+            return Err(IpgIdError::new_err(format!("set_tab_order: window_id {window_id} not found")))
+        }
+        if let Some(wid) = wids.iter().find(|wid| !is_text_input(**wid)) {
+            return Err(IpgParamError::new_err(format!("set_tab_order: wid {wid} is not a text input")))
+        }
+        set_tab_order(window_id, wids);
+        Ok(())
     }
 
     #[pyo3(signature = (
@@ -6125,6 +7585,7 @@ impl IPG {
         all_updates.dataframes.push((wid, param, value));
 
         drop(all_updates);
+        wake_up();
     }
 
     #[pyo3(signature = (wid, param, value))]
@@ -6132,12 +7593,152 @@ impl IPG {
                     wid: usize, 
                     param: PyObject, 
                     value: PyObject) 
+                    -> PyResult<()>
     {
-        let mut all_updates = access_update_items();
-        
-        all_updates.updates.push((wid, param, value));
+        // checked when it's added to a batch, the batch is queued as a whole
+        if in_batch() {
+            validate_update("update_item", wid, &param)?;
+        }
+        if let Some(updates) = add_to_batch(vec![(wid, param, value)]) {
+            push_updates(updates);
+        }
+        Ok(())
+    }
 
-        drop(all_updates);
+    #[pyo3(signature = (updates))]
+    fn update_items(&self, 
+                    updates: Vec<(usize, PyObject, PyObject)>) 
+                    -> PyResult<()>
+    {
+        match add_to_batch(updates) {
+            Some(updates) => queue_updates(updates),
+            None => Ok(()),
+        }
+    }
+
+    #[pyo3(signature = ())]
+    fn batch(&self) -> IpgBatch {
+        IpgBatch {}
+    }
+
+    #[pyo3(signature = (name, value))]
+    fn add_value(&self, 
+                    name: String, 
+                    value: PyObject) 
+                    -> PyResult<()>
+    {
+        let value = try_extract_bind_value(&value)?;
+
+        let mut bindings = access_bindings();
+        if bindings.values.contains_key(&name) {
+            return Err(IpgParamError::new_err(
+                format!("add_value: value {name} already exists, use set_value to change it")))
+        }
+        bindings.values.insert(name, value);
+        Ok(())
+    }
+
+    #[pyo3(signature = (name, value))]
+    fn set_value(&self, 
+                    name: String, 
+                    value: PyObject) 
+                    -> PyResult<()>
+    {
+        let value = try_extract_bind_value(&value)?;
+
+        let mut bindings = access_bindings();
+        match bindings.values.get_mut(&name) {
+            Some(shared) => *shared = value,
+            None => return Err(IpgIdError::new_err(format!("set_value: value {name} not found"))),
+        }
+        drop(bindings);
+
+        queue_bound_value(name);
+        Ok(())
+    }
+
+    #[pyo3(signature = (name))]
+    fn get_value(&self, 
+                    name: String) 
+                    -> PyResult<IpgBindValue>
+    {
+        match access_bindings().values.get(&name) {
+            Some(value) => Ok(value.clone()),
+            None => Err(IpgIdError::new_err(format!("get_value: value {name} not found"))),
+        }
+    }
+
+    #[pyo3(signature = (wid, param, name, two_way=false, 
+                        format=None, scale=1.0, offset=0.0))]
+    fn bind(&self, 
+            wid: usize, 
+            param: PyObject, 
+            name: String, 
+            two_way: bool,
+            format: Option<String>,
+            scale: f64,
+            offset: f64) 
+            -> PyResult<()>
+    {
+        validate_update("bind", wid, &param)?;
+        let (_, variant) = get_param_names(&param)?;
+
+        if scale == 0.0 {
+            return Err(IpgParamError::new_err("bind: scale can not be 0.0"))
+        }
+
+        let mut bindings = access_bindings();
+        if !bindings.values.contains_key(&name) {
+            return Err(IpgIdError::new_err(
+                format!("bind: value {name} not found, use add_value to add it")))
+        }
+
+        bindings.bindings.push(IpgBinding { 
+            wid, 
+            param: Arc::new(param), 
+            variant, 
+            name: name.clone(), 
+            two_way, 
+            converter: IpgConverter { format, scale, offset },
+        });
+        drop(bindings);
+
+        // the widget takes on the value right away
+        queue_bound_value(name);
+        Ok(())
+    }
+
+    #[pyo3(signature = (wid, name=None))]
+    fn unbind(&self, 
+                wid: usize, 
+                name: Option<String>) 
+    {
+        let mut bindings = access_bindings();
+        bindings.bindings.retain(|binding| 
+            binding.wid != wid || name.as_ref().is_some_and(|name| *name != binding.name));
+    }
+
+    #[pyo3(signature = (wid, param))]
+    fn get_item(&self, 
+                wid: usize, 
+                param: PyObject) 
+                -> PyResult<Option<IpgItemValue>>
+    {
+        let (param_class, items) = get_item_list(wid)?;
+
+        get_item_value(items, param_class, &param)
+    }
+
+    #[pyo3(signature = (wid))]
+    fn get_items(&self, 
+                wid: usize) 
+                -> PyResult<HashMap<String, Option<IpgItemValue>>>
+    {
+        let (_, items) = get_item_list(wid)?;
+
+        Ok(items.into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect())
     }
 
     #[pyo3(signature = (wid, param, value))]
@@ -6151,6 +7752,7 @@ impl IPG {
         canvas_items.updates.push((wid, param, value));
 
         drop(canvas_items);
+        wake_up();
     }
 
     #[pyo3(signature = (
@@ -6173,6 +7775,7 @@ impl IPG {
         all_updates.moves.push((window_id, widget_id, target_container_str_id, move_after, move_before));
         
         drop(all_updates);
+        wake_up();
     }
     
     #[pyo3(signature = (color))]
@@ -6218,7 +7821,7 @@ impl IPG {
     fn get_id(
         &self, 
         gen_id: Option<usize>
-        ) -> usize
+        ) -> PyResult<usize>
     {
         // When an id is generated, it is put into the gen_ids state mutex.
         // The below checks that if the user is using the gen_id field for the
@@ -6230,16 +7833,16 @@ impl IPG {
             Some(id) => {
                 if state.gen_ids.contains(&id) {
                     drop(state);
-                    id
+                    Ok(id)
                 } else {
-                    panic!("The gen_id parameter for id {id} was not found in the gen_id list.")
+                    Err(IpgIdError::new_err(format!("The gen_id parameter for id {id} was not found in the gen_id list.")))
                 }
             }
             None => {
                 state.last_id += 1;
                 let id = state.last_id;
                 drop(state);
-                id
+                Ok(id)
                 },
         }
 
@@ -6250,92 +7853,108 @@ fn match_widget(
     widget: &mut IpgWidgets, 
     item: &PyObject, 
     value: &PyObject) 
+    -> PyResult<()>
 {
     match widget {
         IpgWidgets::IpgButton(btn) => {
-            button_item_update(btn, item, value);
+            button_item_update(btn, item, value)
         },
         IpgWidgets::IpgButtonStyle(style) => {
-            button_style_update_item(style, item, value);
+            button_style_update_item(style, item, value)
         },
         IpgWidgets::IpgCard(card) => {
-            card_item_update(card, item, value);
+            card_item_update(card, item, value)
         },
         IpgWidgets::IpgCardStyle(style) => {
-            card_style_update(style, item, value);
+            card_style_update(style, item, value)
+        },
+        IpgWidgets::IpgChart(chart) => {
+            chart_item_update(chart, item, value)
         },
         IpgWidgets::IpgCheckBox(chk) => {
-            checkbox_item_update(chk, item, value);
+            checkbox_item_update(chk, item, value)
         },
         IpgWidgets::IpgCheckboxStyle(style) => {
-            checkbox_style_update_item(style, item, value);
+            checkbox_style_update_item(style, item, value)
         },
         IpgWidgets::IpgColorPicker(cp) => {
-            color_picker_update(cp, item, value);
+            color_picker_update(cp, item, value)
         },
         IpgWidgets::IpgColorPickerStyle(style) => {
-            color_picker_style_update_item(style, item, value);
+            color_picker_style_update_item(style, item, value)
         },
         IpgWidgets::IpgContainerStyle(style) => {
-            container_style_update_item(style, item, value);
+            container_style_update_item(style, item, value)
         },
         IpgWidgets::IpgDatePicker(dp) => {
-            date_picker_item_update(dp, item, value);
+            date_picker_item_update(dp, item, value)
         },
         IpgWidgets::IpgDividerHorizontal(div) => {
-            divider_horizontal_item_update(div, item, value);
+            divider_horizontal_item_update(div, item, value)
         },
         IpgWidgets::IpgDividerVertical(div) => {
-            divider_vertical_item_update(div, item, value);
+            divider_vertical_item_update(div, item, value)
         },
         IpgWidgets::IpgDividerStyle(style) => {
-            divider_style_update_item(style, item, value);
+            divider_style_update_item(style, item, value)
         }
+        IpgWidgets::IpgGauge(gauge) => {
+            gauge_item_update(gauge, item, value)
+        },
+        IpgWidgets::IpgHeatmap(heatmap) => {
+            heatmap_item_update(heatmap, item, value)
+        },
         IpgWidgets::IpgImage(img) => {
-            image_item_update(img, item, value);
+            image_item_update(img, item, value)
+        },
+        IpgWidgets::IpgKnob(knob) => {
+            knob_item_update(knob, item, value)
+        },
+        IpgWidgets::IpgMeter(meter) => {
+            meter_item_update(meter, item, value)
         },
         IpgWidgets::IpgMenuStyle(style) => {
-            menu_style_update_item(style, item, value);
+            menu_style_update_item(style, item, value)
         },
         IpgWidgets::IpgMenuBarStyle(style) => {
-            menu_bar_style_update_item(style, item, value);
+            menu_bar_style_update_item(style, item, value)
         },
         IpgWidgets::IpgOpaqueStyle(style) => {
-            opaque_style_update_item(style, item, value);
+            opaque_style_update_item(style, item, value)
         }
         IpgWidgets::IpgPickList(pl) => {
-            pick_list_item_update(pl, item, value);
+            pick_list_item_update(pl, item, value)
         },
         IpgWidgets::IpgPickListStyle(style) => {
-            pick_list_style_update_item(style, item, value);
+            pick_list_style_update_item(style, item, value)
         },
         IpgWidgets::IpgProgressBar(pb) => {
-            progress_bar_item_update(pb, item, value);
+            progress_bar_item_update(pb, item, value)
         },
         IpgWidgets::IpgProgressBarStyle(style) => {
-            progress_bar_style_update_item(style, item, value);
+            progress_bar_style_update_item(style, item, value)
         },
         IpgWidgets::IpgRadio(rd) => {
-            radio_item_update(rd, item, value);
+            radio_item_update(rd, item, value)
         },
         IpgWidgets::IpgRadioStyle(style) => {
-            radio_style_update_item(style, item, value);
+            radio_style_update_item(style, item, value)
         },
-        IpgWidgets::IpgRule(_) => (),
+        IpgWidgets::IpgRule(_) => Ok(()),
         IpgWidgets::IpgRuleStyle(style) => {
-            rule_style_update_item(style, item, value);
+            rule_style_update_item(style, item, value)
         },
         IpgWidgets::IpgScrollableStyle(style) => {
             scroll_style_update_item(style, item, value)
         },
         IpgWidgets::IpgSelectableText(st) => {
-            selectable_text_item_update(st, item, value);
+            selectable_text_item_update(st, item, value)
         },
         IpgWidgets::IpgSeparator(sep) => {
-            separator_item_update(sep, item, value);
+            separator_item_update(sep, item, value)
         },
         IpgWidgets::IpgSeparatorStyle(style) => {
-            separator_style_update_item(style, item, value);
+            separator_style_update_item(style, item, value)
         },
         IpgWidgets::IpgSlider(slider) => {
             slider_item_update(slider, item, value)
@@ -6343,42 +7962,45 @@ fn match_widget(
         IpgWidgets::IpgSliderStyle(style) => {
             slider_style_update_item(style, item, value)
         },
-        IpgWidgets::IpgSpace(_) => (),
+        IpgWidgets::IpgSpace(_) => Ok(()),
+        IpgWidgets::IpgStreamPlot(plot) => {
+            stream_plot_item_update(plot, item, value)
+        },
         IpgWidgets::IpgSvg(sg) => {
-            svg_item_update(sg, item, value);
+            svg_item_update(sg, item, value)
         },
         IpgWidgets::IpgTableStyle(style) => {
-            table_style_update_item(style, item, value);
+            table_style_update_item(style, item, value)
         }
         IpgWidgets::IpgText(txt) => {
-            text_item_update(txt, item, value);
+            text_item_update(txt, item, value)
         },
         IpgWidgets::IpgTextInput(ti) => {
-            text_input_item_update(ti, item, value);
+            text_input_item_update(ti, item, value)
         },
         IpgWidgets::IpgTextInputStyle(style) => {
-            text_input_style_update_item(style, item, value);
+            text_input_style_update_item(style, item, value)
         },
         IpgWidgets::IpgTimer(tim) => {
-            timer_item_update(tim, item, value);
+            timer_item_update(tim, item, value)
         },
         IpgWidgets::IpgTimerStyle(style) => {
-            timer_style_update_item(style, item, value);
+            timer_style_update_item(style, item, value)
         },
         IpgWidgets::IpgCanvasTimer(ctim) => {
-            canvas_timer_item_update(ctim, item, value);
+            canvas_timer_item_update(ctim, item, value)
         },
         IpgWidgets::IpgCanvasTimerStyle(style) => {
-            canvas_timer_style_update_item(style, item, value);
+            canvas_timer_style_update_item(style, item, value)
         },
         IpgWidgets::IpgToggler(tog) => {
-            toggler_item_update(tog, item, value);
+            toggler_item_update(tog, item, value)
         },
         IpgWidgets::IpgTogglerStyle(style) => {
-            toggler_style_update_item(style, item, value);
+            toggler_style_update_item(style, item, value)
         },
         IpgWidgets::IpgToolTipStyle(style) => {
-            tool_tip_style_update_item(style, item, value);
+            tool_tip_style_update_item(style, item, value)
         },
 
     }
@@ -6390,56 +8012,73 @@ fn match_container(
     value: &PyObject, 
     canvas_state: &mut IpgCanvasState,
     last_id: usize,
-    ) -> Option<usize>
+    ) -> PyResult<Option<usize>>
 {
     match container {
         IpgContainers::IpgCanvas(_can) => {
             canvas_item_update(canvas_state, item, value, last_id)
         },
         IpgContainers::IpgColumn(col) => {
-            column_item_update(col, item, value);
-            None
+            column_item_update(col, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgContainer(cont) => {
-            container_item_update(cont, item, value);
-            None
+            container_item_update(cont, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgMenu(menu) => {
-            menu_item_update(menu, item, value);
-            None
+            menu_item_update(menu, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgMouseArea(m_area) => {
-            mousearea_item_update(m_area, item, value);
-            None
+            mousearea_item_update(m_area, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgOpaque(op) => {
-            opaque_item_update(op, item, value);
-            None
+            opaque_item_update(op, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgRow(row) => {
-            row_item_update(row, item, value);
-            None
+            row_item_update(row, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgStack(stack) => {
-            stack_item_update(stack, item, value);
-            None
+            stack_item_update(stack, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgTable(table) => {
-            table_item_update(table, item, value);
-            None
+            table_item_update(table, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgScrollable(scroll) => {
-            scrollable_item_update(scroll, item, value);
-            None
+            scrollable_item_update(scroll, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgToolTip(tool) => {
-            tooltip_item_update(tool, item, value);
-            None
+            tooltip_item_update(tool, item, value)?;
+            Ok(None)
         },
         IpgContainers::IpgWindow(wnd) => {
-            window_item_update(wnd, item, value);
-            None
+            window_item_update(wnd, item, value)?;
+            Ok(None)
+        },
+    }
+}
+
+fn match_widget_for_df(
+    widget: &mut IpgWidgets, 
+    item: &PyObject, 
+    value: &PyDataFrame) 
+    -> PyResult<()>
+{
+    match widget {
+        IpgWidgets::IpgChart(chart) => {
+            chart_dataframe_update(chart, item, value)
+        },
+        IpgWidgets::IpgHeatmap(heatmap) => {
+            heatmap_dataframe_update(heatmap, item, value)
         },
+        _ => Ok(()),
     }
 }
 
@@ -6447,10 +8086,235 @@ fn match_container_for_df(
     container: &mut IpgContainers, 
     item: &PyObject, 
     value: &PyDataFrame) 
+    -> PyResult<()>
 {
     if let IpgContainers::IpgTable(table) = container {
-        table_dataframe_update(table, item, value);
+        table_dataframe_update(table, item, value)?;
+    }
+    Ok(())
+}
+
+/// Checks the updates and queues them as one batch,
+/// none of them are queued when one is not valid.
+pub fn queue_updates(updates: Vec<(usize, PyObject, PyObject)>) -> PyResult<()> {
+    for (wid, param, _) in updates.iter() {
+        validate_update("update_items", *wid, param)?;
+    }
+    if !updates.is_empty() {
+        push_updates(updates);
+    }
+    Ok(())
+}
+
+fn queue_bound_value(name: String) {
+    let mut all_updates = access_update_items();
+    
+    all_updates.bound_values.push(name);
+
+    drop(all_updates);
+    wake_up();
+}
+
+fn add_scheduled_call(py: Python, 
+                        interval_ms: u64, 
+                        repeat: bool, 
+                        callback: PyObject, 
+                        user_data: Option<PyObject>) 
+                        -> PyResult<usize>
+{
+    if interval_ms == 0 {
+        return Err(IpgParamError::new_err("The time in ms must be greater than 0"))
+    }
+    if !callback.bind(py).is_callable() {
+        return Err(IpgParamError::new_err("The callback must be callable"))
+    }
+    let handle = schedule_call(IpgScheduledCall { interval_ms, repeat, callback, user_data });
+    // the subscriptions are made again after the wake up
+    wake_up();
+    Ok(handle)
+}
+
+fn push_updates(updates: Vec<(usize, PyObject, PyObject)>) {
+    let mut all_updates = access_update_items();
+        
+    all_updates.updates.push(updates);
+
+    drop(all_updates);
+    wake_up();
+}
+
+// The item must exist and the param must be of the item's param class.
+// The values are checked when the update is applied.
+fn validate_update(name: &str, wid: usize, param: &PyObject) -> PyResult<()> {
+    let param_class = match find_item(wid) {
+        (Some(widget), _) => match style_param_class(&widget) {
+            Some(param_class) => param_class,
+            None => match_widget_items(&widget)?.0,
+        },
+        // the canvas can't be read back but can be updated
+        (None, Some(IpgContainers::IpgCanvas(_))) => "IpgCanvasParam",
+        (None, Some(container)) => match_container_items(&container)?.0,
+        (None, None) => return Err(IpgIdError::new_err(
+            format!("{name}: Widget, Container, or Window with id {wid} not found."))),
+    };
+
+    if param_class.is_empty() {
+        return Err(IpgParamError::new_err(
+            format!("{name}: the item with id {wid} has no parameters to update")))
+    }
+
+    let (class_name, _) = get_param_names(param)?;
+
+    if class_name != param_class {
+        return Err(IpgParamError::new_err(
+            format!("{name}: {class_name} can not be used for the item with id {wid}, expected {param_class}")))
     }
+    Ok(())
+}
+
+fn window_ipg_id(name: &str, window_id: &str) -> PyResult<usize> {
+    match access_state().windows_str_ids.get(window_id) {
+        Some(id) => Ok(*id),
+        None => Err(IpgIdError::new_err(format!("{name}: window_id {window_id} not found"))),
+    }
+}
+
+// looks in the running items once the session is started
+// The number of series and the capacity of the stream plot.
+fn stream_plot_series(wid: usize) -> Option<(usize, usize)> {
+    let series = |widget: &IpgWidgets| match widget {
+        IpgWidgets::IpgStreamPlot(plot) => Some((plot.series.len(), plot.capacity)),
+        _ => None,
+    };
+    let running = access_running_items();
+    if let Some(widget) = running.widgets.get(&wid) {
+        return series(widget)
+    }
+    drop(running);
+    access_state().widgets.get(&wid).and_then(series)
+}
+
+// looks in the running items once the session is started
+fn is_text_input(wid: usize) -> bool {
+    let running = access_running_items();
+    if let Some(widget) = running.widgets.get(&wid) {
+        return matches!(widget, IpgWidgets::IpgTextInput(_))
+    }
+    drop(running);
+    matches!(access_state().widgets.get(&wid), Some(IpgWidgets::IpgTextInput(_)))
+}
+
+// The items are read from the running copy, or before the
+// gui is started, from the state being built.
+fn get_item_list(wid: usize) -> PyResult<(&'static str, IpgItems)> {
+    match find_item(wid) {
+        (Some(widget), _) => match_widget_items(&widget),
+        (None, Some(container)) => match_container_items(&container),
+        (None, None) => Err(IpgIdError::new_err(
+            format!("get_item: Widget, Container, or Window with id {wid} not found."))),
+    }
+}
+
+fn find_item(wid: usize) -> (Option<IpgWidgets>, Option<IpgContainers>) {
+    let running = access_running_items();
+    let widget = running.widgets.get(&wid).cloned();
+    let container = running.containers.get(&wid).cloned();
+    drop(running);
+
+    if widget.is_none() && container.is_none() {
+        let state = access_state();
+        let found = (state.widgets.get(&wid).cloned(), state.containers.get(&wid).cloned());
+        drop(state);
+        found
+    } else {
+        (widget, container)
+    }
+}
+
+// The styles can't be read back but can be updated.
+fn style_param_class(widget: &IpgWidgets) -> Option<&'static str> {
+    let param_class = match widget {
+        IpgWidgets::IpgButtonStyle(_) => "IpgButtonStyleParam",
+        IpgWidgets::IpgCardStyle(_) => "IpgCardStyleParam",
+        IpgWidgets::IpgCheckboxStyle(_) => "IpgCheckboxStyleParam",
+        IpgWidgets::IpgColorPickerStyle(_) => "IpgColorPickerStyleParam",
+        IpgWidgets::IpgContainerStyle(_) => "IpgContainerStyleParam",
+        IpgWidgets::IpgDividerStyle(_) => "IpgDividerStyleParam",
+        IpgWidgets::IpgMenuStyle(_) => "IpgMenuStyleParam",
+        IpgWidgets::IpgMenuBarStyle(_) => "IpgMenuBarStyleParam",
+        IpgWidgets::IpgOpaqueStyle(_) => "IpgOpaqueStyleParam",
+        IpgWidgets::IpgPickListStyle(_) => "IpgPickListStyleParam",
+        IpgWidgets::IpgProgressBarStyle(_) => "IpgProgressBarStyleParam",
+        IpgWidgets::IpgRadioStyle(_) => "IpgRadioStyleParam",
+        IpgWidgets::IpgRuleStyle(_) => "IpgRuleStyleParam",
+        IpgWidgets::IpgScrollableStyle(_) => "IpgScrollableStyleParam",
+        IpgWidgets::IpgSeparatorStyle(_) => "IpgSeparatorStyleParam",
+        IpgWidgets::IpgSliderStyle(_) => "IpgSliderStyleParam",
+        IpgWidgets::IpgTableStyle(_) => "IpgTableStyleParam",
+        IpgWidgets::IpgTextInputStyle(_) => "IpgTextInputStyleParam",
+        IpgWidgets::IpgTimerStyle(_) => "IpgTimerStyleParam",
+        IpgWidgets::IpgCanvasTimerStyle(_) => "IpgCanvasTimerStyleParam",
+        IpgWidgets::IpgTogglerStyle(_) => "IpgTogglerStyleParam",
+        IpgWidgets::IpgToolTipStyle(_) => "IpgToolTipStyleParam",
+        _ => return None,
+    };
+    Some(param_class)
+}
+
+fn match_widget_items(widget: &IpgWidgets) -> PyResult<(&'static str, IpgItems)> {
+    let items = match widget {
+        IpgWidgets::IpgButton(btn) => ("IpgButtonParam", button_get_items(btn)?),
+        IpgWidgets::IpgCard(crd) => ("IpgCardParam", card_get_items(crd)?),
+        IpgWidgets::IpgChart(chart) => ("IpgChartParam", chart_get_items(chart)?),
+        IpgWidgets::IpgCheckBox(chk) => ("IpgCheckboxParam", checkbox_get_items(chk)?),
+        IpgWidgets::IpgColorPicker(cp) => ("IpgColorPickerParam", color_picker_get_items(cp)?),
+        IpgWidgets::IpgDatePicker(dp) => ("IpgDatePickerParam", date_picker_get_items(dp)?),
+        IpgWidgets::IpgDividerHorizontal(div) => ("IpgDividerParam", divider_horizontal_get_items(div)?),
+        IpgWidgets::IpgDividerVertical(div) => ("IpgDividerParam", divider_vertical_get_items(div)?),
+        IpgWidgets::IpgGauge(gauge) => ("IpgGaugeParam", gauge_get_items(gauge)?),
+        IpgWidgets::IpgHeatmap(heatmap) => ("IpgHeatmapParam", heatmap_get_items(heatmap)?),
+        IpgWidgets::IpgImage(img) => ("IpgImageParam", image_get_items(img)?),
+        IpgWidgets::IpgKnob(knob) => ("IpgGaugeParam", knob_get_items(knob)?),
+        IpgWidgets::IpgMeter(meter) => ("IpgGaugeParam", meter_get_items(meter)?),
+        IpgWidgets::IpgPickList(pl) => ("IpgPickListParam", pick_list_get_items(pl)?),
+        IpgWidgets::IpgProgressBar(pb) => ("IpgProgressBarParam", progress_bar_get_items(pb)?),
+        IpgWidgets::IpgRadio(rd) => ("IpgRadioParam", radio_get_items(rd)?),
+        IpgWidgets::IpgSelectableText(st) => ("IpgSelectableTextParam", selectable_text_get_items(st)?),
+        IpgWidgets::IpgSeparator(sep) => ("IpgSeparatorParam", separator_get_items(sep)?),
+        IpgWidgets::IpgSlider(slider) => ("IpgSliderParam", slider_get_items(slider)?),
+        IpgWidgets::IpgStreamPlot(plot) => ("IpgStreamPlotParam", stream_plot_get_items(plot)?),
+        IpgWidgets::IpgSvg(sg) => ("IpgSvgParam", svg_get_items(sg)?),
+        IpgWidgets::IpgText(txt) => ("IpgTextParam", text_get_items(txt)?),
+        IpgWidgets::IpgTextInput(ti) => ("IpgTextInputParam", text_input_get_items(ti)?),
+        IpgWidgets::IpgTimer(tim) => ("IpgTimerParam", timer_get_items(tim)?),
+        IpgWidgets::IpgCanvasTimer(ctim) => ("IpgCanvasTimerParam", canvas_timer_get_items(ctim)?),
+        IpgWidgets::IpgToggler(tog) => ("IpgTogglerParam", toggler_get_items(tog)?),
+        // the rule and space have no parameters
+        IpgWidgets::IpgRule(_) | IpgWidgets::IpgSpace(_) => ("", vec![]),
+        _ => return Err(IpgParamError::new_err(
+                "get_item: the styles can not be read back, only widgets and containers")),
+    };
+    Ok(items)
+}
+
+fn match_container_items(container: &IpgContainers) -> PyResult<(&'static str, IpgItems)> {
+    let items = match container {
+        IpgContainers::IpgColumn(col) => ("IpgColumnParam", column_get_items(col)?),
+        IpgContainers::IpgContainer(cont) => ("IpgContainerParam", container_get_items(cont)?),
+        IpgContainers::IpgMenu(menu) => ("IpgMenuParam", menu_get_items(menu)?),
+        IpgContainers::IpgMouseArea(m_area) => ("IpgMouseAreaParam", mousearea_get_items(m_area)?),
+        IpgContainers::IpgOpaque(op) => ("IpgOpaqueParam", opaque_get_items(op)?),
+        IpgContainers::IpgRow(row) => ("IpgRowParam", row_get_items(row)?),
+        IpgContainers::IpgStack(stack) => ("IpgStackParam", stack_get_items(stack)?),
+        IpgContainers::IpgTable(table) => ("IpgTableParam", table_get_items(table)?),
+        IpgContainers::IpgScrollable(scroll) => ("IpgScrollableParam", scrollable_get_items(scroll)?),
+        IpgContainers::IpgToolTip(tool) => ("IpgToolTipParam", tooltip_get_items(tool)?),
+        IpgContainers::IpgWindow(wnd) => ("IpgWindowParam", window_get_items(wnd)?),
+        // the canvas values are kept in the canvas state
+        IpgContainers::IpgCanvas(_) => return Err(IpgParamError::new_err(
+                "get_item: the canvas can not be read back")),
+    };
+    Ok(items)
 }
 
 fn set_state_cont_wnd_ids(
@@ -6458,6 +8322,7 @@ fn set_state_cont_wnd_ids(
     wnd_id: &String, 
     cnt_str_id: String, 
     cnt_id: usize, name: String) 
+    -> PyResult<()>
 {
     state.container_str_ids.insert(cnt_str_id.clone(), cnt_id);
 
@@ -6465,18 +8330,25 @@ fn set_state_cont_wnd_ids(
 
     let wnd_id_usize = match wnd_id_usize_opt {
         Some(id) => *id,
-        None => panic!("{}: could not get window usize id", name),
+        None => return Err(IpgIdError::new_err(format!("{}: could not get window usize id", name))),
     };
 
     state.container_str_ids.insert(cnt_str_id, cnt_id);
 
     state.container_window_usize_ids.insert(cnt_id, wnd_id_usize);
+    Ok(())
 }
 
 
 #[pymodule]
-fn icedpygui(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
+fn icedpygui(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<IPG>()?;
+    m.add_class::<IpgBatch>()?;
+    m.add_class::<IpgEvent>()?;
+    m.add("IpgError", py.get_type::<IpgError>())?;
+    m.add("IpgIdError", py.get_type::<IpgIdError>())?;
+    m.add("IpgParamError", py.get_type::<IpgParamError>())?;
+    m.add("IpgCallbackError", py.get_type::<IpgCallbackError>())?;
     m.add_class::<IpgAlignment>()?;
     m.add_class::<IpgHorizontalAlignment>()?;
     m.add_class::<IpgVerticalAlignment>()?;
@@ -6489,9 +8361,12 @@ fn icedpygui(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<IpgCanvasWidget>()?;
     m.add_class::<IpgCardParam>()?;
     m.add_class::<IpgCardStyleParam>()?;
+    m.add_class::<IpgChartKind>()?;
+    m.add_class::<IpgChartParam>()?;
     m.add_class::<IpgCheckboxParam>()?;
     m.add_class::<IpgCheckboxStyleParam>()?;
     m.add_class::<IpgColor>()?;
+    m.add_class::<IpgColorMap>()?;
     m.add_class::<IpgColorPickerParam>()?;
     m.add_class::<IpgColorPickerStyleParam>()?;
     m.add_class::<IpgColumnParam>()?;
@@ -6502,6 +8377,8 @@ fn icedpygui(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<IpgDividerStyleParam>()?;
     m.add_class::<IpgImageContentFit>()?;
     m.add_class::<IpgImageFilterMethod>()?;
+    m.add_class::<IpgGaugeParam>()?;
+    m.add_class::<IpgHeatmapParam>()?;
     m.add_class::<IpgImageParam>()?;
     m.add_class::<IpgImageRotation>()?;
     m.add_class::<IpgMenuParam>()?;
@@ -6531,6 +8408,7 @@ fn icedpygui(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<IpgSliderParam>()?;
     m.add_class::<IpgSliderStyleParam>()?;
     m.add_class::<IpgStackParam>()?;
+    m.add_class::<IpgStreamPlotParam>()?;
     m.add_class::<IpgStyleStandard>()?;
     m.add_class::<IpgSvgParam>()?;
     m.add_class::<IpgTableParam>()?;
@@ -6554,21 +8432,24 @@ fn icedpygui(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+// The caller wakes up the gui once the item is inserted, the ids alone
+// would be moved over on the wake up without their container or widget.
 fn set_state_of_container(
     id: usize, 
     window_id: String, 
     container_id: Option<String>, 
     parent_id: String) 
+    -> PyResult<()>
 {
     let state = access_state();
 
     let wnd_id_usize = match state.windows_str_ids.get(&window_id) {
         Some(id) => *id,
-        None => panic!("The main window id could not be found using window_id {}", window_id)
+        None => return Err(IpgIdError::new_err(format!("The main window id could not be found using window_id {}", window_id)))
     };
     drop(state);
 
-    check_for_dup_container_ids(wnd_id_usize, container_id.clone());
+    check_for_dup_container_ids(wnd_id_usize, container_id.clone())?;
     
     let mut state = access_state();
 
@@ -6577,7 +8458,7 @@ fn set_state_of_container(
         None => None,
     };
     
-    let parent_uid = find_parent_uid(state.ids_ipd_ids.get(&wnd_id_usize).unwrap(), parent_id.clone());
+    let parent_uid = find_parent_uid(state.ids_ipd_ids.get(&wnd_id_usize).unwrap(), parent_id.clone())?;
     
     state.ids_ipd_ids.get_mut(&wnd_id_usize).unwrap().push(IpgIds{id, parent_uid, container_id,
                                                         parent_id, is_container: true});
@@ -6585,68 +8466,50 @@ fn set_state_of_container(
     state.container_ids.get_mut(&wnd_id_usize).unwrap().push(id);
 
     drop(state);
-
+    Ok(())
 }
 
 fn set_state_of_widget(
     id: usize,  
     parent_id: String)
+    -> PyResult<()>
 {
     let state = access_state();
 
     let wnd_id_str = match state.container_wnd_str_ids.get(&parent_id) {
         Some(id) => id.clone(),
-        None => panic!("The main window id could not be found using parent_id {}, check that your parent_id matches a container ", parent_id)
+        None => return Err(IpgIdError::new_err(format!("The main window id could not be found using parent_id {}, check that your parent_id matches a container ", parent_id)))
     };
 
     let wnd_id_usize = match state.windows_str_ids.get(&wnd_id_str) {
         Some(id) => *id,
-        None => panic!("window id {} could not be found in set_state_of_widget", wnd_id_str),
+        None => return Err(IpgIdError::new_err(format!("window id {} could not be found in set_state_of_widget", wnd_id_str))),
     };
 
     drop(state);
    
     let mut state = access_state();
 
-    let parent_uid = find_parent_uid(state.ids_ipd_ids.get(&wnd_id_usize).unwrap(), parent_id.clone());
+    let parent_uid = find_parent_uid(state.ids_ipd_ids.get(&wnd_id_usize).unwrap(), parent_id.clone())?;
     
     state.ids_ipd_ids.get_mut(&wnd_id_usize).unwrap().push(IpgIds{id, parent_uid, container_id: None,
                                                         parent_id, is_container: false});
 
     drop(state);
-}
-
-fn set_state_of_widget_running_state(
-    state: &mut IpgState,
-    id: usize,  
-    parent_id: String)
-{
-    let wnd_id_str = match state.container_wnd_str_ids.get(&parent_id) {
-        Some(id) => id.clone(),
-        None => panic!("The main window id could not be found using parent_id {}, check that your parent_id matches a container ", parent_id)
-    };
-
-    let wnd_id_usize = match state.windows_str_ids.get(&wnd_id_str) {
-        Some(id) => *id,
-        None => panic!("window id {} could not be found in set_state_of_widget", wnd_id_str),
-    };
-
-    let parent_uid = find_parent_uid(state.ids.get(&wnd_id_usize).unwrap(), parent_id.clone());
-    
-    state.ids.get_mut(&wnd_id_usize).unwrap().push(IpgIds{id, parent_uid, container_id: None,
-                                                        parent_id, is_container: false});
-
+    Ok(())
 }
 
 fn add_callback_to_mutex(
     id: usize, 
     event_name: String, 
     py_obj: PyObject, 
-    ) 
+    ) -> PyResult<()>
 {
+    let py_obj = wrap_async_callback(py_obj)?;
     let mut app_cbs = access_callbacks();
     app_cbs.callbacks.insert((id, event_name), py_obj);
     drop(app_cbs);
+    Ok(())
 }
 
 fn add_user_data_to_mutex(
@@ -6665,17 +8528,112 @@ fn add_user_data_to_mutex(
     drop(lock);
 }
 
+// Removes the item, or only its children, and everything below them from the mutex.
+// The container ids can then be used again before the session has caught up.
+// Returns true when the item was added while running and not moved over yet.
+fn remove_state_items(
+    window_id: &str, 
+    wid: usize, 
+    include_wid: bool) 
+    -> bool 
+{
+    let mut state = access_state();
+
+    let wnd_id = match state.windows_str_ids.get(window_id) {
+        Some(id) => *id,
+        None => return false,
+    };
+
+    let mut ids = match state.ids_ipd_ids.get(&wnd_id) {
+        Some(ipg_ids) => get_subtree_ids(ipg_ids, wid),
+        None => return false,
+    };
+    if !include_wid {
+        ids.retain(|id| *id != wid);
+    }
+
+    // a window is only closed, never deleted
+    if ids.contains(&wnd_id) {
+        return false
+    }
+
+    let pending = state.widgets.contains_key(&wid) || state.containers.contains_key(&wid);
+
+    if let Some(ipg_ids) = state.ids_ipd_ids.get_mut(&wnd_id) {
+        ipg_ids.retain(|ipg_id| !ids.contains(&ipg_id.id));
+    }
+    if let Some(container_ids) = state.container_ids.get_mut(&wnd_id) {
+        container_ids.retain(|id| !ids.contains(id));
+    }
+
+    let str_ids: Vec<String> = state.container_str_ids.iter()
+        .filter(|(_, id)| ids.contains(id))
+        .map(|(str_id, _)| str_id.clone())
+        .collect();
+    for str_id in str_ids.iter() {
+        state.container_str_ids.remove(str_id);
+        state.container_wnd_str_ids.remove(str_id);
+    }
+
+    for id in ids.iter() {
+        state.widgets.remove(id);
+        state.containers.remove(id);
+        state.container_window_usize_ids.remove(id);
+    }
+    drop(state);
+
+    if pending {
+        remove_callbacks_and_user_data(&ids);
+    }
+    pending
+}
+
+pub fn remove_callbacks_and_user_data(ids: &[usize]) {
+    let mut app_cbs = access_callbacks();
+    app_cbs.callbacks.retain(|(id, _), _| !ids.contains(id));
+    drop(app_cbs);
+
+    let mut ud1 = access_user_data1();
+    ud1.user_data.retain(|id, _| !ids.contains(id));
+    drop(ud1);
+
+    let mut ud2 = access_user_data2();
+    ud2.user_data.retain(|id, _| !ids.contains(id));
+    drop(ud2);
+
+    let mut bindings = access_bindings();
+    bindings.bindings.retain(|binding| !ids.contains(&binding.wid));
+    drop(bindings);
+}
+
+// The id followed by the ids of everything placed inside of it.
+pub fn get_subtree_ids(ipg_ids: &[IpgIds], id: usize) -> Vec<usize> {
+    let mut subtree = vec![id];
+    let mut index = 0;
+
+    while index < subtree.len() {
+        let parent_uid = subtree[index];
+        for ipg_id in ipg_ids.iter() {
+            if ipg_id.parent_uid == parent_uid && ipg_id.id != parent_uid {
+                subtree.push(ipg_id.id);
+            }
+        }
+        index += 1;
+    }
+    subtree
+}
+
 pub fn find_parent_uid(
     ipg_ids: &[IpgIds], 
     parent_id: String) 
-    -> usize 
+    -> PyResult<usize> 
 {
     for id in ipg_ids.iter() {
         if id.container_id == Some(parent_id.clone()) {
-            return id.id
+            return Ok(id.id)
         }
     }
-    panic!("Parent id {:?} not found in function find_parent_uid()", parent_id)
+    Err(IpgIdError::new_err(format!("Parent id {:?} not found in function find_parent_uid()", parent_id)))
 }
 
 pub fn get_id() -> usize {
@@ -6685,3 +8643,18 @@ pub fn get_id() -> usize {
     drop(state);
     id
 }
+
+
+#[test]
+fn test_get_subtree_ids() {
+    let ids = |id, parent_uid, container_id: Option<&str>| IpgIds{id, parent_uid, 
+                            container_id: container_id.map(|c| c.to_string()),
+                            parent_id: String::new(), is_container: container_id.is_some()};
+    // window 1 > column 2 > (button 3, row 4 > text 5), text 6
+    let ipg_ids = vec![ids(1, 0, Some("main")), ids(2, 1, Some("col")), ids(3, 2, None),
+                        ids(4, 2, Some("row")), ids(5, 4, None), ids(6, 1, None)];
+
+    assert_eq!(vec![2, 3, 4, 5], get_subtree_ids(&ipg_ids, 2));
+    assert_eq!(vec![4, 5], get_subtree_ids(&ipg_ids, 4));
+    assert_eq!(vec![6], get_subtree_ids(&ipg_ids, 6));
+}